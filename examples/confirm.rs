@@ -1,4 +1,6 @@
-use inquire::{ui::RenderConfig, Confirm};
+use inquire::{
+    key_bindings::KeyBindings, ui::RenderConfig, Confirm, ConfirmVocabulary, PromptSettings,
+};
 
 fn main() {
     let ans = Confirm::new("Do you live in Brazil?")
@@ -35,10 +37,24 @@ fn main() {
             true => String::from("sim"),
             false => String::from("não"),
         },
+        vocabulary: None,
         render_config: RenderConfig::default(),
+        settings: PromptSettings::default(),
+        key_bindings: KeyBindings::new(),
     }
     .prompt()
     .unwrap();
 
     println!("Your answer: {ans}");
+
+    let spanish = ConfirmVocabulary::new("Sí", "No", &["s", "sí", "si"], &["n", "no"]);
+
+    let ans = Confirm::new("¿Quieres continuar?")
+        .with_vocabulary(spanish)
+        .with_default(true)
+        .with_error_message("Respuesta inválida, escribe 's' para sí o 'n' para no")
+        .prompt()
+        .unwrap();
+
+    println!("Your answer: {ans}");
 }
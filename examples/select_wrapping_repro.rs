@@ -14,7 +14,9 @@ enum Scenario {
 impl Scenario {
     fn label(self) -> &'static str {
         match self {
-            Scenario::ManyLongItems => "Scenario 1: many long items (all wrap, list taller than terminal)",
+            Scenario::ManyLongItems => {
+                "Scenario 1: many long items (all wrap, list taller than terminal)"
+            }
             Scenario::MixedLongAndShortItems => {
                 "Scenario 2: many items, only a few are very long (mixed heights)"
             }
@@ -37,11 +39,7 @@ over multiple lines and the list is taller than the terminal.\n\
 Tip: shrink your terminal height to ~10-15 lines to make the bug obvious.\n"
     );
 
-    let scenario = Select::new(
-        "Select a repro scenario",
-        scenarios.clone(),
-    )
-    .prompt();
+    let scenario = Select::new("Select a repro scenario", scenarios.clone()).prompt();
 
     let scenario = match scenario {
         Ok(s) => s,
@@ -60,16 +58,11 @@ Tip: shrink your terminal height to ~10-15 lines to make the bug obvious.\n"
 fn run_many_long_items() {
     // Lots of items, each intentionally long enough to wrap in most terminals.
     // Use a large number so the list is generally taller than the terminal.
-    let options: Vec<String> = (1..=220)
-        .map(|i| long_item(i, 3))
-        .collect();
+    let options: Vec<String> = (1..=220).map(|i| long_item(i, 3)).collect();
 
-    let ans = Select::new(
-        "Scenario 1: choose an item",
-        options,
-    )
-    .with_page_size(PAGE_SIZE)
-    .prompt();
+    let ans = Select::new("Scenario 1: choose an item", options)
+        .with_page_size(PAGE_SIZE)
+        .prompt();
 
     match ans {
         Ok(choice) => println!("You chose: {choice}"),
@@ -80,9 +73,7 @@ fn run_many_long_items() {
 fn run_mixed_items() {
     // Many short items, with a few very long ones scattered that wrap to multiple lines.
     // Use a large number so the list is generally taller than the terminal.
-    let mut options: Vec<String> = (1..=200)
-        .map(|i| format!("item {i}"))
-        .collect();
+    let mut options: Vec<String> = (1..=200).map(|i| format!("item {i}")).collect();
 
     // Insert long items at a few positions (spread out across the list).
     for (idx, i) in [
@@ -97,12 +88,9 @@ fn run_mixed_items() {
         }
     }
 
-    let ans = Select::new(
-        "Scenario 2: choose an item",
-        options,
-    )
-    .with_page_size(PAGE_SIZE)
-    .prompt();
+    let ans = Select::new("Scenario 2: choose an item", options)
+        .with_page_size(PAGE_SIZE)
+        .prompt();
 
     match ans {
         Ok(choice) => println!("You chose: {choice}"),
@@ -121,5 +109,3 @@ this chunk is here to exceed terminal width"
         .collect::<Vec<_>>()
         .join(" | ")
 }
-
-
@@ -1,4 +1,7 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    error::CustomUserError, key_bindings::KeyBindings, length, required, ui::RenderConfig,
+    PromptSettings, Text,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -15,12 +18,21 @@ fn main() {
         initial_value: None,
         default: None,
         placeholder: Some("Good"),
+        rotating_placeholder: None,
         help_message: None,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
+        vim_mode: false,
         autocompleter: None,
+        suggestion_display: Text::DEFAULT_SUGGESTION_DISPLAY,
+        history: None,
+        counter: None,
+        input_filter: None,
+        input_transform: None,
         render_config: RenderConfig::default(),
+        settings: PromptSettings::default(),
+        key_bindings: KeyBindings::new(),
     }
     .prompt()
     .unwrap();
@@ -0,0 +1,153 @@
+//! Predetermined answers played back to prompts, for automated runs.
+//!
+//! An [AnswerSource] registered with
+//! [set_global_answer_source](crate::set_global_answer_source) answers the
+//! next prompts in order, one answer per prompt, as if the user typed them.
+//! Each prompt only renders its final answered line, and once the source is
+//! exhausted, prompts are displayed as usual.
+//!
+//! Answers go through the same parsing and validation as typed ones, failing
+//! with [InquireError::InvalidAnswer](crate::InquireError::InvalidAnswer)
+//! when rejected. Empty answers submit what the prompt submits when the user
+//! presses enter right away, such as its default value.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{answer_source::AnswerSource, Text};
+//!
+//! // e.g. replaying a recorded session, one answer per line
+//! inquire::set_global_answer_source(AnswerSource::from_file("answers.txt")?);
+//!
+//! // or answering all prompts with their defaults, for a `--yes` flag
+//! inquire::set_global_answer_source(AnswerSource::defaults());
+//!
+//! let name = Text::new("Project name:").with_default("demo").prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    iter,
+    path::Path,
+};
+
+use crate::{error::InquireResult, ui::PresetAnswer};
+
+type Answers = Box<dyn Iterator<Item = InquireResult<PresetAnswer>> + Send>;
+
+/// Sequence of predetermined answers consumed by prompts in order.
+pub struct AnswerSource {
+    answers: Answers,
+}
+
+impl AnswerSource {
+    /// Creates a source playing back the provided answers, e.g. a `Vec` of
+    /// strings. Options of [Select](crate::Select) prompts are chosen by their
+    /// text, and those of [MultiSelect](crate::MultiSelect) prompts by a
+    /// comma-separated list.
+    pub fn new<I>(answers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        I::IntoIter: Send + 'static,
+    {
+        let answers = answers
+            .into_iter()
+            .map(|answer| Ok(to_preset_answer(answer.into())));
+
+        Self {
+            answers: Box::new(answers),
+        }
+    }
+
+    /// Creates a source answering every prompt with what it submits when the
+    /// user presses enter right away, such as its default value. Useful to
+    /// implement `--yes` style flags.
+    pub fn defaults() -> Self {
+        Self {
+            answers: Box::new(iter::repeat_with(|| Ok(PresetAnswer::Default))),
+        }
+    }
+
+    /// Creates a source reading one answer per line from the reader, as they
+    /// are needed by prompts.
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: BufRead + Send + 'static,
+    {
+        let answers = reader.lines().map(|line| Ok(to_preset_answer(line?)));
+
+        Self {
+            answers: Box::new(answers),
+        }
+    }
+
+    /// Creates a source reading one answer per line from the file at the
+    /// provided path.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        Ok(Self::from_reader(BufReader::new(file)))
+    }
+
+    /// Returns the next answer of the source, or `None` when it is exhausted.
+    pub fn next_answer(&mut self) -> InquireResult<Option<PresetAnswer>> {
+        self.answers.next().transpose()
+    }
+}
+
+impl std::fmt::Debug for AnswerSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnswerSource").finish_non_exhaustive()
+    }
+}
+
+fn to_preset_answer(answer: String) -> PresetAnswer {
+    let answer = answer.trim_end_matches(['\r', '\n']);
+    match answer.is_empty() {
+        true => PresetAnswer::Default,
+        false => PresetAnswer::Text(answer.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::ui::PresetAnswer;
+
+    use super::AnswerSource;
+
+    #[test]
+    fn answers_are_played_back_in_order() {
+        let mut source = AnswerSource::new(vec!["Ana", "", "eu-west-1"]);
+
+        assert_eq!(
+            Some(PresetAnswer::Text("Ana".into())),
+            source.next_answer().unwrap()
+        );
+        assert_eq!(Some(PresetAnswer::Default), source.next_answer().unwrap());
+        assert_eq!(
+            Some(PresetAnswer::Text("eu-west-1".into())),
+            source.next_answer().unwrap()
+        );
+        assert_eq!(None, source.next_answer().unwrap());
+    }
+
+    #[test]
+    fn answers_are_read_line_by_line() {
+        let mut source = AnswerSource::from_reader(Cursor::new("y\r\n\n3, 4\n"));
+
+        assert_eq!(
+            Some(PresetAnswer::Text("y".into())),
+            source.next_answer().unwrap()
+        );
+        assert_eq!(Some(PresetAnswer::Default), source.next_answer().unwrap());
+        assert_eq!(
+            Some(PresetAnswer::Text("3, 4".into())),
+            source.next_answer().unwrap()
+        );
+        assert_eq!(None, source.next_answer().unwrap());
+    }
+}
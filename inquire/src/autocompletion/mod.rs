@@ -0,0 +1,403 @@
+//! Trait and structs used by prompts to provide autocompletion features.
+//!
+//! Autocompleters receive the user input to a given prompt and may return
+//! a list of suggestions, selectable by the user as an option to be completed.
+//!
+//! When the user presses the autocompletion hotkey (`tab` by default) the
+//! autocompleter receives the current text input and the currently highlighted
+//! selection, if any. Then the developer may return a [Replacement] action
+//! where the current user text input is replaced or not by a provided string.
+//!
+//! Completers can also be token-aware, working on the token under the cursor
+//! rather than on the whole input, e.g. to complete the arguments of a
+//! command line. See [`Autocomplete::get_completion_at`].
+//!
+//! File system paths can be completed with the built-in [`PathAutocomplete`].
+//!
+//! Suggestions that are slow to compute, e.g. fetched from the network, can
+//! be loaded in the background with [`AsyncAutocomplete`], keeping the prompt
+//! responsive while they are loading.
+//!
+//! Check the example files to see some usages, recommended are `expense_tracker.rs`
+//! and `complex_autocompletion.rs`.
+
+use std::{
+    fmt::Debug,
+    ops::Range,
+    sync::{
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc,
+    },
+    thread,
+};
+
+use dyn_clone::DynClone;
+
+use crate::CustomUserError;
+
+mod path;
+
+pub use path::PathAutocomplete;
+
+/// Used when an autocompletion is triggered for the user's text input.
+///
+/// `None` means that no completion will be made.
+/// `Some(String)` will replace the current text input with the `String` in `Some`.
+pub type Replacement = Option<String>;
+
+/// Replacement of a range of the user's text input, returned by token-aware
+/// autocompleters. See [`Autocomplete::get_completion_at`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeReplacement {
+    /// Byte range of the input to replace, e.g. the token under the cursor.
+    pub range: Range<usize>,
+
+    /// Text inserted in place of the range. The cursor is placed right after it.
+    pub value: String,
+}
+
+impl RangeReplacement {
+    /// Creates a replacement of the given byte range of the input.
+    pub fn new<S: Into<String>>(range: Range<usize>, value: S) -> Self {
+        Self {
+            range,
+            value: value.into(),
+        }
+    }
+
+    /// Applies the replacement to the input, returning the new input and the
+    /// byte offset of the end of the inserted text, or `None` if the range is
+    /// out of bounds or not on character boundaries.
+    pub fn apply(&self, input: &str) -> Option<(String, usize)> {
+        if self.range.start > self.range.end {
+            return None;
+        }
+
+        let before = input.get(..self.range.start)?;
+        let after = input.get(self.range.end..)?;
+
+        let cursor = before.len() + self.value.len();
+        Some((format!("{before}{}{after}", self.value), cursor))
+    }
+}
+
+/// Byte range of the whitespace-delimited token under the cursor, which is a
+/// byte offset in the input. The range is empty when the cursor is not
+/// touching any token.
+///
+/// This is the range that command-line-style completers usually replace.
+///
+/// # Example
+///
+/// ```
+/// use inquire::autocompletion::token_range;
+///
+/// assert_eq!(4..7, token_range("git che", 7));
+/// assert_eq!(0..3, token_range("git che", 1));
+/// assert_eq!(8..8, token_range("git che ", 8));
+/// ```
+pub fn token_range(input: &str, cursor: usize) -> Range<usize> {
+    let cursor = cursor.min(input.len());
+    let (before, after) = match (input.get(..cursor), input.get(cursor..)) {
+        (Some(before), Some(after)) => (before, after),
+        _ => return cursor..cursor,
+    };
+
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(0);
+    let end = after
+        .find(char::is_whitespace)
+        .map(|i| cursor + i)
+        .unwrap_or(input.len());
+
+    start..end
+}
+
+/// Mechanism to implement autocompletion features for text inputs. The `Autocomplete` trait has two provided methods: `get_suggestions` and `get_completion`.
+///
+/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
+/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
+///
+/// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
+///
+/// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
+pub trait Autocomplete: DynClone {
+    /// List of input suggestions to be displayed to the user upon typing the
+    /// text input.
+    ///
+    /// If the user presses the autocompletion hotkey (`tab` as default) with
+    /// a suggestion highlighted, the user's text input will be replaced by the
+    /// content of the suggestion string.
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError>;
+
+    /// Standalone autocompletion that can be implemented based solely on the user's
+    /// input.
+    ///
+    /// If the user presses the autocompletion hotkey (`tab` as default) and
+    /// there are no suggestions highlighted (1), this function will be called in an
+    /// attempt to autocomplete the user's input.
+    ///
+    /// If the returned value is of the `Some` variant, the text input will be replaced
+    /// by the content of the string.
+    ///
+    /// (1) This applies where either there are no suggestions at all, or there are
+    /// some displayed but the user hasn't highlighted any.
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError>;
+
+    /// Token-aware variant of `get_suggestions`, which also receives the
+    /// position of the cursor as a byte offset in the input.
+    ///
+    /// This is the method called by prompts. By default, it ignores the cursor
+    /// and calls `get_suggestions`.
+    fn get_suggestions_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+    ) -> Result<Vec<String>, CustomUserError> {
+        let _ = cursor;
+        self.get_suggestions(input)
+    }
+
+    /// Token-aware variant of `get_completion`, which also receives the
+    /// position of the cursor as a byte offset in the input, and returns the
+    /// range of the input to replace instead of the whole input.
+    ///
+    /// This is the method called by prompts, both when the autocompletion
+    /// hotkey is pressed and when the user submits the prompt while a
+    /// suggestion is highlighted. By default, it ignores the cursor and
+    /// replaces the whole input with the result of `get_completion`.
+    ///
+    /// Completers of command lines can use [`token_range`] to complete only
+    /// the argument under the cursor, suggesting values for that argument in
+    /// `get_suggestions_at`.
+    fn get_completion_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Option<RangeReplacement>, CustomUserError> {
+        let _ = cursor;
+        let replacement = self.get_completion(input, highlighted_suggestion)?;
+
+        Ok(replacement.map(|value| RangeReplacement::new(0..input.len(), value)))
+    }
+
+    /// Whether the suggestions for the latest input passed to `get_suggestions`
+    /// are still being computed.
+    ///
+    /// While this returns `true`, the prompt displays a loading indicator in
+    /// place of the suggestions and periodically calls `poll_suggestions`.
+    fn is_loading(&self) -> bool {
+        false
+    }
+
+    /// Returns the suggestions for the latest input passed to `get_suggestions`
+    /// if they became available since the last call, replacing the ones
+    /// currently displayed.
+    fn poll_suggestions(&mut self) -> Result<Option<Vec<String>>, CustomUserError> {
+        Ok(None)
+    }
+}
+
+impl Clone for Box<dyn Autocomplete> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Empty struct and implementation of Autocomplete trait. Used for the default
+/// autocompleter of `Text` prompts.
+#[derive(Clone, Default)]
+pub struct NoAutoCompletion;
+
+impl Autocomplete for NoAutoCompletion {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(vec![])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        _: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(Replacement::None)
+    }
+}
+
+impl<F> Autocomplete for F
+where
+    F: Fn(&str) -> Result<Vec<String>, CustomUserError> + Clone,
+{
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        (self)(input)
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(suggestion)
+    }
+}
+
+/// Thread-safe function computing suggestions, run by [`AsyncAutocomplete`].
+pub type SuggestionFetcher =
+    Arc<dyn Fn(&str) -> Result<Vec<String>, CustomUserError> + Send + Sync>;
+
+type Request = (u64, String);
+type Response = (u64, Result<Vec<String>, CustomUserError>);
+
+/// Autocompleter computing suggestions on a worker thread.
+///
+/// Every time the input changes, it is sent to the worker and the prompt
+/// displays a loading indicator until the suggestions arrive, while the user
+/// keeps typing. Suggestions for inputs that have since changed are discarded.
+///
+/// Pressing the autocompletion hotkey replaces the input with the highlighted
+/// suggestion, if any.
+///
+/// Displaying the suggestions as soon as they arrive requires an input reader
+/// that supports waiting for key events with a timeout, which is the case of
+/// the crossterm backend. With other backends, they are displayed on the next
+/// key press.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::AsyncAutocomplete, Text};
+///
+/// let packages = AsyncAutocomplete::new(|input| {
+///     // e.g. query a package registry
+///     Ok(vec![format!("{input}-core"), format!("{input}-derive")])
+/// });
+///
+/// let package = Text::new("Package:").with_autocomplete(packages).prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct AsyncAutocomplete {
+    fetcher: SuggestionFetcher,
+    worker: Option<(Sender<Request>, Receiver<Response>)>,
+    generation: u64,
+    loading: bool,
+}
+
+impl AsyncAutocomplete {
+    /// Creates an autocompleter running the provided function on a worker thread.
+    pub fn new<F>(fetcher: F) -> Self
+    where
+        F: Fn(&str) -> Result<Vec<String>, CustomUserError> + Send + Sync + 'static,
+    {
+        Self {
+            fetcher: Arc::new(fetcher),
+            worker: None,
+            generation: 0,
+            loading: false,
+        }
+    }
+
+    /// Spawns the worker thread, which exits once the autocompleter is dropped.
+    fn spawn_worker(&self) -> (Sender<Request>, Receiver<Response>) {
+        let (requests, request_rx) = mpsc::channel::<Request>();
+        let (response_tx, responses) = mpsc::channel::<Response>();
+        let fetcher = self.fetcher.clone();
+
+        thread::spawn(move || {
+            while let Ok(mut request) = request_rx.recv() {
+                // Only the latest queued input is relevant.
+                while let Ok(next) = request_rx.try_recv() {
+                    request = next;
+                }
+
+                let (generation, input) = request;
+                if response_tx.send((generation, fetcher(&input))).is_err() {
+                    break;
+                }
+            }
+        });
+
+        (requests, responses)
+    }
+}
+
+impl Clone for AsyncAutocomplete {
+    /// Clones get their own worker thread, spawned on first use.
+    fn clone(&self) -> Self {
+        Self {
+            fetcher: self.fetcher.clone(),
+            worker: None,
+            generation: 0,
+            loading: false,
+        }
+    }
+}
+
+impl Debug for AsyncAutocomplete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncAutocomplete")
+            .field("loading", &self.loading)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Autocomplete for AsyncAutocomplete {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        if self.worker.is_none() {
+            self.worker = Some(self.spawn_worker());
+        }
+
+        self.generation += 1;
+        self.loading = match &self.worker {
+            Some((requests, _)) => requests.send((self.generation, input.to_owned())).is_ok(),
+            None => false,
+        };
+
+        Ok(vec![])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(highlighted_suggestion)
+    }
+
+    fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    fn poll_suggestions(&mut self) -> Result<Option<Vec<String>>, CustomUserError> {
+        let responses = match &self.worker {
+            Some((_, responses)) => responses,
+            None => return Ok(None),
+        };
+
+        let mut latest = None;
+        loop {
+            match responses.try_recv() {
+                Ok((generation, suggestions)) if generation == self.generation => {
+                    self.loading = false;
+                    latest = Some(suggestions);
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // The fetcher panicked, there is nothing left to wait for.
+                    self.loading = false;
+                    break;
+                }
+            }
+        }
+
+        latest.transpose()
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    fs,
+    path::{is_separator, Path, PathBuf, MAIN_SEPARATOR},
+};
+
+use crate::CustomUserError;
+
+use super::{Autocomplete, Replacement};
+
+/// Autocompleter suggesting file system paths, similar to the completion of
+/// paths in shells.
+///
+/// Suggestions are the entries of the directory typed so far whose names
+/// start with the last component of the input. Directories are suggested with
+/// a trailing separator, so that accepting one lets the user continue typing
+/// inside of it. A leading `~` is expanded to the home directory when looking
+/// up entries, while the suggestions keep the `~` typed by the user.
+///
+/// When no suggestion is highlighted, the autocompletion hotkey completes the
+/// input up to the longest prefix shared by all suggestions.
+///
+/// Hidden entries, whose names start with a dot, are only suggested once the
+/// user types a dot, unless enabled with [`PathAutocomplete::with_hidden_files`].
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{autocompletion::PathAutocomplete, Text};
+///
+/// let output_dir = Text::new("Output directory:")
+///     .with_autocomplete(PathAutocomplete::new().with_directories_only(true))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PathAutocomplete {
+    show_hidden: bool,
+    directories_only: bool,
+    cache: Option<(PathBuf, Vec<Entry>)>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    name: String,
+    is_dir: bool,
+}
+
+impl PathAutocomplete {
+    /// Creates a path autocompleter suggesting files and directories,
+    /// excluding hidden ones.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether hidden entries are suggested even when the user hasn't
+    /// typed a leading dot.
+    pub fn with_hidden_files(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Sets whether only directories are suggested.
+    pub fn with_directories_only(mut self, directories_only: bool) -> Self {
+        self.directories_only = directories_only;
+        self
+    }
+
+    /// Entries of the provided directory, read again only when the user
+    /// moves to another directory.
+    fn entries(&mut self, dir: PathBuf) -> &[Entry] {
+        let is_cached = matches!(&self.cache, Some((cached, _)) if *cached == dir);
+        if !is_cached {
+            let entries = read_entries(&dir);
+            self.cache = Some((dir, entries));
+        }
+
+        self.cache
+            .as_ref()
+            .map(|(_, entries)| entries.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn suggestions(&mut self, input: &str) -> Vec<String> {
+        if input == "~" && home_dir().is_some() {
+            return vec![format!("~{MAIN_SEPARATOR}")];
+        }
+
+        let split = input.rfind(is_separator).map(|i| i + 1).unwrap_or(0);
+        let (dir_part, fragment) = input.split_at(split);
+
+        let dir = match dir_part {
+            "" => PathBuf::from("."),
+            dir_part => expand_tilde(dir_part, home_dir()),
+        };
+
+        let show_hidden = self.show_hidden || fragment.starts_with('.');
+        let directories_only = self.directories_only;
+
+        self.entries(dir)
+            .iter()
+            .filter(|entry| entry.name.starts_with(fragment))
+            .filter(|entry| show_hidden || !entry.name.starts_with('.'))
+            .filter(|entry| entry.is_dir || !directories_only)
+            .map(|entry| match entry.is_dir {
+                true => format!("{dir_part}{}{MAIN_SEPARATOR}", entry.name),
+                false => format!("{dir_part}{}", entry.name),
+            })
+            .collect()
+    }
+}
+
+impl Autocomplete for PathAutocomplete {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.suggestions(input))
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion);
+        }
+
+        let suggestions = self.suggestions(input);
+        let prefix = longest_common_prefix(&suggestions);
+
+        Ok((prefix.len() > input.len()).then(|| prefix.to_owned()))
+    }
+}
+
+/// Reads the entries of a directory, sorted by name. Directories that can't
+/// be read, e.g. because they don't exist yet, have no entries.
+fn read_entries(dir: &Path) -> Vec<Entry> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut entries = read_dir
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let is_dir = entry.path().is_dir();
+            let name = entry.file_name().into_string().ok()?;
+            Some(Entry { name, is_dir })
+        })
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    std::env::var_os(var)
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Replaces a leading `~` component with the home directory, if known.
+fn expand_tilde(path: &str, home: Option<PathBuf>) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(is_separator) => rest,
+        _ => return PathBuf::from(path),
+    };
+
+    match home {
+        Some(home) => home.join(rest.trim_start_matches(is_separator)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn longest_common_prefix(values: &[String]) -> &str {
+    let Some((first, rest)) = values.split_first() else {
+        return "";
+    };
+
+    let mut len = first.len();
+    for value in rest {
+        len = first
+            .char_indices()
+            .zip(value.chars())
+            .take_while(|((_, a), b)| a == b)
+            .map(|((i, a), _)| i + a.len_utf8())
+            .last()
+            .unwrap_or(0)
+            .min(len);
+    }
+
+    first.get(..len).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::{expand_tilde, PathAutocomplete, MAIN_SEPARATOR};
+    use crate::Autocomplete;
+
+    fn temp_tree(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("inquire-path-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("alps")).unwrap();
+        fs::create_dir_all(root.join(".config")).unwrap();
+        fs::write(root.join("alpha.txt"), "").unwrap();
+        fs::write(root.join("beta.txt"), "").unwrap();
+
+        root
+    }
+
+    #[test]
+    fn suggests_entries_matching_last_component() {
+        let root = temp_tree("suggestions");
+        let prefix = format!("{}{MAIN_SEPARATOR}", root.display());
+        let mut ac = PathAutocomplete::new();
+
+        assert_eq!(
+            vec![
+                format!("{prefix}alpha.txt"),
+                format!("{prefix}alps{MAIN_SEPARATOR}")
+            ],
+            ac.get_suggestions(&format!("{prefix}al")).unwrap()
+        );
+        assert_eq!(
+            Some(format!("{prefix}alp")),
+            ac.get_completion(&format!("{prefix}al"), None).unwrap()
+        );
+        assert_eq!(
+            vec![format!("{prefix}.config{MAIN_SEPARATOR}")],
+            ac.get_suggestions(&format!("{prefix}.")).unwrap()
+        );
+        assert_eq!(3, ac.get_suggestions(&prefix).unwrap().len());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn hidden_files_and_directories_only_modes() {
+        let root = temp_tree("modes");
+        let prefix = format!("{}{MAIN_SEPARATOR}", root.display());
+
+        let mut ac = PathAutocomplete::new()
+            .with_hidden_files(true)
+            .with_directories_only(true);
+
+        assert_eq!(
+            vec![
+                format!("{prefix}.config{MAIN_SEPARATOR}"),
+                format!("{prefix}alps{MAIN_SEPARATOR}"),
+            ],
+            ac.get_suggestions(&prefix).unwrap()
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn tilde_is_expanded_to_home_directory() {
+        let home = Some(PathBuf::from("/home/user"));
+
+        assert_eq!(
+            PathBuf::from("/home/user/docs/"),
+            expand_tilde("~/docs/", home.clone())
+        );
+        assert_eq!(
+            PathBuf::from("/home/user/"),
+            expand_tilde("~/", home.clone())
+        );
+        assert_eq!(PathBuf::from("~user/"), expand_tilde("~user/", home));
+        assert_eq!(PathBuf::from("~/docs/"), expand_tilde("~/docs/", None));
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Interval at which prompts waiting for key events check whether their
+/// [CancellationToken] was canceled.
+pub(crate) const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Handle to cancel in-flight prompts from another thread or task, e.g. when
+/// a background job finishes or a shutdown signal arrives.
+///
+/// Clones of a token share the same state, so one clone can be passed to
+/// prompts with `with_cancellation`, e.g. [Text::with_cancellation](crate::Text::with_cancellation),
+/// while another one is kept to call [cancel](CancellationToken::cancel).
+/// Canceled prompts are rendered as if the user canceled them and return
+/// [InquireError::OperationCanceled](crate::InquireError::OperationCanceled).
+///
+/// Once canceled, a token stays canceled, and prompts using it are canceled
+/// as soon as they start.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{thread, time::Duration};
+///
+/// use inquire::{CancellationToken, Text};
+///
+/// let token = CancellationToken::new();
+///
+/// let timeout = token.clone();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(30));
+///     timeout.cancel();
+/// });
+///
+/// let name = Text::new("What's your name?")
+///     .with_cancellation(token)
+///     .prompt_skippable()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    canceled: AtomicBool,
+    #[cfg(feature = "async")]
+    wakers: std::sync::Mutex<Vec<std::task::Waker>>,
+}
+
+impl CancellationToken {
+    /// Creates a token that is not canceled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the prompts using this token, or any clone of it.
+    pub fn cancel(&self) {
+        self.inner.canceled.store(true, Ordering::SeqCst);
+
+        #[cfg(feature = "async")]
+        {
+            let wakers = std::mem::take(&mut *self.inner.wakers.lock().unwrap());
+            for waker in wakers {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns whether the token was canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.inner.canceled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the token is canceled, waking the task when it happens.
+    #[cfg(feature = "async")]
+    pub(crate) fn poll_canceled(&self, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        if self.is_canceled() {
+            return std::task::Poll::Ready(());
+        }
+
+        let mut wakers = self.inner.wakers.lock().unwrap();
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        drop(wakers);
+
+        // the token might have been canceled before the waker was registered
+        match self.is_canceled() {
+            true => std::task::Poll::Ready(()),
+            false => std::task::Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::InquireError,
+        ui::{test::FakeBackend, Key},
+        validator::Validation,
+        Text,
+    };
+
+    use super::CancellationToken;
+
+    #[test]
+    fn canceled_token_cancels_prompt_before_reading_keys() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let mut backend = FakeBackend::new(vec![]);
+        backend.cancellation = Some(token);
+        let answer = Text::new("Name:").prompt_with_backend(&mut backend);
+
+        assert!(matches!(answer, Err(InquireError::OperationCanceled)));
+        assert_eq!(1, backend.frames().len());
+    }
+
+    #[test]
+    fn token_canceled_while_prompting_cancels_prompt() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        let mut keys = Key::char_keys_from_str("Ana");
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+        backend.cancellation = Some(token);
+        let answer = Text::new("Name:")
+            .with_validator(move |_: &str| {
+                clone.cancel();
+                Ok(Validation::Invalid("Try again later".into()))
+            })
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(answer, Err(InquireError::OperationCanceled)));
+    }
+}
@@ -1,8 +1,16 @@
 //! Global config definitions.
 
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use crate::ui::RenderConfig;
+use crate::{
+    answer_source::AnswerSource,
+    error::InquireResult,
+    feedback::FeedbackHook,
+    key_bindings::GlobalKeyBindings,
+    terminal::CustomBackend,
+    transcript::{Transcript, TranscriptEntry},
+    ui::{PresetAnswer, RenderConfig},
+};
 use std::sync::LazyLock;
 
 static GLOBAL_RENDER_CONFIGURATION: LazyLock<Mutex<RenderConfig<'static>>> =
@@ -19,8 +27,215 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+static GLOBAL_FEEDBACK_HOOK: LazyLock<Mutex<Option<Arc<dyn FeedbackHook>>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+pub fn get_feedback_hook() -> Option<Arc<dyn FeedbackHook>> {
+    GLOBAL_FEEDBACK_HOOK.lock().unwrap().clone()
+}
+
+/// Acquires a write lock to the global feedback hook and replaces it
+/// with the provided one, which is then invoked by all prompts on
+/// [FeedbackEvent](crate::feedback::FeedbackEvent)s.
+pub fn set_global_feedback_hook<H>(hook: H)
+where
+    H: FeedbackHook + 'static,
+{
+    let mut guard = GLOBAL_FEEDBACK_HOOK.lock().unwrap();
+    *guard = Some(Arc::new(hook));
+}
+
+/// Removes the global feedback hook, if any.
+pub fn clear_global_feedback_hook() {
+    let mut guard = GLOBAL_FEEDBACK_HOOK.lock().unwrap();
+    *guard = None;
+}
+
+/// What prompts do when they can't be displayed because stdin or the
+/// terminal output is not a TTY, e.g. when running in a script or in CI.
+///
+/// It is set for all prompts with [set_global_non_interactive_behavior] and
+/// can be overridden per prompt, e.g. with
+/// [Text::with_non_interactive_behavior](crate::Text::with_non_interactive_behavior).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NonInteractiveBehavior {
+    /// Fail with [InquireError::NotTTY](crate::InquireError::NotTTY).
+    #[default]
+    Fail,
+
+    /// Return the answer the prompt submits when the user presses enter
+    /// right away, such as its default value, failing with
+    /// [InquireError::InvalidAnswer](crate::InquireError::InvalidAnswer)
+    /// when the prompt rejects it.
+    UseDefault,
+
+    /// Read one line from stdin and submit it as if typed by the user,
+    /// failing with [InquireError::InvalidAnswer](crate::InquireError::InvalidAnswer)
+    /// when stdin is exhausted or the prompt rejects the answer.
+    ReadLine,
+}
+
+static GLOBAL_NON_INTERACTIVE_BEHAVIOR: Mutex<NonInteractiveBehavior> =
+    Mutex::new(NonInteractiveBehavior::Fail);
+
+pub fn get_non_interactive_behavior() -> NonInteractiveBehavior {
+    *GLOBAL_NON_INTERACTIVE_BEHAVIOR.lock().unwrap()
+}
+
+/// Acquires a write lock to the global non-interactive behavior and updates
+/// it with the provided one, which is then used by all prompts that don't
+/// override it.
+pub fn set_global_non_interactive_behavior(behavior: NonInteractiveBehavior) {
+    let mut guard = GLOBAL_NON_INTERACTIVE_BEHAVIOR.lock().unwrap();
+    *guard = behavior;
+}
+
+/// Standard stream prompts are rendered to.
+///
+/// Rendering to stderr, the default, keeps stdout free for the output of the
+/// application, e.g. JSON piped to another program, while the user still
+/// answers prompts interactively. Prompts are considered interactive when
+/// stdin and the selected stream are TTYs.
+///
+/// It is set for all prompts with [set_global_output_stream] and can be
+/// overridden per prompt, e.g. with
+/// [Text::with_output_stream](crate::Text::with_output_stream). To render
+/// prompts to any other output, use a [CustomBackend] instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OutputStream {
+    /// Standard error.
+    #[default]
+    Stderr,
+
+    /// Standard output, e.g. for applications logging to stderr.
+    Stdout,
+}
+
+static GLOBAL_OUTPUT_STREAM: Mutex<OutputStream> = Mutex::new(OutputStream::Stderr);
+
+pub fn get_output_stream() -> OutputStream {
+    *GLOBAL_OUTPUT_STREAM.lock().unwrap()
+}
+
+/// Acquires a write lock to the global output stream and updates it with the
+/// provided one, which is then used by all prompts that don't override it.
+pub fn set_global_output_stream(stream: OutputStream) {
+    let mut guard = GLOBAL_OUTPUT_STREAM.lock().unwrap();
+    *guard = stream;
+}
+
+static GLOBAL_ANSWER_SOURCE: Mutex<Option<AnswerSource>> = Mutex::new(None);
+
+/// Takes the next answer of the global answer source, if any.
+pub fn next_global_answer() -> InquireResult<Option<PresetAnswer>> {
+    let mut guard = GLOBAL_ANSWER_SOURCE.lock().unwrap();
+    match guard.as_mut() {
+        Some(source) => source.next_answer(),
+        None => Ok(None),
+    }
+}
+
+/// Acquires a write lock to the global answer source and replaces it with
+/// the provided one, whose answers are then consumed by the next prompts.
+pub fn set_global_answer_source(source: AnswerSource) {
+    let mut guard = GLOBAL_ANSWER_SOURCE.lock().unwrap();
+    *guard = Some(source);
+}
+
+/// Removes the global answer source, if any, so that prompts are answered
+/// by the user again.
+pub fn clear_global_answer_source() {
+    let mut guard = GLOBAL_ANSWER_SOURCE.lock().unwrap();
+    *guard = None;
+}
+
+static GLOBAL_BACKEND: Mutex<Option<CustomBackend>> = Mutex::new(None);
+
+pub fn get_global_backend() -> Option<CustomBackend> {
+    GLOBAL_BACKEND.lock().unwrap().clone()
+}
+
+/// Acquires a write lock to the global backend and replaces it with the
+/// provided one, which is then used by all prompts that don't set their own
+/// instead of the default terminal.
+pub fn set_global_backend(backend: CustomBackend) {
+    let mut guard = GLOBAL_BACKEND.lock().unwrap();
+    *guard = Some(backend);
+}
+
+/// Removes the global backend, if any, so that prompts are rendered to the
+/// default terminal again.
+pub fn clear_global_backend() {
+    let mut guard = GLOBAL_BACKEND.lock().unwrap();
+    *guard = None;
+}
+
+static GLOBAL_TRANSCRIPT: Mutex<Option<Transcript>> = Mutex::new(None);
+
+/// Adds the entry built by the provided function to the global transcript,
+/// if it is being recorded.
+pub fn record_transcript_entry<F>(entry: F)
+where
+    F: FnOnce() -> TranscriptEntry,
+{
+    let mut guard = GLOBAL_TRANSCRIPT.lock().unwrap();
+    if let Some(transcript) = guard.as_mut() {
+        transcript.entries.push(entry());
+    }
+}
+
+/// Starts recording the prompts finished from now on into a global
+/// [Transcript], discarding any transcript being recorded.
+pub fn start_global_transcript() {
+    let mut guard = GLOBAL_TRANSCRIPT.lock().unwrap();
+    *guard = Some(Transcript::default());
+}
+
+/// Stops recording the global transcript and returns it, or `None` if it
+/// wasn't being recorded.
+pub fn take_global_transcript() -> Option<Transcript> {
+    let mut guard = GLOBAL_TRANSCRIPT.lock().unwrap();
+    guard.take()
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
 /// Default value of vim mode.
 pub const DEFAULT_VIM_MODE: bool = false;
+
+static GLOBAL_KEY_BINDINGS: Mutex<Option<GlobalKeyBindings>> = Mutex::new(None);
+
+/// Calls the function with the global key bindings, if they are set.
+pub fn read_global_key_bindings<R>(f: impl FnOnce(&GlobalKeyBindings) -> R) -> Option<R> {
+    let guard = GLOBAL_KEY_BINDINGS.lock().unwrap();
+    guard.as_ref().map(f)
+}
+
+/// Acquires a write lock to the global key bindings and replaces them with
+/// the provided ones, which then override the default bindings of all
+/// prompts, unless the prompt binds the same key itself.
+pub fn set_global_key_bindings(bindings: GlobalKeyBindings) {
+    let mut guard = GLOBAL_KEY_BINDINGS.lock().unwrap();
+    *guard = Some(bindings);
+}
+
+static GLOBAL_VIM_MODE: Mutex<bool> = Mutex::new(DEFAULT_VIM_MODE);
+
+pub fn get_vim_mode() -> bool {
+    *GLOBAL_VIM_MODE.lock().unwrap()
+}
+
+/// Acquires a write lock to the global vim mode and updates it, enabling or
+/// disabling vim mode in all [Text](crate::Text), [Select](crate::Select) and
+/// [MultiSelect](crate::MultiSelect) prompts created afterwards that don't
+/// override it.
+///
+/// Text inputs start in insert mode, escape switching to normal mode and
+/// cancelling the prompt when pressed in normal mode. Lists move the cursor
+/// with `j` and `k`, see [Select::with_vim_filter_mode](crate::Select::with_vim_filter_mode)
+/// for modal keybindings in lists.
+pub fn set_global_vim_mode(enabled: bool) {
+    let mut guard = GLOBAL_VIM_MODE.lock().unwrap();
+    *guard = enabled;
+}
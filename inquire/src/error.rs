@@ -32,6 +32,11 @@ pub enum InquireError {
     /// pressing Ctrl+C will trigger SIGINT.
     OperationInterrupted,
 
+    /// An answer submitted without user interaction, e.g. read from stdin
+    /// when it is not a TTY, was rejected by the prompt. The reason is
+    /// contained in the value string.
+    InvalidAnswer(String),
+
     /// Error while executing IO operations.
     Custom(CustomUserError),
 }
@@ -84,6 +89,7 @@ impl fmt::Display for InquireError {
             InquireError::OperationInterrupted => {
                 f.write_str("Operation was interrupted by the user")
             }
+            InquireError::InvalidAnswer(s) => write!(f, "The answer is invalid: {}", s),
             InquireError::Custom(err) => write!(f, "User-provided error: {}", err),
         }
     }
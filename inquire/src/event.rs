@@ -0,0 +1,138 @@
+//! Events notifying applications of the changes made by the user while a
+//! prompt is active.
+//!
+//! A hook set with `with_on_change`, e.g. [Select::with_on_change], is called
+//! with a [PromptEvent] after each key press changing the text typed by the
+//! user, the highlighted option or the checked ones. This is useful to log
+//! interactions or to update a preview displayed outside of the prompt.
+//!
+//! The hook is called synchronously from the prompt loop, before the prompt
+//! is rendered again, so it should return quickly.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{event::PromptEvent, Select};
+//!
+//! let themes = vec!["light", "dark", "solarized"];
+//!
+//! let theme = Select::new("Theme:", themes.clone())
+//!     .with_on_change(&|event| {
+//!         if let PromptEvent::HighlightChanged(index) = event {
+//!             eprintln!("previewing the {} theme", themes[*index]);
+//!         }
+//!     })
+//!     .prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! [Select::with_on_change]: crate::Select::with_on_change
+
+/// Change made by the user to the state of a prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PromptEvent {
+    /// The text typed by the user changed, e.g. the input of a
+    /// [Text](crate::Text) prompt, the filter of a [Select](crate::Select)
+    /// prompt or the text written in the editor of an `Editor` prompt. Holds
+    /// the new content.
+    ///
+    /// It is not emitted by [Password](crate::Password) prompts, so that the
+    /// hook never receives the password.
+    InputChanged(String),
+
+    /// Another option was highlighted. Holds its index in the list of
+    /// options given to the prompt.
+    HighlightChanged(usize),
+
+    /// Options were checked or unchecked in a
+    /// [MultiSelect](crate::MultiSelect) prompt. Holds the indices of the
+    /// checked options, in ascending order.
+    SelectionChanged(Vec<usize>),
+
+    /// Another date was highlighted in a [DateSelect](crate::DateSelect)
+    /// prompt.
+    #[cfg(feature = "date")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "date")))]
+    DateChanged(chrono::NaiveDate),
+}
+
+/// Type alias for hooks called with the changes made by the user to the
+/// state of a prompt.
+pub type OnChange<'a> = &'a dyn Fn(&PromptEvent);
+
+/// Calls the hook with the events of the current state of a prompt that were
+/// not part of its previous state.
+pub(crate) fn notify(on_change: OnChange<'_>, previous: &[PromptEvent], current: &[PromptEvent]) {
+    for event in current {
+        if !previous.contains(event) {
+            on_change(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use crate::{
+        test::fake_backend,
+        ui::{Key, KeyModifiers},
+        MultiSelect,
+    };
+
+    use super::PromptEvent;
+
+    #[test]
+    fn hook_is_called_with_changes_made_by_keys() {
+        let events = RefCell::new(vec![]);
+        let on_change = |event: &PromptEvent| events.borrow_mut().push(event.clone());
+
+        let mut backend = fake_backend(vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Char('3', KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+        let prompt = MultiSelect::new("Question", vec![1, 2, 3]).with_on_change(&on_change);
+        backend.apply_settings(prompt.settings.clone());
+
+        let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!(1, ans.len());
+        assert_eq!(
+            vec![
+                PromptEvent::HighlightChanged(1),
+                PromptEvent::SelectionChanged(vec![1]),
+                PromptEvent::InputChanged("3".into()),
+                PromptEvent::HighlightChanged(2),
+            ],
+            events.into_inner()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "editor", unix))]
+    fn hook_is_called_with_text_written_in_the_editor() {
+        use std::ffi::OsStr;
+
+        let events = RefCell::new(vec![]);
+        let on_change = |event: &PromptEvent| events.borrow_mut().push(event.clone());
+        let args = [OsStr::new("-c"), OsStr::new("printf 'Hello' > \"$0\"")];
+
+        let mut backend = fake_backend(vec![Key::Char('e', KeyModifiers::NONE), Key::Enter]);
+        let prompt = crate::Editor::new("Question")
+            .with_editor_command(OsStr::new("sh"))
+            .with_args(&args)
+            .with_on_change(&on_change);
+        backend.apply_settings(prompt.settings.clone());
+
+        let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!("Hello", ans);
+        assert_eq!(
+            vec![PromptEvent::InputChanged("Hello".into())],
+            events.into_inner()
+        );
+    }
+}
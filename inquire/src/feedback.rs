@@ -0,0 +1,157 @@
+//! Hooks to provide sound, haptic or accessibility feedback on prompt events.
+//!
+//! Applications can register a single global [FeedbackHook] through
+//! [set_global_feedback_hook](crate::set_global_feedback_hook), which is then
+//! invoked by all prompts whenever a [FeedbackEvent] happens. This is useful to
+//! trigger terminal bells, desktop notifications or accessibility cues in a
+//! uniform way, without configuring each prompt.
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::feedback::{FeedbackEvent, TerminalBell};
+//!
+//! // ring the terminal bell whenever a prompt rejects an answer
+//! inquire::set_global_feedback_hook(TerminalBell);
+//!
+//! // or use a closure to handle any event
+//! inquire::set_global_feedback_hook(|event: FeedbackEvent| {
+//!     if event == FeedbackEvent::Submit {
+//!         // play a sound, send a notification, ...
+//!     }
+//! });
+//! ```
+
+use std::io::Write;
+
+use crate::config::get_feedback_hook;
+
+/// Events emitted by prompts to the global [FeedbackHook].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeedbackEvent {
+    /// The selection state of an option was changed, e.g. an option was
+    /// checked or unchecked in a [MultiSelect](crate::MultiSelect) prompt.
+    Toggle,
+
+    /// The user tried to submit an answer that was rejected, e.g. because
+    /// it did not pass the prompt's validators.
+    Error,
+
+    /// The user submitted an answer that was accepted.
+    Submit,
+
+    /// The user canceled the prompt.
+    Cancel,
+}
+
+/// Hook invoked by all prompts when a [FeedbackEvent] happens.
+///
+/// Implemented for any `Fn(FeedbackEvent)` closure that is `Send` and `Sync`.
+///
+/// The hook is called synchronously from the prompt loop, so implementations
+/// should return quickly and offload any slow work, such as playing a sound,
+/// to another thread.
+pub trait FeedbackHook: Send + Sync {
+    /// Called when the given event happens in the currently running prompt.
+    fn on_event(&self, event: FeedbackEvent);
+}
+
+impl<F> FeedbackHook for F
+where
+    F: Fn(FeedbackEvent) + Send + Sync,
+{
+    fn on_event(&self, event: FeedbackEvent) {
+        self(event);
+    }
+}
+
+/// Feedback hook that rings the terminal bell when an answer is rejected.
+///
+/// The bell character is written to stderr, so it does not interfere with
+/// the prompt rendering.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TerminalBell;
+
+impl FeedbackHook for TerminalBell {
+    fn on_event(&self, event: FeedbackEvent) {
+        if event == FeedbackEvent::Error {
+            let mut stderr = std::io::stderr();
+            let _ = stderr.write_all(b"\x07");
+            let _ = stderr.flush();
+        }
+    }
+}
+
+/// Notifies the global feedback hook, if any, of the given event.
+pub(crate) fn emit(event: FeedbackEvent) {
+    if let Some(hook) = get_feedback_hook() {
+        hook.on_event(event);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use crate::{
+        test::fake_backend,
+        ui::{Key, KeyModifiers},
+        MultiSelect, Select,
+    };
+
+    use super::FeedbackEvent;
+
+    thread_local! {
+        // tests run in parallel and share the global hook, so events are
+        // recorded per thread to only observe the ones of the current test.
+        static EVENTS: RefCell<Vec<FeedbackEvent>> = const { RefCell::new(vec![]) };
+    }
+
+    #[test]
+    fn multiselect_emits_toggle_error_and_submit() {
+        crate::set_global_feedback_hook(|event| EVENTS.with(|e| e.borrow_mut().push(event)));
+
+        let mut backend = fake_backend(vec![
+            Key::Enter,
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_validator(|a: &[crate::list_option::ListOption<&i32>]| {
+                Ok(match a.is_empty() {
+                    true => crate::validator::Validation::Invalid("Pick one".into()),
+                    false => crate::validator::Validation::Valid,
+                })
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(1, ans.len());
+
+        let events = EVENTS.with(|e| e.borrow().clone());
+        assert_eq!(
+            vec![
+                FeedbackEvent::Error,
+                FeedbackEvent::Toggle,
+                FeedbackEvent::Submit
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn select_viewer_emits_no_error_on_enter() {
+        crate::set_global_feedback_hook(|event| EVENTS.with(|e| e.borrow_mut().push(event)));
+
+        let mut backend = fake_backend(vec![Key::Enter, Key::Escape]);
+
+        Select::new("Question", vec![1, 2, 3])
+            .view_with_backend(&mut backend)
+            .unwrap();
+
+        let events = EVENTS.with(|e| e.borrow().clone());
+        assert_eq!(vec![FeedbackEvent::Cancel], events);
+    }
+}
@@ -46,7 +46,7 @@
 //! ? What's your name? My name is Mikael
 //! ```
 
-use crate::list_option::ListOption;
+use crate::{list_option::ListOption, ui::StyledText};
 
 /// Type alias for formatters that receive a string slice as the input,
 /// required by [Text](crate::Text) and [Password](crate::Password) for example.
@@ -169,6 +169,29 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 #[cfg(feature = "date")]
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
+/// Type alias for formatters styling the answer rendered once a prompt is
+/// submitted, available in all prompts.
+///
+/// They receive the output of the formatter of the prompt and return the
+/// [StyledText] rendered instead of it, e.g. to color part of the answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{
+///     formatter::StyledAnswerFormatter,
+///     ui::{Color, Styled, StyledText},
+/// };
+///
+/// let formatter: StyledAnswerFormatter = &|answer| {
+///     StyledText::new()
+///         .with_styled(Styled::new(answer).with_fg(Color::LightGreen))
+///         .with_text(" (saved)")
+/// };
+/// assert_eq!("Yes (saved)", formatter("Yes").as_str());
+/// ```
+pub type StyledAnswerFormatter<'a> = &'a dyn Fn(&str) -> StyledText;
+
 /// String formatter used by default in inputs that return a `String` as input.
 /// Its behavior is to just echo the received input.
 ///
@@ -203,6 +226,67 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
     }
 };
 
+/// Formats a whole number with commas grouping thousands, e.g. `1,234,567`.
+/// Used by [`CustomType::integer`](crate::CustomType::integer).
+pub(crate) fn format_grouped_integer(value: String) -> String {
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", value.as_str()),
+    };
+
+    format!("{sign}{}", group_thousands(digits, ','))
+}
+
+/// Formatter of decimal numbers written with a decimal comma, grouping
+/// thousands with dots, e.g. `1.234,5`. Used by
+/// [`CustomType::decimal_comma`](crate::CustomType::decimal_comma).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::DECIMAL_COMMA_FORMATTER;
+///
+/// assert_eq!(String::from("3,14"), DECIMAL_COMMA_FORMATTER(3.14));
+/// assert_eq!(String::from("-1.234,5"), DECIMAL_COMMA_FORMATTER(-1234.5));
+/// ```
+pub const DECIMAL_COMMA_FORMATTER: CustomTypeFormatter<'_, f64> = &|val| {
+    let formatted = val.abs().to_string();
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let sign = if val.is_sign_negative() && val != 0.0 {
+        "-"
+    } else {
+        ""
+    };
+    match fraction.is_empty() {
+        true => format!("{sign}{}", group_thousands(integer, '.')),
+        false => format!("{sign}{},{fraction}", group_thousands(integer, '.')),
+    }
+};
+
+/// Formatter of percentages, e.g. `42%`. Used by
+/// [`CustomType::percentage`](crate::CustomType::percentage).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::PERCENTAGE_FORMATTER;
+///
+/// assert_eq!(String::from("12.5%"), PERCENTAGE_FORMATTER(12.5));
+/// ```
+pub const PERCENTAGE_FORMATTER: CustomTypeFormatter<'_, f64> = &|val| format!("{val}%");
+
+fn group_thousands(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
 #[cfg(feature = "date")]
 /// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts.
 /// Prints the selected date in the format: Month Day, Year.
@@ -0,0 +1,442 @@
+//! Trait and structs used by prompts to recall previously submitted answers.
+//!
+//! When a prompt has a history, pressing `up` replaces the text input with
+//! the previous entry, similar to how shells recall past commands, and
+//! pressing `down` moves back towards the most recent entries and, finally,
+//! to whatever the user had typed before browsing the history.
+//!
+//! Answers are recorded in the history when the prompt is submitted. Failing
+//! to record an answer doesn't fail the prompt, as the answer was already
+//! submitted by the user.
+
+use std::{
+    collections::VecDeque,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use dyn_clone::DynClone;
+
+use crate::CustomUserError;
+
+/// Storage of previously submitted answers, recalled with the arrow keys.
+///
+/// Prompts load the entries once when they start and record the answer
+/// when they are submitted. Implementations can keep the entries in memory,
+/// like [`MemoryHistory`], or persist them anywhere else, e.g. in a file.
+pub trait History: DynClone {
+    /// Entries of the history, ordered from the oldest to the most recent.
+    fn entries(&self) -> Result<Vec<String>, CustomUserError>;
+
+    /// Records an answer submitted to a prompt.
+    ///
+    /// Errors are ignored by prompts, which still return the answer.
+    fn record(&mut self, entry: &str) -> Result<(), CustomUserError>;
+}
+
+impl Clone for Box<dyn History> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// In-memory history, keeping up to a given number of the most recent entries.
+///
+/// Clones of a `MemoryHistory` share the same entries, so the same history
+/// can be given to several prompts, each one recalling what was submitted
+/// to the previous ones. Consecutive duplicates and empty answers are not
+/// recorded.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{history::MemoryHistory, Text};
+///
+/// let history = MemoryHistory::default();
+///
+/// loop {
+///     let command = Text::new(">")
+///         .with_history(history.clone())
+///         .prompt()?;
+///
+///     if command == "exit" {
+///         break;
+///     }
+/// }
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct MemoryHistory {
+    entries: Arc<Mutex<VecDeque<String>>>,
+    capacity: usize,
+}
+
+impl MemoryHistory {
+    /// Default number of entries kept in the history, 100.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Creates an empty history keeping up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::default(),
+            capacity,
+        }
+    }
+
+    /// Creates a history pre-filled with the provided entries, ordered from
+    /// the oldest to the most recent.
+    pub fn with_entries<I, S>(capacity: usize, entries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut history = Self::new(capacity);
+        for entry in entries {
+            history.push(entry.into());
+        }
+        history
+    }
+
+    fn push(&mut self, entry: String) {
+        let mut entries = self.entries.lock().unwrap();
+
+        if entry.is_empty() || entries.back() == Some(&entry) {
+            return;
+        }
+
+        entries.push_back(entry);
+        while entries.len() > self.capacity {
+            entries.pop_front();
+        }
+    }
+}
+
+impl Default for MemoryHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl History for MemoryHistory {
+    fn entries(&self) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.entries.lock().unwrap().iter().cloned().collect())
+    }
+
+    fn record(&mut self, entry: &str) -> Result<(), CustomUserError> {
+        self.push(entry.to_owned());
+        Ok(())
+    }
+}
+
+/// History persisted to a file, so that answers can be recalled across
+/// invocations of the application, like shell history.
+///
+/// Entries are stored one per line, oldest first. Line breaks and
+/// backslashes in answers are escaped. The file, along with its missing
+/// parent directories, is created when the first answer is recorded, and a
+/// missing file is treated as an empty history.
+///
+/// When an answer is recorded, previous occurrences of it are removed, so
+/// that each entry appears once, at the position it was last used. This can
+/// be disabled with [`FileHistory::with_deduplication`], in which case only
+/// consecutive duplicates are skipped. Once the history exceeds its maximum
+/// size, the oldest entries are dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{history::FileHistory, Text};
+///
+/// let history = FileHistory::new("/home/user/.config/my-cli/history").with_max_entries(500);
+///
+/// let query = Text::new("Search:").with_history(history).prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileHistory {
+    path: PathBuf,
+    max_entries: usize,
+    deduplicate: bool,
+}
+
+impl FileHistory {
+    /// Default maximum number of entries kept in the file, 1000.
+    pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+    /// Creates a history stored in the file at the provided path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            deduplicate: true,
+        }
+    }
+
+    /// Sets the maximum number of entries kept in the file.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Enables or disables the removal of previous occurrences of recorded
+    /// answers. Enabled by default.
+    pub fn with_deduplication(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    fn read(&self) -> io::Result<Vec<String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(content.lines().map(unescape).collect()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates the directory of the file, if missing.
+    fn create_parent_dir(&self) -> io::Result<()> {
+        match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => fs::create_dir_all(parent),
+            _ => Ok(()),
+        }
+    }
+
+    fn append(&self, entry: &str) -> io::Result<()> {
+        self.create_parent_dir()?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{}", escape(entry))
+    }
+
+    /// Replaces the content of the file, writing to a temporary file first so
+    /// that the history is not lost if the process stops halfway through.
+    fn rewrite(&self, entries: &[String]) -> io::Result<()> {
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&escape(entry));
+            content.push('\n');
+        }
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        self.create_parent_dir()?;
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &self.path)
+    }
+}
+
+impl History for FileHistory {
+    fn entries(&self) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.read()?)
+    }
+
+    fn record(&mut self, entry: &str) -> Result<(), CustomUserError> {
+        if entry.is_empty() {
+            return Ok(());
+        }
+
+        let mut entries = self.read()?;
+        if entries.last().map(String::as_str) == Some(entry) {
+            return Ok(());
+        }
+
+        let previous_len = entries.len();
+        if self.deduplicate {
+            entries.retain(|e| e != entry);
+        }
+        entries.push(entry.to_owned());
+
+        if entries.len() > self.max_entries {
+            let excess = entries.len() - self.max_entries;
+            entries.drain(..excess);
+        }
+
+        // Appending is enough when no previous entry was removed.
+        if entries.len() == previous_len + 1 {
+            self.append(entry)?;
+        } else {
+            self.rewrite(&entries)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(entry: &str) -> String {
+    let mut escaped = String::with_capacity(entry.len());
+    for c in entry.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(line: &str) -> String {
+    let mut unescaped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+        } else {
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        }
+    }
+    unescaped
+}
+
+/// Position of a prompt while browsing its history.
+pub(crate) struct HistoryCursor {
+    entries: Vec<String>,
+    index: Option<usize>,
+    draft: String,
+}
+
+impl HistoryCursor {
+    pub fn new(entries: Vec<String>) -> Self {
+        Self {
+            entries,
+            index: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Moves to the entry before the current one, returning it. The current
+    /// input is saved as a draft when leaving it.
+    pub fn older(&mut self, current_input: &str) -> Option<&str> {
+        let index = match self.index {
+            None => {
+                self.draft = current_input.to_owned();
+                self.entries.len().checked_sub(1)?
+            }
+            Some(index) => index.checked_sub(1)?,
+        };
+
+        self.index = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Moves to the entry after the current one, returning it, or the draft
+    /// when moving past the most recent entry.
+    pub fn newer(&mut self) -> Option<&str> {
+        let index = self.index?;
+
+        if let Some(entry) = self.entries.get(index + 1) {
+            self.index = Some(index + 1);
+            return Some(entry.as_str());
+        }
+
+        self.index = None;
+        Some(self.draft.as_str())
+    }
+
+    /// Whether an entry of the history is currently recalled.
+    pub fn is_browsing(&self) -> bool {
+        self.index.is_some()
+    }
+
+    /// Stops browsing, e.g. when the recalled entry is edited.
+    pub fn reset(&mut self) {
+        self.index = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FileHistory, History, HistoryCursor, MemoryHistory};
+
+    fn temp_history_path(name: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("inquire-history-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn memory_history_skips_consecutive_duplicates_and_respects_capacity() {
+        let mut history = MemoryHistory::new(2);
+        let shared = history.clone();
+
+        history.record("a").unwrap();
+        history.record("b").unwrap();
+        history.record("b").unwrap();
+        history.record("").unwrap();
+        history.record("c").unwrap();
+
+        assert_eq!(vec!["b", "c"], shared.entries().unwrap());
+    }
+
+    #[test]
+    fn cursor_restores_draft_after_most_recent_entry() {
+        let mut cursor = HistoryCursor::new(vec!["a".into(), "b".into()]);
+
+        assert_eq!(None, cursor.newer());
+        assert_eq!(Some("b"), cursor.older("dra"));
+        assert_eq!(Some("a"), cursor.older("b"));
+        assert_eq!(None, cursor.older("a"));
+        assert_eq!(Some("b"), cursor.newer());
+        assert_eq!(Some("dra"), cursor.newer());
+        assert!(!cursor.is_browsing());
+    }
+
+    #[test]
+    fn file_history_persists_deduplicated_entries_up_to_max_entries() {
+        let path = temp_history_path("dedup");
+        let mut history = FileHistory::new(&path).with_max_entries(3);
+
+        assert!(history.entries().unwrap().is_empty());
+
+        for entry in ["a", "b", "a", "c", "multi\nline \\n", "c"] {
+            history.record(entry).unwrap();
+        }
+
+        let reloaded = FileHistory::new(&path);
+        assert_eq!(
+            vec!["a", "multi\nline \\n", "c"],
+            reloaded.entries().unwrap()
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn file_history_without_deduplication_only_skips_consecutive_duplicates() {
+        let path = temp_history_path("no-dedup");
+        let mut history = FileHistory::new(&path).with_deduplication(false);
+
+        for entry in ["a", "b", "b", "a"] {
+            history.record(entry).unwrap();
+        }
+
+        assert_eq!(vec!["a", "b", "a"], history.entries().unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn file_history_creates_missing_parent_directories() {
+        let dir = temp_history_path("missing-dir");
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut history = FileHistory::new(dir.join("nested").join("history"));
+
+        history.record("a").unwrap();
+
+        assert_eq!(vec!["a"], history.entries().unwrap());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
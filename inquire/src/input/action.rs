@@ -16,6 +16,32 @@ pub enum InputAction {
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Reverts the last edit to the content. Consecutive characters typed
+    /// or deleted one by one are reverted together.
+    Undo,
+    /// Reapplies the last edit reverted by [`Undo`](InputAction::Undo).
+    Redo,
+    /// Deletes a substring of the input like [`Delete`](InputAction::Delete),
+    /// saving it to be inserted back with [`Yank`](InputAction::Yank).
+    /// Consecutive kills are saved together.
+    Kill(Magnitude, LineDirection),
+    /// Inserts the text saved by the last kills at the cursor position.
+    Yank,
+    /// Swaps the character before the cursor with the one at the cursor,
+    /// moving the cursor forward. At the end of the input, the last two
+    /// characters are swapped instead.
+    Transpose,
+    /// Inserts the text in the system clipboard at the cursor position.
+    ///
+    /// Only has an effect with the `clipboard` feature enabled.
+    Paste,
+    /// Copies the whole content of the input to the system clipboard.
+    ///
+    /// Only has an effect with the `clipboard` feature enabled.
+    Copy,
+    /// Deletes the whole content of the input, as a single edit that can be
+    /// undone. Not bound to any key by default.
+    Clear,
 }
 
 impl InnerAction for InputAction {
@@ -51,6 +77,40 @@ impl InnerAction for InputAction {
             }
             Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
 
+            Key::Char('z' | 'Z', m) if m.contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) => {
+                Self::Redo
+            }
+            Key::Char('z', m) if m.contains(KeyModifiers::CONTROL) => Self::Undo,
+            // Terminals send the readline binding ctrl+_ as ctrl+7.
+            Key::Char('_' | '7', m) if m.contains(KeyModifiers::CONTROL) => Self::Undo,
+            Key::Char('_', m) if m.contains(KeyModifiers::ALT) => Self::Redo,
+
+            Key::Char('w', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::Kill(Magnitude::Word, LineDirection::Left)
+            }
+            Key::Char('d', m) if m.contains(KeyModifiers::ALT) => {
+                Self::Kill(Magnitude::Word, LineDirection::Right)
+            }
+            Key::Char('u', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::Kill(Magnitude::Line, LineDirection::Left)
+            }
+            Key::Char('k', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::Kill(Magnitude::Line, LineDirection::Right)
+            }
+            Key::Char('y', m) if m.contains(KeyModifiers::CONTROL) => Self::Yank,
+            Key::Char('t', m) if m.contains(KeyModifiers::CONTROL) => Self::Transpose,
+
+            // Ctrl+C interrupts the prompt, so copying is bound to Ctrl+Shift+C,
+            // on terminals able to report it, and to Alt+W as in emacs.
+            #[cfg(feature = "clipboard")]
+            Key::Char('c' | 'C', m) if m.contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) => {
+                Self::Copy
+            }
+            #[cfg(feature = "clipboard")]
+            Key::Char('w', m) if m.contains(KeyModifiers::ALT) => Self::Copy,
+            #[cfg(feature = "clipboard")]
+            Key::Char('v' | 'V', m) if m.contains(KeyModifiers::CONTROL) => Self::Paste,
+
             Key::Char(c, _) => Self::Write(c),
             _ => return None,
         };
@@ -128,6 +188,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn ctrl_z_and_alt_underscore_result_in_undo_and_redo() {
+        assert_eq!(
+            InputAction::from_key(Key::Char('z', KeyModifiers::CONTROL), &()),
+            Some(InputAction::Undo)
+        );
+        assert_eq!(
+            InputAction::from_key(Key::Char('7', KeyModifiers::CONTROL), &()),
+            Some(InputAction::Undo)
+        );
+        assert_eq!(
+            InputAction::from_key(Key::Char('_', KeyModifiers::ALT), &()),
+            Some(InputAction::Redo)
+        );
+        assert_eq!(
+            InputAction::from_key(
+                Key::Char('Z', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+                &()
+            ),
+            Some(InputAction::Redo)
+        );
+    }
+
+    #[test]
+    fn readline_bindings_result_in_kills_yank_and_transpose() {
+        let cases = [
+            (
+                Key::Char('w', KeyModifiers::CONTROL),
+                InputAction::Kill(Magnitude::Word, LineDirection::Left),
+            ),
+            (
+                Key::Char('d', KeyModifiers::ALT),
+                InputAction::Kill(Magnitude::Word, LineDirection::Right),
+            ),
+            (
+                Key::Char('u', KeyModifiers::CONTROL),
+                InputAction::Kill(Magnitude::Line, LineDirection::Left),
+            ),
+            (
+                Key::Char('k', KeyModifiers::CONTROL),
+                InputAction::Kill(Magnitude::Line, LineDirection::Right),
+            ),
+            (Key::Char('y', KeyModifiers::CONTROL), InputAction::Yank),
+            (
+                Key::Char('t', KeyModifiers::CONTROL),
+                InputAction::Transpose,
+            ),
+        ];
+
+        for (key, action) in cases {
+            assert_eq!(InputAction::from_key(key, &()), Some(action));
+        }
+    }
+
     #[test]
     fn home_moves_to_beginning_of_line() {
         assert_eq!(
@@ -200,6 +314,27 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "clipboard")]
+    fn clipboard_bindings_result_in_copy_and_paste() {
+        let cases = [
+            (
+                Key::Char('C', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+                InputAction::Copy,
+            ),
+            (Key::Char('w', KeyModifiers::ALT), InputAction::Copy),
+            (Key::Char('v', KeyModifiers::CONTROL), InputAction::Paste),
+            (
+                Key::Char('V', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+                InputAction::Paste,
+            ),
+        ];
+
+        for (key, action) in cases {
+            assert_eq!(InputAction::from_key(key, &()), Some(action));
+        }
+    }
+
     #[test]
     fn page_up_and_down_do_nothing() {
         assert_eq!(
@@ -0,0 +1,25 @@
+//! Access to the system clipboard, used by the copy and paste actions.
+
+use arboard::Clipboard;
+
+/// Text currently in the system clipboard, if any could be read.
+///
+/// Text inputs hold a single line, so line breaks are replaced with spaces,
+/// apart from trailing ones, which are usually copied along by accident.
+pub fn get_text() -> Option<String> {
+    let text = Clipboard::new().ok()?.get_text().ok()?;
+    let line = text
+        .trim_end_matches(['\r', '\n'])
+        .replace("\r\n", " ")
+        .replace(['\r', '\n'], " ");
+
+    Some(line)
+}
+
+/// Replaces the content of the system clipboard, returning whether it was
+/// possible to access it.
+pub fn set_text(text: &str) -> bool {
+    Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .is_ok()
+}
@@ -1,10 +1,18 @@
 pub mod action;
+#[cfg(feature = "clipboard")]
+mod clipboard;
 #[cfg(test)]
 mod test;
+mod undo;
 
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::InputAction;
+use crate::{
+    type_aliases::{InputFilter, InputTransform},
+    InputAction,
+};
+
+use self::undo::{EditKind, Snapshot, UndoHistory};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Magnitude {
@@ -23,12 +31,33 @@ fn is_alphanumeric(grapheme: &str) -> bool {
     grapheme.unicode_words().count() > 0
 }
 
+/// Transforms a character entered by the user, then checks whether it is
+/// accepted by the filter, returning the character to insert if so.
+pub fn filter_char(
+    c: char,
+    transform: Option<InputTransform<'_>>,
+    filter: Option<InputFilter<'_>>,
+) -> Option<char> {
+    let c = match transform {
+        Some(transform) => transform(c),
+        None => c,
+    };
+
+    match filter {
+        Some(filter) if !filter(c) => None,
+        _ => Some(c),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Input {
     content: String,
     placeholder: Option<String>,
     cursor: usize,
     length: usize,
+    history: UndoHistory,
+    killed: String,
+    follows_kill: bool,
 }
 
 impl Input {
@@ -38,6 +67,9 @@ impl Input {
             placeholder: None,
             cursor: 0,
             length: 0,
+            history: UndoHistory::default(),
+            killed: String::new(),
+            follows_kill: false,
         }
     }
 
@@ -54,6 +86,9 @@ impl Input {
             placeholder: None,
             length: len,
             cursor: len,
+            history: UndoHistory::default(),
+            killed: String::new(),
+            follows_kill: false,
         }
     }
 
@@ -62,6 +97,10 @@ impl Input {
         self
     }
 
+    pub fn set_placeholder(&mut self, placeholder: Option<String>) {
+        self.placeholder = placeholder;
+    }
+
     pub fn with_cursor(mut self, cursor: usize) -> Self {
         assert!(
             cursor <= self.length,
@@ -83,6 +122,66 @@ impl Input {
     }
 
     pub fn handle(&mut self, action: InputAction) -> InputActionResult {
+        self.handle_filtered(action, &Some)
+    }
+
+    /// Handles an action, passing the typed or pasted characters through the
+    /// provided filter, which can replace them or reject them with `None`.
+    pub fn handle_filtered(
+        &mut self,
+        action: InputAction,
+        filter: &dyn Fn(char) -> Option<char>,
+    ) -> InputActionResult {
+        let action = match action {
+            InputAction::Write(c) => match filter(c) {
+                Some(c) => InputAction::Write(c),
+                None => return InputActionResult::Clean,
+            },
+            action => action,
+        };
+
+        let follows_kill = std::mem::take(&mut self.follows_kill);
+
+        let kind = match action {
+            InputAction::Undo => return self.undo(),
+            InputAction::Redo => return self.redo(),
+            // Whitespace starts a new group, so that words are undone one at a time.
+            InputAction::Write(c) if c.is_whitespace() => EditKind::Other,
+            InputAction::Write(_) => EditKind::Insert,
+            InputAction::Delete(Magnitude::Char, _) => EditKind::DeleteChar,
+            InputAction::Delete(_, _)
+            | InputAction::MoveCursor(_, _)
+            | InputAction::Kill(_, _)
+            | InputAction::Yank
+            | InputAction::Transpose
+            | InputAction::Paste
+            | InputAction::Copy
+            | InputAction::Clear => EditKind::Other,
+        };
+
+        let before = self.snapshot();
+        let result = self.apply(action, follows_kill, filter);
+
+        if let InputAction::Kill(_, _) = action {
+            self.follows_kill = result == InputActionResult::ContentChanged;
+        }
+
+        match result {
+            InputActionResult::ContentChanged => self.history.record(before, kind),
+            InputActionResult::PositionChanged => self.history.break_group(),
+            InputActionResult::Clean => {}
+        }
+
+        result
+    }
+
+    #[cfg_attr(not(feature = "clipboard"), allow(unused_variables))]
+    fn apply(
+        &mut self,
+        action: InputAction,
+        follows_kill: bool,
+        filter: &dyn Fn(char) -> Option<char>,
+    ) -> InputActionResult {
         match action {
             InputAction::MoveCursor(mag, dir) => match dir {
                 LineDirection::Left => self.move_left(mag),
@@ -93,6 +192,17 @@ impl Input {
                 LineDirection::Right => self.forwards_delete(mag),
             },
             InputAction::Write(c) => self.insert(c),
+            InputAction::Kill(mag, dir) => self.kill(mag, dir, follows_kill),
+            InputAction::Yank => self.yank(),
+            InputAction::Transpose => self.transpose(),
+            InputAction::Clear => self.clear_content(),
+            #[cfg(feature = "clipboard")]
+            InputAction::Paste => self.paste(filter),
+            #[cfg(feature = "clipboard")]
+            InputAction::Copy => self.copy(),
+            #[cfg(not(feature = "clipboard"))]
+            InputAction::Paste | InputAction::Copy => InputActionResult::Clean,
+            InputAction::Undo | InputAction::Redo => InputActionResult::Clean,
         }
     }
 
@@ -100,6 +210,83 @@ impl Input {
         self.content.clear();
         self.cursor = 0;
         self.length = 0;
+        self.history = UndoHistory::default();
+    }
+
+    /// Clears the input like [`Input::clear`], first overwriting its content,
+    /// the killed text and the undo history with zeroes, so that no copy of a
+    /// secret is left behind in memory.
+    #[cfg(feature = "zeroize")]
+    pub fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        self.content.zeroize();
+        self.killed.zeroize();
+        self.history.wipe();
+        self.clear();
+    }
+
+    /// Replaces the content of the input, e.g. with an accepted suggestion,
+    /// as a single edit that can be undone. The cursor is a grapheme index,
+    /// clamped to the length of the new content.
+    pub fn replace_content<S>(&mut self, content: S, cursor: usize)
+    where
+        S: Into<String>,
+    {
+        let before = self.snapshot();
+
+        self.restore(Snapshot {
+            content: content.into(),
+            cursor,
+        });
+
+        if self.content != before.content {
+            self.history.record(before, EditKind::Other);
+        }
+    }
+
+    fn clear_content(&mut self) -> InputActionResult {
+        if self.content.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        self.content.clear();
+        self.cursor = 0;
+        self.length = 0;
+        InputActionResult::ContentChanged
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            content: self.content.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.content = snapshot.content;
+        self.update_length();
+        self.cursor = snapshot.cursor.min(self.length);
+    }
+
+    fn undo(&mut self) -> InputActionResult {
+        match self.history.undo(self.snapshot()) {
+            Some(previous) => {
+                self.restore(previous);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
+        }
+    }
+
+    fn redo(&mut self) -> InputActionResult {
+        match self.history.redo(self.snapshot()) {
+            Some(next) => {
+                self.restore(next);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
+        }
     }
 
     pub fn content(&self) -> &str {
@@ -286,6 +473,112 @@ impl Input {
         result
     }
 
+    /// Deletes a substring of the input, saving it to be yanked later. The
+    /// text killed right after another kill is joined to the saved text.
+    fn kill(
+        &mut self,
+        mag: Magnitude,
+        dir: LineDirection,
+        follows_kill: bool,
+    ) -> InputActionResult {
+        let (start, end) = match dir {
+            LineDirection::Left => {
+                let start = match mag {
+                    Magnitude::Char => self.cursor.saturating_sub(1),
+                    Magnitude::Word => self.prev_word_index(),
+                    Magnitude::Line => 0,
+                };
+                (start, self.cursor)
+            }
+            LineDirection::Right => {
+                let end = match mag {
+                    Magnitude::Char => self.cursor.saturating_add(1).min(self.length),
+                    Magnitude::Word => self.next_word_index(),
+                    Magnitude::Line => self.length,
+                };
+                (self.cursor, end)
+            }
+        };
+
+        if start >= end {
+            return InputActionResult::Clean;
+        }
+
+        let text: String = self
+            .content
+            .graphemes(true)
+            .skip(start)
+            .take(end - start)
+            .collect();
+
+        match (follows_kill, dir) {
+            (true, LineDirection::Left) => self.killed.insert_str(0, &text),
+            (true, LineDirection::Right) => self.killed.push_str(&text),
+            (false, _) => self.killed = text,
+        }
+
+        self.cursor = start;
+        self.delete_chars_at_right(end - start)
+    }
+
+    fn yank(&mut self) -> InputActionResult {
+        let killed = self.killed.clone();
+        self.insert_str(&killed)
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn paste(&mut self, filter: &dyn Fn(char) -> Option<char>) -> InputActionResult {
+        match clipboard::get_text() {
+            Some(text) => self.insert_str(&text.chars().filter_map(filter).collect::<String>()),
+            None => InputActionResult::Clean,
+        }
+    }
+
+    /// Copies the whole content of the input to the system clipboard.
+    #[cfg(feature = "clipboard")]
+    fn copy(&self) -> InputActionResult {
+        clipboard::set_text(&self.content);
+        InputActionResult::Clean
+    }
+
+    /// Inserts text at the cursor position, moving the cursor past it.
+    fn insert_str(&mut self, text: &str) -> InputActionResult {
+        if text.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        let at = self.pre_cursor().len();
+        self.content.insert_str(at, text);
+        self.update_length();
+
+        let inserted_end = at + text.len();
+        self.cursor = self
+            .content
+            .get(..inserted_end)
+            .unwrap_or_default()
+            .graphemes(true)
+            .count();
+
+        InputActionResult::ContentChanged
+    }
+
+    fn transpose(&mut self) -> InputActionResult {
+        if self.cursor == 0 || self.length < 2 {
+            return InputActionResult::Clean;
+        }
+
+        let right = self.cursor.min(self.length - 1);
+
+        let mut graphemes: Vec<&str> = self.content.graphemes(true).collect();
+        graphemes.swap(right - 1, right);
+        self.content = graphemes.concat();
+        self.update_length();
+
+        self.cursor = (right + 1).min(self.length);
+
+        InputActionResult::ContentChanged
+    }
+
     fn update_length(&mut self) -> bool {
         let new_len = self.content[..].graphemes(true).count();
         let old_len = self.length;
@@ -475,3 +475,208 @@ fn generic_user_scenario() {
     assert_eq!("great idea! you", input.pre_cursor());
     assert_eq!(15, input.cursor());
 }
+
+fn type_text(input: &mut Input, text: &str) {
+    for c in text.chars() {
+        input.handle(InputAction::Write(c));
+    }
+}
+
+#[test]
+fn undo_reverts_typed_words_one_at_a_time() {
+    let mut input = Input::new();
+    type_text(&mut input, "ab cd");
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Undo)
+    );
+    assert_eq!("ab ", input.content());
+    assert_eq!(3, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("ab", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("", input.content());
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Undo));
+}
+
+#[test]
+fn undo_restores_deleted_word_and_cursor() {
+    let mut input = Input::new_with("great idea! you are a genius").with_cursor(19);
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Right));
+    assert_eq!("great idea! you ", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("great idea! you  a genius", input.content());
+    assert_eq!(16, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("great idea! you are a genius", input.content());
+    assert_eq!(19, input.cursor());
+}
+
+#[test]
+fn moving_cursor_splits_undo_groups() {
+    let mut input = Input::new();
+    type_text(&mut input, "ac");
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    type_text(&mut input, "b");
+    assert_eq!("abc", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("ac", input.content());
+    assert_eq!(1, input.cursor());
+}
+
+#[test]
+fn redo_reapplies_undone_edits_until_a_new_edit() {
+    let mut input = Input::new();
+    type_text(&mut input, "ab cd");
+    input.handle(InputAction::Undo);
+    input.handle(InputAction::Undo);
+    assert_eq!("ab", input.content());
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Redo)
+    );
+    assert_eq!("ab ", input.content());
+
+    type_text(&mut input, "x");
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Redo));
+    assert_eq!("ab x", input.content());
+}
+
+#[test]
+fn replaced_content_can_be_undone() {
+    let mut input = Input::new_with("git ch");
+    input.replace_content("git checkout", 12);
+    assert_eq!(12, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("git ch", input.content());
+    assert_eq!(6, input.cursor());
+}
+
+#[test]
+fn consecutive_kills_are_yanked_together() {
+    let mut input = Input::new_with("great idea! you are a genius").with_cursor(19);
+
+    input.handle(InputAction::Kill(Magnitude::Word, LineDirection::Left));
+    input.handle(InputAction::Kill(Magnitude::Line, LineDirection::Right));
+    assert_eq!("great idea! you ", input.content());
+    assert_eq!(16, input.cursor());
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Yank)
+    );
+    assert_eq!("great idea! you are a genius", input.content());
+    assert_eq!(28, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("great idea! you ", input.content());
+}
+
+#[test]
+fn kill_after_another_action_replaces_killed_text() {
+    let mut input = Input::new_with("one two three");
+
+    input.handle(InputAction::Kill(Magnitude::Word, LineDirection::Left));
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Kill(Magnitude::Word, LineDirection::Right));
+    assert_eq!(" two ", input.content());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Right,
+    ));
+    input.handle(InputAction::Yank);
+    assert_eq!(" two one", input.content());
+}
+
+#[test]
+fn yank_without_killed_text_is_clean() {
+    let mut input = Input::new_with("abc");
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Yank));
+    assert_eq!("abc", input.content());
+}
+
+#[test]
+fn transpose_swaps_characters_around_cursor() {
+    let mut input = Input::new_with("ab🌍").with_cursor(1);
+
+    input.handle(InputAction::Transpose);
+    assert_eq!("ba🌍", input.content());
+    assert_eq!(2, input.cursor());
+
+    input.handle(InputAction::Transpose);
+    assert_eq!("b🌍a", input.content());
+    assert_eq!(3, input.cursor());
+
+    // At the end of the input, the last two characters are swapped.
+    input.handle(InputAction::Transpose);
+    assert_eq!("ba🌍", input.content());
+    assert_eq!(3, input.cursor());
+
+    let mut input = Input::new_with("ab").with_cursor(0);
+    assert_eq!(
+        InputActionResult::Clean,
+        input.handle(InputAction::Transpose)
+    );
+}
+
+#[test]
+fn grapheme_clusters_are_edited_as_a_whole() {
+    let man_running = "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}";
+    let mut input = Input::new_with(format!("a{man_running}\u{1f1e7}\u{1f1f7}b"));
+    assert_eq!(4, input.length());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    assert_eq!(format!("a{man_running}"), input.pre_cursor());
+
+    input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    assert_eq!("a\u{1f1e7}\u{1f1f7}b", input.content());
+    assert_eq!(1, input.cursor());
+
+    // A combining mark joins the previous grapheme, so the cursor stays
+    // after it.
+    let mut input = Input::new_with("e");
+    input.handle(InputAction::Write('\u{301}'));
+    assert_eq!(1, input.length());
+    assert_eq!(1, input.cursor());
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn wipe_forgets_content_and_undo_history() {
+    let mut input = Input::new();
+    type_text(&mut input, "hunter2 ");
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+
+    input.wipe();
+
+    assert_eq!("", input.content());
+    assert_eq!(0, input.cursor());
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Undo));
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Redo));
+}
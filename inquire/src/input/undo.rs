@@ -0,0 +1,81 @@
+/// Maximum number of edits that can be undone.
+const MAX_UNDO_STEPS: usize = 100;
+
+/// State of an input before or after an edit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub content: String,
+    pub cursor: usize,
+}
+
+/// Kind of an edit, used to group consecutive edits of the same kind into
+/// a single undo step, e.g. all characters of a typed word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditKind {
+    Insert,
+    DeleteChar,
+    Other,
+}
+
+/// Edit history of an input, holding the snapshots restored by undo and redo.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UndoHistory {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+}
+
+impl UndoHistory {
+    /// Records an edit, given the state of the input before it. Redoing is
+    /// no longer possible after a new edit.
+    pub fn record(&mut self, before: Snapshot, kind: EditKind) {
+        self.redo.clear();
+
+        let extends_last_edit = kind != EditKind::Other && self.last_edit == Some(kind);
+        self.last_edit = Some(kind);
+
+        if extends_last_edit {
+            return;
+        }
+
+        self.undo.push(before);
+        if self.undo.len() > MAX_UNDO_STEPS {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Stops grouping the following edits with the previous ones, e.g. when
+    /// the cursor is moved between them.
+    pub fn break_group(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Overwrites every snapshot with zeroes before forgetting them.
+    #[cfg(feature = "zeroize")]
+    pub fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        for snapshot in self.undo.iter_mut().chain(self.redo.iter_mut()) {
+            snapshot.content.zeroize();
+        }
+        *self = Self::default();
+    }
+
+    /// Returns the snapshot to restore to undo the last edit, saving the
+    /// current state so that it can be redone.
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_edit = None;
+        Some(previous)
+    }
+
+    /// Returns the snapshot to restore to redo the last undone edit, saving
+    /// the current state so that it can be undone again.
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_edit = None;
+        Some(next)
+    }
+}
@@ -0,0 +1,241 @@
+//! Recording of per-frame timings of prompts, exportable in the Chrome
+//! tracing format.
+//!
+//! When recording, prompts time each phase of their loop: waiting for user
+//! input, scoring list options, rendering a frame and flushing it to the
+//! terminal. The resulting [`Trace`] can be exported as JSON and loaded in
+//! `chrome://tracing` or [Perfetto](https://ui.perfetto.dev), which is the
+//! most useful thing to attach to a report about a slow prompt.
+//!
+//! Recording is global and only costs an atomic load per phase while
+//! stopped.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{instrument, Select};
+//!
+//! instrument::start_recording();
+//!
+//! let options = (0..100_000).map(|i| format!("option {i}")).collect();
+//! let _ = Select::new("Pick one:", options).prompt();
+//!
+//! let trace = instrument::stop_recording();
+//! std::fs::write("inquire-trace.json", trace.to_chrome_json())?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{
+    fmt::Write as _,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Phase of the prompt loop timed while recording.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Phase {
+    /// Waiting for the next key event from the input reader.
+    InputWait,
+
+    /// Scoring and sorting the options of a list prompt after the filter
+    /// input changed.
+    Scoring,
+
+    /// Rendering the prompt into a frame. Each render starts a new frame.
+    Render,
+
+    /// Flushing a rendered frame to the terminal.
+    Flush,
+}
+
+impl Phase {
+    /// Name of the phase, as displayed in the exported trace.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::InputWait => "input wait",
+            Self::Scoring => "scoring",
+            Self::Render => "render",
+            Self::Flush => "flush",
+        }
+    }
+}
+
+/// Timing of a single phase of the prompt loop.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Phase that was timed.
+    pub phase: Phase,
+
+    /// Number of the frame the phase belongs to, starting at 1 with the first
+    /// render. Phases before the first render belong to frame 0.
+    pub frame: u64,
+
+    /// Start of the phase, relative to the start of the recording.
+    pub start: Duration,
+
+    /// Duration of the phase.
+    pub duration: Duration,
+}
+
+/// Timings recorded between [`start_recording`] and [`stop_recording`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// Recorded events, in the order in which their phases ended.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Exports the trace as a JSON document in the Chrome trace event format.
+    pub fn to_chrome_json(&self) -> String {
+        let mut json = String::from("{\"displayTimeUnit\":\"ms\",\"traceEvents\":[");
+
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                "{{\"name\":\"{}\",\"cat\":\"inquire\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1,\"args\":{{\"frame\":{}}}}}",
+                event.phase.name(),
+                event.start.as_micros(),
+                event.duration.as_micros(),
+                event.frame,
+            );
+        }
+
+        json.push_str("]}");
+        json
+    }
+
+    /// Writes the trace to the provided writer in the Chrome trace event format.
+    pub fn write_chrome_json<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_chrome_json().as_bytes())
+    }
+}
+
+struct Recorder {
+    origin: Instant,
+    frame: u64,
+    events: Vec<TraceEvent>,
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Starts recording timings of all prompts, discarding any previous recording.
+pub fn start_recording() {
+    *RECORDER.lock().unwrap() = Some(Recorder {
+        origin: Instant::now(),
+        frame: 0,
+        events: vec![],
+    });
+    RECORDING.store(true, Ordering::Release);
+}
+
+/// Stops recording, returning the recorded timings. The trace is empty if
+/// recording wasn't started.
+pub fn stop_recording() -> Trace {
+    RECORDING.store(false, Ordering::Release);
+
+    let events = RECORDER
+        .lock()
+        .unwrap()
+        .take()
+        .map(|recorder| recorder.events)
+        .unwrap_or_default();
+
+    Trace { events }
+}
+
+/// Returns the timings recorded so far, without stopping the recording.
+pub fn snapshot() -> Trace {
+    let events = RECORDER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|recorder| recorder.events.clone())
+        .unwrap_or_default();
+
+    Trace { events }
+}
+
+/// Guard timing a phase, recorded when dropped.
+pub(crate) struct Span {
+    phase: Phase,
+    start: Instant,
+}
+
+/// Starts timing a phase, if recording.
+pub(crate) fn span(phase: Phase) -> Option<Span> {
+    if !RECORDING.load(Ordering::Acquire) {
+        return None;
+    }
+
+    Some(Span {
+        phase,
+        start: Instant::now(),
+    })
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let duration = self.start.elapsed();
+
+        if let Some(recorder) = RECORDER.lock().unwrap().as_mut() {
+            if self.phase == Phase::Render {
+                recorder.frame += 1;
+            }
+
+            recorder.events.push(TraceEvent {
+                phase: self.phase,
+                frame: recorder.frame,
+                start: self.start.saturating_duration_since(recorder.origin),
+                duration,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Phase, Trace, TraceEvent};
+
+    #[test]
+    fn chrome_json_contains_complete_events_in_microseconds() {
+        let trace = Trace {
+            events: vec![
+                TraceEvent {
+                    phase: Phase::Render,
+                    frame: 1,
+                    start: Duration::from_micros(10),
+                    duration: Duration::from_micros(250),
+                },
+                TraceEvent {
+                    phase: Phase::Flush,
+                    frame: 1,
+                    start: Duration::from_micros(260),
+                    duration: Duration::from_millis(1),
+                },
+            ],
+        };
+
+        assert_eq!(
+            "{\"displayTimeUnit\":\"ms\",\"traceEvents\":[\
+             {\"name\":\"render\",\"cat\":\"inquire\",\"ph\":\"X\",\"ts\":10,\"dur\":250,\"pid\":1,\"tid\":1,\"args\":{\"frame\":1}},\
+             {\"name\":\"flush\",\"cat\":\"inquire\",\"ph\":\"X\",\"ts\":260,\"dur\":1000,\"pid\":1,\"tid\":1,\"args\":{\"frame\":1}}]}",
+            trace.to_chrome_json()
+        );
+    }
+}
@@ -0,0 +1,829 @@
+//! Remapping of the keys pressed by the user to prompt actions.
+//!
+//! Each prompt parses key events into actions with its default bindings,
+//! listed in the `KEY_BINDINGS.md` file. [KeyBindings] override them, either
+//! for all prompts with [set_global_key_bindings](crate::set_global_key_bindings)
+//! or for a single prompt with `with_key_bindings`, e.g.
+//! [Select::with_key_bindings](crate::Select::with_key_bindings). Bindings of
+//! a prompt take precedence over the global ones, and keys bound in neither
+//! keep their default behavior.
+//!
+//! Actions can also be bound to sequences of two keys, e.g. `g` then `g`,
+//! pressed within a timeout. The first key of a bound sequence waits for the
+//! second one, and when another key follows, or the timeout expires before
+//! it, the first key is handled on its own before the next one.
+//!
+//! For shortcuts that prompts don't know about, a [KeyHandler] set with
+//! `with_key_handler` is called with each key before any binding, and can
+//! read the state of the prompt and submit or cancel it through a
+//! [PromptStateView].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{
+//!     key_bindings::KeyBindings,
+//!     set_global_key_bindings,
+//!     ui::{Key, KeyModifiers},
+//!     Action, MultiSelect,
+//! };
+//!
+//! // Ctrl+C no longer interrupts prompts, and Escape must be used instead.
+//! set_global_key_bindings(KeyBindings::new().unbind(Key::Char('c', KeyModifiers::CONTROL)));
+//!
+//! // Space submits the selection, while Enter toggles the highlighted option.
+//! let toppings = MultiSelect::new("Toppings:", vec!["cheese", "olives", "basil"])
+//!     .with_key_bindings(
+//!         KeyBindings::new()
+//!             .bind(Key::Char(' ', KeyModifiers::NONE), Action::Submit)
+//!             .remap(Key::Enter, Key::Char(' ', KeyModifiers::NONE))
+//!             .remap_sequence(
+//!                 Key::Char('g', KeyModifiers::NONE),
+//!                 Key::Char('g', KeyModifiers::NONE),
+//!                 Key::Home,
+//!             ),
+//!     )
+//!     .prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    convert::Infallible,
+    time::{Duration, Instant},
+};
+
+use crate::{event::PromptEvent, ui::Key, Action};
+
+/// What a key bound in [KeyBindings] does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Binding<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    /// Triggers the action.
+    Action(Action<I>),
+    /// Behaves as another key does with the default bindings, e.g. to add
+    /// alternate keys for an existing binding.
+    Key(Key),
+    /// Does nothing, disabling the default binding of the key.
+    Unbound,
+}
+
+/// Key bindings overriding the default ones of prompts whose specialized
+/// actions are of type `I`, e.g. [SelectPromptAction](crate::SelectPromptAction).
+///
+/// When a key or a sequence is bound more than once, the last binding is
+/// used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindings<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    bindings: Vec<(Key, Binding<I>)>,
+    sequences: Vec<([Key; 2], Binding<I>)>,
+    sequence_timeout: Duration,
+}
+
+/// Key bindings shared by all prompts, set with
+/// [set_global_key_bindings](crate::set_global_key_bindings). As they apply
+/// to prompts of all types, they can't trigger specialized actions.
+pub type GlobalKeyBindings = KeyBindings<Infallible>;
+
+impl<I> KeyBindings<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    /// Default time within which the second key of a sequence must be
+    /// pressed, one second.
+    pub const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Creates key bindings overriding none of the default ones.
+    pub fn new() -> Self {
+        Self {
+            bindings: vec![],
+            sequences: vec![],
+            sequence_timeout: Self::DEFAULT_SEQUENCE_TIMEOUT,
+        }
+    }
+
+    /// Binds the key to the action.
+    pub fn bind(self, key: Key, action: Action<I>) -> Self {
+        self.with_binding(key, Binding::Action(action))
+    }
+
+    /// Makes the key behave as the target one does with the default
+    /// bindings.
+    pub fn remap(self, key: Key, target: Key) -> Self {
+        self.with_binding(key, Binding::Key(target))
+    }
+
+    /// Disables the default binding of the key.
+    pub fn unbind(self, key: Key) -> Self {
+        self.with_binding(key, Binding::Unbound)
+    }
+
+    /// Adds a binding of the key.
+    pub fn with_binding(mut self, key: Key, binding: Binding<I>) -> Self {
+        self.bindings.push((key, binding));
+        self
+    }
+
+    /// Binds the sequence of the two keys to the action.
+    pub fn bind_sequence(self, first: Key, second: Key, action: Action<I>) -> Self {
+        self.with_sequence_binding(first, second, Binding::Action(action))
+    }
+
+    /// Makes the sequence of the two keys behave as the target key does with
+    /// the default bindings.
+    pub fn remap_sequence(self, first: Key, second: Key, target: Key) -> Self {
+        self.with_sequence_binding(first, second, Binding::Key(target))
+    }
+
+    /// Adds a binding of the sequence of the two keys.
+    pub fn with_sequence_binding(mut self, first: Key, second: Key, binding: Binding<I>) -> Self {
+        self.sequences.push(([first, second], binding));
+        self
+    }
+
+    /// Sets the time within which the second key of a sequence must be
+    /// pressed after the first one.
+    pub fn with_sequence_timeout(mut self, timeout: Duration) -> Self {
+        self.sequence_timeout = timeout;
+        self
+    }
+
+    /// Returns the binding of the key, if it is overridden.
+    pub fn get(&self, key: Key) -> Option<Binding<I>> {
+        self.bindings
+            .iter()
+            .rev()
+            .find(|(bound, _)| *bound == key)
+            .map(|(_, binding)| *binding)
+    }
+
+    /// Returns the binding of the sequence of the two keys, if any.
+    pub fn get_sequence(&self, first: Key, second: Key) -> Option<Binding<I>> {
+        self.sequences
+            .iter()
+            .rev()
+            .find(|(keys, _)| *keys == [first, second])
+            .map(|(_, binding)| *binding)
+    }
+
+    /// Time within which the second key of a sequence must be pressed.
+    pub fn sequence_timeout(&self) -> Duration {
+        self.sequence_timeout
+    }
+
+    fn starts_sequence(&self, key: Key) -> bool {
+        self.sequences.iter().any(|([first, _], _)| *first == key)
+    }
+}
+
+impl<I> Default for KeyBindings<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a [KeyHandler] handled a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Handled {
+    /// The key was handled, and the prompt ignores it.
+    Yes,
+    /// The key was not handled, and the prompt parses it into an action as
+    /// usual.
+    No,
+}
+
+/// What pressing Escape does in a prompt, when it is bound to canceling the
+/// prompt as it is by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{key_bindings::EscapeBehavior, Select};
+///
+/// // Escape clears the filter, and cancels the prompt once it is empty
+/// let fruit = Select::new("Fruit:", vec!["apple", "banana", "cherry"])
+///     .with_escape_behavior(EscapeBehavior::ClearInput)
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EscapeBehavior {
+    /// The prompt is canceled.
+    #[default]
+    Cancel,
+
+    /// The text typed by the user, e.g. the filter of a
+    /// [Select](crate::Select) prompt, is cleared. The prompt is canceled
+    /// only when there is nothing to clear.
+    ClearInput,
+
+    /// Nothing happens. The prompt can still be canceled with the other
+    /// keys bound to it, e.g. Ctrl+G.
+    Ignore,
+}
+
+/// Guard against canceling a prompt by accident, e.g. with a stray Escape,
+/// once the user entered something that would be lost: typed text or
+/// checked options.
+///
+/// Prompts holding nothing entered by the user are canceled right away.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use inquire::{key_bindings::CancelGuard, Text};
+///
+/// // Escape must be pressed twice within a second to discard the bio
+/// let bio = Text::new("Tell us about yourself:")
+///     .with_cancel_guard(CancelGuard::PressTwice(Duration::from_secs(1)))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CancelGuard {
+    /// The prompt is canceled right away.
+    #[default]
+    None,
+
+    /// The prompt is canceled only when it is asked to again within the
+    /// duration, e.g. by pressing Escape twice. A hint is displayed below the
+    /// prompt after the first request.
+    PressTwice(Duration),
+
+    /// The user is asked "Discard input? (y/n)" below the prompt, which is
+    /// canceled when they press `y`. Pressing `n` or Escape dismisses the
+    /// question, as does any other key, which is then handled as usual.
+    Confirm,
+}
+
+impl CancelGuard {
+    /// Line displayed below the prompt while a request to cancel it waits
+    /// for the user to confirm it.
+    pub(crate) fn notice(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::PressTwice(_) => Some("Press esc again to discard input"),
+            Self::Confirm => Some("Discard input? (y/n)"),
+        }
+    }
+}
+
+/// State of a prompt given to a [KeyHandler], through which the handler can
+/// also submit or cancel the prompt.
+pub struct PromptStateView<'s> {
+    message: &'s str,
+    input: Option<&'s str>,
+    highlighted: Option<usize>,
+    action: Option<Action<Infallible>>,
+}
+
+impl<'s> PromptStateView<'s> {
+    pub(crate) fn new(message: &'s str, input: Option<&'s str>, state: &[PromptEvent]) -> Self {
+        let highlighted = state.iter().find_map(|event| match event {
+            PromptEvent::HighlightChanged(index) => Some(*index),
+            _ => None,
+        });
+
+        Self {
+            message,
+            input,
+            highlighted,
+            action: None,
+        }
+    }
+
+    /// Message of the prompt.
+    pub fn message(&self) -> &str {
+        self.message
+    }
+
+    /// Text typed by the user, e.g. the filter of a [Select](crate::Select)
+    /// prompt, if the prompt has a text input.
+    pub fn input(&self) -> Option<&str> {
+        self.input
+    }
+
+    /// Index of the highlighted option, in the list of options given to the
+    /// prompt, if the prompt has options.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Submits the prompt once the handler returns [Handled::Yes], as if
+    /// the user pressed Enter.
+    pub fn submit(&mut self) {
+        self.action = Some(Action::Submit);
+    }
+
+    /// Cancels the prompt once the handler returns [Handled::Yes], as if
+    /// the user pressed Escape.
+    pub fn cancel(&mut self) {
+        self.action = Some(Action::Cancel);
+    }
+
+    pub(crate) fn action<I>(&self) -> Option<Action<I>>
+    where
+        I: Copy + Clone + PartialEq + Eq,
+    {
+        self.action.map(common_action)
+    }
+}
+
+/// Type alias for handlers called with the keys pressed by the user before
+/// prompts parse them into actions.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::cell::Cell;
+///
+/// use inquire::{
+///     key_bindings::{Handled, PromptStateView},
+///     ui::{Key, KeyModifiers},
+///     InquireError, Select,
+/// };
+///
+/// let refresh = Cell::new(false);
+/// let on_key = |key: Key, view: &mut PromptStateView<'_>| match key {
+///     Key::F(5, KeyModifiers::NONE) => {
+///         refresh.set(true);
+///         view.cancel();
+///         Handled::Yes
+///     }
+///     _ => Handled::No,
+/// };
+///
+/// let server = loop {
+///     refresh.set(false);
+///     let servers = vec!["eu-west", "us-east"];
+///     match Select::new("Server (F5 to refresh):", servers)
+///         .with_key_handler(&on_key)
+///         .prompt()
+///     {
+///         Err(InquireError::OperationCanceled) if refresh.get() => continue,
+///         answer => break answer?,
+///     }
+/// };
+/// # Ok::<(), InquireError>(())
+/// ```
+pub type KeyHandler<'a> = &'a dyn Fn(Key, &mut PromptStateView<'_>) -> Handled;
+
+/// Converts an action common to all prompts into an action of a prompt.
+fn common_action<I>(action: Action<Infallible>) -> Action<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    match action {
+        Action::Submit => Action::Submit,
+        Action::Cancel => Action::Cancel,
+        Action::Interrupt => Action::Interrupt,
+        Action::Inner(never) => match never {},
+    }
+}
+
+/// Converts a global binding into a binding of a prompt.
+fn common_binding<I>(binding: Binding<Infallible>) -> Binding<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    match binding {
+        Binding::Action(action) => Binding::Action(common_action(action)),
+        Binding::Key(target) => Binding::Key(target),
+        Binding::Unbound => Binding::Unbound,
+    }
+}
+
+/// Resolves the binding of a key, looking it up in the bindings of the prompt
+/// and then in the global ones. Keys bound in neither are returned as is.
+pub(crate) fn resolve<I>(key: Key, prompt_bindings: Option<&KeyBindings<I>>) -> Binding<I>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    if let Some(binding) = prompt_bindings.and_then(|bindings| bindings.get(key)) {
+        return binding;
+    }
+
+    crate::config::read_global_key_bindings(|bindings| bindings.get(key))
+        .flatten()
+        .map(common_binding)
+        .unwrap_or(Binding::Key(key))
+}
+
+/// Keys pressed by the user that may be the start of a bound sequence, kept
+/// by the loop of a prompt between key events.
+#[derive(Default)]
+pub(crate) struct KeySequence {
+    pending: Option<PendingKey>,
+}
+
+/// First key of a bound sequence, waiting for the second one.
+#[derive(Copy, Clone)]
+struct PendingKey {
+    key: Key,
+    pressed_at: Instant,
+    timeout: Duration,
+}
+
+impl KeySequence {
+    /// Feeds a key pressed by the user, returning the bindings to apply, in
+    /// order. None are returned when the key starts a bound sequence, while
+    /// two are returned when a pending first key is not followed by the rest
+    /// of its sequence.
+    pub fn feed<I>(&mut self, key: Key, prompt_bindings: Option<&KeyBindings<I>>) -> Vec<Binding<I>>
+    where
+        I: Copy + Clone + PartialEq + Eq,
+    {
+        let mut bindings = vec![];
+
+        if let Some(pending) = self.pending.take() {
+            if let Some(binding) =
+                sequence_binding(pending.key, key, pending.pressed_at, prompt_bindings)
+            {
+                return vec![binding];
+            }
+            bindings.push(resolve(pending.key, prompt_bindings));
+        }
+
+        match sequence_timeout(key, prompt_bindings) {
+            Some(timeout) => {
+                self.pending = Some(PendingKey {
+                    key,
+                    pressed_at: Instant::now(),
+                    timeout,
+                });
+            }
+            None => bindings.push(resolve(key, prompt_bindings)),
+        }
+
+        bindings
+    }
+
+    /// Time left for the second key of the pending sequence to be pressed,
+    /// if a first key is pending.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.pending
+            .map(|pending| pending.timeout.saturating_sub(pending.pressed_at.elapsed()))
+    }
+
+    /// Returns the binding of the pending first key once its sequence timed
+    /// out, applied as if the key was not the start of any sequence.
+    pub fn flush_expired<I>(&mut self, prompt_bindings: Option<&KeyBindings<I>>) -> Vec<Binding<I>>
+    where
+        I: Copy + Clone + PartialEq + Eq,
+    {
+        if self.remaining() != Some(Duration::ZERO) {
+            return vec![];
+        }
+
+        self.pending
+            .take()
+            .map(|pending| resolve(pending.key, prompt_bindings))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Time within which the second key of a sequence started by the key must be
+/// pressed, taken from the bindings of the prompt and then from the global
+/// ones. Returns `None` when the key starts no bound sequence.
+fn sequence_timeout<I>(key: Key, prompt_bindings: Option<&KeyBindings<I>>) -> Option<Duration>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    if let Some(bindings) = prompt_bindings.filter(|bindings| bindings.starts_sequence(key)) {
+        return Some(bindings.sequence_timeout);
+    }
+
+    crate::config::read_global_key_bindings(|bindings| {
+        bindings
+            .starts_sequence(key)
+            .then_some(bindings.sequence_timeout)
+    })
+    .flatten()
+}
+
+/// Looks up the binding of a sequence in the bindings of the prompt and then
+/// in the global ones, if its second key was pressed in time.
+fn sequence_binding<I>(
+    first: Key,
+    second: Key,
+    pressed_at: Instant,
+    prompt_bindings: Option<&KeyBindings<I>>,
+) -> Option<Binding<I>>
+where
+    I: Copy + Clone + PartialEq + Eq,
+{
+    let elapsed = pressed_at.elapsed();
+
+    if let Some(bindings) = prompt_bindings.filter(|bindings| bindings.starts_sequence(first)) {
+        if elapsed > bindings.sequence_timeout {
+            return None;
+        }
+        return bindings.get_sequence(first, second);
+    }
+
+    crate::config::read_global_key_bindings(|bindings| {
+        if elapsed > bindings.sequence_timeout {
+            return None;
+        }
+        bindings.get_sequence(first, second).map(common_binding)
+    })
+    .flatten()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, time::Duration};
+
+    use crate::{
+        list_option::ListOption,
+        test::fake_backend,
+        ui::{Key, KeyModifiers},
+        Action, InquireError, MultiSelect, Select, SelectPromptAction, Text,
+    };
+
+    use super::{
+        resolve, Binding, CancelGuard, EscapeBehavior, Handled, KeyBindings, KeySequence,
+        PromptStateView,
+    };
+
+    #[test]
+    fn last_binding_of_a_key_is_used() {
+        let bindings = KeyBindings::<SelectPromptAction>::new()
+            .bind(Key::Tab, Action::Submit)
+            .bind(Key::Tab, Action::Inner(SelectPromptAction::MoveDown));
+
+        assert_eq!(
+            Binding::Action(Action::Inner(SelectPromptAction::MoveDown)),
+            resolve(Key::Tab, Some(&bindings))
+        );
+        assert_eq!(Binding::Key(Key::Home), resolve(Key::Home, Some(&bindings)));
+    }
+
+    #[test]
+    fn space_and_enter_can_be_swapped() {
+        let space = Key::Char(' ', KeyModifiers::NONE);
+        let mut backend = fake_backend(vec![Key::Enter, Key::Down(KeyModifiers::NONE), space]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_key_bindings(
+                KeyBindings::new()
+                    .bind(space, Action::Submit)
+                    .remap(Key::Enter, space),
+            )
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![1],
+            ans.into_iter().map(|o| o.value).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn first_keys_of_sequences_wait_for_the_second_one() {
+        let g = Key::Char('g', KeyModifiers::NONE);
+        let a = Key::Char('a', KeyModifiers::NONE);
+        let bindings = KeyBindings::<SelectPromptAction>::new().remap_sequence(g, g, Key::Home);
+        let mut sequence = KeySequence::default();
+
+        assert_eq!(Vec::<Binding<_>>::new(), sequence.feed(g, Some(&bindings)));
+        assert_eq!(
+            vec![Binding::Key(Key::Home)],
+            sequence.feed(g, Some(&bindings))
+        );
+
+        assert_eq!(Vec::<Binding<_>>::new(), sequence.feed(g, Some(&bindings)));
+        assert_eq!(
+            vec![Binding::Key(g), Binding::Key(a)],
+            sequence.feed(a, Some(&bindings))
+        );
+    }
+
+    #[test]
+    fn sequences_completed_after_the_timeout_are_not_matched() {
+        let g = Key::Char('g', KeyModifiers::NONE);
+        let bindings = KeyBindings::<SelectPromptAction>::new()
+            .remap_sequence(g, g, Key::Home)
+            .with_sequence_timeout(Duration::ZERO);
+        let mut sequence = KeySequence::default();
+
+        sequence.feed(g, Some(&bindings));
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(vec![Binding::Key(g)], sequence.feed(g, Some(&bindings)));
+    }
+
+    #[test]
+    fn first_keys_of_sequences_are_applied_once_the_sequence_times_out() {
+        use crate::{prompts::test::PollingInput, terminal::test::NullTerminal, ui::Backend};
+
+        let g = Key::Char('g', KeyModifiers::NONE);
+        // each `g` times out before the next key, instead of moving to the start
+        let input = PollingInput(
+            vec![Some(g), None, Some(g), None, Some(Key::Enter)]
+                .into_iter()
+                .collect(),
+        );
+        let mut backend = Backend::new(input, NullTerminal, Default::default()).unwrap();
+
+        let ans = Text::new("Question")
+            .with_key_bindings(
+                KeyBindings::new()
+                    .remap_sequence(g, g, Key::Home)
+                    .with_sequence_timeout(Duration::from_millis(10)),
+            )
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("gg", ans);
+    }
+
+    #[test]
+    fn sequences_trigger_actions_in_prompts() {
+        let d = Key::Char('d', KeyModifiers::NONE);
+        let mut backend = fake_backend(vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Down(KeyModifiers::NONE),
+            d,
+            d,
+            Key::Down(KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_key_bindings(KeyBindings::new().bind_sequence(
+                d,
+                d,
+                Action::Inner(SelectPromptAction::MoveToStart),
+            ))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, 2), ans);
+    }
+
+    #[test]
+    fn key_handler_runs_before_bindings_and_can_submit() {
+        let seen = RefCell::new(vec![]);
+        let on_key = |key: Key, view: &mut PromptStateView<'_>| {
+            seen.borrow_mut()
+                .push((view.input().map(str::to_owned), view.highlighted()));
+            match key {
+                Key::F(5, _) => {
+                    view.submit();
+                    Handled::Yes
+                }
+                Key::Down(_) => Handled::Yes,
+                _ => Handled::No,
+            }
+        };
+
+        let mut backend = fake_backend(vec![
+            Key::Char('2', KeyModifiers::NONE),
+            Key::Down(KeyModifiers::NONE),
+            Key::F(5, KeyModifiers::NONE),
+        ]);
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_key_handler(&on_key)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, 2), ans);
+        assert_eq!(
+            vec![
+                (Some(String::new()), Some(0)),
+                (Some("2".to_owned()), Some(1)),
+                (Some("2".to_owned()), Some(1)),
+            ],
+            seen.into_inner()
+        );
+    }
+
+    #[test]
+    fn unbound_keys_are_ignored() {
+        let mut backend = fake_backend(vec![Key::Char('c', KeyModifiers::CONTROL), Key::Escape]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_key_bindings(KeyBindings::new().unbind(Key::Char('c', KeyModifiers::CONTROL)))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn escape_clears_the_filter_before_canceling() {
+        let mut backend = fake_backend(vec![
+            Key::Char('3', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Down(KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_escape_behavior(EscapeBehavior::ClearInput)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, 2), ans);
+
+        let mut backend = fake_backend(vec![
+            Key::Char('3', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Escape,
+        ]);
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_escape_behavior(EscapeBehavior::ClearInput)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn escape_can_be_ignored() {
+        let mut backend = fake_backend(vec![Key::Escape, Key::Enter]);
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_escape_behavior(EscapeBehavior::Ignore)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(0, 1), ans);
+    }
+
+    #[test]
+    fn cancel_guard_requires_pressing_escape_twice() {
+        let mut backend = fake_backend(vec![
+            Key::Char('a', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Char('b', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Enter,
+        ]);
+
+        let ans = Text::new("Question")
+            .with_cancel_guard(CancelGuard::PressTwice(Duration::from_secs(60)))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("ab", ans);
+
+        let mut backend = fake_backend(vec![
+            Key::Char('a', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Escape,
+        ]);
+
+        let ans = Text::new("Question")
+            .with_cancel_guard(CancelGuard::PressTwice(Duration::from_secs(60)))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn cancel_guard_asks_for_confirmation_once_options_are_checked() {
+        let space = Key::Char(' ', KeyModifiers::NONE);
+        let mut backend = fake_backend(vec![
+            space,
+            Key::Escape,
+            Key::Char('n', KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_cancel_guard(CancelGuard::Confirm)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, 1)], ans);
+
+        let mut backend =
+            fake_backend(vec![space, Key::Escape, Key::Char('y', KeyModifiers::NONE)]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_cancel_guard(CancelGuard::Confirm)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+
+        let mut backend = fake_backend(vec![Key::Escape]);
+
+        let ans = MultiSelect::new("Question", vec![1, 2, 3])
+            .with_cancel_guard(CancelGuard::Confirm)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+}
@@ -10,10 +10,14 @@
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
-//! - [`Password`] for secretive text prompts.
+//! - [`Password`] for secretive text prompts;
+//! - [`TypedConfirm`] to guard destructive operations behind typing an exact phrase;
+//! - [`Amount`] to enter amounts of money with a fixed number of decimal places.
 //!
 //! \* The Editor and DateSelect prompts are available by enabling the `editor` and `date` features, respectively.
 //!
+//! Prompts can also be chained into a multi-step [`Form`], where the user can go back to edit earlier answers.
+//!
 //! Check out the [GitHub repository](https://github.com/mikaelmello/inquire) to see demos of what you can do with `inquire`.
 //!
 //! # Features
@@ -60,31 +64,66 @@
 //! [`Confirm`]: crate::Confirm
 //! [`CustomType`]: crate::CustomType
 //! [`Password`]: crate::Password
+//! [`TypedConfirm`]: crate::TypedConfirm
+//! [`Amount`]: crate::Amount
 //! [`Editor`]: crate::Editor
+//! [`Form`]: crate::Form
 
 #![warn(missing_docs)]
 #![deny(unused_crate_dependencies)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::bool_to_int_with_if)]
+
+// dev-dependencies only used by the tests of the `serde` feature
+#[cfg(all(test, not(feature = "serde")))]
+use {serde as _, serde_json as _};
+
 mod ansi;
+pub mod answer_source;
 pub mod autocompletion;
+mod cancellation;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
 pub mod error;
+pub mod event;
+pub mod feedback;
 pub mod formatter;
+pub mod history;
 mod input;
+#[cfg(feature = "instrument")]
+#[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+pub mod instrument;
+pub mod key_bindings;
 pub mod list_option;
 pub mod parser;
 mod prompts;
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+pub mod ratatui;
 mod terminal;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+pub mod transcript;
+pub mod transliteration;
 pub mod type_aliases;
 pub mod ui;
 mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::cancellation::CancellationToken;
+pub use crate::config::{
+    clear_global_answer_source, clear_global_backend, clear_global_feedback_hook,
+    set_global_answer_source, set_global_backend, set_global_feedback_hook,
+    set_global_key_bindings, set_global_non_interactive_behavior, set_global_output_stream,
+    set_global_render_config, set_global_vim_mode, start_global_transcript, take_global_transcript,
+    NonInteractiveBehavior, OutputStream,
+};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize::Zeroizing;
@@ -5,6 +5,11 @@ use std::fmt;
 
 /// Represents a selection made by the user when prompted to select one or several
 /// options among those presented.
+///
+/// The index always refers to the position of the option in the list passed
+/// to the prompt, regardless of how the options were filtered or sorted for
+/// display. Options with equal values are told apart by their index, so it can
+/// be safely used to look up the source data of the selected option.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ListOption<T> {
     /// Index of the selected option relative to the original (full) list passed to the prompt.
@@ -6,6 +6,8 @@
 //! a successful result ([Ok]) containing the parsed value or an empty [Err]
 //! if a value could not be parsed.
 
+use std::str::FromStr;
+
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
 /// [`BoolParser`]s receive the user input to a given prompt and return either
@@ -67,6 +69,173 @@ pub const DEFAULT_BOOL_PARSER: BoolParser<'_> = &|ans| {
     }
 };
 
+/// Parses whole numbers, optionally grouped with thousands separators: `,`,
+/// `_` or spaces, e.g. `1,234,567`. Used by [`CustomType::integer`](crate::CustomType::integer).
+///
+/// Groups after the first one must have exactly three digits, so that typos
+/// such as `1,2345` are rejected rather than silently accepted.
+pub(crate) fn parse_grouped_integer<T: FromStr>(input: &str) -> Result<T, ()> {
+    parse_grouped_digits(input.trim(), [',', '_', ' '])?
+        .parse::<T>()
+        .map_err(|_e| ())
+}
+
+/// Parser of decimal numbers written with a decimal comma, as in most of
+/// continental Europe and South America, e.g. `3,14` or `1.234,5`. Dots and
+/// spaces may group thousands. Used by [`CustomType::decimal_comma`](crate::CustomType::decimal_comma).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::DECIMAL_COMMA_PARSER;
+///
+/// assert_eq!(Ok(3.14), DECIMAL_COMMA_PARSER("3,14"));
+/// assert_eq!(Ok(1234.5), DECIMAL_COMMA_PARSER("1.234,5"));
+/// assert_eq!(Ok(-2.0), DECIMAL_COMMA_PARSER("-2"));
+/// assert_eq!(Err(()), DECIMAL_COMMA_PARSER("3.14"));
+/// ```
+pub const DECIMAL_COMMA_PARSER: CustomTypeParser<'_, f64> = &|input| {
+    let input = input.trim();
+    let (integer, fraction) = match input.split_once(',') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (input, None),
+    };
+
+    let integer = parse_grouped_digits(integer, ['.', ' '])?;
+    let number = match fraction {
+        Some(fraction) if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{integer}.{fraction}")
+        }
+        Some(_) => return Err(()),
+        None => integer,
+    };
+
+    number.parse::<f64>().map_err(|_e| ())
+};
+
+/// Parser of percentages, with or without a trailing `%`, e.g. `42%` or
+/// `12.5`. The number of percents is returned, e.g. `42.0` for `42%`. Used
+/// by [`CustomType::percentage`](crate::CustomType::percentage).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::PERCENTAGE_PARSER;
+///
+/// assert_eq!(Ok(42.0), PERCENTAGE_PARSER("42%"));
+/// assert_eq!(Ok(12.5), PERCENTAGE_PARSER("12.5 %"));
+/// assert_eq!(Ok(7.0), PERCENTAGE_PARSER("7"));
+/// assert_eq!(Err(()), PERCENTAGE_PARSER("%"));
+/// ```
+pub const PERCENTAGE_PARSER: CustomTypeParser<'_, f64> = &|input| {
+    let input = input.trim();
+    let number = input.strip_suffix('%').unwrap_or(input).trim_end();
+
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(()),
+    }
+};
+
+/// Parser of email addresses, checking that they have a single `@`, a
+/// non-empty local part and a domain with at least two labels. This is not
+/// a full validation of RFC 5322, which accepts many addresses no one uses.
+/// Used by [`CustomType::email`](crate::CustomType::email).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::EMAIL_PARSER;
+///
+/// assert_eq!(Ok(String::from("jane@example.com")), EMAIL_PARSER(" jane@example.com "));
+/// assert_eq!(Err(()), EMAIL_PARSER("jane@localhost"));
+/// assert_eq!(Err(()), EMAIL_PARSER("jane doe@example.com"));
+/// ```
+pub const EMAIL_PARSER: CustomTypeParser<'_, String> = &|input| {
+    let input = input.trim();
+    let Some((local, domain)) = input.split_once('@') else {
+        return Err(());
+    };
+
+    let valid_domain = domain.contains('.')
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+        && domain
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '.');
+    let valid_local = !local.is_empty()
+        && !local.contains(char::is_whitespace)
+        && !local.contains(['@', '"', '(', ')', ',', ':', ';', '<', '>', '[', ']', '\\']);
+
+    match valid_local && valid_domain {
+        true => Ok(input.to_owned()),
+        false => Err(()),
+    }
+};
+
+/// Parser of semantic versions, e.g. `1.2.3` or `v2.0.0-rc.1`. A leading `v`
+/// is ignored. Used by [`CustomType::semver`](crate::CustomType::semver).
+#[cfg(feature = "semver")]
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+pub const SEMVER_PARSER: CustomTypeParser<'_, semver::Version> = &|input| {
+    let input = input.trim();
+    let input = input.strip_prefix(['v', 'V']).unwrap_or(input);
+
+    semver::Version::parse(input).map_err(|_e| ())
+};
+
+/// Parser of UUIDs, in any of the formats accepted by [`uuid::Uuid::parse_str`],
+/// e.g. hyphenated or braced. Used by [`CustomType::uuid`](crate::CustomType::uuid).
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub const UUID_PARSER: CustomTypeParser<'_, uuid::Uuid> =
+    &|input| uuid::Uuid::parse_str(input.trim()).map_err(|_e| ());
+
+/// Parser of absolute URLs, e.g. `https://example.com/path`. Used by
+/// [`CustomType::url`](crate::CustomType::url).
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub const URL_PARSER: CustomTypeParser<'_, url::Url> =
+    &|input| url::Url::parse(input.trim()).map_err(|_e| ());
+
+/// Removes the thousands separators of a signed or unsigned integer,
+/// checking that every group after the first one has three digits.
+fn parse_grouped_digits<const N: usize>(input: &str, separators: [char; N]) -> Result<String, ()> {
+    let (sign, digits) = match input.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let mut groups = digits.split(separators);
+    let first = groups.next().unwrap_or_default();
+    let mut ungrouped = format!("{sign}{first}");
+
+    let mut grouped = false;
+    for group in groups {
+        if group.len() != 3 {
+            return Err(());
+        }
+        grouped = true;
+        ungrouped.push_str(group);
+    }
+
+    let valid_first = match grouped {
+        true => (1..=3).contains(&first.len()),
+        false => !first.is_empty(),
+    };
+    match valid_first
+        && ungrouped
+            .get(sign.len()..)
+            .unwrap_or_default()
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+    {
+        true => Ok(ungrouped),
+        false => Err(()),
+    }
+}
+
 #[macro_export]
 #[cfg(feature = "macros")]
 /// Built-in parser creator that checks whether the answer is able to be successfully
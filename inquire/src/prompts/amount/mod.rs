@@ -0,0 +1,305 @@
+mod action;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    key_bindings::KeyBindings,
+    prompts::prompt::Prompt,
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{prompt_backend, CustomTypeBackend, RenderConfig},
+    validator::CustomTypeValidator,
+};
+
+use self::prompt::AmountPrompt;
+
+/// Prompt for amounts of money, entered with a fixed number of decimal
+/// places and returned as an integer number of minor units, e.g. cents, so
+/// that no precision is lost to floating-point rounding.
+///
+/// While the user types, the amount is displayed with the currency symbol and
+/// thousands separators, e.g. typing `1234.5` displays `$1,234.5`. Only digits
+/// and a single decimal separator are accepted, and no more fractional digits
+/// than the precision allows. Besides the configured decimal separator, `.`
+/// and `,` are accepted in its place unless they are the thousands separator.
+///
+/// The submitted answer is displayed with all of its decimal places, e.g.
+/// `$1,234.50`, unless a custom formatter is set.
+///
+/// Negative amounts are not supported.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Amount;
+///
+/// let cents = Amount::new("Price:")
+///     .with_currency_symbol("$")
+///     .with_default(9_99)
+///     .with_help_message("Up to two decimal places")
+///     .prompt()?;
+///
+/// println!("Charging {} cents", cents);
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct Amount<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Default amount, in minor units, returned when the user submits an
+    /// empty input.
+    pub default: Option<u64>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Symbol displayed before the amount, e.g. `$`.
+    pub currency_symbol: Option<&'a str>,
+
+    /// Number of decimal places, i.e. how many minor units make a major unit
+    /// as a power of ten. Defaults to 2.
+    pub precision: u32,
+
+    /// Separator displayed between the major and minor units.
+    pub decimal_separator: char,
+
+    /// Separator displayed between groups of thousands.
+    pub thousands_separator: char,
+
+    /// Function that formats the answer, in minor units, to be presented to
+    /// the user as the final rendering of the prompt. When `None`, the amount
+    /// is displayed with the currency symbol and all of its decimal places.
+    pub formatter: Option<CustomTypeFormatter<'a, u64>>,
+
+    /// Collection of validators to apply to the answer, in minor units.
+    pub validators: Vec<Box<dyn CustomTypeValidator<u64>>>,
+
+    /// Error message displayed when the input is not a valid amount.
+    pub error_message: String,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
+    pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<AmountPromptAction>,
+}
+
+impl<'a> Amount<'a> {
+    /// Default number of decimal places.
+    pub const DEFAULT_PRECISION: u32 = 2;
+
+    /// Default decimal separator.
+    pub const DEFAULT_DECIMAL_SEPARATOR: char = '.';
+
+    /// Default thousands separator.
+    pub const DEFAULT_THOUSANDS_SEPARATOR: char = ',';
+
+    /// Default error message displayed when the input is not a valid amount.
+    pub const DEFAULT_ERROR_MESSAGE: &'static str = "Please type a valid amount";
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Default validators added to the [Amount] prompt, none.
+    pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<u64>>> = vec![];
+
+    /// Creates an [Amount] with the provided message and default options.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            currency_symbol: None,
+            precision: Self::DEFAULT_PRECISION,
+            decimal_separator: Self::DEFAULT_DECIMAL_SEPARATOR,
+            thousands_separator: Self::DEFAULT_THOUSANDS_SEPARATOR,
+            formatter: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
+        }
+    }
+
+    /// Sets the default amount, in minor units.
+    pub fn with_default(mut self, default: u64) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the currency symbol displayed before the amount.
+    pub fn with_currency_symbol(mut self, symbol: &'a str) -> Self {
+        self.currency_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets the number of decimal places, e.g. 0 for currencies without minor
+    /// units, such as the Japanese yen, or 3 for the Kuwaiti dinar.
+    pub fn with_precision(mut self, precision: u32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the decimal and thousands separators, e.g. `','` and `'.'` for
+    /// `1.234,50`.
+    pub fn with_separators(mut self, decimal: char, thousands: char) -> Self {
+        self.decimal_separator = decimal;
+        self.thousands_separator = thousands;
+        self
+    }
+
+    /// Sets the formatter of the submitted answer, in minor units.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, u64>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to limit the amount, e.g. to the balance of an account.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: CustomTypeValidator<u64> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<u64>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
+        self
+    }
+
+    /// Sets the error message displayed when the input is not a valid amount.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<AmountPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<u64>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// The answer is an amount in minor units, e.g. `123450` for `1,234.50`
+    /// with a precision of 2.
+    pub fn prompt(self) -> InquireResult<u64> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Async version of [prompt](Self::prompt), awaiting the keys pressed by
+    /// the user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<u64> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        crate::prompts::prompt_async::prompt_async(AmountPrompt::from(self), &mut backend).await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the amount typed by the user. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, u64>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            AmountPrompt::from(self),
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
+    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<u64> {
+        AmountPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,310 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    error::InquireResult,
+    formatter::CustomTypeFormatter,
+    input::Input,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::CustomTypeBackend,
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    Amount, InputAction, InquireError,
+};
+
+use super::action::AmountPromptAction;
+
+/// Prompt state. The input holds the amount as typed, made of digits and at
+/// most one `.` standing for the decimal separator, e.g. `1234.5`, while the
+/// separators and currency symbol are only added when rendering.
+pub struct AmountPrompt<'a> {
+    message: &'a str,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<u64>,
+    currency_symbol: Option<&'a str>,
+    precision: u32,
+    decimal_separator: char,
+    thousands_separator: char,
+    formatter: Option<CustomTypeFormatter<'a, u64>>,
+    validators: Vec<Box<dyn CustomTypeValidator<u64>>>,
+    error_message: String,
+    input: Input,
+    key_bindings: KeyBindings<AmountPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
+}
+
+impl<'a> From<Amount<'a>> for AmountPrompt<'a> {
+    fn from(am: Amount<'a>) -> Self {
+        Self {
+            message: am.message,
+            error: None,
+            help_message: am.help_message,
+            default: am.default,
+            currency_symbol: am.currency_symbol,
+            precision: am.precision,
+            decimal_separator: am.decimal_separator,
+            thousands_separator: am.thousands_separator,
+            formatter: am.formatter,
+            validators: am.validators,
+            error_message: am.error_message,
+            input: Input::new(),
+            key_bindings: am.key_bindings,
+            key_handler: am.settings.key_handler,
+            escape_behavior: am.settings.escape_behavior,
+            cancel_guard: am.settings.cancel_guard,
+        }
+    }
+}
+
+impl<'a> AmountPrompt<'a> {
+    fn validate_current_answer(&self, value: &u64) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Displays an amount as typed, e.g. `1234.5` as `$1,234.5`, returning
+    /// the grapheme index in the display matching the provided one in the
+    /// typed amount.
+    fn display(&self, typed: &str, cursor: usize) -> (String, usize) {
+        let integer_len = typed.find('.').unwrap_or(typed.len());
+
+        let mut display = String::from(self.currency_symbol.unwrap_or_default());
+        let mut len = display.graphemes(true).count();
+        let mut display_cursor = None;
+
+        for (i, c) in typed.chars().enumerate() {
+            if i > 0 && i < integer_len && (integer_len - i) % 3 == 0 {
+                display.push(self.thousands_separator);
+                len += 1;
+            }
+            if i == cursor {
+                display_cursor = Some(len);
+            }
+
+            display.push(match c {
+                '.' => self.decimal_separator,
+                c => c,
+            });
+            len += 1;
+        }
+
+        (display, display_cursor.unwrap_or(len))
+    }
+
+    /// Displays an amount in minor units with all of its decimal places.
+    fn format_amount(&self, amount: u64) -> String {
+        let typed = match 10u64.checked_pow(self.precision) {
+            Some(scale) if self.precision > 0 => format!(
+                "{}.{:0>width$}",
+                amount / scale,
+                amount % scale,
+                width = self.precision as usize
+            ),
+            _ => amount.to_string(),
+        };
+
+        self.display(&typed, 0).0
+    }
+
+    fn format_answer_value(&self, amount: u64) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(amount),
+            None => self.format_amount(amount),
+        }
+    }
+
+    /// Parses the typed amount into minor units, returning `None` when it is
+    /// malformed or too large.
+    fn parse(&self, typed: &str) -> Option<u64> {
+        let (integer, fraction) = typed.split_once('.').unwrap_or((typed, ""));
+
+        let malformed = (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > self.precision as usize
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit());
+        if malformed {
+            return None;
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<u64>().ok()?,
+        };
+        let fraction = match self.precision {
+            0 => 0,
+            precision => format!("{:0<width$}", fraction, width = precision as usize)
+                .parse::<u64>()
+                .ok()?,
+        };
+
+        integer
+            .checked_mul(10u64.checked_pow(self.precision)?)?
+            .checked_add(fraction)
+    }
+
+    fn get_final_answer(&self) -> Result<u64, String> {
+        match self.default {
+            Some(val) if self.input.is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        self.parse(self.input.content())
+            .ok_or_else(|| self.error_message.clone())
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for AmountPrompt<'a>
+where
+    Backend: CustomTypeBackend,
+{
+    type Config = ();
+    type InnerAction = AmountPromptAction;
+    type Output = u64;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &() {
+        &()
+    }
+
+    fn key_bindings(&self) -> Option<&KeyBindings<AmountPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(self, AmountPromptAction::ValueInput(InputAction::Clear))
+    }
+
+    fn format_answer(&self, answer: &u64) -> String {
+        self.format_answer_value(*answer)
+    }
+
+    fn partial_input(&self) -> Option<&str> {
+        Some(self.input.content())
+    }
+
+    /// Accepts amounts as displayed, e.g. `$1,234.50`, or as typed, e.g. `1234.50`.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        let answer = answer.trim();
+        let answer = match self.currency_symbol.map(str::trim) {
+            Some(symbol) if !symbol.is_empty() => answer.strip_prefix(symbol).unwrap_or(answer),
+            _ => answer,
+        };
+
+        let typed: String = answer
+            .trim()
+            .chars()
+            .filter(|c| *c != self.thousands_separator)
+            .map(|c| match c == self.decimal_separator {
+                true => '.',
+                false => c,
+            })
+            .collect();
+
+        self.input = Input::new_with(typed);
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<u64>> {
+        let answer = match self.get_final_answer() {
+            Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid => Some(answer),
+                Validation::Invalid(msg) => {
+                    self.error = Some(msg);
+                    None
+                }
+            },
+            Err(message) => {
+                self.error = Some(message.into());
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: AmountPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            AmountPromptAction::ValueInput(input_action) => {
+                let len = self.input.content().len();
+                let cursor = self.input.cursor();
+                let dot = self.input.content().find('.');
+                let precision = self.precision as usize;
+                let (decimal, thousands) = (self.decimal_separator, self.thousands_separator);
+
+                let filter = |c: char| {
+                    let is_separator = c == decimal || (matches!(c, '.' | ',') && c != thousands);
+
+                    match dot {
+                        _ if c.is_ascii_digit() => match dot {
+                            Some(dot) if cursor > dot && len - dot > precision => None,
+                            _ => Some(c),
+                        },
+                        None if is_separator && precision > 0 && len - cursor <= precision => {
+                            Some('.')
+                        }
+                        _ => None,
+                    }
+                };
+
+                self.input.handle_filtered(input_action, &filter).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(|val| self.format_answer_value(val));
+
+        let input = match (self.input.is_empty(), self.default) {
+            (true, Some(_)) => Input::new(),
+            (true, None) => Input::new().with_placeholder(&self.format_amount(0)),
+            (false, _) => {
+                let (display, cursor) = self.display(self.input.content(), self.input.cursor());
+                Input::new_with(display).with_cursor(cursor)
+            }
+        };
+
+        backend.render_prompt(self.message, default_message.as_deref(), &input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
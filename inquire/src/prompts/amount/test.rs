@@ -0,0 +1,117 @@
+use crate::{
+    ui::{
+        test::{FakeBackend, Token},
+        Key,
+    },
+    validator::Validation,
+    Amount,
+};
+
+fn answer(prompt: Amount<'_>, keys: Vec<Key>) -> (u64, FakeBackend) {
+    let mut backend = FakeBackend::new(keys);
+    let answer = prompt.prompt_with_backend(&mut backend).unwrap();
+    (answer, backend)
+}
+
+fn typed(input: &str) -> Vec<Key> {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+    keys
+}
+
+fn answered(backend: &FakeBackend, formatted: &str) -> bool {
+    backend.frames().last().is_some_and(|frame| {
+        frame.has_token(&Token::AnsweredPrompt("Price:".into(), formatted.into()))
+    })
+}
+
+#[test]
+fn amount_is_returned_in_minor_units() {
+    let (cents, backend) = answer(
+        Amount::new("Price:").with_currency_symbol("$"),
+        typed("1234.5"),
+    );
+
+    assert_eq!(123_450, cents);
+    assert!(answered(&backend, "$1,234.50"));
+}
+
+#[test]
+fn thousands_separators_are_rendered_while_typing() {
+    let (_, backend) = answer(
+        Amount::new("Price:").with_currency_symbol("$"),
+        typed("1234567"),
+    );
+
+    let rendered = backend.frames().iter().any(|frame| {
+        frame
+            .tokens()
+            .iter()
+            .any(|token| matches!(token, Token::Input(input) if input.content() == "$1,234,567"))
+    });
+    assert!(rendered);
+}
+
+#[test]
+fn extra_decimal_places_and_letters_are_ignored() {
+    let (cents, _) = answer(Amount::new("Price:"), typed("12.3456ab.7"));
+
+    assert_eq!(1_234, cents);
+}
+
+#[test]
+fn separators_and_precision_are_configurable() {
+    let (minor, backend) = answer(
+        Amount::new("Price:")
+            .with_currency_symbol("€ ")
+            .with_separators(',', '.')
+            .with_precision(3),
+        typed("1234,5"),
+    );
+
+    assert_eq!(1_234_500, minor);
+    assert!(answered(&backend, "€ 1.234,500"));
+
+    let (yen, backend) = answer(Amount::new("Price:").with_precision(0), typed("1500.5"));
+
+    assert_eq!(15_005, yen);
+    assert!(answered(&backend, "15,005"));
+}
+
+#[test]
+fn empty_input_submits_default() {
+    let (cents, _) = answer(Amount::new("Price:").with_default(999), typed(""));
+
+    assert_eq!(999, cents);
+}
+
+#[test]
+fn empty_input_without_default_and_overflow_are_rejected() {
+    let mut keys = typed("");
+    keys.append(&mut typed("99999999999999999999"));
+    keys.push(Key::Escape);
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Amount::new("Price:").prompt_with_backend(&mut backend);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn validators_receive_minor_units() {
+    let mut keys = typed("100.01");
+    for _ in 0..6 {
+        keys.push(Key::Backspace);
+    }
+    keys.append(&mut typed("100"));
+
+    let (cents, _) = answer(
+        Amount::new("Price:").with_validator(|cents: &u64| match *cents <= 10_000 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Too expensive".into())),
+        }),
+        keys,
+    );
+
+    assert_eq!(10_000, cents);
+}
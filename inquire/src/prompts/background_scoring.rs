@@ -0,0 +1,205 @@
+//! Scoring of list options on a worker thread.
+
+use std::{
+    fmt::Debug,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Thread-safe scorer run by [`BackgroundScoring`].
+///
+/// It receives the filter input, the string value of the option and its
+/// index in the original list, and returns the score of the option, or `None`
+/// if it does not match the filter.
+pub type BackgroundScorer = Arc<dyn Fn(&str, &str, usize) -> Option<i64> + Send + Sync>;
+
+/// Settings to score the options of list prompts on a worker thread.
+///
+/// By default, options are scored synchronously on every key press, which
+/// blocks input when the scorer is expensive or the list is very large. With
+/// background scoring, the filter is sent to a worker thread once the user
+/// stops typing for the configured debounce duration. The current list stays
+/// on screen, along with a "filtering…" indicator, until the results arrive.
+///
+/// Since the scorer runs on another thread, it only has access to the string
+/// values of the options, which are computed once when the prompt starts.
+///
+/// Updating the list as results arrive requires an input reader that supports
+/// waiting for key events with a timeout, which is the case of the crossterm
+/// backend. With other backends, results are picked up on the next key press.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::{BackgroundScoring, Select};
+///
+/// let scoring = BackgroundScoring::new(|input, value, _idx| {
+///     value.contains(input).then_some(0)
+/// })
+/// .with_debounce(Duration::from_millis(100));
+///
+/// let select = Select::new("Package:", vec!["serde", "serde_json", "tokio"])
+///     .with_background_scoring(scoring);
+/// ```
+#[derive(Clone)]
+pub struct BackgroundScoring {
+    /// Scorer run on the worker thread.
+    pub scorer: BackgroundScorer,
+
+    /// Time to wait after the last change to the filter before scoring.
+    pub debounce: Duration,
+}
+
+impl BackgroundScoring {
+    /// Default debounce duration, 50 milliseconds.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+    /// Creates background scoring settings with the provided scorer and the
+    /// default debounce duration.
+    pub fn new<F>(scorer: F) -> Self
+    where
+        F: Fn(&str, &str, usize) -> Option<i64> + Send + Sync + 'static,
+    {
+        Self {
+            scorer: Arc::new(scorer),
+            debounce: Self::DEFAULT_DEBOUNCE,
+        }
+    }
+
+    /// Sets the time to wait after the last change to the filter before scoring.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+}
+
+impl Debug for BackgroundScoring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundScoring")
+            .field("debounce", &self.debounce)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Help message rendered while results of the worker are pending.
+pub(crate) const FILTERING_MESSAGE: &str = "filtering…";
+
+/// Interval at which prompts check for results of the worker.
+pub(crate) const TICK_INTERVAL: Duration = Duration::from_millis(15);
+
+type Request = (u64, String);
+type Response = (u64, Vec<(usize, i64)>);
+
+/// Handle to the worker thread of a prompt with background scoring.
+///
+/// Requests are tagged with a generation number, so that results for filters
+/// that have since changed are discarded. The thread exits once the handle is
+/// dropped.
+pub(crate) struct ScoringWorker {
+    requests: Sender<Request>,
+    responses: Receiver<Response>,
+    debounce: Duration,
+    generation: u64,
+    pending: Option<(String, Instant)>,
+    in_flight: bool,
+}
+
+impl ScoringWorker {
+    /// Spawns a worker scoring the provided values. When transliterated
+    /// values are provided, options get the best score of both.
+    pub fn spawn(
+        scoring: &BackgroundScoring,
+        values: Vec<String>,
+        transliterated: Option<Vec<String>>,
+    ) -> Self {
+        let (requests, request_rx) = mpsc::channel::<Request>();
+        let (response_tx, responses) = mpsc::channel::<Response>();
+        let scorer = scoring.scorer.clone();
+
+        thread::spawn(move || {
+            while let Ok(mut request) = request_rx.recv() {
+                // Only the latest queued filter is relevant.
+                while let Ok(next) = request_rx.try_recv() {
+                    request = next;
+                }
+
+                let (generation, filter) = request;
+                let scores = values
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, value)| {
+                        let score = scorer(&filter, value, i);
+                        let transliterated_score = transliterated
+                            .as_ref()
+                            .and_then(|t| t.get(i))
+                            .and_then(|value| scorer(&filter, value, i));
+
+                        score.max(transliterated_score).map(|score| (i, score))
+                    })
+                    .collect();
+
+                if response_tx.send((generation, scores)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            requests,
+            responses,
+            debounce: scoring.debounce,
+            generation: 0,
+            pending: None,
+            in_flight: false,
+        }
+    }
+
+    /// Schedules the filter to be scored once the debounce elapses,
+    /// replacing any previously scheduled one.
+    pub fn request(&mut self, filter: String) {
+        self.pending = Some((filter, Instant::now()));
+    }
+
+    /// Discards scheduled filters and results of in-flight ones.
+    pub fn cancel(&mut self) {
+        self.generation += 1;
+        self.pending = None;
+        self.in_flight = false;
+    }
+
+    /// Whether there is a filter whose results haven't been received yet.
+    pub fn is_busy(&self) -> bool {
+        self.pending.is_some() || self.in_flight
+    }
+
+    /// Sends the scheduled filter to the worker if the debounce elapsed,
+    /// and returns the scores of the latest filter if they arrived.
+    pub fn poll(&mut self) -> Option<Vec<(usize, i64)>> {
+        let debounced = match &self.pending {
+            Some((_, since)) => since.elapsed() >= self.debounce,
+            None => false,
+        };
+
+        if debounced {
+            if let Some((filter, _)) = self.pending.take() {
+                self.generation += 1;
+                self.in_flight = self.requests.send((self.generation, filter)).is_ok();
+            }
+        }
+
+        let mut latest = None;
+        while let Ok((generation, scores)) = self.responses.try_recv() {
+            if generation == self.generation && self.pending.is_none() {
+                self.in_flight = false;
+                latest = Some(scores);
+            }
+        }
+
+        latest
+    }
+}
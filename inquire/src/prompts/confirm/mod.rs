@@ -1,19 +1,48 @@
 mod action;
 #[cfg(test)]
 mod test;
+mod vocabulary;
 
 pub use action::*;
+pub use vocabulary::ConfirmVocabulary;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
+    key_bindings::KeyBindings,
     parser::{BoolParser, DEFAULT_BOOL_PARSER},
-    terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{prompt_backend, CustomTypeBackend, RenderConfig},
     CustomType,
 };
 
+/// Answer of a [Confirm] prompt that can also be cancelled, returned by
+/// [`Confirm::prompt_with_cancel`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfirmAnswer {
+    /// The user answered yes.
+    Yes,
+
+    /// The user answered no.
+    No,
+
+    /// The user chose to cancel, either by typing `c`/`cancel` or by
+    /// pressing `ESC`.
+    Cancel,
+}
+
+impl From<bool> for ConfirmAnswer {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::Yes,
+            false => Self::No,
+        }
+    }
+}
+
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
 ///
 /// This prompt is basically a wrapper around the behavior of `CustomType` prompts, providing a sensible set of defaults to ask for simple `true/false` questions, such as confirming an action.
@@ -23,6 +52,8 @@ use crate::{
 ///
 /// Finally, once the answer is submitted, [`Confirm`] prompts display the bool value formatted as either "Yes", if a `true` value was parsed, or "No" otherwise.
 ///
+/// To tell apart aborting the whole operation from answering no, use [`Confirm::prompt_with_cancel`], which returns a [`ConfirmAnswer`] instead of a `bool`.
+///
 /// The Confirm prompt does not support custom validators because of the nature of the prompt. The user input is always parsed to true or false. If one of the two alternatives is invalid, a Confirm prompt that only allows yes or no answers does not make a lot of sense to me, but if someone provides a clear use-case I will reconsider.
 ///
 /// Confirm prompts provide several options of configuration:
@@ -39,6 +70,8 @@ use crate::{
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
 /// - **Error message**: Error message to display when a value could not be parsed from the input.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Vocabulary**: Accepted inputs and displayed labels for other languages, see [`ConfirmVocabulary`].
+///   - When set, the parser, formatter and default value formatter are derived from it.
 ///
 /// # Example
 ///
@@ -91,15 +124,25 @@ pub struct Confirm<'a> {
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Accepted inputs and displayed labels of the prompt. When set, it takes
+    /// precedence over the parser, the formatter and the default value
+    /// formatter.
+    pub vocabulary: Option<ConfirmVocabulary<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<ConfirmPromptAction>,
 }
 
 impl<'a> Confirm<'a> {
@@ -120,6 +163,15 @@ impl<'a> Confirm<'a> {
     pub const DEFAULT_ERROR_MESSAGE: &'a str =
         "Invalid answer, try typing 'y' for yes or 'n' for no";
 
+    /// Default error message displayed when parsing fails in prompts started
+    /// with [`Confirm::prompt_with_cancel`].
+    pub const DEFAULT_CANCEL_ERROR_MESSAGE: &'a str =
+        "Invalid answer, try typing 'y' for yes, 'n' for no or 'c' to cancel";
+
+    /// Formatted answer displayed when the user cancels a prompt started with
+    /// [`Confirm::prompt_with_cancel`].
+    pub const DEFAULT_CANCEL_FORMATTED: &'a str = "Cancel";
+
     /// Creates a [Confirm] with the provided message and default configuration values.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -132,7 +184,10 @@ impl<'a> Confirm<'a> {
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            vocabulary: None,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -188,19 +243,47 @@ impl<'a> Confirm<'a> {
         self
     }
 
-    /// Sets the provided color theme to this prompt.
+    /// Sets the vocabulary from which the parser, the formatter and the
+    /// default value formatter are derived, e.g. to accept `j/n` in German.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
+    /// The error message is not derived from the vocabulary and should be
+    /// translated with [`Confirm::with_error_message`].
+    pub fn with_vocabulary(mut self, vocabulary: ConfirmVocabulary<'a>) -> Self {
+        self.vocabulary = Some(vocabulary);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
     ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<ConfirmPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -221,16 +304,168 @@ impl<'a> Confirm<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<bool> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Prompts the CLI user for a yes/no answer that can also be cancelled,
+    /// distinguishing "abort the whole operation" from "answer no" without
+    /// matching on [`InquireError::OperationCanceled`].
+    ///
+    /// Besides the inputs accepted by the parser, typing `c` or `cancel`
+    /// (case-insensitive) or pressing `ESC` returns [`ConfirmAnswer::Cancel`].
+    /// The default value is displayed with a trailing `/c`, e.g. `(Y/n/c)`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{Confirm, ConfirmAnswer};
+    ///
+    /// match Confirm::new("Overwrite existing files?").prompt_with_cancel()? {
+    ///     ConfirmAnswer::Yes => println!("Overwriting"),
+    ///     ConfirmAnswer::No => println!("Skipping existing files"),
+    ///     ConfirmAnswer::Cancel => return Ok(()),
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_cancel(self) -> InquireResult<ConfirmAnswer> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        self.prompt_with_cancel_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_cancel_with_backend<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ConfirmAnswer> {
+        self.with_vocabulary_applied(|prompt| prompt.prompt_tri_state(backend))
+    }
+
+    fn prompt_tri_state<B: CustomTypeBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ConfirmAnswer> {
+        let bool_parser = self.parser;
+        let parser = |input: &str| match input.to_lowercase().as_str() {
+            "c" | "cancel" => Ok(ConfirmAnswer::Cancel),
+            _ => bool_parser(input).map(ConfirmAnswer::from),
+        };
+
+        let bool_formatter = self.formatter;
+        let formatter = |answer: ConfirmAnswer| match answer {
+            ConfirmAnswer::Yes => bool_formatter(true),
+            ConfirmAnswer::No => bool_formatter(false),
+            ConfirmAnswer::Cancel => String::from(Self::DEFAULT_CANCEL_FORMATTED),
+        };
+
+        let bool_default_value_formatter = self.default_value_formatter;
+        let default_value_formatter = |answer: ConfirmAnswer| match answer {
+            ConfirmAnswer::Yes => format!("{}/c", bool_default_value_formatter(true)),
+            ConfirmAnswer::No => format!("{}/c", bool_default_value_formatter(false)),
+            ConfirmAnswer::Cancel => String::from("y/n/C"),
+        };
+
+        let error_message = match self.error_message == Self::DEFAULT_ERROR_MESSAGE {
+            true => String::from(Self::DEFAULT_CANCEL_ERROR_MESSAGE),
+            false => self.error_message,
+        };
+
+        let prompt = CustomType {
+            message: self.message,
+            starting_input: self.starting_input,
+            default: self.default.map(ConfirmAnswer::from),
+            default_value_formatter: &default_value_formatter,
+            placeholder: self.placeholder,
+            help_message: self.help_message,
+            formatter: &formatter,
+            parser: &parser,
+            validators: vec![],
+            history: None,
+            input_filter: None,
+            input_transform: None,
+            units: None,
+            range_description: None,
+            error_message,
+            render_config: self.render_config,
+            settings: self.settings.clone(),
+            key_bindings: self.key_bindings.clone(),
+        };
+
+        match prompt.prompt_with_backend(backend) {
+            Err(InquireError::OperationCanceled) => Ok(ConfirmAnswer::Cancel),
+            answer => answer,
+        }
+    }
+
+    /// Async version of [prompt](Self::prompt), awaiting the answer of the
+    /// user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<bool> {
+        // same as with_vocabulary_applied, which can't take an async closure
+        let Some(vocabulary) = self.vocabulary else {
+            return CustomType::from(self).prompt_async().await;
+        };
+
+        let parser = |input: &str| vocabulary.parse(input).ok_or(());
+        let formatter = |answer: bool| vocabulary.format(answer).to_owned();
+        let default_value_formatter = |answer: bool| vocabulary.format_default(answer);
+
+        CustomType::from(Confirm {
+            parser: &parser,
+            formatter: &formatter,
+            default_value_formatter: &default_value_formatter,
+            vocabulary: None,
+            ..self
+        })
+        .prompt_async()
+        .await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the choice of the user. See [Text::embed](crate::Text::embed)
+    /// for more details.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [InquireError::InvalidConfiguration] when a vocabulary is
+    /// set, as it can't be embedded yet.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, bool>> {
+        if self.vocabulary.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "confirm prompts with a vocabulary can't be embedded".into(),
+            ));
+        }
+
+        CustomType::from(self).embed()
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<bool> {
-        CustomType::from(self).prompt_with_backend(backend)
+        self.with_vocabulary_applied(|prompt| CustomType::from(prompt).prompt_with_backend(backend))
+    }
+
+    /// Calls `f` with this prompt, after replacing its parser and formatters
+    /// with ones derived from the vocabulary, if set.
+    fn with_vocabulary_applied<R>(self, f: impl FnOnce(Confirm<'_>) -> R) -> R {
+        let Some(vocabulary) = self.vocabulary else {
+            return f(self);
+        };
+
+        let parser = |input: &str| vocabulary.parse(input).ok_or(());
+        let formatter = |answer: bool| vocabulary.format(answer).to_owned();
+        let default_value_formatter = |answer: bool| vocabulary.format_default(answer);
+
+        f(Confirm {
+            parser: &parser,
+            formatter: &formatter,
+            default_value_formatter: &default_value_formatter,
+            vocabulary: None,
+            ..self
+        })
     }
 }
 
@@ -252,8 +487,15 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             formatter: co.formatter,
             parser: co.parser,
             validators: vec![],
+            history: None,
+            input_filter: None,
+            input_transform: None,
+            units: None,
+            range_description: None,
             error_message: co.error_message,
             render_config: co.render_config,
+            settings: co.settings,
+            key_bindings: co.key_bindings,
         }
     }
 }
@@ -8,7 +8,7 @@ use crate::{
         test::{FakeBackend, Token},
         Key, KeyModifiers,
     },
-    Confirm, InquireError,
+    Confirm, ConfirmAnswer, ConfirmVocabulary, InquireError,
 };
 
 #[test]
@@ -548,3 +548,110 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[case("y", ConfirmAnswer::Yes)]
+#[case("No", ConfirmAnswer::No)]
+#[case("c", ConfirmAnswer::Cancel)]
+#[case("CANCEL", ConfirmAnswer::Cancel)]
+fn prompt_with_cancel_parses_tri_state_input(
+    #[case] input: &str,
+    #[case] expected_result: ConfirmAnswer,
+) -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::from("Question").prompt_with_cancel_with_backend(&mut backend)?;
+    assert_eq!(expected_result, result);
+
+    Ok(())
+}
+
+#[test]
+fn prompt_with_cancel_returns_cancel_on_escape() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('y', KeyModifiers::NONE), Key::Escape]);
+
+    let result = Confirm::from("Question").prompt_with_cancel_with_backend(&mut backend)?;
+    assert_eq!(ConfirmAnswer::Cancel, result);
+
+    Ok(())
+}
+
+#[test]
+fn prompt_with_cancel_renders_cancel_hints() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("maybe");
+    keys.push(Key::Enter);
+    keys.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::from("Question")
+        .with_default(false)
+        .prompt_with_cancel_with_backend(&mut backend)?;
+    assert_eq!(ConfirmAnswer::Cancel, result);
+
+    let rendered_frames = backend.frames();
+    assert!(rendered_frames
+        .first()
+        .is_some_and(|frame| frame.has_token(&Token::DefaultValue("y/N/c".into()))));
+    assert!(rendered_frames
+        .iter()
+        .any(|frame| frame.has_token(&Token::ErrorMessage(
+            Confirm::DEFAULT_CANCEL_ERROR_MESSAGE.into()
+        ))));
+
+    Ok(())
+}
+
+#[rstest]
+#[case("j", true)]
+#[case("JA", true)]
+#[case("nein", false)]
+fn vocabulary_is_used_to_parse_and_render(
+    #[case] input: &str,
+    #[case] expected_result: bool,
+) -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let german = ConfirmVocabulary::new("Ja", "Nein", &["j", "ja"], &["n", "nein"]);
+    let result = Confirm::from("Question")
+        .with_vocabulary(german)
+        .with_default(false)
+        .prompt_with_backend(&mut backend)?;
+    assert_eq!(expected_result, result);
+
+    let rendered_frames = backend.frames();
+    assert!(rendered_frames
+        .first()
+        .is_some_and(|frame| frame.has_token(&Token::DefaultValue("j/N".into()))));
+    assert!(rendered_frames
+        .last()
+        .is_some_and(|frame| frame.has_token(&Token::AnsweredPrompt(
+            "Question".into(),
+            german.format(expected_result).into()
+        ))));
+
+    Ok(())
+}
+
+#[test]
+fn vocabulary_rejects_english_inputs() -> InquireResult<()> {
+    let mut keys = Key::char_keys_from_str("yes");
+    keys.push(Key::Enter);
+    keys.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let spanish = ConfirmVocabulary::new("Sí", "No", &["s", "sí"], &["n", "no"]);
+    let result = Confirm::from("Question")
+        .with_vocabulary(spanish)
+        .prompt_with_backend(&mut backend);
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+
+    Ok(())
+}
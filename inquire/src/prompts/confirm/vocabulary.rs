@@ -0,0 +1,105 @@
+/// Words accepted and displayed by a [Confirm](crate::Confirm) prompt, used to
+/// ask yes/no questions in other languages.
+///
+/// The parser, the formatter of the submitted answer and the formatter of
+/// the default value are all derived from the vocabulary:
+///
+/// - Inputs are matched case-insensitively against the accepted inputs.
+/// - Answers are displayed with the `yes` and `no` labels.
+/// - Default values are displayed with the first accepted input of each
+///   answer, the default one capitalized, e.g. `J/n` or `s/N`.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Confirm, ConfirmVocabulary};
+///
+/// let german = ConfirmVocabulary::new("Ja", "Nein", &["j", "ja"], &["n", "nein"]);
+///
+/// let ans = Confirm::new("Fortfahren?")
+///     .with_vocabulary(german)
+///     .with_default(true)
+///     .with_error_message("Ungültige Antwort, bitte 'j' oder 'n' eingeben")
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConfirmVocabulary<'a> {
+    /// Label displayed when the answer is affirmative.
+    pub yes: &'a str,
+
+    /// Label displayed when the answer is negative.
+    pub no: &'a str,
+
+    /// Inputs accepted as an affirmative answer. The first one is displayed
+    /// in the default value.
+    pub yes_inputs: &'a [&'a str],
+
+    /// Inputs accepted as a negative answer. The first one is displayed in
+    /// the default value.
+    pub no_inputs: &'a [&'a str],
+}
+
+impl<'a> ConfirmVocabulary<'a> {
+    /// English vocabulary, matching the default behavior of [Confirm](crate::Confirm) prompts.
+    pub const ENGLISH: ConfirmVocabulary<'static> = ConfirmVocabulary {
+        yes: "Yes",
+        no: "No",
+        yes_inputs: &["y", "yes"],
+        no_inputs: &["n", "no"],
+    };
+
+    /// Creates a vocabulary with the provided labels and accepted inputs.
+    pub fn new(
+        yes: &'a str,
+        no: &'a str,
+        yes_inputs: &'a [&'a str],
+        no_inputs: &'a [&'a str],
+    ) -> Self {
+        Self {
+            yes,
+            no,
+            yes_inputs,
+            no_inputs,
+        }
+    }
+
+    /// Parses an input into an answer, returning `None` if it is not accepted.
+    pub fn parse(&self, input: &str) -> Option<bool> {
+        let input = input.to_lowercase();
+        let matches = |inputs: &[&str]| inputs.iter().any(|i| i.to_lowercase() == input);
+
+        if matches(self.yes_inputs) {
+            Some(true)
+        } else if matches(self.no_inputs) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Label of an answer.
+    pub fn format(&self, answer: bool) -> &'a str {
+        match answer {
+            true => self.yes,
+            false => self.no,
+        }
+    }
+
+    /// Formats a default value, e.g. `Y/n` when the default answer is affirmative.
+    pub fn format_default(&self, default: bool) -> String {
+        let yes = self.yes_inputs.first().copied().unwrap_or(self.yes);
+        let no = self.no_inputs.first().copied().unwrap_or(self.no);
+
+        match default {
+            true => format!("{}/{}", yes.to_uppercase(), no.to_lowercase()),
+            false => format!("{}/{}", yes.to_lowercase(), no.to_uppercase()),
+        }
+    }
+}
+
+impl Default for ConfirmVocabulary<'_> {
+    fn default() -> Self {
+        ConfirmVocabulary::ENGLISH
+    }
+}
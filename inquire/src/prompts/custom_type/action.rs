@@ -1,4 +1,7 @@
-use crate::{ui::Key, InnerAction, InputAction};
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
 
 use super::config::CustomTypeConfig;
 
@@ -7,15 +10,27 @@ use super::config::CustomTypeConfig;
 pub enum CustomTypePromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
+    /// Recalls the previous entry of the history, if any.
+    PreviousHistoryEntry,
+    /// Recalls the next entry of the history, if any.
+    NextHistoryEntry,
 }
 
 impl InnerAction for CustomTypePromptAction {
     type Config = CustomTypeConfig;
 
     fn from_key(key: Key, _config: &CustomTypeConfig) -> Option<Self> {
-        let action = match InputAction::from_key(key, &()) {
-            Some(action) => Self::ValueInput(action),
-            None => return None,
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
+                Self::PreviousHistoryEntry
+            }
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Self::NextHistoryEntry
+            }
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
         };
 
         Some(action)
@@ -1,19 +1,28 @@
 mod action;
 mod config;
+mod presets;
 mod prompt;
+mod range;
+mod units;
 
 pub use action::*;
+pub use units::Units;
 
 use std::str::FromStr;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
+    history::History,
+    key_bindings::KeyBindings,
     parser::CustomTypeParser,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    type_aliases::{InputFilter, InputTransform},
+    ui::{prompt_backend, CustomTypeBackend, RenderConfig},
     validator::CustomTypeValidator,
 };
 
@@ -38,7 +47,7 @@ use self::prompt::CustomTypePrompt;
 /// If your type `T` does not satisfy these constraints, you can always manually instantiate the entire struct yourself like this:
 ///
 /// ```no_run
-/// use inquire::{CustomType, ui::RenderConfig};
+/// use inquire::{key_bindings::KeyBindings, CustomType, PromptSettings, ui::RenderConfig};
 ///
 /// let amount_prompt: CustomType<f64> = CustomType {
 ///     message: "How much is your travel going to cost?",
@@ -47,6 +56,11 @@ use self::prompt::CustomTypePrompt;
 ///     default_value_formatter: &|i| format!("${:.2}", i),
 ///     default: None,
 ///     validators: vec![],
+///     history: None,
+///     input_filter: None,
+///     input_transform: None,
+///     units: None,
+///     range_description: None,
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
@@ -55,6 +69,8 @@ use self::prompt::CustomTypePrompt;
 ///         Err(_) => Err(()),
 ///     },
 ///     render_config: RenderConfig::default(),
+///     settings: PromptSettings::default(),
+///     key_bindings: KeyBindings::new(),
 /// };
 /// ```
 ///
@@ -114,18 +130,42 @@ pub struct CustomType<'a, T> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
 
+    /// History of previous inputs, recalled with the up and down arrow keys.
+    pub history: Option<Box<dyn History>>,
+
+    /// Function deciding whether a typed character is accepted, applied after
+    /// the [`input_transform`](Self::input_transform).
+    pub input_filter: Option<InputFilter<'a>>,
+
+    /// Function transforming each typed character before it is inserted.
+    pub input_transform: Option<InputTransform<'a>>,
+
+    /// Unit suffixes accepted after the number, multiplied into the canonical
+    /// unit before the input is parsed.
+    pub units: Option<Units<'a>>,
+
+    /// Description of the range the answer is restricted to, e.g. `between 1
+    /// and 10`, displayed when no help message is set. Set by
+    /// [`with_range`](Self::with_range).
+    pub range_description: Option<String>,
+
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<CustomTypePromptAction>,
 }
 
 impl<'a, T> CustomType<'a, T>
@@ -150,8 +190,15 @@ where
             default_value_formatter: &|val| val.to_string(),
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
             validators: Self::DEFAULT_VALIDATORS,
+            history: None,
+            input_filter: None,
+            input_transform: None,
+            units: None,
+            range_description: None,
             error_message: "Invalid input".into(),
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -219,6 +266,19 @@ where
         self
     }
 
+    /// Sets the history of previous inputs.
+    ///
+    /// The up and down arrow keys replace the text input with the previous
+    /// and next entries of the history. The text input is recorded in the
+    /// history when the prompt is submitted with a valid value.
+    pub fn with_history<H>(mut self, history: H) -> Self
+    where
+        H: History + 'static,
+    {
+        self.history = Some(Box::new(history));
+        self
+    }
+
     /// Adds the validators to the collection of validators in the order they are given.
     /// You might want to use this feature in case you need to require certain features
     /// from the parsed user's answer.
@@ -235,6 +295,34 @@ where
         self
     }
 
+    /// Sets a filter deciding, as the user types, whether each character is
+    /// accepted in the input, e.g. to only allow digits. Rejected characters
+    /// are not inserted, instead of failing validation when submitting.
+    ///
+    /// Pasted text goes through the filter as well, while initial values and
+    /// recalled history entries don't.
+    pub fn with_input_filter(mut self, filter: InputFilter<'a>) -> Self {
+        self.input_filter = Some(filter);
+        self
+    }
+
+    /// Sets a function transforming each character typed in the input, e.g.
+    /// to uppercase it. The transform is applied before the input filter.
+    pub fn with_input_transform(mut self, transform: InputTransform<'a>) -> Self {
+        self.input_transform = Some(transform);
+        self
+    }
+
+    /// Sets the unit suffixes accepted after the number, e.g. `k` or `Gi`.
+    ///
+    /// The input is rewritten in the canonical unit before it is handed to
+    /// the parser, and the canonical unit is displayed after the formatted
+    /// answer and default value. See [`Units`] for more details.
+    pub fn with_units(mut self, units: Units<'a>) -> Self {
+        self.units = Some(units);
+        self
+    }
+
     /// Sets a custom error message displayed when a submission could not be parsed to a value.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
@@ -243,17 +331,35 @@ where
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<CustomTypePromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -274,11 +380,37 @@ where
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<T> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [prompt](Self::prompt), but awaits the keys pressed by the
+    /// user without blocking the thread.
+    /// See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<T> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        crate::prompts::prompt_async::prompt_async(CustomTypePrompt::from(self), &mut backend).await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the value typed by the user. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, T>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            CustomTypePrompt::from(self),
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
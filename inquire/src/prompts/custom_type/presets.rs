@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use crate::{
+    formatter::{format_grouped_integer, DECIMAL_COMMA_FORMATTER, PERCENTAGE_FORMATTER},
+    parser::{parse_grouped_integer, DECIMAL_COMMA_PARSER, EMAIL_PARSER, PERCENTAGE_PARSER},
+    CustomType,
+};
+
+impl<'a, T> CustomType<'a, T>
+where
+    T: Clone + FromStr + ToString,
+{
+    /// Creates a [CustomType] for whole numbers, such as `u64` or `i32`,
+    /// accepting thousands separators (`,`, `_` or spaces) in the input and
+    /// displaying the answer with commas, e.g. `1,234,567`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let population = CustomType::<u64>::integer("Population:").prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn integer(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(&|input| parse_grouped_integer(input))
+            .with_formatter(&|val| format_grouped_integer(val.to_string()))
+            .with_default_value_formatter(&|val| format_grouped_integer(val.to_string()))
+            .with_error_message("Please type a whole number, e.g. 1,234")
+    }
+}
+
+impl<'a> CustomType<'a, f64> {
+    /// Creates a [CustomType] for decimal numbers written with a decimal
+    /// comma, e.g. `3,14` or `1.234,5`, as in most of continental Europe and
+    /// South America.
+    ///
+    /// See [`DECIMAL_COMMA_PARSER`](crate::parser::DECIMAL_COMMA_PARSER) and
+    /// [`DECIMAL_COMMA_FORMATTER`](crate::formatter::DECIMAL_COMMA_FORMATTER).
+    pub fn decimal_comma(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(DECIMAL_COMMA_PARSER)
+            .with_formatter(DECIMAL_COMMA_FORMATTER)
+            .with_default_value_formatter(DECIMAL_COMMA_FORMATTER)
+            .with_error_message("Please type a number with a decimal comma, e.g. 3,14")
+    }
+
+    /// Creates a [CustomType] for percentages, accepting inputs with or
+    /// without a trailing `%`, e.g. `42%` or `12.5`. The answer is the number
+    /// of percents, e.g. `42.0` for `42%`.
+    pub fn percentage(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(PERCENTAGE_PARSER)
+            .with_formatter(PERCENTAGE_FORMATTER)
+            .with_default_value_formatter(PERCENTAGE_FORMATTER)
+            .with_error_message("Please type a percentage, e.g. 42%")
+    }
+}
+
+impl<'a> CustomType<'a, String> {
+    /// Creates a [CustomType] for email addresses.
+    ///
+    /// See [`EMAIL_PARSER`](crate::parser::EMAIL_PARSER) for the addresses
+    /// that are accepted.
+    pub fn email(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(EMAIL_PARSER)
+            .with_error_message("Please type a valid email address, e.g. jane@example.com")
+    }
+}
+
+#[cfg(feature = "semver")]
+impl<'a> CustomType<'a, semver::Version> {
+    /// Creates a [CustomType] for semantic versions, e.g. `1.2.3` or
+    /// `v2.0.0-rc.1`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+    pub fn semver(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(crate::parser::SEMVER_PARSER)
+            .with_error_message("Please type a semantic version, e.g. 1.2.3")
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'a> CustomType<'a, uuid::Uuid> {
+    /// Creates a [CustomType] for UUIDs, displayed in their hyphenated form.
+    #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+    pub fn uuid(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(crate::parser::UUID_PARSER)
+            .with_error_message(
+                "Please type a valid UUID, e.g. 67e55044-10b1-426f-9247-bb680e5fe0c8",
+            )
+    }
+}
+
+#[cfg(feature = "url")]
+impl<'a> CustomType<'a, url::Url> {
+    /// Creates a [CustomType] for absolute URLs, e.g. `https://example.com`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    pub fn url(message: &'a str) -> Self {
+        Self::new(message)
+            .with_parser(crate::parser::URL_PARSER)
+            .with_error_message("Please type a valid URL, e.g. https://example.com")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ui::{
+            test::{FakeBackend, Token},
+            Key,
+        },
+        CustomType,
+    };
+
+    fn answer<T: Clone>(prompt: CustomType<'_, T>, input: &str) -> (T, Option<String>) {
+        let mut keys = Key::char_keys_from_str(input);
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+
+        let answer = prompt.prompt_with_backend(&mut backend).unwrap();
+        let formatted = backend.frames().last().and_then(|frame| {
+            frame.tokens().iter().find_map(|token| match token {
+                Token::AnsweredPrompt(_, formatted) => Some(formatted.clone()),
+                _ => None,
+            })
+        });
+
+        (answer, formatted)
+    }
+
+    #[test]
+    fn integer_accepts_and_displays_thousands_separators() {
+        let (value, formatted) = answer(CustomType::<i64>::integer("Count:"), "-1_234 567");
+        assert_eq!(-1_234_567, value);
+        assert_eq!(Some("-1,234,567".into()), formatted);
+
+        let (value, formatted) = answer(CustomType::<u32>::integer("Count:"), "42");
+        assert_eq!(42, value);
+        assert_eq!(Some("42".into()), formatted);
+    }
+
+    #[test]
+    fn integer_rejects_misplaced_separators() {
+        let mut keys = Key::char_keys_from_str("1,2345");
+        keys.push(Key::Enter);
+        keys.push(Key::Escape);
+        let mut backend = FakeBackend::new(keys);
+
+        let result = CustomType::<u64>::integer("Count:").prompt_with_backend(&mut backend);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decimal_comma_and_percentage_round_trip() {
+        let (value, formatted) = answer(CustomType::decimal_comma("Amount:"), "1.234,5");
+        assert_eq!(1234.5, value);
+        assert_eq!(Some("1.234,5".into()), formatted);
+
+        let (value, formatted) = answer(CustomType::percentage("Rate:"), "12.5 %");
+        assert_eq!(12.5, value);
+        assert_eq!(Some("12.5%".into()), formatted);
+    }
+
+    #[test]
+    fn email_is_trimmed() {
+        let (value, _) = answer(CustomType::email("Email:"), " jane@example.com");
+        assert_eq!("jane@example.com", value);
+    }
+
+    #[test]
+    #[cfg(all(feature = "semver", feature = "uuid", feature = "url"))]
+    fn feature_gated_types_are_parsed() {
+        let (version, _) = answer(CustomType::semver("Version:"), "v1.2.3-rc.1");
+        assert_eq!(semver::Version::parse("1.2.3-rc.1").unwrap(), version);
+
+        let (id, formatted) = answer(
+            CustomType::uuid("Id:"),
+            "{67E55044-10B1-426F-9247-BB680E5FE0C8}",
+        );
+        assert_eq!(
+            uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+            id
+        );
+        assert_eq!(
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8".into()),
+            formatted
+        );
+
+        let (url, _) = answer(CustomType::url("Homepage:"), "https://example.com/a");
+        assert_eq!("https://example.com/a", url.as_str());
+    }
+}
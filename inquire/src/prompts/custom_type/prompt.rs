@@ -1,15 +1,22 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::{
     error::InquireResult,
     formatter::CustomTypeFormatter,
-    input::Input,
+    history::{History, HistoryCursor},
+    input::{filter_char, Input, InputActionResult},
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     parser::CustomTypeParser,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::{InputFilter, InputTransform},
     ui::CustomTypeBackend,
     validator::{CustomTypeValidator, ErrorMessage, Validation},
-    CustomType, InquireError,
+    CustomType, InputAction, InquireError,
 };
 
-use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
+use super::{action::CustomTypePromptAction, config::CustomTypeConfig, units::Units};
 
 pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
@@ -18,11 +25,21 @@ pub struct CustomTypePrompt<'a, T> {
     help_message: Option<&'a str>,
     default: Option<T>,
     input: Input,
+    input_filter: Option<InputFilter<'a>>,
+    input_transform: Option<InputTransform<'a>>,
+    units: Option<Units<'a>>,
+    range_description: Option<String>,
     formatter: CustomTypeFormatter<'a, T>,
     default_value_formatter: CustomTypeFormatter<'a, T>,
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
     error_message: String,
+    history: Option<Box<dyn History>>,
+    history_cursor: Option<HistoryCursor>,
+    key_bindings: KeyBindings<CustomTypePromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -48,7 +65,17 @@ where
             validators: co.validators,
             parser: co.parser,
             input,
+            input_filter: co.input_filter,
+            input_transform: co.input_transform,
+            units: co.units,
+            range_description: co.range_description,
             error_message: co.error_message,
+            history: co.history,
+            history_cursor: None,
+            key_bindings: co.key_bindings,
+            key_handler: co.settings.key_handler,
+            escape_behavior: co.settings.escape_behavior,
+            cancel_guard: co.settings.cancel_guard,
         }
     }
 }
@@ -69,15 +96,50 @@ where
         Ok(Validation::Valid)
     }
 
+    /// Replaces the input with the older or newer entry of the history,
+    /// if there is one to recall.
+    fn recall_history_entry(&mut self, older: bool) -> ActionResult {
+        let cursor = match self.history_cursor.as_mut() {
+            Some(cursor) => cursor,
+            None => return ActionResult::Clean,
+        };
+
+        let entry = match older {
+            true => cursor.older(self.input.content()),
+            false => cursor.newer(),
+        };
+
+        match entry {
+            Some(entry) => {
+                let cursor = entry.graphemes(true).count();
+                self.input.replace_content(entry, cursor);
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn append_unit(&self, formatted: String) -> String {
+        match &self.units {
+            Some(units) => units.format(formatted),
+            None => formatted,
+        }
+    }
+
     fn get_final_answer(&self) -> Result<T, String> {
         match &self.default {
             Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
             _ => {}
         }
 
-        match (self.parser)(self.input.content()) {
-            Ok(val) => Ok(val),
-            Err(_) => Err(self.error_message.clone()),
+        let input = match &self.units {
+            Some(units) => units.normalize(self.input.content()),
+            None => Some(Cow::Borrowed(self.input.content())),
+        };
+
+        match input.map(|input| (self.parser)(&input)) {
+            Some(Ok(val)) => Ok(val),
+            _ => Err(self.error_message.clone()),
         }
     }
 }
@@ -95,18 +157,70 @@ where
         self.message
     }
 
+    fn partial_input(&self) -> Option<&str> {
+        Some(self.input.content())
+    }
+
     fn config(&self) -> &CustomTypeConfig {
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<CustomTypePromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(
+            self,
+            CustomTypePromptAction::ValueInput(InputAction::Clear),
+        )
+    }
+
     fn format_answer(&self, answer: &T) -> String {
-        (self.formatter)((*answer).clone())
+        self.append_unit((self.formatter)((*answer).clone()))
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        if let Some(history) = &self.history {
+            self.history_cursor = Some(HistoryCursor::new(history.entries()?));
+        }
+
+        Ok(())
+    }
+
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.input = Input::new_with(answer);
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
     }
 
     fn submit(&mut self) -> InquireResult<Option<T>> {
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
-                Validation::Valid => Some(answer),
+                Validation::Valid => {
+                    if let Some(history) = self.history.as_mut() {
+                        if !self.input.content().is_empty() {
+                            // the answer was submitted even if it can't be recorded
+                            let _ = history.record(self.input.content());
+                        }
+                    }
+                    Some(answer)
+                }
                 Validation::Invalid(msg) => {
                     self.error = Some(msg);
                     None
@@ -124,8 +238,21 @@ where
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+                let (transform, filter) = (self.input_transform, self.input_filter);
+                let result = self
+                    .input
+                    .handle_filtered(input_action, &|c| filter_char(c, transform, filter));
+
+                if let InputActionResult::ContentChanged = result {
+                    if let Some(cursor) = self.history_cursor.as_mut() {
+                        cursor.reset();
+                    }
+                }
+
+                result.into()
             }
+            CustomTypePromptAction::PreviousHistoryEntry => self.recall_history_entry(true),
+            CustomTypePromptAction::NextHistoryEntry => self.recall_history_entry(false),
         };
 
         Ok(result)
@@ -142,14 +269,24 @@ where
         let default_message = self
             .default
             .as_ref()
-            .map(|val| default_value_formatter(val.clone()));
+            .map(|val| self.append_unit(default_value_formatter(val.clone())));
 
         backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
+        } else if let Some(range) = &self.range_description {
+            backend.render_help_message(&capitalize(range))?;
         }
 
         Ok(())
     }
 }
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::{validator::Validation, CustomType};
+
+impl<'a, T> CustomType<'a, T>
+where
+    T: Clone + PartialOrd + 'static,
+{
+    /// Restricts the answer to the provided range, e.g. `1..=10` or `0.0..`.
+    ///
+    /// Answers out of the range are rejected when submitted, with an error
+    /// message such as "The value must be between 1 and 10". When no help
+    /// message is set, the allowed range is displayed as the help message.
+    ///
+    /// The bounds are displayed with the
+    /// [default value formatter](Self::with_default_value_formatter) and the
+    /// [units](Self::with_units), as they are set when this method is called.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let replicas = CustomType::<u8>::new("Replicas:")
+    ///     .with_range(1..=5)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_range<R>(mut self, range: R) -> Self
+    where
+        R: RangeBounds<T>,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        let format = |value: &T| {
+            let formatted = (self.default_value_formatter)(value.clone());
+            match &self.units {
+                Some(units) => units.format(formatted),
+                None => formatted,
+            }
+        };
+
+        let description = match &bounds {
+            (Bound::Included(min), Bound::Included(max)) => {
+                Some(format!("between {} and {}", format(min), format(max)))
+            }
+            (start, end) => {
+                let start = match start {
+                    Bound::Included(min) => Some(format!("at least {}", format(min))),
+                    Bound::Excluded(min) => Some(format!("greater than {}", format(min))),
+                    Bound::Unbounded => None,
+                };
+                let end = match end {
+                    Bound::Included(max) => Some(format!("at most {}", format(max))),
+                    Bound::Excluded(max) => Some(format!("less than {}", format(max))),
+                    Bound::Unbounded => None,
+                };
+
+                match (start, end) {
+                    (Some(start), Some(end)) => Some(format!("{start} and {end}")),
+                    (start, end) => start.or(end),
+                }
+            }
+        };
+
+        let Some(description) = description else {
+            return self;
+        };
+
+        let error_message = format!("The value must be {description}");
+        self.validators
+            .push(Box::new(move |value: &T| match bounds.contains(value) {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(error_message.as_str().into())),
+            }));
+        self.range_description = Some(description);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ui::{
+            test::{FakeBackend, Token},
+            Key, KeyModifiers,
+        },
+        CustomType,
+    };
+
+    /// Keys typing each input and submitting it, clearing the input between
+    /// submissions.
+    fn keys(inputs: &[&str]) -> Vec<Key> {
+        let mut keys = vec![];
+        for input in inputs {
+            keys.push(Key::Char('u', KeyModifiers::CONTROL));
+            keys.append(&mut Key::char_keys_from_str(input));
+            keys.push(Key::Enter);
+        }
+        keys
+    }
+
+    #[test]
+    fn out_of_range_answers_are_rejected() {
+        let mut backend = FakeBackend::new(keys(&["0", "11", "10"]));
+
+        let ans = CustomType::<u8>::new("Replicas:")
+            .with_range(1..=10)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(10, ans);
+        assert!(backend
+            .frames()
+            .iter()
+            .any(|frame| frame.has_token(&Token::ErrorMessage(
+                "The value must be between 1 and 10".into()
+            ))));
+    }
+
+    #[test]
+    fn range_is_displayed_as_help_message() {
+        let mut backend = FakeBackend::new(keys(&["2.5"]));
+
+        let ans = CustomType::<f64>::new("Ratio:")
+            .with_range(0.0..5.0)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(2.5, ans);
+        assert!(backend.frames().first().is_some_and(
+            |frame| frame.has_token(&Token::HelpMessage("At least 0 and less than 5".into()))
+        ));
+    }
+
+    #[test]
+    fn explicit_help_message_takes_precedence() {
+        let mut backend = FakeBackend::new(keys(&["3"]));
+
+        CustomType::<i32>::new("Count:")
+            .with_range(..=3)
+            .with_help_message("How many?")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(backend
+            .frames()
+            .first()
+            .is_some_and(|frame| frame.has_token(&Token::HelpMessage("How many?".into()))));
+    }
+}
@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+
+/// Units accepted by a [CustomType](crate::CustomType) prompt, such as byte
+/// sizes or durations.
+///
+/// When typing a value, the user may follow the number with one of the
+/// suffixes, e.g. `1.5k` or `2 Gi`. The number is multiplied by the
+/// multiplier of the suffix before the input is handed to the parser, so
+/// that the prompt always returns a value in the canonical unit. Numbers
+/// without a suffix, or followed by the canonical unit itself, are taken as
+/// they are.
+///
+/// The multiplication is exact: `1.5k` is parsed as `1500` and `0.5k` as
+/// `500`, so integer types can be used as long as the result is a whole
+/// number. Suffixes are case-sensitive, to tell apart e.g. `m` and `M`.
+///
+/// The formatted answer and default value are followed by the canonical unit.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{CustomType, Units};
+///
+/// let cache_size = CustomType::<u64>::new("Cache size:")
+///     .with_units(Units::BYTES)
+///     .with_default(512 * 1024 * 1024)
+///     .with_help_message("e.g. 512Mi, 2 GB or 1500000")
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Units<'a> {
+    /// Canonical unit, in which values are returned and displayed, e.g. `B`.
+    pub canonical: &'a str,
+
+    /// Accepted suffixes along with the number of canonical units they stand
+    /// for, e.g. `("k", 1000)`.
+    pub suffixes: &'a [(&'a str, u64)],
+}
+
+impl<'a> Units<'a> {
+    /// Byte sizes in bytes, accepting decimal (`kB`, `MB`, `GB`, `TB`, or `k`,
+    /// `M`, `G`, `T`) and binary (`KiB`, `MiB`, `GiB`, `TiB`, or `Ki`, `Mi`,
+    /// `Gi`, `Ti`) suffixes.
+    pub const BYTES: Units<'static> = Units {
+        canonical: "B",
+        suffixes: &[
+            ("k", 1_000),
+            ("kB", 1_000),
+            ("M", 1_000_000),
+            ("MB", 1_000_000),
+            ("G", 1_000_000_000),
+            ("GB", 1_000_000_000),
+            ("T", 1_000_000_000_000),
+            ("TB", 1_000_000_000_000),
+            ("Ki", 1 << 10),
+            ("KiB", 1 << 10),
+            ("Mi", 1 << 20),
+            ("MiB", 1 << 20),
+            ("Gi", 1 << 30),
+            ("GiB", 1 << 30),
+            ("Ti", 1 << 40),
+            ("TiB", 1 << 40),
+        ],
+    };
+
+    /// Durations in seconds, accepting minutes (`m` or `min`), hours (`h`)
+    /// and days (`d`).
+    pub const SECONDS: Units<'static> = Units {
+        canonical: "s",
+        suffixes: &[("m", 60), ("min", 60), ("h", 3_600), ("d", 86_400)],
+    };
+
+    /// Creates units with the provided canonical unit and suffixes.
+    pub fn new(canonical: &'a str, suffixes: &'a [(&'a str, u64)]) -> Self {
+        Self {
+            canonical,
+            suffixes,
+        }
+    }
+
+    /// Rewrites an input with a unit suffix as a plain number in the
+    /// canonical unit, e.g. `1.5k` as `1500`. Inputs without a known suffix
+    /// are returned unchanged, while `None` is returned when a suffix follows
+    /// something other than a decimal number, or the result overflows.
+    pub fn normalize<'i>(&self, input: &'i str) -> Option<Cow<'i, str>> {
+        let input = input.trim();
+
+        let canonical = match self.canonical.is_empty() {
+            true => None,
+            false => Some((self.canonical, 1)),
+        };
+        let matched = self
+            .suffixes
+            .iter()
+            .copied()
+            .chain(canonical)
+            .filter(|(suffix, _)| input.ends_with(suffix))
+            .max_by_key(|(suffix, _)| suffix.len());
+
+        let Some((suffix, multiplier)) = matched else {
+            return Some(Cow::Borrowed(input));
+        };
+
+        let number = input
+            .get(..input.len() - suffix.len())
+            .unwrap_or_default()
+            .trim_end();
+
+        scale(number, multiplier).map(Cow::Owned)
+    }
+
+    /// Appends the canonical unit to a formatted value.
+    pub fn format(&self, value: String) -> String {
+        match self.canonical.is_empty() {
+            true => value,
+            false => format!("{} {}", value, self.canonical),
+        }
+    }
+}
+
+/// Multiplies a decimal number, given as a string, by an integer without
+/// losing precision.
+fn scale(number: &str, multiplier: u64) -> Option<String> {
+    let (negative, number) = match number.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let digits = format!("{integer}{fraction}");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let product = digits
+        .parse::<u128>()
+        .ok()?
+        .checked_mul(u128::from(multiplier))?;
+
+    let mut result = format!("{:0>width$}", product, width = fraction.len() + 1);
+    if !fraction.is_empty() {
+        result.insert(result.len() - fraction.len(), '.');
+        let trimmed_len = result.trim_end_matches('0').trim_end_matches('.').len();
+        result.truncate(trimmed_len);
+    }
+
+    if negative && result != "0" {
+        result.insert(0, '-');
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Units;
+    use crate::{
+        ui::{
+            test::{FakeBackend, Token},
+            Key,
+        },
+        CustomType,
+    };
+
+    #[test]
+    fn suffixes_are_multiplied_exactly() {
+        let normalize = |input| Units::BYTES.normalize(input).map(|n| n.into_owned());
+
+        assert_eq!(Some("1500".into()), normalize("1.5k"));
+        assert_eq!(Some("2147483648".into()), normalize("2 Gi"));
+        assert_eq!(Some("2147483648".into()), normalize("2GiB"));
+        assert_eq!(Some("0.5".into()), normalize("0.0005 kB"));
+        assert_eq!(Some("-3000000".into()), normalize("-3M"));
+        assert_eq!(Some("42".into()), normalize("42 B"));
+        assert_eq!(Some("42".into()), normalize(" 42 "));
+        assert_eq!(Some("1e3".into()), normalize("1e3"));
+        assert_eq!(None, normalize("k"));
+        assert_eq!(None, normalize("1e3k"));
+        assert_eq!(None, normalize("1.2.3M"));
+    }
+
+    #[test]
+    fn canonical_unit_is_appended_when_formatting() {
+        assert_eq!("90 s", Units::SECONDS.format("90".into()));
+        assert_eq!("90", Units::new("", &[("k", 1000)]).format("90".into()));
+    }
+
+    #[test]
+    fn prompt_returns_value_in_canonical_unit() {
+        let mut keys = Key::char_keys_from_str("1.5 Mi");
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+
+        let size = CustomType::<u64>::new("Size:")
+            .with_units(Units::BYTES)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(1_572_864, size);
+        assert!(backend.frames().last().is_some_and(
+            |frame| frame.has_token(&Token::AnsweredPrompt("Size:".into(), "1572864 B".into()))
+        ));
+    }
+}
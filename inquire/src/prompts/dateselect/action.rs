@@ -1,6 +1,6 @@
 use crate::{
     ui::{Key, KeyModifiers},
-    InnerAction,
+    CalendarLayout, InnerAction, InputAction,
 };
 
 use super::config::DateSelectConfig;
@@ -25,12 +25,49 @@ pub enum DateSelectPromptAction {
     GoToPrevYear,
     /// Move day cursor to the next year.
     GoToNextYear,
+    /// Check or uncheck the date under the cursor, when selecting multiple dates.
+    ToggleDate,
+    /// Open the month picker, to jump to a month of any year.
+    OpenMonthPicker,
+    /// Close the month picker, jumping to the picked month.
+    ConfirmMonthPicker,
+    /// Close the month picker without jumping.
+    CloseMonthPicker,
+    /// Type a digit of the year to jump to in the month picker.
+    TypeYearDigit(char),
+    /// Erase the last typed digit of the year in the month picker.
+    EraseYearDigit,
+    /// Open the date input, to type a date such as `tomorrow` or `+3d`.
+    OpenDateInput,
+    /// Action on the value text input handler of the date input.
+    DateInput(InputAction),
+    /// Close the date input, jumping to the typed date.
+    ConfirmDateInput,
+    /// Close the date input, leaving the cursor where it is.
+    CloseDateInput,
 }
 
 impl InnerAction for DateSelectPromptAction {
     type Config = DateSelectConfig;
 
-    fn from_key(key: Key, _: &DateSelectConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &DateSelectConfig) -> Option<Self> {
+        if config.multiple && key == Key::Char(' ', KeyModifiers::NONE) {
+            return Some(Self::ToggleDate);
+        }
+
+        if config.layout == CalendarLayout::Agenda {
+            // days are listed vertically, so up and down move between days
+            match key {
+                Key::Up(KeyModifiers::NONE)
+                | Key::Char('p', KeyModifiers::CONTROL)
+                | Key::Char('k', KeyModifiers::NONE) => return Some(Self::GoToPrevDay),
+                Key::Down(KeyModifiers::NONE)
+                | Key::Char('n', KeyModifiers::CONTROL)
+                | Key::Char('j', KeyModifiers::NONE) => return Some(Self::GoToNextDay),
+                _ => {}
+            }
+        }
+
         let action = match key {
             Key::Left(KeyModifiers::NONE) // standard
             | Key::Char('b', KeyModifiers::CONTROL) // emacs
@@ -77,6 +114,10 @@ impl InnerAction for DateSelectPromptAction {
             | Key::Down(_) // alternative 2, when the down above with no modifiers is not matched
             => Self::GoToNextYear,
 
+            Key::Char('g', KeyModifiers::NONE) => Self::OpenMonthPicker,
+
+            Key::Char('/', KeyModifiers::NONE) => Self::OpenDateInput,
+
             _ => return None,
         };
 
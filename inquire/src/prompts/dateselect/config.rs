@@ -1,6 +1,6 @@
 use chrono::NaiveDate;
 
-use crate::DateSelect;
+use crate::{CalendarLayout, DateSelect};
 
 /// Configuration settings used in the execution of a DateSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -13,6 +13,15 @@ pub struct DateSelectConfig {
 
     /// Weekday to start the week on.
     pub week_start: chrono::Weekday,
+
+    /// Layout used to display the calendar.
+    pub layout: CalendarLayout,
+
+    /// Whether ISO week numbers are displayed to the left of the calendar.
+    pub show_week_numbers: bool,
+
+    /// Whether multiple dates are being selected, toggled with the space key.
+    pub multiple: bool,
 }
 
 impl From<&DateSelect<'_>> for DateSelectConfig {
@@ -21,6 +30,9 @@ impl From<&DateSelect<'_>> for DateSelectConfig {
             min_date: value.min_date,
             max_date: value.max_date,
             week_start: value.week_start,
+            layout: value.layout,
+            show_week_numbers: value.show_week_numbers,
+            multiple: false,
         }
     }
 }
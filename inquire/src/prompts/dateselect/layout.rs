@@ -0,0 +1,23 @@
+/// Layout used to display the calendar of a [DateSelect](crate::DateSelect) prompt.
+///
+/// The default grid takes 20 columns and 8 rows. The alternative layouts are
+/// meant for narrow or short terminals where the grid does not fit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CalendarLayout {
+    /// Month grid with one row per week and one column per weekday.
+    #[default]
+    Grid,
+
+    /// Compact strip listing the days of the selected month in a single line.
+    ///
+    /// When the whole month does not fit in the terminal width, the strip is
+    /// scrolled horizontally to keep the selected day visible.
+    Strip,
+
+    /// Vertical agenda-style list with one line per day, showing the week of
+    /// the selected date.
+    ///
+    /// In this layout, the up and down arrows move to the previous and next
+    /// day instead of the previous and next week.
+    Agenda,
+}
@@ -0,0 +1,246 @@
+use std::fmt::Write;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::formatter::DateFormatter;
+
+/// Names and conventions used to display dates in a
+/// [DateSelect](crate::DateSelect) prompt: the calendar header, the weekday
+/// abbreviations, the first day of the week and the formatted answer.
+///
+/// Common locales are provided as constants, e.g. [DateLocale::GERMAN], and
+/// others can be created by filling in the fields.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{DateLocale, DateSelect};
+///
+/// let date = DateSelect::new("Reisedatum:")
+///     .with_locale(DateLocale::GERMAN)
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone)]
+pub struct DateLocale<'a> {
+    /// Full month names, from January to December, e.g. `Januar`.
+    pub month_names: [&'a str; 12],
+
+    /// Month abbreviations, from January to December, used where space is
+    /// limited. They should be three characters wide, e.g. `jan`.
+    pub month_abbreviations: [&'a str; 12],
+
+    /// Weekday abbreviations, from Monday to Sunday, displayed above the
+    /// columns of the calendar. They should be two characters wide, e.g. `mo`.
+    pub weekday_abbreviations: [&'a str; 7],
+
+    /// Day displayed in the first column of the calendar.
+    pub week_start: Weekday,
+
+    /// Formatter of the submitted answer.
+    pub formatter: DateFormatter<'a>,
+}
+
+impl<'a> DateLocale<'a> {
+    /// English, with weeks starting on Sunday and dates formatted as `July 25, 2021`.
+    pub const ENGLISH: DateLocale<'static> = DateLocale {
+        month_names: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        month_abbreviations: [
+            "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+        ],
+        weekday_abbreviations: ["mo", "tu", "we", "th", "fr", "sa", "su"],
+        week_start: Weekday::Sun,
+        formatter: &|date| DateLocale::ENGLISH.format(date, "%B %-e, %Y"),
+    };
+
+    /// German, with weeks starting on Monday and dates formatted as `25. Juli 2021`.
+    pub const GERMAN: DateLocale<'static> = DateLocale {
+        month_names: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        month_abbreviations: [
+            "jan", "feb", "mär", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "dez",
+        ],
+        weekday_abbreviations: ["mo", "di", "mi", "do", "fr", "sa", "so"],
+        week_start: Weekday::Mon,
+        formatter: &|date| DateLocale::GERMAN.format(date, "%-e. %B %Y"),
+    };
+
+    /// French, with weeks starting on Monday and dates formatted as `25 juillet 2021`.
+    pub const FRENCH: DateLocale<'static> = DateLocale {
+        month_names: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        month_abbreviations: [
+            "jan", "fév", "mar", "avr", "mai", "jun", "jul", "aoû", "sep", "oct", "nov", "déc",
+        ],
+        weekday_abbreviations: ["lu", "ma", "me", "je", "ve", "sa", "di"],
+        week_start: Weekday::Mon,
+        formatter: &|date| DateLocale::FRENCH.format(date, "%-e %B %Y"),
+    };
+
+    /// Spanish, with weeks starting on Monday and dates formatted as `25 de julio de 2021`.
+    pub const SPANISH: DateLocale<'static> = DateLocale {
+        month_names: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        month_abbreviations: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+        ],
+        weekday_abbreviations: ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+        week_start: Weekday::Mon,
+        formatter: &|date| DateLocale::SPANISH.format(date, "%-e de %B de %Y"),
+    };
+
+    /// Italian, with weeks starting on Monday and dates formatted as `25 luglio 2021`.
+    pub const ITALIAN: DateLocale<'static> = DateLocale {
+        month_names: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        month_abbreviations: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekday_abbreviations: ["lu", "ma", "me", "gi", "ve", "sa", "do"],
+        week_start: Weekday::Mon,
+        formatter: &|date| DateLocale::ITALIAN.format(date, "%-e %B %Y"),
+    };
+
+    /// Full name of the month of a date.
+    pub fn month_name(&self, date: NaiveDate) -> &'a str {
+        self.month_names
+            .get(date.month0() as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Abbreviation of the month of a date.
+    pub fn month_abbreviation(&self, date: NaiveDate) -> &'a str {
+        self.month_abbreviations
+            .get(date.month0() as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Abbreviation of a weekday.
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &'a str {
+        self.weekday_abbreviations
+            .get(weekday.num_days_from_monday() as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Formats a date with a [chrono format string](chrono::format::strftime),
+    /// where `%B` and `%b` stand for the month name and abbreviation of the
+    /// locale. Useful to write the formatter of a custom locale.
+    ///
+    /// Invalid format strings fall back to the ISO 8601 format, e.g. `2021-07-25`.
+    pub fn format(&self, date: NaiveDate, format: &str) -> String {
+        let format = format
+            .replace("%B", self.month_name(date))
+            .replace("%b", self.month_abbreviation(date));
+
+        let mut formatted = String::new();
+        match write!(formatted, "{}", date.format(&format)) {
+            Ok(()) => formatted,
+            Err(_e) => date.to_string(),
+        }
+    }
+}
+
+impl Default for DateLocale<'_> {
+    fn default() -> Self {
+        DateLocale::ENGLISH
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, Weekday};
+
+    use super::DateLocale;
+
+    #[test]
+    fn shipped_locales_format_dates_in_their_language() {
+        let date = NaiveDate::from_ymd_opt(2021, 3, 7).unwrap();
+
+        assert_eq!("March 7, 2021", (DateLocale::ENGLISH.formatter)(date));
+        assert_eq!("7. März 2021", (DateLocale::GERMAN.formatter)(date));
+        assert_eq!("7 mars 2021", (DateLocale::FRENCH.formatter)(date));
+        assert_eq!("7 de marzo de 2021", (DateLocale::SPANISH.formatter)(date));
+        assert_eq!("7 marzo 2021", (DateLocale::ITALIAN.formatter)(date));
+    }
+
+    #[test]
+    fn names_are_looked_up_by_date_and_weekday() {
+        let date = NaiveDate::from_ymd_opt(2021, 12, 1).unwrap();
+
+        assert_eq!("décembre", DateLocale::FRENCH.month_name(date));
+        assert_eq!("dez", DateLocale::GERMAN.month_abbreviation(date));
+        assert_eq!("so", DateLocale::GERMAN.weekday_abbreviation(Weekday::Sun));
+        assert_eq!("mo", DateLocale::ENGLISH.weekday_abbreviation(Weekday::Mon));
+    }
+
+    #[test]
+    fn invalid_format_falls_back_to_iso_8601() {
+        let date = NaiveDate::from_ymd_opt(2021, 12, 1).unwrap();
+
+        assert_eq!("2021-12-01", DateLocale::ENGLISH.format(date, "%Q"));
+    }
+}
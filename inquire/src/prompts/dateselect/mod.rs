@@ -1,26 +1,35 @@
 mod action;
 mod config;
+mod layout;
+mod locale;
 mod prompt;
+mod relative;
 #[cfg(test)]
-#[cfg(feature = "crossterm")]
 mod test;
+#[cfg(feature = "time")]
+mod time;
 
 pub use action::*;
+pub use layout::*;
+pub use locale::*;
 
 use chrono::NaiveDate;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     date_utils::get_current_date,
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
+    key_bindings::KeyBindings,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{date::DateSelectBackend, Backend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{date::DateSelectBackend, prompt_backend, RenderConfig, StyleSheet},
     validator::DateValidator,
 };
 
-use self::prompt::DateSelectPrompt;
+use self::prompt::{DateSelectPrompt, MultiDateSelectPrompt};
 
 /// Prompt that allows user to select a date (time not supported) from an interactive calendar. Available via the `date` feature.
 ///
@@ -31,6 +40,8 @@ use self::prompt::DateSelectPrompt;
 /// - Analogously, right arrow does the same, but moving to the next day or month.
 /// - Up arrow moves to the day above to the one selected, basically a week before the selected date. When pressed with `ctrl`, it moves to the previous year.
 /// - Analogously, the down arrow moves to a week later or a year later.
+/// - `g` opens a month picker to jump to any month and year without paging: the arrows pick a month, typing four digits jumps to that year, enter jumps to the picked month and esc goes back to the calendar.
+/// - `/` opens a text input to type a date such as `tomorrow`, `next friday`, `+3d`, `2 weeks ago` or `2025-03-01`. The cursor follows the typed date and enter submits it, or jumps to it when selecting multiple dates.
 ///
 /// Finally, the user selects a date by pressing the space or enter keys.
 ///
@@ -44,6 +55,12 @@ use self::prompt::DateSelectPrompt;
 /// - **Validators**: Custom validators to the user's selected date, displaying an error message if the date does not pass the requirements.
 /// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
 /// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range.
+/// - **Disabled dates**: Specific dates, weekdays or dates matching a predicate, such as holidays and weekends, that are displayed dimmed and can't be selected. The cursor skips over them when navigating.
+/// - **Multiple dates**: With [prompt_multiple](DateSelect::prompt_multiple), the user checks any number of dates with the space key, optionally within a minimum and maximum number of dates.
+/// - **Locale**: Language of the month and weekday names, along with the week start and formatter that go with it. English by default, see [DateLocale].
+/// - **Week numbers**: Whether ISO week numbers are displayed to the left of the calendar, hidden by default.
+/// - **Date style**: Custom style sheets for specific dates, such as deadlines, set with a function.
+/// - **Layout**: How the calendar is displayed. A month grid by default, with compact single-line and vertical agenda alternatives for narrow terminals. See [CalendarLayout].
 ///
 /// # Example
 ///
@@ -81,6 +98,43 @@ pub struct DateSelect<'a> {
     /// Max date allowed to be selected.
     pub max_date: Option<NaiveDate>,
 
+    /// Dates that can't be selected, such as holidays.
+    pub disabled_dates: Vec<NaiveDate>,
+
+    /// Weekdays that can't be selected, such as weekends.
+    pub disabled_weekdays: Vec<chrono::Weekday>,
+
+    /// Function deciding whether a date can't be selected, in addition to
+    /// the [`disabled_dates`](Self::disabled_dates) and
+    /// [`disabled_weekdays`](Self::disabled_weekdays).
+    pub disabled_dates_predicate: Option<&'a dyn Fn(NaiveDate) -> bool>,
+
+    /// Layout used to display the calendar.
+    pub layout: CalendarLayout,
+
+    /// Names of the months and weekdays displayed in the calendar.
+    pub locale: DateLocale<'a>,
+
+    /// Whether ISO week numbers are displayed to the left of the calendar,
+    /// in the grid layout.
+    pub show_week_numbers: bool,
+
+    /// Function returning a custom style sheet for a date, e.g. to highlight
+    /// deadlines. It takes precedence over the styles of today's date and of
+    /// dates from other months, but not over the selected, checked and
+    /// unavailable ones.
+    pub date_style: Option<&'a dyn Fn(NaiveDate) -> Option<StyleSheet>>,
+
+    /// Dates initially checked when selecting multiple dates with
+    /// [`prompt_multiple`](Self::prompt_multiple).
+    pub selected_dates: Vec<NaiveDate>,
+
+    /// Minimum number of dates to check when selecting multiple dates.
+    pub min_selections: Option<usize>,
+
+    /// Maximum number of dates to check when selecting multiple dates.
+    pub max_selections: Option<usize>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
@@ -97,13 +151,18 @@ pub struct DateSelect<'a> {
 
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<DateSelectPromptAction>,
 }
 
 impl<'a> DateSelect<'a> {
@@ -117,6 +176,11 @@ impl<'a> DateSelect<'a> {
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("arrows to move, []{} move months and years, enter to select");
 
+    /// Help message displayed instead of the [default one](Self::DEFAULT_HELP_MESSAGE)
+    /// when selecting multiple dates.
+    pub const DEFAULT_MULTIPLE_HELP_MESSAGE: Option<&'a str> =
+        Some("arrows to move, space to check a date, enter to submit");
+
     /// Default validators added to the [DateSelect] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateValidator>> = vec![];
 
@@ -129,6 +193,15 @@ impl<'a> DateSelect<'a> {
     /// Default max date.
     pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
 
+    /// Default calendar layout, a month grid.
+    pub const DEFAULT_LAYOUT: CalendarLayout = CalendarLayout::Grid;
+
+    /// Default locale, English.
+    pub const DEFAULT_LOCALE: DateLocale<'static> = DateLocale::ENGLISH;
+
+    /// Default value of whether week numbers are displayed, false.
+    pub const DEFAULT_SHOW_WEEK_NUMBERS: bool = false;
+
     /// Creates a [DateSelect] with the provided message, along with default configuration values.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -136,11 +209,23 @@ impl<'a> DateSelect<'a> {
             starting_date: get_current_date(),
             min_date: Self::DEFAULT_MIN_DATE,
             max_date: Self::DEFAULT_MAX_DATE,
+            disabled_dates: vec![],
+            disabled_weekdays: vec![],
+            disabled_dates_predicate: None,
+            layout: Self::DEFAULT_LAYOUT,
+            locale: Self::DEFAULT_LOCALE,
+            show_week_numbers: Self::DEFAULT_SHOW_WEEK_NUMBERS,
+            date_style: None,
+            selected_dates: vec![],
+            min_selections: None,
+            max_selections: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -179,6 +264,116 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Adds dates that can't be selected, such as holidays. They are
+    /// displayed dimmed and skipped over when navigating.
+    pub fn with_disabled_dates(mut self, dates: &[NaiveDate]) -> Self {
+        self.disabled_dates.extend_from_slice(dates);
+        self
+    }
+
+    /// Adds weekdays that can't be selected, such as weekends. They are
+    /// displayed dimmed and skipped over when navigating.
+    pub fn with_disabled_weekdays(mut self, weekdays: &[chrono::Weekday]) -> Self {
+        self.disabled_weekdays.extend_from_slice(weekdays);
+        self
+    }
+
+    /// Sets a function deciding whether a date can't be selected, for rules
+    /// that are not easily listed, e.g. the last day of every month. Matching
+    /// dates are displayed dimmed and skipped over when navigating.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::{Datelike, NaiveDate};
+    /// use inquire::DateSelect;
+    ///
+    /// let is_month_end = |date: NaiveDate| date.succ_opt().is_some_and(|next| next.day() == 1);
+    ///
+    /// let date = DateSelect::new("Delivery date:")
+    ///     .with_disabled_dates_where(&is_month_end)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_disabled_dates_where(mut self, predicate: &'a dyn Fn(NaiveDate) -> bool) -> Self {
+        self.disabled_dates_predicate = Some(predicate);
+        self
+    }
+
+    /// Sets the layout used to display the calendar.
+    pub fn with_layout(mut self, layout: CalendarLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the locale used to display the calendar, along with its
+    /// [week start](DateLocale::week_start) and [formatter](DateLocale::formatter).
+    ///
+    /// Call [with_week_start](Self::with_week_start) or
+    /// [with_formatter](Self::with_formatter) afterwards to override them.
+    pub fn with_locale(mut self, locale: DateLocale<'a>) -> Self {
+        self.week_start = locale.week_start;
+        self.formatter = locale.formatter;
+        self.locale = locale;
+        self
+    }
+
+    /// Sets whether ISO week numbers are displayed to the left of the
+    /// calendar, in the grid layout.
+    ///
+    /// Each row is numbered after the ISO week its thursday belongs to, so
+    /// that the numbers stay meaningful when weeks start on sunday.
+    pub fn with_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
+
+    /// Sets a function returning a custom style sheet for a date, or `None`
+    /// to keep its default style, e.g. to display deadlines in red.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use inquire::{ui::{Color, StyleSheet}, DateSelect};
+    ///
+    /// let deadline = NaiveDate::from_ymd_opt(2021, 8, 13).unwrap();
+    /// let highlight = move |date: NaiveDate| {
+    ///     (date == deadline).then(|| StyleSheet::new().with_fg(Color::LightRed))
+    /// };
+    ///
+    /// let date = DateSelect::new("Release date:")
+    ///     .with_date_style(&highlight)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_date_style(
+        mut self,
+        date_style: &'a dyn Fn(NaiveDate) -> Option<StyleSheet>,
+    ) -> Self {
+        self.date_style = Some(date_style);
+        self
+    }
+
+    /// Sets the dates initially checked when selecting multiple dates with
+    /// [`prompt_multiple`](Self::prompt_multiple).
+    pub fn with_selected_dates(mut self, dates: &[NaiveDate]) -> Self {
+        self.selected_dates = dates.to_vec();
+        self
+    }
+
+    /// Sets the minimum number of dates to check when selecting multiple dates.
+    pub fn with_min_selections(mut self, min_selections: usize) -> Self {
+        self.min_selections = Some(min_selections);
+        self
+    }
+
+    /// Sets the maximum number of dates to check when selecting multiple dates.
+    pub fn with_max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
     /// Sets the starting date. Equivalent to [DateSelect::with_default](DateSelect::with_default).
     pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
         self.starting_date = starting_date;
@@ -223,17 +418,35 @@ impl<'a> DateSelect<'a> {
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<DateSelectPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -254,15 +467,84 @@ impl<'a> DateSelect<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<NaiveDate> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Async version of [prompt](Self::prompt), awaiting the keys pressed by
+    /// the user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<NaiveDate> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        crate::prompts::prompt_async::prompt_async(DateSelectPrompt::new(self)?, &mut backend).await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the date selected by the user. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, NaiveDate>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            DateSelectPrompt::new(self)?,
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
     pub(crate) fn prompt_with_backend<B: DateSelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<NaiveDate> {
         DateSelectPrompt::new(self)?.prompt(backend)
     }
+
+    /// Same as [prompt_multiple](Self::prompt_multiple), but returns `Ok(None)`
+    /// when the user skips or cancels the prompt by pressing ESC.
+    pub fn prompt_multiple_skippable(self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        match self.prompt_multiple() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Prompts the user to select any number of dates from the calendar,
+    /// e.g. to ask which days work for a meeting.
+    ///
+    /// The space key checks or unchecks the date under the cursor, and enter
+    /// submits the checked dates, returned in chronological order. Disabled
+    /// dates can't be checked, and the validators are applied to each date
+    /// as it is checked. The number of checked dates must be within the
+    /// [min](Self::with_min_selections) and [max](Self::with_max_selections)
+    /// selections, if set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::Weekday;
+    /// use inquire::DateSelect;
+    ///
+    /// let days = DateSelect::new("Which days work for you?")
+    ///     .with_disabled_weekdays(&[Weekday::Sat, Weekday::Sun])
+    ///     .with_min_selections(1)
+    ///     .prompt_multiple()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_multiple(self) -> InquireResult<Vec<NaiveDate>> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        self.prompt_multiple_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_multiple_with_backend<B: DateSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<NaiveDate>> {
+        MultiDateSelectPrompt::new(self)?.prompt(backend)
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
     cmp::{max, min, Ordering},
+    collections::BTreeSet,
     ops::Add,
 };
 
@@ -8,23 +9,63 @@ use chrono::{Datelike, Duration, Months, NaiveDate};
 use crate::{
     date_utils::{get_current_date, get_month},
     error::InquireResult,
+    event::PromptEvent,
     formatter::DateFormatter,
+    input::Input,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     prompts::prompt::{ActionResult, Prompt},
-    ui::date::DateSelectBackend,
+    ui::{date::DateSelectBackend, Key, KeyModifiers, StyleSheet},
     validator::{DateValidator, ErrorMessage, Validation},
-    DateSelect, InquireError,
+    Action, DateLocale, DateSelect, InnerAction, InputAction, InquireError,
 };
 
-use super::{action::DateSelectPromptAction, config::DateSelectConfig};
+use super::{
+    action::DateSelectPromptAction, config::DateSelectConfig, relative::parse_relative_date,
+};
+
+/// How many days the cursor looks ahead, and then behind, for a date that
+/// is not disabled when landing on a disabled one.
+const MAX_SKIPPED_DAYS: usize = 366;
+
+/// Help message displayed while the month picker is open.
+const MONTH_PICKER_HELP_MESSAGE: &str =
+    "arrows to pick a month, type a year, enter to jump, esc to go back";
+
+/// Help message displayed while the date input is open.
+const DATE_INPUT_HELP_MESSAGE: &str =
+    "type a date, e.g. tomorrow, next fri, +3d or 2025-03-01, esc to go back";
+
+/// Error message displayed when the typed date can't be parsed.
+const UNRECOGNIZED_DATE_ERROR: &str = "Unrecognized date, try e.g. tomorrow, next fri or +3d";
+
+/// State of the month picker, used to jump to a month of any year without
+/// paging through the calendar.
+struct MonthPicker {
+    /// First day of the picked month.
+    month: NaiveDate,
+    /// Digits of the year typed so far, applied once it has four digits.
+    typed_year: String,
+}
 
 pub struct DateSelectPrompt<'a> {
     message: &'a str,
     config: DateSelectConfig,
     current_date: NaiveDate,
+    disabled_dates: Vec<NaiveDate>,
+    disabled_weekdays: Vec<chrono::Weekday>,
+    disabled_dates_predicate: Option<&'a dyn Fn(NaiveDate) -> bool>,
+    month_picker: Option<MonthPicker>,
+    date_input: Option<Input>,
+    locale: DateLocale<'a>,
+    date_style: Option<&'a dyn Fn(NaiveDate) -> Option<StyleSheet>>,
     help_message: Option<&'a str>,
     formatter: DateFormatter<'a>,
     validators: Vec<Box<dyn DateValidator>>,
     error: Option<ErrorMessage>,
+    key_bindings: KeyBindings<DateSelectPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a> DateSelectPrompt<'a> {
@@ -44,15 +85,62 @@ impl<'a> DateSelectPrompt<'a> {
             }
         }
 
-        Ok(Self {
+        let mut prompt = Self {
             message: so.message,
             current_date: so.starting_date,
             config: (&so).into(),
+            disabled_dates: so.disabled_dates,
+            disabled_weekdays: so.disabled_weekdays,
+            disabled_dates_predicate: so.disabled_dates_predicate,
+            month_picker: None,
+            date_input: None,
+            locale: so.locale,
+            date_style: so.date_style,
             help_message: so.help_message,
             formatter: so.formatter,
             validators: so.validators,
             error: None,
-        })
+            key_bindings: so.key_bindings,
+            key_handler: so.settings.key_handler,
+            escape_behavior: so.settings.escape_behavior,
+            cancel_guard: so.settings.cancel_guard,
+        };
+
+        if let Some(date) = prompt.nearest_enabled_date(prompt.current_date, true) {
+            prompt.current_date = date;
+        }
+
+        Ok(prompt)
+    }
+
+    fn is_disabled(&self, date: NaiveDate) -> bool {
+        self.config.min_date.is_some_and(|min_date| date < min_date)
+            || self.config.max_date.is_some_and(|max_date| date > max_date)
+            || self.disabled_dates.contains(&date)
+            || self.disabled_weekdays.contains(&date.weekday())
+            || self
+                .disabled_dates_predicate
+                .is_some_and(|predicate| predicate(date))
+    }
+
+    /// Closest date to the provided one that is not disabled, looking first
+    /// in the direction of the movement and then in the opposite one.
+    fn nearest_enabled_date(&self, date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+        let search = |forward: bool| {
+            let mut date = date;
+            for _ in 0..MAX_SKIPPED_DAYS {
+                if !self.is_disabled(date) {
+                    return Some(date);
+                }
+                date = match forward {
+                    true => date.succ_opt()?,
+                    false => date.pred_opt()?,
+                };
+            }
+            None
+        };
+
+        search(forward).or_else(|| search(!forward))
     }
 
     fn shift_date(&mut self, duration: Duration) -> ActionResult {
@@ -60,22 +148,139 @@ impl<'a> DateSelectPrompt<'a> {
     }
 
     fn shift_months(&mut self, qty: i32) -> ActionResult {
-        let new_date = match qty.cmp(&0) {
-            Ordering::Greater | Ordering::Equal => {
-                let qty_as_months = Months::new(qty as u32);
-                self.current_date
-                    .checked_add_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MAX)
-            }
-            Ordering::Less => {
-                let qty_as_months = Months::new((-qty) as u32);
-                self.current_date
-                    .checked_sub_months(qty_as_months)
-                    .unwrap_or(NaiveDate::MIN)
+        self.update_date(add_months(self.current_date, qty))
+    }
+
+    fn open_month_picker(&mut self) -> ActionResult {
+        self.month_picker = Some(MonthPicker {
+            month: self.current_date.with_day(1).unwrap_or(self.current_date),
+            typed_year: String::new(),
+        });
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn confirm_month_picker(&mut self) -> ActionResult {
+        let Some(picker) = self.month_picker.take() else {
+            return ActionResult::Clean;
+        };
+
+        // keep the day of the month, unless the picked month is shorter
+        let last_day = add_months(picker.month, 1)
+            .pred_opt()
+            .map(|date| date.day())
+            .unwrap_or(31);
+        let day = min(self.current_date.day(), last_day);
+
+        self.update_date(picker.month.with_day(day).unwrap_or(picker.month));
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn handle_month_picker(&mut self, action: DateSelectPromptAction) -> ActionResult {
+        let Some(picker) = self.month_picker.as_mut() else {
+            return ActionResult::Clean;
+        };
+
+        // the picker is a grid of three months per row
+        let months = match action {
+            DateSelectPromptAction::GoToPrevDay => -1,
+            DateSelectPromptAction::GoToNextDay => 1,
+            DateSelectPromptAction::GoToPrevWeek => -3,
+            DateSelectPromptAction::GoToNextWeek => 3,
+            DateSelectPromptAction::GoToPrevMonth => -12,
+            DateSelectPromptAction::GoToNextMonth => 12,
+            DateSelectPromptAction::GoToPrevYear => -120,
+            DateSelectPromptAction::GoToNextYear => 120,
+            DateSelectPromptAction::TypeYearDigit(digit) => {
+                picker.typed_year.push(digit);
+                if picker.typed_year.len() == 4 {
+                    let year = picker.typed_year.parse().ok();
+                    if let Some(month) = year.and_then(|year| picker.month.with_year(year)) {
+                        picker.month = month;
+                    }
+                    picker.typed_year.clear();
+                }
+                return ActionResult::NeedsRedraw;
+            }
+            DateSelectPromptAction::EraseYearDigit => {
+                return match picker.typed_year.pop() {
+                    Some(_) => ActionResult::NeedsRedraw,
+                    None => ActionResult::Clean,
+                };
+            }
+            DateSelectPromptAction::ConfirmMonthPicker => return self.confirm_month_picker(),
+            DateSelectPromptAction::CloseMonthPicker => {
+                self.month_picker = None;
+                return ActionResult::NeedsRedraw;
+            }
+            DateSelectPromptAction::ToggleDate
+            | DateSelectPromptAction::OpenMonthPicker
+            | DateSelectPromptAction::OpenDateInput
+            | DateSelectPromptAction::DateInput(_)
+            | DateSelectPromptAction::ConfirmDateInput
+            | DateSelectPromptAction::CloseDateInput => {
+                return ActionResult::Clean;
             }
         };
 
-        self.update_date(new_date)
+        picker.typed_year.clear();
+        picker.month = add_months(picker.month, months);
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Date typed in the date input, when open, or the error to display when
+    /// it can't be selected. An empty input stands for the current date.
+    fn typed_date(&self) -> Option<Result<NaiveDate, ErrorMessage>> {
+        let input = self.date_input.as_ref()?;
+        if input.is_empty() {
+            return Some(Ok(self.current_date));
+        }
+
+        let result = match parse_relative_date(input.content(), get_current_date()) {
+            Some(date) if self.is_disabled(date) => Err("This date is not available".into()),
+            Some(date) => Ok(date),
+            None => Err(UNRECOGNIZED_DATE_ERROR.into()),
+        };
+
+        Some(result)
+    }
+
+    /// Closes the date input, jumping to the typed date. Returns false,
+    /// keeping the input open and displaying an error, when the typed date
+    /// can't be selected.
+    fn confirm_date_input(&mut self) -> bool {
+        match self.typed_date() {
+            Some(Ok(date)) => {
+                self.current_date = date;
+                self.date_input = None;
+                self.error = None;
+                true
+            }
+            Some(Err(message)) => {
+                self.error = Some(message);
+                false
+            }
+            None => true,
+        }
+    }
+
+    fn handle_date_input(&mut self, action: InputAction) -> ActionResult {
+        let Some(input) = self.date_input.as_mut() else {
+            return ActionResult::Clean;
+        };
+
+        let result: ActionResult = input.handle(action).into();
+
+        // the cursor follows the typed date as soon as it can be selected
+        if result.needs_redraw() {
+            if let Some(Ok(date)) = self.typed_date() {
+                self.current_date = date;
+            }
+        }
+
+        result
     }
 
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
@@ -83,12 +288,18 @@ impl<'a> DateSelectPrompt<'a> {
             return ActionResult::Clean;
         }
 
-        self.current_date = new_date;
+        let forward = new_date > self.current_date;
+
+        let mut new_date = new_date;
         if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
+            new_date = max(new_date, min_date);
         }
         if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
+            new_date = min(new_date, max_date);
+        }
+
+        if let Some(date) = self.nearest_enabled_date(new_date, forward) {
+            self.current_date = date;
         }
 
         ActionResult::NeedsRedraw
@@ -109,6 +320,58 @@ impl<'a> DateSelectPrompt<'a> {
     fn cur_answer(&self) -> NaiveDate {
         self.current_date
     }
+
+    fn render_calendar<B: DateSelectBackend>(
+        &self,
+        backend: &mut B,
+        is_checked: &dyn Fn(NaiveDate) -> bool,
+    ) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_calendar_prompt(prompt, self.date_input.as_ref())?;
+
+        if let Some(picker) = &self.month_picker {
+            backend.render_month_picker(
+                get_month(picker.month.month()),
+                picker.month.year(),
+                &picker.typed_year,
+                self.config.min_date,
+                self.config.max_date,
+                &self.locale,
+            )?;
+            backend.render_help_message(MONTH_PICKER_HELP_MESSAGE)?;
+
+            return Ok(());
+        }
+
+        backend.render_calendar(
+            self.config.layout,
+            get_month(self.current_date.month()),
+            self.current_date.year(),
+            self.config.week_start,
+            get_current_date(),
+            self.current_date,
+            self.config.min_date,
+            self.config.max_date,
+            &|date| self.is_disabled(date),
+            is_checked,
+            &|date| self.date_style.and_then(|date_style| date_style(date)),
+            self.config.show_week_numbers,
+            &self.locale,
+        )?;
+
+        if self.date_input.is_some() {
+            backend.render_help_message(DATE_INPUT_HELP_MESSAGE)?;
+        } else if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, B> Prompt<B> for DateSelectPrompt<'a>
@@ -131,7 +394,85 @@ where
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<DateSelectPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        vec![PromptEvent::DateChanged(self.current_date)]
+    }
+
+    fn action_from_key(&mut self, key: Key) -> Option<Action<DateSelectPromptAction>> {
+        if self.date_input.is_some() {
+            let action = match key {
+                // when selecting multiple dates, enter jumps to the typed date to be checked
+                Key::Enter if self.config.multiple => {
+                    Some(DateSelectPromptAction::ConfirmDateInput)
+                }
+                Key::Enter => None,
+                Key::Escape => Some(DateSelectPromptAction::CloseDateInput),
+                key => InputAction::from_key(key, &()).map(DateSelectPromptAction::DateInput),
+            };
+
+            if let Some(action) = action {
+                return Some(Action::Inner(action));
+            }
+        }
+
+        if self.month_picker.is_some() {
+            let action = match key {
+                Key::Enter => Some(DateSelectPromptAction::ConfirmMonthPicker),
+                Key::Escape | Key::Char('g', KeyModifiers::NONE) => {
+                    Some(DateSelectPromptAction::CloseMonthPicker)
+                }
+                Key::Char(c, KeyModifiers::NONE) if c.is_ascii_digit() => {
+                    Some(DateSelectPromptAction::TypeYearDigit(c))
+                }
+                Key::Backspace => Some(DateSelectPromptAction::EraseYearDigit),
+                _ => None,
+            };
+
+            if let Some(action) = action {
+                return Some(Action::Inner(action));
+            }
+        }
+
+        Action::from_key(key, &self.config)
+    }
+
+    /// Accepts the same dates as the date input, e.g. `2025-03-01`, `tomorrow`
+    /// or `+3d`.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.date_input = Some(Input::new_with(answer.trim()));
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
+        if !self.confirm_date_input() {
+            return Ok(None);
+        }
+
+        if self.is_disabled(self.cur_answer()) {
+            self.error = Some("This date is not available".into());
+            return Ok(None);
+        }
+
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()),
             Validation::Invalid(msg) => {
@@ -144,6 +485,10 @@ where
     }
 
     fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
+        if self.month_picker.is_some() {
+            return Ok(self.handle_month_picker(action));
+        }
+
         let result = match action {
             DateSelectPromptAction::GoToPrevWeek => self.shift_date(
                 Duration::try_weeks(-1)
@@ -165,34 +510,219 @@ where
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
+            DateSelectPromptAction::OpenMonthPicker => self.open_month_picker(),
+            DateSelectPromptAction::OpenDateInput => {
+                self.date_input = Some(Input::new());
+                self.error = None;
+                ActionResult::NeedsRedraw
+            }
+            DateSelectPromptAction::DateInput(input_action) => self.handle_date_input(input_action),
+            DateSelectPromptAction::ConfirmDateInput => {
+                self.confirm_date_input();
+                ActionResult::NeedsRedraw
+            }
+            DateSelectPromptAction::CloseDateInput => {
+                self.date_input = None;
+                self.error = None;
+                ActionResult::NeedsRedraw
+            }
+            DateSelectPromptAction::ToggleDate
+            | DateSelectPromptAction::ConfirmMonthPicker
+            | DateSelectPromptAction::CloseMonthPicker
+            | DateSelectPromptAction::TypeYearDigit(_)
+            | DateSelectPromptAction::EraseYearDigit => ActionResult::Clean,
         };
 
         Ok(result)
     }
 
     fn render(&self, backend: &mut B) -> InquireResult<()> {
-        let prompt = &self.message;
+        self.render_calendar(backend, &|_| false)
+    }
+}
 
-        if let Some(err) = &self.error {
-            backend.render_error_message(err)?;
+/// Prompt selecting multiple dates, checked and unchecked with the space key
+/// while navigating the calendar of a [DateSelectPrompt].
+pub struct MultiDateSelectPrompt<'a> {
+    inner: DateSelectPrompt<'a>,
+    checked: BTreeSet<NaiveDate>,
+    min_selections: Option<usize>,
+    max_selections: Option<usize>,
+}
+
+impl<'a> MultiDateSelectPrompt<'a> {
+    pub fn new(so: DateSelect<'a>) -> InquireResult<Self> {
+        if let (Some(min), Some(max)) = (so.min_selections, so.max_selections) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min selections can not be greater than max selections".into(),
+                ));
+            }
         }
 
-        backend.render_calendar_prompt(prompt)?;
+        let checked = so.selected_dates.iter().copied().collect();
+        let (min_selections, max_selections) = (so.min_selections, so.max_selections);
 
-        backend.render_calendar(
-            get_month(self.current_date.month()),
-            self.current_date.year(),
-            self.config.week_start,
-            get_current_date(),
-            self.current_date,
-            self.config.min_date,
-            self.config.max_date,
-        )?;
+        let help_message = match so.help_message == DateSelect::DEFAULT_HELP_MESSAGE {
+            true => DateSelect::DEFAULT_MULTIPLE_HELP_MESSAGE,
+            false => so.help_message,
+        };
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+        let mut inner = DateSelectPrompt::new(so)?;
+        inner.config.multiple = true;
+        inner.help_message = help_message;
+
+        Ok(Self {
+            inner,
+            checked,
+            min_selections,
+            max_selections,
+        })
+    }
+
+    fn toggle_date(&mut self) -> InquireResult<ActionResult> {
+        let date = self.inner.cur_answer();
+
+        if !self.checked.remove(&date) {
+            if self.inner.is_disabled(date) {
+                return Ok(ActionResult::Clean);
+            }
+
+            if let Validation::Invalid(msg) = self.inner.validate_current_answer()? {
+                self.inner.error = Some(msg);
+                return Ok(ActionResult::NeedsRedraw);
+            }
+
+            self.checked.insert(date);
+        }
+
+        self.inner.error = None;
+        Ok(ActionResult::NeedsRedraw)
+    }
+}
+
+impl<'a, B> Prompt<B> for MultiDateSelectPrompt<'a>
+where
+    B: DateSelectBackend,
+{
+    type Config = DateSelectConfig;
+    type InnerAction = DateSelectPromptAction;
+    type Output = Vec<NaiveDate>;
+
+    fn message(&self) -> &str {
+        self.inner.message
+    }
+
+    fn format_answer(&self, answer: &Vec<NaiveDate>) -> String {
+        answer
+            .iter()
+            .map(|date| (self.inner.formatter)(*date))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn config(&self) -> &DateSelectConfig {
+        &self.inner.config
+    }
+
+    fn key_bindings(&self) -> Option<&KeyBindings<DateSelectPromptAction>> {
+        Prompt::<B>::key_bindings(&self.inner)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        Prompt::<B>::key_handler(&self.inner)
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        Prompt::<B>::escape_behavior(&self.inner)
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        Prompt::<B>::cancel_guard(&self.inner)
+    }
+
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        Prompt::<B>::observable_state(&self.inner)
+    }
+
+    fn action_from_key(&mut self, key: Key) -> Option<Action<DateSelectPromptAction>> {
+        Prompt::<B>::action_from_key(&mut self.inner, key)
+    }
+
+    /// Checks the dates listed in the answer, separated by commas, accepting
+    /// the same dates as the date input.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.checked.clear();
+
+        for answer in answer.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+            self.inner.date_input = Some(Input::new_with(answer));
+            if !self.inner.confirm_date_input() {
+                self.inner.date_input = None;
+                return Err(InquireError::InvalidAnswer(format!(
+                    "\"{}\" is not an available date",
+                    answer
+                )));
+            }
+
+            if let Validation::Invalid(msg) = self.inner.validate_current_answer()? {
+                let reason = match msg {
+                    ErrorMessage::Custom(msg) => msg,
+                    ErrorMessage::Default => format!("\"{}\" is not a valid date", answer),
+                };
+                return Err(InquireError::InvalidAnswer(reason));
+            }
+
+            self.checked.insert(self.inner.cur_answer());
         }
 
         Ok(())
     }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.inner.error.as_ref()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<NaiveDate>>> {
+        let count = self.checked.len();
+
+        let error = match (self.min_selections, self.max_selections) {
+            (Some(min), _) if count < min => Some(format!("Please select at least {min} dates")),
+            (_, Some(max)) if count > max => Some(format!("Please select at most {max} dates")),
+            _ => None,
+        };
+
+        if let Some(error) = error {
+            self.inner.error = Some(error.into());
+            return Ok(None);
+        }
+
+        Ok(Some(self.checked.iter().copied().collect()))
+    }
+
+    fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
+        match action {
+            DateSelectPromptAction::ToggleDate if self.inner.month_picker.is_none() => {
+                self.toggle_date()
+            }
+            action => Prompt::<B>::handle(&mut self.inner, action),
+        }
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        self.inner
+            .render_calendar(backend, &|date| self.checked.contains(&date))
+    }
+}
+
+/// Adds the provided number of months, negative to subtract, saturating at
+/// the earliest and latest representable dates.
+pub(super) fn add_months(date: NaiveDate, qty: i32) -> NaiveDate {
+    match qty.cmp(&0) {
+        Ordering::Greater | Ordering::Equal => date
+            .checked_add_months(Months::new(qty.unsigned_abs()))
+            .unwrap_or(NaiveDate::MAX),
+        Ordering::Less => date
+            .checked_sub_months(Months::new(qty.unsigned_abs()))
+            .unwrap_or(NaiveDate::MIN),
+    }
 }
@@ -0,0 +1,186 @@
+use std::convert::TryFrom;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use super::prompt::add_months;
+
+/// Parses a date typed in the date input of a [DateSelect](crate::DateSelect),
+/// relative to today when applicable.
+///
+/// Accepted entries, case-insensitive:
+/// - `today`, `tomorrow` and `yesterday`.
+/// - A weekday, e.g. `friday` or `fri`, for the first one from today on.
+///   Preceded by `next` for the first one after today, or by `last` for the
+///   last one before today.
+/// - `next` or `last` followed by `week`, `month` or `year`.
+/// - An offset such as `+3d`, `-2w`, `+1m` or `+1y`, also written as
+///   `3 days`, `in 2 weeks` or `1 year ago`.
+/// - A date in the ISO 8601 format, `2025-03-01`, or as `2025/03/01`.
+pub(crate) fn parse_relative_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    let words: Vec<&str> = input.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["today"] => return Some(today),
+        ["tomorrow"] => return today.succ_opt(),
+        ["yesterday"] => return today.pred_opt(),
+        [direction @ ("next" | "last"), word] => {
+            let qty = match *direction {
+                "next" => 1,
+                _ => -1,
+            };
+
+            if let Ok(weekday) = word.parse::<Weekday>() {
+                return nearest_weekday(today, weekday, qty);
+            }
+
+            return shift(today, qty, word);
+        }
+        [word] => {
+            if let Ok(weekday) = word.parse::<Weekday>() {
+                return match today.weekday() == weekday {
+                    true => Some(today),
+                    false => nearest_weekday(today, weekday, 1),
+                };
+            }
+        }
+        _ => {}
+    }
+
+    for format in ["%Y-%m-%d", "%Y/%m/%d"] {
+        if let Ok(date) = NaiveDate::parse_from_str(&input, format) {
+            return Some(date);
+        }
+    }
+
+    parse_offset(&words).and_then(|(qty, unit)| shift(today, qty, unit))
+}
+
+/// Parses an offset such as `+3d`, `3 days`, `in 3 days` or `3 days ago`
+/// into a signed quantity and a unit.
+fn parse_offset<'a>(words: &[&'a str]) -> Option<(i64, &'a str)> {
+    let (words, negative) = match words {
+        ["in", rest @ ..] => (rest, false),
+        [rest @ .., "ago"] => (rest, true),
+        rest => (rest, false),
+    };
+
+    let (number, unit) = match words {
+        [number, unit] => (*number, *unit),
+        [offset] => {
+            let split = offset
+                .char_indices()
+                .find(|(i, c)| *i > 0 && c.is_ascii_alphabetic())
+                .map(|(i, _)| i)?;
+            offset.split_at(split)
+        }
+        _ => return None,
+    };
+
+    let qty = number
+        .strip_prefix('+')
+        .unwrap_or(number)
+        .parse::<i64>()
+        .ok()?;
+
+    match negative {
+        true => Some((-qty, unit)),
+        false => Some((qty, unit)),
+    }
+}
+
+/// Shifts a date by a quantity of the provided unit, e.g. `d`, `weeks` or
+/// `month`.
+fn shift(date: NaiveDate, qty: i64, unit: &str) -> Option<NaiveDate> {
+    match unit {
+        "d" | "day" | "days" => date.checked_add_signed(Duration::try_days(qty)?),
+        "w" | "week" | "weeks" => date.checked_add_signed(Duration::try_weeks(qty)?),
+        "m" | "month" | "months" => Some(add_months(date, i32::try_from(qty).ok()?)),
+        "y" | "year" | "years" => Some(add_months(date, i32::try_from(qty.checked_mul(12)?).ok()?)),
+        _ => None,
+    }
+}
+
+/// First date after, or before when `direction` is negative, the provided
+/// one that falls on the weekday.
+fn nearest_weekday(date: NaiveDate, weekday: Weekday, direction: i64) -> Option<NaiveDate> {
+    let mut date = date;
+    for _ in 0..7 {
+        date = date.checked_add_signed(Duration::try_days(direction)?)?;
+        if date.weekday() == weekday {
+            return Some(date);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::parse_relative_date;
+
+    fn date(y: i32, m: u32, d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(y, m, d)
+    }
+
+    // a wednesday
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+    }
+
+    #[test]
+    fn keywords_are_relative_to_today() {
+        assert_eq!(date(2025, 1, 15), parse_relative_date("Today", today()));
+        assert_eq!(date(2025, 1, 16), parse_relative_date("tomorrow", today()));
+        assert_eq!(
+            date(2025, 1, 14),
+            parse_relative_date(" yesterday ", today())
+        );
+        assert_eq!(date(2025, 1, 22), parse_relative_date("next week", today()));
+        assert_eq!(
+            date(2024, 12, 15),
+            parse_relative_date("last month", today())
+        );
+    }
+
+    #[test]
+    fn weekdays_are_looked_up_from_today() {
+        assert_eq!(date(2025, 1, 17), parse_relative_date("friday", today()));
+        assert_eq!(date(2025, 1, 15), parse_relative_date("wed", today()));
+        assert_eq!(
+            date(2025, 1, 22),
+            parse_relative_date("next wednesday", today())
+        );
+        assert_eq!(date(2025, 1, 13), parse_relative_date("last mon", today()));
+    }
+
+    #[test]
+    fn offsets_are_added_to_today() {
+        assert_eq!(date(2025, 1, 18), parse_relative_date("+3d", today()));
+        assert_eq!(date(2025, 1, 1), parse_relative_date("-2w", today()));
+        assert_eq!(date(2025, 2, 15), parse_relative_date("+1m", today()));
+        assert_eq!(date(2026, 1, 15), parse_relative_date("1y", today()));
+        assert_eq!(
+            date(2025, 1, 25),
+            parse_relative_date("in 10 days", today())
+        );
+        assert_eq!(
+            date(2024, 1, 15),
+            parse_relative_date("1 year ago", today())
+        );
+    }
+
+    #[test]
+    fn absolute_dates_are_parsed() {
+        assert_eq!(date(2025, 3, 1), parse_relative_date("2025-03-01", today()));
+        assert_eq!(date(2025, 3, 1), parse_relative_date("2025/3/1", today()));
+    }
+
+    #[test]
+    fn unknown_entries_are_rejected() {
+        for input in ["", "someday", "+3x", "next", "2025-02-30", "in days", "d"] {
+            assert_eq!(None, parse_relative_date(input, today()), "{}", input);
+        }
+    }
+}
@@ -5,10 +5,10 @@ use crate::{
     error::InquireResult,
     ui::{
         test::{FakeBackend, Token},
-        Key, KeyModifiers,
+        Key, KeyModifiers, Styled,
     },
     validator::{ErrorMessage, Validation},
-    DateSelect, InquireError,
+    CalendarLayout, DateSelect, InquireError,
 };
 use chrono::{Datelike, NaiveDate};
 
@@ -44,6 +44,70 @@ date_test!(
     DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
 );
 
+date_test!(
+    agenda_layout_moves_by_day_vertically,
+    vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter
+    ],
+    NaiveDate::from_ymd_opt(2021, 1, 10).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .with_layout(CalendarLayout::Agenda)
+);
+
+date_test!(
+    strip_layout_moves_by_week_vertically,
+    vec![Key::Down(KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 1, 16).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .with_layout(CalendarLayout::Strip)
+);
+
+date_test!(
+    disabled_weekdays_are_skipped_when_moving_forward,
+    vec![Key::Right(KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 1, 11).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 8).unwrap())
+        .with_disabled_weekdays(&[chrono::Weekday::Sat, chrono::Weekday::Sun])
+);
+
+date_test!(
+    disabled_dates_are_skipped_when_moving_backward,
+    vec![Key::Up(KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 1, 3).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 11).unwrap())
+        .with_disabled_dates(&[
+            NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 5).unwrap(),
+        ])
+        .with_disabled_weekdays(&[chrono::Weekday::Mon])
+);
+
+date_test!(
+    disabled_starting_date_moves_to_next_available_date,
+    vec![Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 1, 3).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 1).unwrap())
+        .with_disabled_dates_where(&|date| date.day() < 3)
+);
+
+date_test!(
+    cursor_stays_when_every_date_ahead_is_disabled,
+    vec![Key::Right(KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 1, 9).unwrap(),
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .with_max_date(NaiveDate::from_ymd_opt(2021, 1, 12).unwrap())
+        .with_disabled_dates_where(&|date| date.day() > 9)
+);
+
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
 /// as a DateSelect validator.
@@ -776,6 +840,98 @@ fn default_formatter_outputs_answer_as_extensive_locale() -> InquireResult<()> {
     Ok(())
 }
 
+#[test]
+fn locale_translates_calendar_and_answer() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+        DateLocale,
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output);
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::default(),
+        )
+        .unwrap();
+        let ans = DateSelect::new("Datum")
+            .with_default(NaiveDate::from_ymd_opt(2021, 3, 7).unwrap())
+            .with_locale(DateLocale::GERMAN)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!(NaiveDate::from_ymd_opt(2021, 3, 7).unwrap(), ans);
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content.contains(text)))
+    };
+    assert!(rendered("märz 2021"));
+    assert!(rendered("mo di mi do fr sa so"));
+    assert!(rendered("7. März 2021"));
+}
+
+#[test]
+fn week_numbers_and_date_styles_are_rendered() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, Color, RenderConfig, StyleSheet},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output);
+    let deadline = NaiveDate::from_ymd_opt(2021, 1, 13).unwrap();
+    let highlight = move |date: NaiveDate| {
+        (date == deadline).then(|| StyleSheet::new().with_fg(Color::LightRed))
+    };
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::empty(),
+        )
+        .unwrap();
+        DateSelect::new("Date")
+            .with_default(NaiveDate::from_ymd_opt(2021, 1, 4).unwrap())
+            .with_week_start(chrono::Weekday::Mon)
+            .with_week_numbers(true)
+            .with_date_style(&highlight)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let texts: Vec<&Styled<String>> = output
+        .iter()
+        .filter_map(|token| match token {
+            MockTerminalToken::Text(t) => Some(t),
+            _ => None,
+        })
+        .collect();
+
+    // january 1st, 2021 belongs to the last ISO week of 2020
+    for week in ["53", " 1", " 2", " 3", " 4", " 5"] {
+        assert!(
+            texts.iter().any(|t| t.content == week),
+            "missing week {}",
+            week
+        );
+    }
+    assert!(texts
+        .iter()
+        .any(|t| t.content == "13" && t.style.fg == Some(Color::LightRed)));
+    assert!(texts
+        .iter()
+        .any(|t| t.content == "14" && t.style.fg.is_none()));
+}
+
 #[test]
 fn escape_raises_error() -> InquireResult<()> {
     let mut backend = FakeBackend::new(vec![Key::Escape]);
@@ -1017,3 +1173,278 @@ fn multiple_validators_are_respected() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn backend_receives_custom_layout() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let _ = DateSelect::new("Question")
+        .with_layout(CalendarLayout::Strip)
+        .prompt_with_backend(&mut backend)?;
+
+    let first_frame = backend.frames().first().expect("no frames were rendered");
+    assert!(
+        first_frame.tokens().iter().any(|t| matches!(
+            t,
+            Token::Calendar {
+                layout: CalendarLayout::Strip,
+                ..
+            }
+        )),
+        "Rendered frame did not contain a calendar token with the custom layout",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn strip_layout_scrolls_to_keep_selected_day_visible() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(20, 10).unwrap());
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::default(),
+        )
+        .unwrap();
+        let ans = DateSelect::new("Date")
+            .with_default(NaiveDate::from_ymd_opt(2021, 1, 15).unwrap())
+            .with_layout(CalendarLayout::Strip)
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!(NaiveDate::from_ymd_opt(2021, 1, 15).unwrap(), ans);
+    }
+
+    // 18 columns after the prefix fit 6 days, centered around the 15th.
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("12"));
+    assert!(rendered("17"));
+    assert!(!rendered("11"));
+    assert!(!rendered("18"));
+}
+
+#[test]
+fn multiple_dates_are_toggled_with_space_and_returned_in_order() -> InquireResult<()> {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let mut backend = FakeBackend::new(vec![
+        space,
+        Key::Left(KeyModifiers::NONE),
+        space,
+        Key::Left(KeyModifiers::NONE),
+        space,
+        space,
+        Key::Enter,
+    ]);
+
+    let ans = DateSelect::new("Which days work for you?")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(
+        vec![
+            NaiveDate::from_ymd_opt(2021, 1, 8).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 9).unwrap(),
+        ],
+        ans
+    );
+
+    Ok(())
+}
+
+#[test]
+fn multiple_selection_count_is_validated() -> InquireResult<()> {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        space,
+        Key::Right(KeyModifiers::NONE),
+        space,
+        Key::Right(KeyModifiers::NONE),
+        space,
+        Key::Enter,
+        space,
+        Key::Enter,
+    ]);
+
+    let ans = DateSelect::new("Which days work for you?")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .with_selected_dates(&[NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()])
+        .with_disabled_dates(&[NaiveDate::from_ymd_opt(2021, 1, 10).unwrap()])
+        .with_min_selections(3)
+        .with_max_selections(3)
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(
+        vec![
+            NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 9).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 11).unwrap(),
+        ],
+        ans
+    );
+
+    let errors = [
+        "Please select at least 3 dates",
+        "Please select at most 3 dates",
+    ];
+    for error in errors {
+        assert!(
+            backend
+                .frames()
+                .iter()
+                .any(|frame| frame
+                    .has_token(&Token::ErrorMessage(ErrorMessage::Custom(error.into()))))
+        );
+    }
+
+    Ok(())
+}
+
+date_test!(
+    month_picker_jumps_to_typed_year_and_picked_month,
+    {
+        let mut keys = vec![Key::Char('g', KeyModifiers::NONE)];
+        keys.append(&mut Key::char_keys_from_str("1987"));
+        keys.push(Key::Right(KeyModifiers::NONE));
+        keys.push(Key::Right(KeyModifiers::NONE));
+        keys.push(Key::Enter);
+        keys.push(Key::Enter);
+        keys
+    },
+    NaiveDate::from_ymd_opt(1987, 3, 9).unwrap(),
+    DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+date_test!(
+    month_picker_keeps_day_within_shorter_months,
+    vec![
+        Key::Char('g', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter
+    ],
+    NaiveDate::from_ymd_opt(2021, 2, 28).unwrap(),
+    DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 31).unwrap())
+);
+
+date_test!(
+    escape_closes_month_picker_without_jumping_or_canceling,
+    vec![
+        Key::Char('g', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Escape,
+        Key::Enter
+    ],
+    NaiveDate::from_ymd_opt(2021, 1, 9).unwrap(),
+    DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+#[test]
+fn month_picker_displays_year_being_typed() -> InquireResult<()> {
+    let mut keys = vec![Key::Char('g', KeyModifiers::NONE)];
+    keys.append(&mut Key::char_keys_from_str("19"));
+    keys.push(Key::Escape);
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::MonthPicker {
+            month: chrono::Month::January,
+            year: 2021,
+            typed_year: "19".into(),
+        })));
+
+    Ok(())
+}
+
+date_test!(
+    typed_date_is_submitted_with_enter,
+    {
+        let mut keys = vec![Key::Char('/', KeyModifiers::NONE)];
+        keys.append(&mut Key::char_keys_from_str("2025-03-01"));
+        keys.push(Key::Enter);
+        keys
+    },
+    NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+    DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+date_test!(
+    escape_closes_date_input_without_jumping_or_canceling,
+    {
+        let mut keys = vec![Key::Char('/', KeyModifiers::NONE)];
+        keys.append(&mut Key::char_keys_from_str("2025-03-01"));
+        keys.push(Key::Escape);
+        keys.push(Key::Enter);
+        keys
+    },
+    NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+    DateSelect::new("Date").with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+#[test]
+fn unrecognized_typed_date_is_not_submitted() -> InquireResult<()> {
+    let mut keys = vec![Key::Char('/', KeyModifiers::NONE)];
+    keys.append(&mut Key::char_keys_from_str("someday"));
+    keys.push(Key::Enter);
+    keys.push(Key::Char('u', KeyModifiers::CONTROL));
+    keys.append(&mut Key::char_keys_from_str("+3d"));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(get_current_date() + chrono::Duration::days(3), ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(
+            |frame| frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+                "Unrecognized date, try e.g. tomorrow, next fri or +3d".into()
+            )))
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn typed_date_is_checked_when_selecting_multiple_dates() -> InquireResult<()> {
+    let mut keys = vec![Key::Char('/', KeyModifiers::NONE)];
+    keys.append(&mut Key::char_keys_from_str("2021-01-12"));
+    keys.push(Key::Enter);
+    keys.push(Key::Char(' ', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = DateSelect::new("Date")
+        .with_default(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .prompt_multiple_with_backend(&mut backend)?;
+
+    assert_eq!(vec![NaiveDate::from_ymd_opt(2021, 1, 12).unwrap()], ans);
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use std::convert::TryFrom;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::{date::DateSelectBackend, prompt_backend},
+    DateSelect,
+};
+
+/// Converts a [time::Date] to the date type used by the calendar.
+fn from_time_date(date: time::Date) -> NaiveDate {
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u8::from(date.month()).into(),
+        date.day().into(),
+    )
+    .unwrap_or_else(|| match date.year() < 0 {
+        true => NaiveDate::MIN,
+        false => NaiveDate::MAX,
+    })
+}
+
+/// Converts a date selected in the calendar to a [time::Date], failing when
+/// it is out of the range supported by the `time` crate.
+fn to_time_date(date: NaiveDate) -> Option<time::Date> {
+    let month = time::Month::try_from(u8::try_from(date.month()).ok()?).ok()?;
+    let day = u8::try_from(date.day()).ok()?;
+    time::Date::from_calendar_date(date.year(), month, day).ok()
+}
+
+fn from_time_weekday(weekday: time::Weekday) -> chrono::Weekday {
+    match weekday {
+        time::Weekday::Monday => chrono::Weekday::Mon,
+        time::Weekday::Tuesday => chrono::Weekday::Tue,
+        time::Weekday::Wednesday => chrono::Weekday::Wed,
+        time::Weekday::Thursday => chrono::Weekday::Thu,
+        time::Weekday::Friday => chrono::Weekday::Fri,
+        time::Weekday::Saturday => chrono::Weekday::Sat,
+        time::Weekday::Sunday => chrono::Weekday::Sun,
+    }
+}
+
+impl<'a> DateSelect<'a> {
+    /// Sets the date selected when the calendar is displayed, as a [time::Date].
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_starting_date(self, starting_date: time::Date) -> Self {
+        self.with_starting_date(from_time_date(starting_date))
+    }
+
+    /// Sets the min date, as a [time::Date].
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_min_date(self, min_date: time::Date) -> Self {
+        self.with_min_date(from_time_date(min_date))
+    }
+
+    /// Sets the max date, as a [time::Date].
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_max_date(self, max_date: time::Date) -> Self {
+        self.with_max_date(from_time_date(max_date))
+    }
+
+    /// Sets the week start, as a [time::Weekday].
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_week_start(self, week_start: time::Weekday) -> Self {
+        self.with_week_start(from_time_weekday(week_start))
+    }
+
+    /// Sets dates that can't be selected, as [time::Date]s.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_disabled_dates(mut self, dates: &[time::Date]) -> Self {
+        self.disabled_dates = dates.iter().copied().map(from_time_date).collect();
+        self
+    }
+
+    /// Sets weekdays that can't be selected, as [time::Weekday]s.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn with_time_disabled_weekdays(mut self, weekdays: &[time::Weekday]) -> Self {
+        self.disabled_weekdays = weekdays.iter().copied().map(from_time_weekday).collect();
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, returning the
+    /// selected date as a [time::Date].
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn prompt_time_skippable(self) -> InquireResult<Option<time::Date>> {
+        match self.prompt_time() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, returning the
+    /// selected date as a [time::Date].
+    ///
+    /// The calendar is limited to the dates supported by the `time` crate,
+    /// from the year -9999 to 9999.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::DateSelect;
+    /// use time::{Date, Month};
+    ///
+    /// let date = DateSelect::new("When do you want to travel?")
+    ///     .with_time_min_date(Date::from_calendar_date(2021, Month::August, 1).unwrap())
+    ///     .with_time_week_start(time::Weekday::Monday)
+    ///     .prompt_time()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn prompt_time(self) -> InquireResult<time::Date> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        self.prompt_time_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_time_with_backend<B: DateSelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<time::Date> {
+        let (min, max) = (
+            from_time_date(time::Date::MIN),
+            from_time_date(time::Date::MAX),
+        );
+        self.min_date = Some(self.min_date.unwrap_or(min).max(min));
+        self.max_date = Some(self.max_date.unwrap_or(max).min(max));
+
+        let answer = self.prompt_with_backend(backend)?;
+
+        to_time_date(answer).ok_or_else(|| {
+            InquireError::InvalidConfiguration(format!(
+                "Selected date {answer} is not supported by the time crate"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::{Date, Month};
+
+    use crate::{
+        ui::{test::FakeBackend, Key, KeyModifiers},
+        DateSelect,
+    };
+
+    #[test]
+    fn dates_are_converted_to_and_from_time() {
+        let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+        let ans = DateSelect::new("Date")
+            .with_time_starting_date(Date::from_calendar_date(2021, Month::February, 28).unwrap())
+            .with_time_max_date(Date::from_calendar_date(2021, Month::March, 31).unwrap())
+            .prompt_time_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            Date::from_calendar_date(2021, Month::March, 1).unwrap(),
+            ans
+        );
+    }
+
+    #[test]
+    fn calendar_is_limited_to_dates_supported_by_time() {
+        let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+        let ans = DateSelect::new("Date")
+            .with_time_starting_date(Date::MAX)
+            .prompt_time_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(Date::MAX, ans);
+    }
+}
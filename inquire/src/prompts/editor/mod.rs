@@ -13,9 +13,10 @@ use std::{
 use crate::{
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    key_bindings::KeyBindings,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, EditorBackend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{prompt_backend, EditorBackend, RenderConfig},
     validator::StringValidator,
 };
 
@@ -80,13 +81,18 @@ pub struct Editor<'a> {
 
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<EditorPromptAction>,
 }
 
 impl<'a> Editor<'a> {
@@ -111,6 +117,8 @@ impl<'a> Editor<'a> {
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
             render_config: RenderConfig::default(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -184,17 +192,35 @@ impl<'a> Editor<'a> {
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<EditorPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -215,8 +241,7 @@ impl<'a> Editor<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
@@ -4,7 +4,9 @@ use tempfile::NamedTempFile;
 
 use crate::{
     error::InquireResult,
+    event::PromptEvent,
     formatter::StringFormatter,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     prompts::prompt::{ActionResult, Prompt},
     ui::EditorBackend,
     validator::{ErrorMessage, StringValidator, Validation},
@@ -21,6 +23,10 @@ pub struct EditorPrompt<'a> {
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
     tmp_file: NamedTempFile,
+    key_bindings: KeyBindings<EditorPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a> From<&'a str> for Editor<'a> {
@@ -39,6 +45,10 @@ impl<'a> EditorPrompt<'a> {
             validators: so.validators,
             error: None,
             tmp_file: Self::create_file(so.file_extension, so.predefined_text)?,
+            key_bindings: so.key_bindings,
+            key_handler: so.settings.key_handler,
+            escape_behavior: so.settings.escape_behavior,
+            cancel_guard: so.settings.cancel_guard,
         })
     }
 
@@ -112,10 +122,44 @@ where
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<EditorPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
 
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        fs::write(self.tmp_file.path(), answer)?;
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
+    }
+
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        // the text is only changed by the editor, once it is closed
+        self.cur_answer()
+            .ok()
+            .map(PromptEvent::InputChanged)
+            .into_iter()
+            .collect()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()?),
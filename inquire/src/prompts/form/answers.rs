@@ -0,0 +1,118 @@
+use std::{any::Any, fmt};
+
+/// Answers given to the steps of a [Form](crate::Form), identified by the
+/// keys of the steps.
+///
+/// Each answer has the type returned by the prompt of its step, e.g. a
+/// `String` for [Text](crate::Text) or a `bool` for [Confirm](crate::Confirm),
+/// and is retrieved with that type.
+#[derive(Default)]
+pub struct FormAnswers {
+    answers: Vec<(String, Box<dyn Any>)>,
+    #[cfg(feature = "serde")]
+    json: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FormAnswers {
+    /// Returns the answer of the step with the provided key, or `None` when
+    /// the step was not answered or its answer is not of type `T`.
+    pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
+        self.answers
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, answer)| answer.downcast_ref())
+    }
+
+    /// Removes and returns the answer of the step with the provided key, or
+    /// `None` when the step was not answered or its answer is not of type `T`,
+    /// in which case it is kept.
+    pub fn take<T: 'static>(&mut self, key: &str) -> Option<T> {
+        let index = self
+            .answers
+            .iter()
+            .position(|(k, answer)| k == key && answer.is::<T>())?;
+        let (_, answer) = self.answers.remove(index);
+        #[cfg(feature = "serde")]
+        self.json.remove(key);
+
+        answer.downcast().ok().map(|answer| *answer)
+    }
+
+    /// Returns whether the step with the provided key was answered.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.answers.iter().any(|(k, _)| k == key)
+    }
+
+    /// Keys of the answered steps, in the order they were answered.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.answers.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Number of answered steps.
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns whether no step was answered.
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Answers of the steps added with [Form::with_serde_step](crate::Form::with_serde_step),
+    /// as a JSON object keyed by the keys of the steps.
+    ///
+    /// The object can be saved and used as the
+    /// [saved answers](crate::Form::with_saved_answers) of a later form.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Object(self.json.clone())
+    }
+
+    /// Deserializes the answers of the steps added with
+    /// [Form::with_serde_step](crate::Form::with_serde_step) into a struct
+    /// whose fields are named after the keys of the steps.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn deserialize<T>(&self) -> serde_json::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(self.to_json())
+    }
+
+    pub(super) fn get_any(&self, key: &str) -> Option<&dyn Any> {
+        self.answers
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, answer)| answer.as_ref())
+    }
+
+    pub(super) fn insert_any(&mut self, key: &str, answer: Box<dyn Any>) {
+        #[cfg(feature = "serde")]
+        self.json.remove(key);
+        match self.answers.iter_mut().find(|(k, _)| k == key) {
+            Some((_, previous)) => *previous = answer,
+            None => self.answers.push((key.to_string(), answer)),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(super) fn insert_json(&mut self, key: &str, answer: serde_json::Value) {
+        self.json.insert(key.to_string(), answer);
+    }
+
+    pub(super) fn remove_any(&mut self, key: &str) {
+        self.answers.retain(|(k, _)| k != key);
+        #[cfg(feature = "serde")]
+        self.json.remove(key);
+    }
+}
+
+impl fmt::Debug for FormAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormAnswers")
+            .field("keys", &self.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
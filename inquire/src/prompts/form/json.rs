@@ -0,0 +1,128 @@
+use std::any::Any;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::{step::FormStepPrompt, Form, FormAnswers, FormPrompt};
+
+/// Conversions of the answer of a step from and to JSON.
+pub(super) struct StepJson {
+    to_json: fn(&dyn Any) -> Option<Value>,
+    from_json: fn(Value) -> Option<Box<dyn Any>>,
+}
+
+impl StepJson {
+    fn new<T>() -> Self
+    where
+        T: Serialize + DeserializeOwned + 'static,
+    {
+        Self {
+            to_json: |answer| serde_json::to_value(answer.downcast_ref::<T>()?).ok(),
+            from_json: |value| {
+                let answer: Box<dyn Any> = Box::new(serde_json::from_value::<T>(value).ok()?);
+                Some(answer)
+            },
+        }
+    }
+
+    pub(super) fn to_json(&self, answer: &dyn Any) -> Option<Value> {
+        (self.to_json)(answer)
+    }
+}
+
+impl<'a> Form<'a> {
+    /// Adds a step to the form whose answer is also collected as JSON,
+    /// in [FormAnswers::to_json] and [FormAnswers::deserialize], and
+    /// initialized from the [saved answers](Self::with_saved_answers).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{Confirm, CustomType, Form, Text};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    ///     port: u16,
+    ///     tls: bool,
+    /// }
+    ///
+    /// let saved = serde_json::json!({ "name": "api", "port": 8080 });
+    ///
+    /// let answers = Form::new()
+    ///     .with_saved_answers(saved)
+    ///     .with_serde_step("name", |_| Text::new("Service name:"))
+    ///     .with_serde_step("port", |_| CustomType::<u16>::new("Port:"))
+    ///     .with_serde_step("tls", |_| Confirm::new("Enable TLS?"))
+    ///     .prompt()?;
+    ///
+    /// println!("{}", answers.to_json());
+    /// let config: Config = answers.deserialize().unwrap();
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn with_serde_step<P, F>(self, key: &str, prompt: F) -> Self
+    where
+        P: FormPrompt,
+        <P as FormStepPrompt>::Output: Serialize + DeserializeOwned,
+        F: Fn(&FormAnswers) -> P + 'a,
+    {
+        self.with_step(key, prompt)
+            .with_last_step_json(StepJson::new::<<P as FormStepPrompt>::Output>())
+    }
+
+    /// Adds a conditional step to the form, as [Form::with_step_if], whose
+    /// answer is also collected as JSON, as [Form::with_serde_step].
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn with_serde_step_if<P, C, F>(self, key: &str, condition: C, prompt: F) -> Self
+    where
+        P: FormPrompt,
+        <P as FormStepPrompt>::Output: Serialize + DeserializeOwned,
+        C: Fn(&FormAnswers) -> bool + 'a,
+        F: Fn(&FormAnswers) -> P + 'a,
+    {
+        self.with_step_if(key, condition, prompt)
+            .with_last_step_json(StepJson::new::<<P as FormStepPrompt>::Output>())
+    }
+
+    /// Sets the answers saved from a previous session, e.g. the output of
+    /// [FormAnswers::to_json] read back from a file.
+    ///
+    /// Saved answers are pre-filled in the steps added with
+    /// [Form::with_serde_step] the first time they are displayed, where the
+    /// prompt supports it. Entries that don't match the type of the answer
+    /// of their step are ignored.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn with_saved_answers(mut self, answers: Value) -> Self {
+        self.saved_answers = Some(answers);
+        self
+    }
+
+    fn with_last_step_json(mut self, json: StepJson) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.json = Some(json);
+        }
+        self
+    }
+
+    pub(super) fn hydrate_saved_answers(&self) -> FormAnswers {
+        let mut answers = FormAnswers::default();
+        let saved = match &self.saved_answers {
+            Some(Value::Object(saved)) => saved,
+            _ => return answers,
+        };
+
+        for step in &self.steps {
+            let answer = step.json.as_ref().and_then(|json| {
+                let value = saved.get(&step.key)?.clone();
+                (json.from_json)(value)
+            });
+            if let Some(answer) = answer {
+                answers.insert_any(&step.key, answer);
+            }
+        }
+
+        answers
+    }
+}
@@ -0,0 +1,425 @@
+mod answers;
+#[cfg(feature = "serde")]
+mod json;
+mod step;
+#[cfg(test)]
+mod test;
+
+pub use answers::FormAnswers;
+pub use step::FormPrompt;
+
+use std::{any::Any, cell::Cell};
+
+use crate::{
+    error::{CustomUserError, InquireError, InquireResult},
+    terminal::CustomBackend,
+    ui::{Key, KeyModifiers},
+    validator::ErrorMessage,
+};
+
+use self::step::FormStep;
+
+/// Type alias for the function displaying the progress of a [Form] before
+/// the message of each prompt, receiving the number of the current step,
+/// starting at 1, and the total number of steps.
+pub type FormProgressFormatter<'a> = &'a dyn Fn(usize, usize) -> String;
+
+/// Type alias for validators of the answers of a [Form], run once all its
+/// steps are answered. See [Form::with_validator].
+pub type FormValidator<'a> =
+    Box<dyn Fn(&FormAnswers) -> Result<FormValidation, CustomUserError> + 'a>;
+
+/// Result of a [FormValidator].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormValidation {
+    /// The answers are valid, and the form returns them.
+    Valid,
+
+    /// The answers are invalid. The step with the given key is asked again,
+    /// displaying the error message.
+    Invalid {
+        /// Key of the step asked again.
+        step: String,
+        /// Error message displayed above the prompt of the step.
+        message: ErrorMessage,
+    },
+}
+
+impl FormValidation {
+    /// Rejects the answers, asking the step with the given key again with
+    /// the error message.
+    pub fn invalid<M: Into<ErrorMessage>>(step: &str, message: M) -> Self {
+        Self::Invalid {
+            step: step.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+type StepCondition<'a> = Box<dyn Fn(&FormAnswers) -> bool + 'a>;
+type StepRunner<'a> =
+    Box<dyn Fn(&FormAnswers, Option<&dyn Any>, &FormStep) -> InquireResult<Box<dyn Any>> + 'a>;
+
+struct Step<'a> {
+    key: String,
+    condition: Option<StepCondition<'a>>,
+    run: StepRunner<'a>,
+    #[cfg(feature = "serde")]
+    json: Option<json::StepJson>,
+}
+
+/// Sequence of prompts declared up front and run one after the other, where
+/// the user can go back to edit earlier answers.
+///
+/// Each step is identified by a key and built by a function receiving the
+/// answers given so far, so that prompts can depend on previous answers.
+/// Steps can also be made conditional, being skipped unless a condition on
+/// the previous answers holds.
+///
+/// While a step is displayed, pressing `ctrl+p` goes back to the previous
+/// step, which is displayed again with the previous answer pre-filled where
+/// the prompt supports it. The back key can be changed with
+/// [Form::with_back_key], and takes precedence over what the key does in the
+/// prompts of the steps but the first one. Canceling a step, e.g. by pressing
+/// ESC, cancels the form.
+///
+/// The progress of the form is displayed before the message of each prompt,
+/// e.g. `[2/5] What's your name?`. The total is the number of steps whose
+/// condition holds with the answers given so far.
+///
+/// Answers are returned as [FormAnswers], where each one can be retrieved
+/// with the type returned by its prompt. Rules involving several answers can
+/// be checked with [Form::with_validator], asking the step at fault again
+/// until they hold.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Confirm, CustomType, Form, Text};
+///
+/// let answers = Form::new()
+///     .with_step("name", |_| Text::new("What's your name?"))
+///     .with_step("age", |_| CustomType::<u8>::new("How old are you?"))
+///     .with_step("has_pet", |_| Confirm::new("Do you have a pet?"))
+///     .with_step_if(
+///         "pet",
+///         |answers| answers.get::<bool>("has_pet") == Some(&true),
+///         |_| Text::new("What's your pet's name?"),
+///     )
+///     .prompt()?;
+///
+/// let name: &String = answers.get("name").unwrap();
+/// println!("Hello {}", name);
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct Form<'a> {
+    steps: Vec<Step<'a>>,
+
+    validators: Vec<FormValidator<'a>>,
+
+    /// Function displaying the progress of the form before the message of
+    /// each prompt. When `None`, no progress is displayed.
+    pub progress_formatter: Option<FormProgressFormatter<'a>>,
+
+    /// Key going back to the previous step.
+    pub back_key: Key,
+
+    /// Backend used by the steps whose prompt doesn't set one. When `None`,
+    /// the backend set with [set_global_backend](crate::set_global_backend)
+    /// is used, if any.
+    pub backend: Option<CustomBackend>,
+
+    /// Answers saved from a previous session, as a JSON object keyed by the
+    /// keys of the steps, used as the initial answers of the steps added with
+    /// [Form::with_serde_step].
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub saved_answers: Option<serde_json::Value>,
+}
+
+impl<'a> Form<'a> {
+    /// Default progress formatter, displaying the progress as `[2/5] `.
+    pub const DEFAULT_PROGRESS_FORMATTER: FormProgressFormatter<'a> =
+        &|step, total| format!("[{step}/{total}] ");
+
+    /// Default key going back to the previous step, `ctrl+p`.
+    pub const DEFAULT_BACK_KEY: Key = Key::Char('p', KeyModifiers::CONTROL);
+
+    /// Creates an empty [Form].
+    pub fn new() -> Self {
+        Self {
+            steps: vec![],
+            validators: vec![],
+            progress_formatter: Some(Self::DEFAULT_PROGRESS_FORMATTER),
+            back_key: Self::DEFAULT_BACK_KEY,
+            backend: None,
+            #[cfg(feature = "serde")]
+            saved_answers: None,
+        }
+    }
+
+    /// Adds a step to the form, identified by the key, whose prompt is built
+    /// by the provided function from the answers given so far.
+    pub fn with_step<P, F>(self, key: &str, prompt: F) -> Self
+    where
+        P: FormPrompt,
+        F: Fn(&FormAnswers) -> P + 'a,
+    {
+        self.push_step(key, None, prompt)
+    }
+
+    /// Adds a step to the form that is only displayed when the condition
+    /// holds with the answers given so far, and skipped otherwise.
+    ///
+    /// Answers of skipped steps are not returned, even if the step was
+    /// answered before the user went back and changed a previous answer.
+    pub fn with_step_if<P, C, F>(self, key: &str, condition: C, prompt: F) -> Self
+    where
+        P: FormPrompt,
+        C: Fn(&FormAnswers) -> bool + 'a,
+        F: Fn(&FormAnswers) -> P + 'a,
+    {
+        self.push_step(key, Some(Box::new(condition)), prompt)
+    }
+
+    /// Adds a validator of the answers of the form, run once all its steps
+    /// are answered, e.g. to check that an end date is after a start date.
+    ///
+    /// When the answers are rejected, the step named by the validator is
+    /// asked again with the error message, pre-filled with its answer where
+    /// the prompt supports it. The following steps keep their answers, and
+    /// only the ones that were not answered yet, such as conditional steps
+    /// now shown, are asked before the answers are validated again.
+    ///
+    /// Validators are run in the order they were added, and the form fails
+    /// with [InquireError::Custom] when one of them returns an error, or
+    /// with [InquireError::InvalidConfiguration] when the step it names is
+    /// not one of the shown steps of the form.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{CustomType, Form, FormValidation};
+    ///
+    /// let answers = Form::new()
+    ///     .with_step("min", |_| CustomType::<u32>::new("Minimum:"))
+    ///     .with_step("max", |_| CustomType::<u32>::new("Maximum:"))
+    ///     .with_validator(|answers| {
+    ///         match (answers.get::<u32>("min"), answers.get::<u32>("max")) {
+    ///             (Some(min), Some(max)) if max < min => Ok(FormValidation::invalid(
+    ///                 "max",
+    ///                 "The maximum must not be less than the minimum",
+    ///             )),
+    ///             _ => Ok(FormValidation::Valid),
+    ///         }
+    ///     })
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: Fn(&FormAnswers) -> Result<FormValidation, CustomUserError> + 'a,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Sets the function displaying the progress of the form before the
+    /// message of each prompt.
+    pub fn with_progress_formatter(mut self, formatter: FormProgressFormatter<'a>) -> Self {
+        self.progress_formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the key going back to the previous step.
+    pub fn with_back_key(mut self, key: Key) -> Self {
+        self.back_key = key;
+        self
+    }
+
+    /// Sets the input reader and terminal used to display the steps whose
+    /// prompt doesn't set its own backend, e.g. to render the form in an
+    /// embedded terminal widget.
+    pub fn with_backend(mut self, backend: CustomBackend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Hides the progress of the form.
+    pub fn without_progress(mut self) -> Self {
+        self.progress_formatter = None;
+        self
+    }
+
+    fn push_step<P, F>(mut self, key: &str, condition: Option<StepCondition<'a>>, prompt: F) -> Self
+    where
+        P: FormPrompt,
+        F: Fn(&FormAnswers) -> P + 'a,
+    {
+        let run = move |answers: &FormAnswers, previous: Option<&dyn Any>, step: &FormStep| {
+            let previous = previous.and_then(|previous| previous.downcast_ref());
+            let answer: Box<dyn Any> = Box::new(prompt(answers).prompt_in_form(step, previous)?);
+            Ok(answer)
+        };
+
+        self.steps.push(Step {
+            key: key.to_string(),
+            condition,
+            run: Box::new(run),
+            #[cfg(feature = "serde")]
+            json: None,
+        });
+        self
+    }
+
+    /// Runs the steps of the form and returns the answers.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the form - by pressing ESC - is considered normal
+    /// behavior. In this case, it does not return
+    /// `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<FormAnswers>> {
+        match self.prompt() {
+            Ok(answers) => Ok(Some(answers)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Runs the steps of the form and returns the answers.
+    pub fn prompt(self) -> InquireResult<FormAnswers> {
+        let form_step = FormStep {
+            message_prefix: String::new(),
+            backend: self.backend.clone(),
+            back_key: None,
+            going_back: Cell::new(false),
+            error: None,
+        };
+        self.run(form_step)
+    }
+
+    fn is_shown(&self, index: usize, answers: &FormAnswers) -> bool {
+        match self
+            .steps
+            .get(index)
+            .and_then(|step| step.condition.as_ref())
+        {
+            Some(condition) => condition(answers),
+            None => true,
+        }
+    }
+
+    /// Runs the validators of the form, returning the index of the step to
+    /// ask again along with the error message when the answers are rejected.
+    fn validate(&self, answers: &FormAnswers) -> InquireResult<Option<(usize, ErrorMessage)>> {
+        for validator in &self.validators {
+            let (key, message) = match validator(answers) {
+                Ok(FormValidation::Valid) => continue,
+                Ok(FormValidation::Invalid { step, message }) => (step, message),
+                Err(err) => return Err(InquireError::Custom(err)),
+            };
+
+            let index = (0..self.steps.len()).find(|i| {
+                self.steps.get(*i).map(|step| step.key.as_str()) == Some(key.as_str())
+                    && self.is_shown(*i, answers)
+            });
+
+            return match index {
+                Some(index) => Ok(Some((index, message))),
+                None => Err(InquireError::InvalidConfiguration(format!(
+                    "form validator rejected the answer of \"{key}\", which is not a shown step"
+                ))),
+            };
+        }
+
+        Ok(None)
+    }
+
+    fn run(self, mut form_step: FormStep) -> InquireResult<FormAnswers> {
+        let mut answers = FormAnswers::default();
+        #[cfg(feature = "serde")]
+        let saved = self.hydrate_saved_answers();
+        #[cfg(not(feature = "serde"))]
+        let saved = FormAnswers::default();
+        // steps answered so far, in order, to go back to with the back key
+        let mut visited: Vec<usize> = vec![];
+        let mut index = 0;
+        // whether a step rejected by a validator was asked again, in which
+        // case the following steps already answered are skipped
+        let mut revalidating = false;
+
+        loop {
+            while let Some(step) = self.steps.get(index) {
+                if !self.is_shown(index, &answers) {
+                    answers.remove_any(&step.key);
+                    index += 1;
+                    continue;
+                }
+
+                if revalidating && form_step.error.is_none() && answers.get_any(&step.key).is_some()
+                {
+                    visited.push(index);
+                    index += 1;
+                    continue;
+                }
+
+                if let Some(formatter) = self.progress_formatter {
+                    let shown = |range: std::ops::Range<usize>| {
+                        range.filter(|i| self.is_shown(*i, &answers)).count()
+                    };
+                    let current = shown(0..index) + 1;
+                    let total = current + shown(index + 1..self.steps.len());
+                    form_step.message_prefix = formatter(current, total);
+                }
+
+                let previous = answers
+                    .get_any(&step.key)
+                    .or_else(|| saved.get_any(&step.key));
+
+                form_step.back_key = (!visited.is_empty()).then_some(self.back_key);
+                form_step.going_back.set(false);
+
+                let result = (step.run)(&answers, previous, &form_step);
+                form_step.error = None;
+
+                match result {
+                    Ok(answer) => {
+                        #[cfg(feature = "serde")]
+                        let json = step.json.as_ref().and_then(|json| json.to_json(&*answer));
+                        answers.insert_any(&step.key, answer);
+                        #[cfg(feature = "serde")]
+                        if let Some(json) = json {
+                            answers.insert_json(&step.key, json);
+                        }
+                        visited.push(index);
+                        index += 1;
+                    }
+                    Err(InquireError::OperationCanceled) if form_step.going_back.get() => {
+                        if let Some(previous) = visited.pop() {
+                            index = previous;
+                        }
+                        // the steps are walked through again from there
+                        revalidating = false;
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+
+            match self.validate(&answers)? {
+                None => return Ok(answers),
+                Some((rejected, message)) => {
+                    visited.retain(|visited| *visited < rejected);
+                    index = rejected;
+                    form_step.error = Some(message);
+                    revalidating = true;
+                }
+            }
+        }
+    }
+}
+
+impl Default for Form<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
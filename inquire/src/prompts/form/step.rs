@@ -0,0 +1,276 @@
+use std::{cell::Cell, fmt::Display};
+
+use crate::{
+    error::InquireResult,
+    key_bindings::{Handled, KeyHandler, PromptStateView},
+    terminal::CustomBackend,
+    ui::Key,
+    validator::ErrorMessage,
+    Amount, Confirm, CustomType, MultiSelect, Password, Select, Text, TypedConfirm,
+};
+
+/// Prompts that can be used as steps of a [Form](crate::Form).
+///
+/// Implemented by all prompts of this crate.
+pub trait FormPrompt: FormStepPrompt {}
+
+impl<P: FormStepPrompt> FormPrompt for P {}
+
+/// Context in which a step of a form is prompted.
+pub struct FormStep {
+    /// Text displayed before the message of the prompt, e.g. the progress.
+    pub(crate) message_prefix: String,
+
+    /// Backend of the form, used by the prompts that don't set their own.
+    pub(crate) backend: Option<CustomBackend>,
+
+    /// Key going back to the previous step, if there is one.
+    pub(crate) back_key: Option<Key>,
+
+    /// Whether the user pressed the back key, set when the step is canceled
+    /// to go back to the previous one.
+    pub(crate) going_back: Cell<bool>,
+
+    /// Error message of the form validator that rejected the answer of the
+    /// step, displayed when it is asked again.
+    pub(crate) error: Option<ErrorMessage>,
+}
+
+impl FormStep {
+    fn message(&self, message: &str) -> String {
+        format!("{}{}", self.message_prefix, message)
+    }
+
+    /// Handles the keys pressed in the prompt of the step, canceling it when
+    /// the back key is pressed, before they reach the key handler of the
+    /// prompt, if any.
+    fn handle_key(
+        &self,
+        key: Key,
+        view: &mut PromptStateView<'_>,
+        prompt_handler: Option<KeyHandler<'_>>,
+    ) -> Handled {
+        if self.back_key == Some(key) {
+            self.going_back.set(true);
+            view.cancel();
+            return Handled::Yes;
+        }
+
+        match prompt_handler {
+            Some(handler) => handler(key, view),
+            None => Handled::No,
+        }
+    }
+}
+
+/// Runs the prompt of a step, on the backend of the form unless the prompt
+/// sets its own, going back to the previous step with the back key.
+macro_rules! prompt_step {
+    ($prompt:ident, $step:expr) => {{
+        if $prompt.settings.backend.is_none() {
+            $prompt.settings.backend = $step.backend.clone();
+        }
+
+        $prompt.settings.initial_error = $step.error.clone();
+
+        let prompt_handler = $prompt.settings.key_handler;
+        let key_handler =
+            |key: Key, view: &mut PromptStateView<'_>| $step.handle_key(key, view, prompt_handler);
+        $prompt.settings.key_handler = Some(&key_handler);
+
+        $prompt.prompt()
+    }};
+}
+
+pub trait FormStepPrompt: Sized {
+    type Output: 'static;
+
+    /// Prompts the step, with the answer given when the step was last
+    /// displayed, if any.
+    fn prompt_in_form(
+        self,
+        step: &FormStep,
+        previous: Option<&Self::Output>,
+    ) -> InquireResult<Self::Output>;
+}
+
+impl<'a, 'b> FormStepPrompt for Text<'a, 'b> {
+    type Output = String;
+
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&String>) -> InquireResult<String> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.initial_value = Some(previous);
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a> FormStepPrompt for Password<'a> {
+    type Output = String;
+
+    /// Passwords are never pre-filled.
+    fn prompt_in_form(self, step: &FormStep, _previous: Option<&String>) -> InquireResult<String> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a> FormStepPrompt for Confirm<'a> {
+    type Output = bool;
+
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&bool>) -> InquireResult<bool> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.default = Some(*previous);
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a> FormStepPrompt for TypedConfirm<'a> {
+    type Output = bool;
+
+    fn prompt_in_form(self, step: &FormStep, _previous: Option<&bool>) -> InquireResult<bool> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a, T> FormStepPrompt for CustomType<'a, T>
+where
+    T: Clone + 'static,
+{
+    type Output = T;
+
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&T>) -> InquireResult<T> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.default = Some(previous.clone());
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a> FormStepPrompt for Amount<'a> {
+    type Output = u64;
+
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&u64>) -> InquireResult<u64> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.default = Some(*previous);
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a, T> FormStepPrompt for Select<'a, T>
+where
+    T: Display + 'static,
+{
+    type Output = T;
+
+    /// The cursor starts at the first option displayed as the previous
+    /// answer, if any.
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&T>) -> InquireResult<T> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            let previous = previous.to_string();
+            if let Some(index) = prompt
+                .options
+                .iter()
+                .position(|o| o.to_string() == previous)
+            {
+                prompt.starting_cursor = index;
+            }
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+impl<'a, T> FormStepPrompt for MultiSelect<'a, T>
+where
+    T: Display + 'static,
+{
+    type Output = Vec<T>;
+
+    /// The options displayed as the previous answers are selected, instead
+    /// of the ones the prompt is configured with.
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&Vec<T>>) -> InquireResult<Vec<T>> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            let mut selected: Vec<usize> = vec![];
+            for answer in previous.iter().map(ToString::to_string) {
+                let index = prompt
+                    .options
+                    .iter()
+                    .enumerate()
+                    .find(|(i, option)| !selected.contains(i) && option.to_string() == answer)
+                    .map(|(i, _)| i);
+                selected.extend(index);
+            }
+            prompt.default = Some(selected);
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+#[cfg(feature = "date")]
+impl<'a> FormStepPrompt for crate::DateSelect<'a> {
+    type Output = chrono::NaiveDate;
+
+    fn prompt_in_form(
+        self,
+        step: &FormStep,
+        previous: Option<&chrono::NaiveDate>,
+    ) -> InquireResult<chrono::NaiveDate> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.starting_date = *previous;
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
+
+#[cfg(feature = "editor")]
+impl<'a> FormStepPrompt for crate::Editor<'a> {
+    type Output = String;
+
+    fn prompt_in_form(self, step: &FormStep, previous: Option<&String>) -> InquireResult<String> {
+        let message = step.message(self.message);
+        let mut prompt = self;
+        prompt.message = &message;
+        if let Some(previous) = previous {
+            prompt.predefined_text = Some(previous);
+        }
+
+        prompt_step!(prompt, step)
+    }
+}
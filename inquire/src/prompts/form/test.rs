@@ -0,0 +1,375 @@
+use crate::{
+    error::InquireError,
+    terminal::test::recording_backend,
+    ui::{Key, KeyModifiers},
+    CancellationToken, Confirm, CustomType, Form, FormAnswers, FormValidation, MultiSelect,
+    Password, Select, Text, TypedConfirm,
+};
+
+const BACK: Key = Form::DEFAULT_BACK_KEY;
+
+fn keys(inputs: &[&str]) -> Vec<Key> {
+    let mut keys = vec![];
+    for input in inputs {
+        keys.append(&mut Key::char_keys_from_str(input));
+        keys.push(Key::Enter);
+    }
+    keys
+}
+
+#[test]
+fn steps_are_answered_in_order() {
+    let (backend, _) = recording_backend(keys(&["Ana", "32"]));
+
+    let answers = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("age", |_| CustomType::<u8>::new("Age:"))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&String::from("Ana")), answers.get("name"));
+    assert_eq!(Some(&32u8), answers.get("age"));
+    assert_eq!(None, answers.get::<String>("age"));
+    assert_eq!(vec!["name", "age"], answers.keys().collect::<Vec<_>>());
+}
+
+#[test]
+fn steps_are_built_from_previous_answers() {
+    let (backend, output) = recording_backend(keys(&["Ana", "y"]));
+
+    let mut answers = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("confirm", |answers| {
+            let name: &String = answers.get("name").unwrap();
+            Confirm::new(match name.as_str() {
+                "Ana" => "Hi Ana, continue?",
+                _ => "Continue?",
+            })
+        })
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert!(output.lock().unwrap().contains("[2/2] Hi Ana, continue?"));
+    assert_eq!(Some(true), answers.take::<bool>("confirm"));
+    assert!(!answers.contains_key("confirm"));
+    assert_eq!(1, answers.len());
+}
+
+#[test]
+fn back_key_goes_back_to_previous_step_with_answer_prefilled() {
+    let mut input = keys(&["Ana"]);
+    input.push(BACK);
+    input.push(Key::Backspace);
+    input.append(&mut keys(&["e", "Lisbon"]));
+    let (backend, _) = recording_backend(input);
+
+    let answers = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("city", |_| Text::new("City:"))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&String::from("Ane")), answers.get("name"));
+    assert_eq!(Some(&String::from("Lisbon")), answers.get("city"));
+}
+
+#[test]
+fn back_key_can_be_changed() {
+    let mut input = keys(&["Ana"]);
+    input.push(Key::F(2, KeyModifiers::NONE));
+    input.push(Key::Backspace);
+    input.append(&mut keys(&["e", "Lisbon"]));
+    let (backend, _) = recording_backend(input);
+
+    let answers = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("city", |_| Text::new("City:"))
+        .with_back_key(Key::F(2, KeyModifiers::NONE))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&String::from("Ane")), answers.get("name"));
+}
+
+#[test]
+fn esc_cancels_the_form_from_any_step() {
+    let mut input = keys(&["Ana"]);
+    input.push(Key::Escape);
+    let (backend, _) = recording_backend(input);
+
+    let result = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("city", |_| Text::new("City:"))
+        .with_backend(backend)
+        .prompt();
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn canceled_tokens_cancel_the_form() {
+    let token = CancellationToken::new();
+    let canceled = token.clone();
+    let (backend, _) = recording_backend(keys(&["Ana"]));
+
+    let result = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_step("city", move |_| {
+            canceled.cancel();
+            Text::new("City:").with_cancellation(canceled.clone())
+        })
+        .with_backend(backend)
+        .prompt();
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn back_key_on_first_step_is_handled_by_the_prompt() {
+    let mut input = vec![Key::Down(KeyModifiers::NONE), BACK];
+    input.append(&mut keys(&[""]));
+    let (backend, _) = recording_backend(input);
+
+    let answers = Form::new()
+        .with_step("color", |_| Select::new("Color:", vec!["red", "green"]))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&"red"), answers.get("color"));
+}
+
+#[test]
+fn select_steps_start_from_previous_answers() {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let mut input = vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        space,
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        space,
+        Key::Enter,
+        BACK,
+        BACK,
+        Key::Enter,
+        Key::Enter,
+    ];
+    input.append(&mut keys(&["Ana"]));
+    let (backend, _) = recording_backend(input);
+
+    let answers = Form::new()
+        .with_step("color", |_| {
+            Select::new("Color:", vec!["red", "green", "blue"])
+        })
+        .with_step("sizes", |_| MultiSelect::new("Sizes:", vec!["S", "M", "L"]))
+        .with_step("name", |_| Text::new("Name:"))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&"green"), answers.get("color"));
+    assert_eq!(Some(&vec!["S", "L"]), answers.get("sizes"));
+}
+
+#[test]
+fn esc_on_first_step_cancels_the_form() {
+    let (backend, _) = recording_backend(vec![Key::Escape]);
+
+    let result = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_backend(backend)
+        .prompt();
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn conditional_steps_are_skipped_and_their_answers_dropped() {
+    let mut input = keys(&["y", "Rex"]);
+    input.push(BACK);
+    input.push(BACK);
+    input.append(&mut keys(&["n", "Rome"]));
+    let (backend, output) = recording_backend(input);
+
+    let answers = Form::new()
+        .with_step("has_pet", |_| Confirm::new("Pet?"))
+        .with_step_if(
+            "pet",
+            |answers| answers.get::<bool>("has_pet") == Some(&true),
+            |_| Text::new("Pet name:"),
+        )
+        .with_step("city", |_| Text::new("City:"))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&false), answers.get("has_pet"));
+    assert!(!answers.contains_key("pet"));
+    assert_eq!(Some(&String::from("Rome")), answers.get("city"));
+
+    let output = output.lock().unwrap();
+    assert!(output.contains("[3/3] City:"));
+    assert!(output.contains("[2/2] City:"));
+}
+
+#[test]
+fn password_and_typed_confirm_steps_are_prompted() {
+    let (backend, output) = recording_backend(keys(&["hunter2", "delete"]));
+
+    let answers = Form::new()
+        .with_step("password", |_| {
+            Password::new("Password:").without_confirmation()
+        })
+        .with_step("confirm", |_| TypedConfirm::new("Type delete:", "delete"))
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&String::from("hunter2")), answers.get("password"));
+    assert_eq!(Some(&true), answers.get("confirm"));
+    let output = output.lock().unwrap();
+    assert!(output.contains("[1/2] Password:"));
+    assert!(output.contains("[2/2] Type delete:"));
+}
+
+#[test]
+fn progress_can_be_hidden() {
+    let (backend, output) = recording_backend(keys(&["Ana"]));
+
+    Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .without_progress()
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    let output = output.lock().unwrap();
+    assert!(output.contains("Name:"));
+    assert!(!output.contains("[1/1]"));
+}
+
+fn range_validator(answers: &FormAnswers) -> Result<FormValidation, crate::CustomUserError> {
+    match (answers.get::<u32>("min"), answers.get::<u32>("max")) {
+        (Some(min), Some(max)) if max < min => {
+            Ok(FormValidation::invalid("max", "Max is below min"))
+        }
+        _ => Ok(FormValidation::Valid),
+    }
+}
+
+#[test]
+fn validators_ask_the_rejected_step_again_with_the_error() {
+    let (backend, output) = recording_backend(keys(&["5", "3", "8"]));
+
+    let answers = Form::new()
+        .with_step("min", |_| CustomType::<u32>::new("Min:"))
+        .with_step("max", |_| CustomType::<u32>::new("Max:"))
+        .with_validator(range_validator)
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&5), answers.get::<u32>("min"));
+    assert_eq!(Some(&8), answers.get::<u32>("max"));
+    assert!(output.lock().unwrap().contains("Max is below min"));
+}
+
+#[test]
+fn steps_answered_after_the_rejected_one_are_kept() {
+    let (backend, _) = recording_backend(keys(&["3", "5", "Ana", "9"]));
+
+    let answers = Form::new()
+        .with_step("max", |_| CustomType::<u32>::new("Max:"))
+        .with_step("min", |_| CustomType::<u32>::new("Min:"))
+        .with_step("name", |_| Text::new("Name:"))
+        .with_validator(range_validator)
+        .with_backend(backend)
+        .prompt()
+        .unwrap();
+
+    assert_eq!(Some(&9), answers.get::<u32>("max"));
+    assert_eq!(Some(&5), answers.get::<u32>("min"));
+    assert_eq!(Some(&String::from("Ana")), answers.get("name"));
+}
+
+#[test]
+fn validators_rejecting_unknown_steps_fail() {
+    let (backend, _) = recording_backend(keys(&["Ana"]));
+
+    let result = Form::new()
+        .with_step("name", |_| Text::new("Name:"))
+        .with_validator(|_| Ok(FormValidation::invalid("age", "Too young")))
+        .with_backend(backend)
+        .prompt();
+
+    assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::{terminal::test::recording_backend, Confirm, CustomType, Form, Text};
+
+    use super::keys;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        name: String,
+        port: u16,
+        tls: bool,
+    }
+
+    #[test]
+    fn serde_steps_are_collected_as_json() {
+        let (backend, _) = recording_backend(keys(&["api", "8080", "y", "extra"]));
+
+        let answers = Form::new()
+            .with_serde_step("name", |_| Text::new("Name:"))
+            .with_serde_step("port", |_| CustomType::<u16>::new("Port:"))
+            .with_serde_step("tls", |_| Confirm::new("TLS?"))
+            .with_step("notes", |_| Text::new("Notes:"))
+            .with_backend(backend)
+            .prompt()
+            .unwrap();
+
+        assert_eq!(
+            json!({ "name": "api", "port": 8080, "tls": true }),
+            answers.to_json()
+        );
+        assert_eq!(
+            Config {
+                name: "api".into(),
+                port: 8080,
+                tls: true
+            },
+            answers.deserialize().unwrap()
+        );
+    }
+
+    #[test]
+    fn saved_answers_are_prefilled() {
+        // the saved name is submitted as is, and the mistyped port ignored
+        let (backend, _) = recording_backend(keys(&["", "443", "n"]));
+
+        let answers = Form::new()
+            .with_saved_answers(json!({ "name": "api", "port": "https" }))
+            .with_serde_step("name", |_| Text::new("Name:"))
+            .with_serde_step("port", |_| CustomType::<u16>::new("Port:"))
+            .with_serde_step("tls", |_| Confirm::new("TLS?"))
+            .with_backend(backend)
+            .prompt()
+            .unwrap();
+
+        assert_eq!(
+            json!({ "name": "api", "port": 443, "tls": false }),
+            answers.to_json()
+        );
+    }
+}
@@ -0,0 +1,22 @@
+/// Layout used to display the list of options in [Select](crate::Select) and
+/// [MultiSelect](crate::MultiSelect) prompts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// One option per line.
+    #[default]
+    List,
+
+    /// Options laid out row by row in a grid with the given number of columns,
+    /// similar to the output of `ls`.
+    ///
+    /// Useful to reduce the vertical space taken by many short options, such as
+    /// country codes or single-word choices. Columns are as wide as the widest
+    /// option in the current page, and the page size is counted in rows.
+    ///
+    /// The cursor is moved in two dimensions: up and down move to the row above
+    /// or below, while left and right move to the previous or next option.
+    Grid {
+        /// Number of options displayed in each row.
+        columns: usize,
+    },
+}
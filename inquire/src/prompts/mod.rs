@@ -1,29 +1,51 @@
 mod action;
+mod amount;
+mod background_scoring;
 mod confirm;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
 #[cfg(feature = "editor")]
 mod editor;
+mod form;
+mod layout;
 mod multiselect;
+#[cfg(feature = "normalization")]
+mod normalization;
+#[cfg(feature = "one-liners")]
 mod one_liners;
+mod option_strings;
 mod password;
-mod prompt;
+pub(crate) mod prompt;
+#[cfg(feature = "async")]
+mod prompt_async;
 mod select;
+mod settings;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+mod typed_confirm;
+mod vi;
 
 pub use action::*;
+pub use amount::*;
+pub use background_scoring::{BackgroundScorer, BackgroundScoring};
 pub use confirm::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
 pub use dateselect::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use form::*;
+pub use layout::*;
 pub use multiselect::*;
+#[cfg(feature = "normalization")]
+pub use normalization::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
 pub use password::*;
+pub use prompt::{ActionResult, AdaptivePagePrompt, Prompt};
 pub use select::*;
+pub use settings::PromptSettings;
 pub use text::*;
+pub use typed_confirm::*;
@@ -1,6 +1,6 @@
 use crate::{
     ui::{Key, KeyModifiers},
-    InnerAction, InputAction,
+    InnerAction, InputAction, Layout,
 };
 
 use super::config::MultiSelectConfig;
@@ -14,6 +14,10 @@ pub enum MultiSelectPromptAction {
     MoveUp,
     /// Moves the cursor to the option below.
     MoveDown,
+    /// Moves the cursor to the previous option, on the left in the grid layout.
+    MoveLeft,
+    /// Moves the cursor to the next option, on the right in the grid layout.
+    MoveRight,
     /// Moves the cursor to the page above.
     PageUp,
     /// Moves the cursor to the page below.
@@ -48,6 +52,14 @@ impl InnerAction for MultiSelectPromptAction {
             }
         }
 
+        if let Layout::Grid { .. } = config.layout {
+            match key {
+                Key::Left(KeyModifiers::NONE) => return Some(Self::MoveLeft),
+                Key::Right(KeyModifiers::NONE) => return Some(Self::MoveRight),
+                _ => {}
+            }
+        }
+
         let action = match key {
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
             Key::PageUp(_) => Self::PageUp,
@@ -1,4 +1,4 @@
-use crate::MultiSelect;
+use crate::{Layout, MultiSelect};
 
 /// Configuration settings used in the execution of a MultiSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,6 +7,8 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Layout of the list of options.
+    pub layout: Layout,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
@@ -16,8 +18,9 @@ pub struct MultiSelectConfig {
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
     fn from(value: &MultiSelect<'_, T>) -> Self {
         Self {
-            vim_mode: value.vim_mode,
+            vim_mode: value.vim_mode && !value.vim_filter_mode,
             page_size: value.page_size,
+            layout: value.layout,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
         }
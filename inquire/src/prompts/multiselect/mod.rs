@@ -2,25 +2,32 @@ mod action;
 mod config;
 mod prompt;
 #[cfg(test)]
-#[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
 
 use std::fmt::Display;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
+    key_bindings::KeyBindings,
     list_option::ListOption,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, MultiSelectBackend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    prompts::{prompt::Prompt, BackgroundScoring, Layout},
+    transliteration::Transliterator,
+    type_aliases::{OptionIcon, OptionPrefix, Scorer, Sorter},
+    ui::{prompt_backend, MultiSelectBackend, RenderConfig},
+    utils::natural_cmp,
     validator::MultiOptionValidator,
 };
 
+#[cfg(feature = "normalization")]
+use crate::Normalization;
+
 use self::prompt::MultiSelectPrompt;
 
 #[cfg(feature = "fuzzy")]
@@ -34,7 +41,7 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 ///
 /// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
 ///
-/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it. To avoid moving or cloning large collections, use [`MultiSelect::new_borrowed`] to borrow the options from a slice instead.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
 /// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
@@ -51,9 +58,16 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
+/// - **Option prefix**: Function that generates a custom prefix for each option, such as a status emoji or file-type icon, rendered in a dedicated column before the option. None by default.
+/// - **Icons**: Function that returns an [Icon](crate::ui::Icon) for each option, rendered in a dedicated column before the option and its prefix. Icons have an ASCII fallback, used when enabled in the `RenderConfig`. None by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Layout**: Whether options are displayed one per line or in a multi-column grid. See [Layout].
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Transliterator**: Converts the string values of the options to another script before scoring, so that e.g. typing "moskva" matches "Москва". None by default. See [Transliterator].
+/// - **Virtualization**: Optimizes filtering of very large option lists, at the cost of assumptions about the scorer. Disabled by default. See [`MultiSelect::with_virtualization`].
+/// - **Sorter function**: Function that defines the order of options when no filter is applied. By default, options are displayed in the order they were provided.
+/// - **Normalization**: Unicode normalization applied to the filter input and option strings before scoring, e.g. so that "jose" matches "José". None by default. Requires the `normalization` feature.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
 ///
 /// # Example
@@ -78,10 +92,21 @@ pub struct MultiSelect<'a, T> {
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Layout of the options displayed to the user.
+    ///
+    /// In the grid layout, the page size is the number of rows displayed at once.
+    pub layout: Layout,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
 
+    /// Whether modal vi keybindings are enabled. When enabled, the prompt
+    /// starts in normal mode, where the user navigates through the options
+    /// with `j`, `k`, `gg` and `G`, `h` and `l` unselect and select all
+    /// options, and `/` focuses the filter input until escape is pressed.
+    pub vim_filter_mode: bool,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
@@ -102,12 +127,53 @@ pub struct MultiSelect<'a, T> {
     /// The list of options is sorted in descending order (highest score first)
     pub scorer: Scorer<'a, T>,
 
+    /// Function used to sort the options for display when no filter is applied.
+    ///
+    /// When `None`, options are displayed in the order they were provided.
+    pub sorter: Option<Sorter<'a, T>>,
+
+    /// Transliterator applied to the string values of the options. When set,
+    /// options are scored against both their original and transliterated
+    /// string values, and the best score is kept.
+    pub transliterator: Option<Box<dyn Transliterator>>,
+
+    /// Whether filtering is optimized for very large option lists.
+    ///
+    /// See [`MultiSelect::with_virtualization`] for more details.
+    pub virtualized: bool,
+
+    /// Whether the scorer only narrows down the matches when characters are
+    /// appended to the filter input.
+    ///
+    /// Enabled by default, and disabled by [`MultiSelect::with_scorer`]. See
+    /// [`MultiSelect::with_incremental_scoring`] for more details.
+    pub incremental_scoring: bool,
+
+    /// Settings to score options on a worker thread instead of on every key
+    /// press, if enabled.
+    ///
+    /// See [`MultiSelect::with_background_scoring`] for more details.
+    pub background_scoring: Option<BackgroundScoring>,
+
+    /// Normalization applied to the filter input and the string values of
+    /// the options before they are passed to the scorer.
+    #[cfg(feature = "normalization")]
+    pub normalization: Normalization,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
+    /// Function that generates a custom prefix for each option, rendered in a
+    /// dedicated column before the option's string value.
+    pub option_prefix: Option<OptionPrefix<'a, T>>,
+
+    /// Function that returns the icon of each option, rendered in a dedicated
+    /// column before the option and its custom prefix.
+    pub option_icon: Option<OptionIcon<'a, T>>,
+
     /// Validator to apply to the user input.
     ///
     /// In case of error, the message is displayed one line above the prompt.
@@ -115,13 +181,18 @@ pub struct MultiSelect<'a, T> {
 
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<MultiSelectPromptAction>,
 }
 
 impl<'a, T> MultiSelect<'a, T>
@@ -184,6 +255,8 @@ where
             DEFAULT_MATCHER.fuzzy_match(string_value, input)
         };
 
+    /// Default scoring function, which keeps the options whose string value
+    /// contains the input, ignoring case, all with the same score.
     #[cfg(not(feature = "fuzzy"))]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
@@ -194,12 +267,51 @@ where
             }
         };
 
+    /// Sorter that orders options alphabetically by their string values, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use inquire::MultiSelect;
+    ///
+    /// let sorter = MultiSelect::<&str>::ALPHABETICAL_SORTER;
+    /// assert_eq!(Ordering::Less, sorter(&"apple", &"Banana"));
+    /// assert_eq!(Ordering::Greater, sorter(&"item2", &"item10"));
+    /// ```
+    pub const ALPHABETICAL_SORTER: Sorter<'a, T> = &|a, b| {
+        a.to_string()
+            .to_lowercase()
+            .cmp(&b.to_string().to_lowercase())
+    };
+
+    /// Sorter that orders options in natural order by their string values, ignoring case,
+    /// where numbers are compared by their value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use inquire::MultiSelect;
+    ///
+    /// let sorter = MultiSelect::<&str>::NATURAL_SORTER;
+    /// assert_eq!(Ordering::Less, sorter(&"item2", &"Item10"));
+    /// ```
+    pub const NATURAL_SORTER: Sorter<'a, T> =
+        &|a, b| natural_cmp(&a.to_string().to_lowercase(), &b.to_string().to_lowercase());
+
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
+    /// Default layout, one option per line.
+    pub const DEFAULT_LAYOUT: Layout = Layout::List;
+
     /// Default value of vim mode, equal to the global default value [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
+    /// Default value of vim filter mode.
+    pub const DEFAULT_VIM_FILTER_MODE: bool = false;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
@@ -207,6 +319,13 @@ where
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
 
+    /// Default virtualization behaviour, disabled.
+    pub const DEFAULT_VIRTUALIZED: bool = false;
+
+    /// Default incremental scoring behaviour, enabled, since the default
+    /// scorer only narrows down its matches as the filter is extended.
+    pub const DEFAULT_INCREMENTAL_SCORING: bool = true;
+
     /// Default filter input enabled behaviour.
     /// Defaults to true.
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
@@ -226,16 +345,29 @@ where
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            layout: Self::DEFAULT_LAYOUT,
+            vim_mode: crate::config::get_vim_mode(),
+            vim_filter_mode: Self::DEFAULT_VIM_FILTER_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             starting_filter_input: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
             scorer: Self::DEFAULT_SCORER,
+            sorter: None,
+            transliterator: None,
+            virtualized: Self::DEFAULT_VIRTUALIZED,
+            incremental_scoring: Self::DEFAULT_INCREMENTAL_SCORING,
+            background_scoring: None,
+            #[cfg(feature = "normalization")]
+            normalization: Normalization::NONE,
             formatter: Self::DEFAULT_FORMATTER,
+            option_prefix: None,
+            option_icon: None,
             validator: None,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -257,12 +389,30 @@ where
         self
     }
 
-    /// Enables or disables vim_mode.
+    /// Sets the layout of the options.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Enables or disables vim_mode, overriding the global setting of
+    /// [set_global_vim_mode](crate::set_global_vim_mode).
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
+    /// Enables or disables modal vi keybindings, taking precedence over
+    /// [`MultiSelect::with_vim_mode`].
+    ///
+    /// The prompt starts in normal mode, where keys move the cursor instead
+    /// of being typed into the filter input. Pressing `/` switches to insert
+    /// mode to type a filter, and escape switches back to normal mode.
+    pub fn with_vim_filter_mode(mut self, vim_filter_mode: bool) -> Self {
+        self.vim_filter_mode = vim_filter_mode;
+        self
+    }
+
     /// Sets the keep filter behavior.
     pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
         self.keep_filter = keep_filter;
@@ -270,8 +420,105 @@ where
     }
 
     /// Sets the scoring function.
+    ///
+    /// Incremental scoring is disabled, as custom scorers might not hold the
+    /// assumptions it relies on. If yours does, re-enable it with
+    /// [`MultiSelect::with_incremental_scoring`] after setting the scorer.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
+        self.incremental_scoring = false;
+        self
+    }
+
+    /// Enables or disables incremental scoring.
+    ///
+    /// Scores are cached for each filter typed so far. When characters are
+    /// appended to the filter, only the options matched by the previous one
+    /// are scored again, and deleting characters restores the cached scores
+    /// of the shorter filter without calling the scorer.
+    ///
+    /// The scorer must be monotonic for this to be correct: if it rejects an
+    /// option for a filter, it must also reject it for every filter starting
+    /// with that one. This holds for the default scorer and for substring
+    /// matching, but not for scorers matching e.g. whole words or regular
+    /// expressions. With a scorer that isn't monotonic, options matching the
+    /// current filter can be missing from the list.
+    ///
+    /// The scores of the last 8 filters are cached.
+    pub fn with_incremental_scoring(mut self, incremental_scoring: bool) -> Self {
+        self.incremental_scoring = incremental_scoring;
+        self
+    }
+
+    /// Sets the Unicode normalization applied to the filter input and the
+    /// string values of the options before scoring, so that, for example,
+    /// typing "jose" matches "José" when diacritics are stripped.
+    ///
+    /// The scorer receives the normalized strings, while options are still
+    /// displayed as provided.
+    #[cfg(feature = "normalization")]
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Enables or disables virtualization, which keeps filtering responsive
+    /// on lists with hundreds of thousands of options.
+    ///
+    /// Options are always converted to strings lazily, only when they are
+    /// scored. When virtualization is enabled:
+    ///
+    /// - An empty filter matches all options without calling the scorer, so
+    ///   options that are never filtered are never converted to strings.
+    /// - At most 10,000 options are scored per key press. The matches found
+    ///   so far are displayed along with a "filtering…" indicator, and the
+    ///   other options are scored while the user doesn't type.
+    ///
+    /// Combined with [`MultiSelect::with_incremental_scoring`], extending the filter
+    /// only scores again the options matched by the previous one, including
+    /// the ones matched so far when it was still being scored.
+    pub fn with_virtualization(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
+
+    /// Scores options on a worker thread, so that typing stays responsive
+    /// with expensive scorers or very large lists.
+    ///
+    /// Filter changes are debounced and sent to the worker, and the list is
+    /// updated when the results arrive, showing a "filtering…" indicator in
+    /// the meantime. The scorer set with [`MultiSelect::with_scorer`] and the
+    /// virtualization setting are not used when background scoring is enabled.
+    ///
+    /// See [`BackgroundScoring`] for more details.
+    pub fn with_background_scoring(mut self, scoring: BackgroundScoring) -> Self {
+        self.background_scoring = Some(scoring);
+        self
+    }
+
+    /// Sets the transliterator, used to match options written in non-Latin
+    /// scripts with input typed in Latin characters, e.g. "beijing" for "北京".
+    ///
+    /// Options are scored against both their original and transliterated
+    /// string values, keeping the best score. With the `transliteration`
+    /// feature, [AsciiTransliterator](crate::transliteration::AsciiTransliterator)
+    /// is available as a default implementation.
+    pub fn with_transliterator<TL>(mut self, transliterator: TL) -> Self
+    where
+        TL: Transliterator + 'static,
+    {
+        self.transliterator = Some(Box::new(transliterator));
+        self
+    }
+
+    /// Sets the sorter, which defines the display order of the options
+    /// when no filter is applied. While filtering, options are still
+    /// ordered by the scorer.
+    ///
+    /// Unlike sorting the options beforehand, the index of each option in
+    /// the original list is preserved in the answer.
+    pub fn with_sorter(mut self, sorter: Sorter<'a, T>) -> Self {
+        self.sorter = Some(sorter);
         self
     }
 
@@ -281,6 +528,28 @@ where
         self
     }
 
+    /// Sets the function that generates a custom prefix for each option,
+    /// such as a status emoji or a file-type icon.
+    ///
+    /// Prefixes are rendered in a dedicated column, independent of the options'
+    /// string values, and are not considered when filtering.
+    pub fn with_option_prefix(mut self, option_prefix: OptionPrefix<'a, T>) -> Self {
+        self.option_prefix = Some(option_prefix);
+        self
+    }
+
+    /// Sets the function that returns the icon of each option, such as an
+    /// emoji or a nerd-font glyph with an ASCII fallback.
+    ///
+    /// Icons are rendered in a dedicated column, aligned across the options
+    /// of a page, and are not taken into account when filtering. Whether the
+    /// glyph or the fallback is rendered is defined by
+    /// [`RenderConfig::icon_fallback`](crate::ui::RenderConfig::icon_fallback).
+    pub fn with_icons(mut self, option_icon: OptionIcon<'a, T>) -> Self {
+        self.option_icon = Some(option_icon);
+        self
+    }
+
     /// Sets the validator to apply to the user input. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as limiting the number
     /// of selections.
@@ -312,7 +581,8 @@ where
         self
     }
 
-    /// Sets the starting cursor index.
+    /// Sets the starting cursor index, the index of the highlighted option in
+    /// the list of options, wherever the sorter displays it.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
     /// and starting_filter_input is set to something other than None.
@@ -348,17 +618,35 @@ where
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<MultiSelectPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -413,11 +701,39 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Async version of [prompt](Self::prompt), awaiting the options selected
+    /// by the user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<Vec<T>> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        let answer =
+            crate::prompts::prompt_async::prompt_async(MultiSelectPrompt::new(self)?, &mut backend)
+                .await?;
+        Ok(answer.into_iter().map(|o| o.value).collect())
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the options selected by the user. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, Vec<T>>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            MultiSelectPrompt::new(self)?,
+            render_config,
+            settings,
+            |answer| answer.into_iter().map(|o| o.value).collect(),
+        )
+    }
+
     pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
         self,
         backend: &mut B,
@@ -425,3 +741,30 @@ where
         MultiSelectPrompt::new(self)?.prompt(backend)
     }
 }
+
+impl<'a, T> MultiSelect<'a, &'a T>
+where
+    T: Display,
+{
+    /// Creates a [MultiSelect] that borrows its options from the provided slice,
+    /// along with default configuration values.
+    ///
+    /// The options are neither cloned nor moved into the prompt: the answer
+    /// holds references to the selected elements, along with their indexes in
+    /// the slice.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let fruits = vec![String::from("Banana"), String::from("Apple")];
+    ///
+    /// let answer = MultiSelect::new_borrowed("Fruits:", &fruits).raw_prompt()?;
+    /// let indexes: Vec<usize> = answer.iter().map(|option| option.index).collect();
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_borrowed(message: &'a str, options: &'a [T]) -> Self {
+        Self::new(message, options.iter().collect())
+    }
+}
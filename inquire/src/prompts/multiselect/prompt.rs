@@ -1,17 +1,25 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{cmp::Reverse, collections::BTreeSet, fmt::Display, time::Duration};
 
 use crate::{
     error::InquireResult,
+    event::PromptEvent,
+    feedback::{self, FeedbackEvent},
     formatter::MultiOptionFormatter,
     input::{Input, InputActionResult},
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     list_option::ListOption,
-    prompts::action::Action,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::MultiSelectBackend,
-    utils::paginate,
+    prompts::{
+        background_scoring::{ScoringWorker, FILTERING_MESSAGE, TICK_INTERVAL},
+        option_strings::{OptionStrings, ScoreCache, ScoringPass},
+        prompt::{ActionResult, AdaptivePagePrompt, Prompt},
+        vi::{ViKeys, ViMode},
+        Action,
+    },
+    type_aliases::{OptionIcon, OptionPrefix, Scorer, Sorter},
+    ui::{Icon, Key, MultiSelectBackend},
+    utils::{paginate, paginate_grid},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    InputAction, InquireError, Layout, MultiSelect,
 };
 
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
@@ -20,16 +28,32 @@ pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
     options: Vec<T>,
-    string_options: Vec<String>,
+    option_strings: OptionStrings,
     help_message: Option<&'a str>,
     cursor_index: usize,
     checked: BTreeSet<usize>,
     input: Option<Input>,
     scored_options: Vec<usize>,
     scorer: Scorer<'a, T>,
+    virtualized: bool,
+    incremental_scoring: bool,
+    /// Only used with incremental scoring.
+    score_cache: ScoreCache,
+    /// Scoring of the current filter continued on the next ticks, when it
+    /// exceeds the budget of a key press. Only used with virtualization.
+    scoring_pass: Option<ScoringPass>,
+    scoring_worker: Option<ScoringWorker>,
+    sorter: Option<Sorter<'a, T>>,
+    option_prefix: Option<OptionPrefix<'a, T>>,
+    option_icon: Option<OptionIcon<'a, T>>,
     formatter: MultiOptionFormatter<'a, T>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
+    vi: Option<ViKeys>,
+    key_bindings: KeyBindings<MultiSelectPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
@@ -54,8 +78,18 @@ where
             }
         }
 
-        let string_options = mso.options.iter().map(T::to_string).collect();
-        let scored_options = (0..mso.options.len()).collect();
+        if let Layout::Grid { columns: 0 } = mso.layout {
+            return Err(InquireError::InvalidConfiguration(
+                "Grid layout must have at least one column".into(),
+            ));
+        }
+
+        let config = (&mso).into();
+        let mut scored_options = (0..mso.options.len()).collect::<Vec<usize>>();
+        if let Some(sorter) = mso.sorter {
+            scored_options
+                .sort_by(|a, b| sorter(mso.options.get(*a).unwrap(), mso.options.get(*b).unwrap()));
+        }
         let checked_options = mso
             .default
             .as_ref()
@@ -67,6 +101,26 @@ where
             })
             .unwrap_or_default();
 
+        let option_strings = OptionStrings::new(mso.options.len(), mso.transliterator);
+        #[cfg(feature = "normalization")]
+        let option_strings = option_strings.with_normalization(mso.normalization);
+
+        let options = &mso.options;
+        let scoring_worker = mso.background_scoring.as_ref().map(|scoring| {
+            let values = (0..options.len())
+                .map(|i| option_strings.scoring_value(options, i).to_owned())
+                .collect();
+            let transliterated = (0..options.len())
+                .map(|i| {
+                    option_strings
+                        .transliterated_value(options, i)
+                        .map(str::to_owned)
+                })
+                .collect();
+
+            ScoringWorker::spawn(scoring, values, transliterated)
+        });
+
         let input = match mso.filter_input_enabled {
             true => Some(Input::new_with(
                 mso.starting_filter_input.unwrap_or_default(),
@@ -74,20 +128,41 @@ where
             false => None,
         };
 
+        // the starting cursor is the index of an option, which the sorter
+        // may have moved to another row.
+        let starting_cursor = mso.starting_cursor;
+        let cursor_index = scored_options
+            .iter()
+            .position(|i| *i == starting_cursor)
+            .unwrap_or(starting_cursor);
+
         Ok(Self {
             message: mso.message,
-            config: (&mso).into(),
+            config,
             options: mso.options,
-            string_options,
+            option_strings,
             scored_options,
             help_message: mso.help_message,
-            cursor_index: mso.starting_cursor,
+            cursor_index,
             input,
             scorer: mso.scorer,
+            virtualized: mso.virtualized,
+            incremental_scoring: mso.incremental_scoring,
+            score_cache: ScoreCache::default(),
+            scoring_pass: None,
+            scoring_worker,
+            sorter: mso.sorter,
+            option_prefix: mso.option_prefix,
+            option_icon: mso.option_icon,
             formatter: mso.formatter,
             validator: mso.validator,
             error: None,
             checked: checked_options,
+            vi: mso.vim_filter_mode.then(|| ViKeys::new(ViMode::Normal)),
+            key_bindings: mso.key_bindings,
+            key_handler: mso.settings.key_handler,
+            escape_behavior: mso.settings.escape_behavior,
+            cancel_guard: mso.settings.cancel_guard,
         })
     }
 
@@ -120,6 +195,52 @@ where
         self.update_cursor_position(new_position)
     }
 
+    fn move_cursor_row_up(&mut self) -> ActionResult {
+        match self.cursor_index.checked_sub(self.row_length()) {
+            Some(new_position) => self.update_cursor_position(new_position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn move_cursor_row_down(&mut self) -> ActionResult {
+        let row_length = self.row_length();
+        let last_row = self.scored_options.len().saturating_sub(1) / row_length;
+
+        if self.cursor_index / row_length < last_row {
+            self.move_cursor_down(row_length, false)
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn option_icons(&self, options: &[ListOption<&T>]) -> Vec<Icon> {
+        match self.option_icon {
+            Some(option_icon) => options
+                .iter()
+                .map(|option| option_icon(option.value))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn option_prefixes(&self, options: &[ListOption<&T>]) -> Vec<String> {
+        match self.option_prefix {
+            Some(option_prefix) => options
+                .iter()
+                .map(|option| option_prefix(option.value))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Number of options displayed in each row of the current layout.
+    fn row_length(&self) -> usize {
+        match self.config.layout {
+            Layout::List => 1,
+            Layout::Grid { columns } => columns,
+        }
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -141,6 +262,8 @@ where
             self.checked.insert(*idx);
         }
 
+        feedback::emit(FeedbackEvent::Toggle);
+
         ActionResult::NeedsRedraw
     }
 
@@ -189,6 +312,11 @@ where
         }
     }
 
+    fn format_options(&self, answer: &[ListOption<T>]) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
     fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
         let mut answer = vec![];
 
@@ -207,22 +335,113 @@ where
     }
 
     fn run_scorer(&mut self) {
+        #[cfg(feature = "instrument")]
+        let _span = crate::instrument::span(crate::instrument::Phase::Scoring);
+
         let content = match &self.input {
-            Some(input) => input.content(),
+            Some(input) => self.option_strings.scoring_filter(input.content()),
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+        if let Some(worker) = self.scoring_worker.as_mut() {
+            if !content.is_empty() {
+                worker.request(content.into_owned());
+                return;
+            }
+
+            // An empty filter matches everything, no need to wait for the worker.
+            worker.cancel();
+            self.apply_scores((0..self.options.len()).map(|i| (i, 0)).collect(), true);
+            return;
+        }
+
+        let content = content.into_owned();
+        let incremental = self.incremental_scoring;
+
+        self.score_cache.retain_prefixes_of(&content);
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        if let Some((filter, scores)) = self.score_cache.last() {
+            if *filter == content {
+                let scores = scores.clone();
+                self.apply_scores(scores, content.is_empty());
+                return;
+            }
+        }
+
+        // A filter that extends a cached one can only narrow down its
+        // matches, so there is no need to score the other options. The same
+        // goes for the options matched so far by an unfinished pass.
+        let unfinished_pass = self
+            .scoring_pass
+            .take()
+            .filter(|pass| incremental && content.starts_with(pass.filter()));
+        let candidates = match (unfinished_pass, self.score_cache.last()) {
+            (Some(pass), _) => pass.into_candidates(),
+            (None, Some((_, previous))) => previous.iter().map(|(i, _)| *i).collect(),
+            (None, None) => (0..self.options.len()).collect::<Vec<usize>>(),
+        };
+
+        let options = match self.virtualized {
+            true if content.is_empty() => candidates.into_iter().map(|i| (i, 0)).collect(),
+            true => {
+                self.continue_scoring(ScoringPass::new(content, candidates));
+                return;
+            }
+            false => candidates
+                .into_iter()
+                .filter_map(|i| self.score_option(&content, i).map(|score| (i, score)))
+                .collect::<Vec<(usize, i64)>>(),
+        };
+
+        let filter_is_empty = content.is_empty();
+        if incremental {
+            self.score_cache.push((content, options.clone()));
+        }
+        self.apply_scores(options, filter_is_empty);
+    }
+
+    /// Best score of the option against the filter, among its string value
+    /// and its transliterated one.
+    fn score_option(&self, filter: &str, i: usize) -> Option<i64> {
+        let opt = self.options.get(i).unwrap();
+        let value = self.option_strings.scoring_value(&self.options, i);
+        let score = (self.scorer)(filter, opt, value, i);
+        let transliterated_score = self
+            .option_strings
+            .transliterated_value(&self.options, i)
+            .and_then(|value| (self.scorer)(filter, opt, value, i));
+
+        score.max(transliterated_score)
+    }
+
+    /// Scores the next chunk of options of the pass and displays the matches
+    /// found so far, keeping the pass to be continued on the next tick until
+    /// all options are scored.
+    fn continue_scoring(&mut self, mut pass: ScoringPass) {
+        pass.advance(|filter, i| self.score_option(filter, i));
+
+        let scores = pass.scores().to_vec();
+        match pass.is_done() {
+            true if self.incremental_scoring => self.score_cache.push(pass.into_scores()),
+            true => {}
+            false => self.scoring_pass = Some(pass),
+        }
+        self.apply_scores(scores, false);
+    }
+
+    /// Replaces the filtered options with the provided scored ones, sorting
+    /// them by score, or with the sorter if the filter is empty.
+    ///
+    /// Options with the same score keep their original order, so that the
+    /// list is the same regardless of whether the scores were cached or
+    /// computed in the background.
+    fn apply_scores(&mut self, mut options: Vec<(usize, i64)>, filter_is_empty: bool) {
+        match self.sorter {
+            Some(sorter) if filter_is_empty => options.sort_by(|(a, _), (b, _)| {
+                sorter(self.options.get(*a).unwrap(), self.options.get(*b).unwrap())
+            }),
+            _ => options.sort_unstable_by_key(|(idx, score)| (Reverse(*score), *idx)),
+        }
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
 
@@ -253,13 +472,82 @@ where
         self.message
     }
 
+    fn partial_input(&self) -> Option<&str> {
+        self.input.as_ref().map(Input::content)
+    }
+
+    fn has_input(&self) -> bool {
+        !self.checked.is_empty() || self.input.as_ref().is_some_and(|input| !input.is_empty())
+    }
+
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        let input = self.input.as_ref().map(|input| input.content().to_owned());
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+        let checked = self.checked.iter().copied().collect();
+
+        input
+            .map(PromptEvent::InputChanged)
+            .into_iter()
+            .chain(highlighted.map(PromptEvent::HighlightChanged))
+            .chain(Some(PromptEvent::SelectionChanged(checked)))
+            .collect()
+    }
+
     fn config(&self) -> &MultiSelectConfig {
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<MultiSelectPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(
+            self,
+            MultiSelectPromptAction::FilterInput(InputAction::Clear),
+        )
+    }
+
+    fn action_from_key(&mut self, key: Key) -> Option<Action<MultiSelectPromptAction>> {
+        let key = match &mut self.vi {
+            Some(vi) => vi.translate(key)?,
+            None => key,
+        };
+        Action::from_key(key, &self.config)
+    }
+
     fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
-        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
-        (self.formatter)(&refs)
+        self.format_options(answer)
+    }
+
+    fn render_answer(
+        &self,
+        answer: &Vec<ListOption<T>>,
+        backend: &mut Backend,
+    ) -> InquireResult<()> {
+        let tags = answer
+            .iter()
+            .map(|option| option.value.to_string())
+            .collect::<Vec<_>>();
+
+        backend.render_prompt_with_answer_tags(
+            self.message,
+            &self.format_options(answer),
+            &tags,
+        )?;
+        Ok(())
     }
 
     fn setup(&mut self) -> InquireResult<()> {
@@ -267,79 +555,53 @@ where
         Ok(())
     }
 
-    fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
-        // NOTE ABOUT OVERRIDING `Prompt::prompt`
-        //
-        // See the analogous comment in `select/prompt.rs`. In short: we override the
-        // base prompt loop here to implement adaptive page sizing for multi-line/wrapping
-        // option lists without changing the public `Prompt` trait.
-        //
-        // The default `Prompt::prompt` loop always flushes a frame immediately after
-        // `render(&self, ...)` with no chance to abort/retry, and it cannot mutate
-        // `self.config.page_size` because `Self::Config` is opaque at the trait level.
-        // MultiSelect also has prompt-specific state to reconcile (checked set, cursor)
-        // after resizing. Therefore, the redraw path is owned here via
-        // `redraw_with_adaptive_page_size`.
-        //
-        // Future direction: add a minimal opt-in adaptive-sizing trait/hook to the base
-        // prompt so list-style prompts (Select/MultiSelect/Text suggestions, etc.) can
-        // share this behavior without overrides.
-        //
-        <Self as Prompt<Backend>>::setup(&mut self)?;
-
-        let mut last_handle = ActionResult::NeedsRedraw;
-        let final_answer = loop {
-            if last_handle.needs_redraw() {
-                self.redraw_with_adaptive_page_size(backend)?;
-                last_handle = ActionResult::Clean;
-            }
+    fn tick_interval(&self) -> Option<Duration> {
+        match self.scoring_worker.is_some() || self.scoring_pass.is_some() {
+            true => Some(TICK_INTERVAL),
+            false => None,
+        }
+    }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, <Self as Prompt<Backend>>::config(&self));
-
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) =
-                            <Self as Prompt<Backend>>::submit(&mut self)?
-                        {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Cancel => {
-                        let pre_cancel_result =
-                            <Self as Prompt<Backend>>::pre_cancel(&mut self)?;
-
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(
-                                <Self as Prompt<Backend>>::message(&self),
-                            )?;
-                            backend.frame_finish(true)?;
-                            return Err(InquireError::OperationCanceled);
-                        }
-
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => {
-                        <Self as Prompt<Backend>>::handle(&mut self, inner_action)?
-                    }
-                };
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        if let Some(pass) = self.scoring_pass.take() {
+            self.continue_scoring(pass);
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        match self.scoring_worker.as_mut().and_then(ScoringWorker::poll) {
+            Some(scores) => {
+                self.apply_scores(scores, false);
+                Ok(ActionResult::NeedsRedraw)
             }
-        };
+            None => Ok(ActionResult::Clean),
+        }
+    }
 
-        let formatted = <Self as Prompt<Backend>>::format_answer(&self, &final_answer);
+    /// Checks the options listed in the answer, separated by commas, where
+    /// each one is displayed exactly as listed or differs only in case.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        let mut checked = BTreeSet::new();
+
+        for answer in answer.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+            let find = |matches: &dyn Fn(&str) -> bool| {
+                (0..self.options.len())
+                    .find(|i| matches(self.option_strings.value(&self.options, *i)))
+            };
+
+            let index = find(&|option| option == answer)
+                .or_else(|| find(&|option| option.eq_ignore_ascii_case(answer)))
+                .ok_or_else(|| {
+                    InquireError::InvalidAnswer(format!("\"{}\" is not one of the options", answer))
+                })?;
+            checked.insert(index);
+        }
 
-        backend.frame_setup()?;
-        backend.render_prompt_with_answer(
-            <Self as Prompt<Backend>>::message(&self),
-            &formatted,
-        )?;
-        backend.frame_finish(true)?;
+        self.checked = checked;
+        Ok(())
+    }
 
-        Ok(final_answer)
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
     }
 
     fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
@@ -356,11 +618,21 @@ where
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
+            MultiSelectPromptAction::MoveUp => match self.config.layout {
+                Layout::List => self.move_cursor_up(1, true),
+                Layout::Grid { .. } => self.move_cursor_row_up(),
+            },
+            MultiSelectPromptAction::MoveDown => match self.config.layout {
+                Layout::List => self.move_cursor_down(1, true),
+                Layout::Grid { .. } => self.move_cursor_row_down(),
+            },
+            MultiSelectPromptAction::MoveLeft => self.move_cursor_up(1, true),
+            MultiSelectPromptAction::MoveRight => self.move_cursor_down(1, true),
+            MultiSelectPromptAction::PageUp => {
+                self.move_cursor_up(self.config.page_size * self.row_length(), false)
+            }
             MultiSelectPromptAction::PageDown => {
-                self.move_cursor_down(self.config.page_size, false)
+                self.move_cursor_down(self.config.page_size * self.row_length(), false)
             }
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
@@ -370,10 +642,12 @@ where
                 for idx in &self.scored_options {
                     self.checked.insert(*idx);
                 }
+                feedback::emit(FeedbackEvent::Toggle);
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::ClearSelections => {
                 self.checked.clear();
+                feedback::emit(FeedbackEvent::Toggle);
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
@@ -411,64 +685,62 @@ where
             .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
             .collect::<Vec<ListOption<&T>>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
-
-        backend.render_options(page, &self.checked)?;
+        match self.config.layout {
+            Layout::List => {
+                let mut page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+                page.prefixes = self.option_prefixes(page.content);
+                page.icons = self.option_icons(page.content);
+                backend.render_options(page, &self.checked)?;
+            }
+            Layout::Grid { columns } => {
+                let mut page = paginate_grid(
+                    columns,
+                    self.config.page_size,
+                    &choices,
+                    Some(self.cursor_index),
+                );
+                page.prefixes = self.option_prefixes(page.content);
+                page.icons = self.option_icons(page.content);
+                backend.render_options_grid(page, &self.checked, columns)?;
+            }
+        }
 
-        if let Some(help_message) = self.help_message {
+        let filtering = self.scoring_pass.is_some()
+            || self
+                .scoring_worker
+                .as_ref()
+                .is_some_and(ScoringWorker::is_busy);
+        if filtering {
+            backend.render_help_message(FILTERING_MESSAGE)?;
+        } else if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
         }
 
         Ok(())
     }
+
+    fn adaptive_page(&mut self) -> Option<&mut dyn AdaptivePagePrompt> {
+        Some(self)
+    }
 }
 
-impl<'a, T> MultiSelectPrompt<'a, T>
+impl<'a, T> AdaptivePagePrompt for MultiSelectPrompt<'a, T>
 where
     T: Display,
 {
-    fn redraw_with_adaptive_page_size<Backend>(
-        &mut self,
-        backend: &mut Backend,
-    ) -> InquireResult<()>
-    where
-        Backend: MultiSelectBackend,
-    {
-        let mut page_size = self.config.page_size.max(1);
-
-        loop {
-            backend.frame_setup()?;
-            self.render(backend)?;
-
-            // Use the height that would actually be flushed (max of last/current)
-            // to avoid scrolling when clearing a previously taller frame.
-            let frame_h = backend.current_flush_height().unwrap_or(0);
-            let term_h = backend.current_terminal_height().unwrap_or(u16::MAX);
-
-            if frame_h <= term_h || page_size <= 1 {
-                backend.frame_finish(false)?;
-                break;
-            }
-
-            backend.frame_abort()?;
-
-            let mut new_size = (page_size as u32)
-                .saturating_mul(term_h.max(1) as u32)
-                .checked_div(frame_h.max(1) as u32)
-                .unwrap_or(1) as usize;
-
-            if new_size >= page_size {
-                new_size = page_size.saturating_sub(1).max(1);
-            }
+    fn page_size(&self) -> usize {
+        self.config.page_size
+    }
 
-            page_size = new_size;
-            self.config.page_size = page_size;
-            let _ = self.update_cursor_position(
-                self.cursor_index
-                    .min(self.scored_options.len().saturating_sub(1)),
-            );
-        }
+    fn set_page_size(&mut self, page_size: usize) {
+        self.config.page_size = page_size;
+    }
 
-        Ok(())
+    fn reconcile_after_resize(&mut self) {
+        // Ensure cursor stays within bounds after size change.
+        let _ = self.update_cursor_position(
+            self.cursor_index
+                .min(self.scored_options.len().saturating_sub(1)),
+        );
     }
 }
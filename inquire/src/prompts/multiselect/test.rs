@@ -3,8 +3,9 @@ use crate::{
     list_option::ListOption,
     terminal::test::match_text,
     test::fake_backend,
+    type_aliases::Scorer,
     ui::{Key, KeyModifiers},
-    MultiSelect,
+    Layout, MultiSelect,
 };
 
 #[test]
@@ -110,6 +111,7 @@ fn starting_cursor_is_respected() {
 }
 
 #[test]
+#[cfg(feature = "fuzzy")]
 fn naive_assert_fuzzy_match_as_default_scorer() {
     let mut backend = fake_backend(vec![
         Key::Char('w', KeyModifiers::NONE),
@@ -301,3 +303,252 @@ fn first_option_renders_on_new_line_without_filtering() {
     match_text(&mut output, "\r");
     match_text(&mut output, "\n");
 }
+
+#[test]
+fn grid_layout_arrows_move_instead_of_changing_selection() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = (0..6).collect();
+
+    let ans = MultiSelect::new("Question", options)
+        .with_layout(Layout::Grid { columns: 3 })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 1), ListOption::new(3, 3)], ans);
+}
+
+#[test]
+fn sorter_changes_display_order_but_keeps_original_indices() {
+    let mut backend = fake_backend(vec![
+        Key::Home,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["cherry", "Apple", "banana"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_sorter(MultiSelect::ALPHABETICAL_SORTER)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "cherry"), ListOption::new(1, "Apple")],
+        ans
+    );
+}
+
+#[test]
+fn starting_cursor_highlights_the_given_option_with_sorter() {
+    let mut backend = fake_backend(vec![Key::Char(' ', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = MultiSelect::new("Question", vec!["c", "a", "b"])
+        .with_sorter(MultiSelect::ALPHABETICAL_SORTER)
+        .with_starting_cursor(0)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "c")], ans);
+}
+
+#[test]
+fn duplicate_options_keep_their_original_indices() {
+    let mut backend = fake_backend(vec![
+        Key::Home,
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["x", "y", "x"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_sorter(&|a, b| b.cmp(a))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "x"), ListOption::new(2, "x")], ans);
+}
+
+#[test]
+fn new_borrowed_returns_references_to_selected_options() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![String::from("Lisbon"), String::from("Tokyo")];
+
+    let ans = MultiSelect::new_borrowed("Question", &options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, options.get(1).unwrap())], ans);
+}
+
+#[test]
+fn transliterated_values_are_scored_along_with_original_values() {
+    let mut backend = fake_backend(vec![
+        Key::Char('m', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let cyrillic_to_latin = |value: &str| {
+        value
+            .chars()
+            .map(|c| match c {
+                'М' => "M",
+                'о' => "o",
+                'с' => "s",
+                'к' => "k",
+                'в' => "v",
+                'а' => "a",
+                _ => "?",
+            })
+            .collect::<String>()
+    };
+
+    let options = vec!["Paris", "Москва", "Tokyo"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_transliterator(cyrillic_to_latin)
+        .with_scorer(&|input, _option, value, _idx| {
+            value.to_lowercase().starts_with(input).then_some(0)
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "Москва")], ans);
+}
+
+#[test]
+fn background_scoring_updates_options_when_results_arrive() {
+    use crate::{
+        prompts::test::PollingInput,
+        terminal::test::NullTerminal,
+        ui::{Backend, RenderConfig},
+        BackgroundScoring,
+    };
+    use std::time::Duration;
+
+    let scoring = BackgroundScoring::new(|input, value, _idx| value.contains(input).then_some(0))
+        .with_debounce(Duration::ZERO);
+    let options = vec!["tokio", "serde", "serde_json", "rayon"];
+
+    let input = PollingInput::new(
+        vec![Key::Char('j', KeyModifiers::NONE)],
+        20,
+        vec![Key::Char(' ', KeyModifiers::NONE), Key::Enter],
+    );
+    let terminal = NullTerminal;
+    let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Packages", options)
+        .with_background_scoring(scoring)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "serde_json")], ans);
+}
+
+#[test]
+fn answer_is_rendered_as_tags_wrapped_to_terminal_width() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, Color, RenderConfig, StyleSheet},
+    };
+    use std::collections::VecDeque;
+
+    let tag_style = StyleSheet::new().with_bg(Color::DarkBlue);
+    let render_config = RenderConfig::default().with_answer_tag(Some(tag_style));
+
+    let keys = vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(20, 10).unwrap());
+        let mut backend = Backend::new(keys.into_iter(), terminal, render_config).unwrap();
+
+        MultiSelect::new("Tags", vec!["alpha", "beta", "gamma"])
+            .without_filtering()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let texts = output
+        .iter()
+        .filter_map(|token| match token {
+            MockTerminalToken::Text(t) => Some(t),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let first_tag = texts.iter().rposition(|t| t.content == " alpha ").unwrap();
+    let answer = texts
+        .get(first_tag..)
+        .unwrap()
+        .iter()
+        .take(6)
+        .map(|t| t.content.as_str())
+        .collect::<Vec<_>>();
+
+    // The second tag doesn't fit after the first one in 20 columns.
+    assert_eq!(
+        vec![" alpha ", "\r", "\n", " beta ", " ", " gamma "],
+        answer
+    );
+    assert_eq!(Some(tag_style), texts.get(first_tag).map(|t| t.style));
+}
+
+#[test]
+fn virtualization_resumes_scoring_on_the_next_key_presses() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", (0..25_000).collect())
+        .with_virtualization(true)
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 1)], ans);
+    // at most 10,000 options are scored on each key press
+    assert_eq!(25_000, calls.get());
+}
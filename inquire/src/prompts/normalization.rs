@@ -0,0 +1,103 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Policy used to normalize both the filter input and the string values of
+/// the options before they are passed to the scorer of [Select](crate::Select)
+/// and [MultiSelect](crate::MultiSelect) prompts.
+///
+/// Normalization only affects filtering, options are still displayed as they
+/// were provided. By default, no normalization is applied.
+///
+/// # Example
+///
+/// ```
+/// use inquire::Normalization;
+///
+/// let normalization = Normalization::ALL;
+///
+/// assert_eq!("jose", normalization.normalize("José"));
+/// assert_eq!("file", normalization.normalize("ﬁle"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Normalization {
+    /// Applies Unicode compatibility decomposition (NFKD), so that
+    /// compatibility characters such as ligatures or full-width letters
+    /// match their plain counterparts.
+    pub decompose: bool,
+
+    /// Applies case folding, so that matching is case-insensitive.
+    pub fold_case: bool,
+
+    /// Removes diacritical marks, so that e.g. "e" matches "é".
+    ///
+    /// Implies decomposition, as precomposed characters must be decomposed
+    /// before their marks can be removed.
+    pub strip_diacritics: bool,
+}
+
+impl Normalization {
+    /// No normalization is applied.
+    pub const NONE: Self = Self {
+        decompose: false,
+        fold_case: false,
+        strip_diacritics: false,
+    };
+
+    /// Compatibility decomposition, case folding and diacritic stripping
+    /// are all applied.
+    pub const ALL: Self = Self {
+        decompose: true,
+        fold_case: true,
+        strip_diacritics: true,
+    };
+
+    /// Normalizes the provided string according to this policy.
+    pub fn normalize(&self, value: &str) -> String {
+        let decomposed: String = match self.decompose || self.strip_diacritics {
+            true => value
+                .nfkd()
+                .filter(|c| !(self.strip_diacritics && is_combining_mark(*c)))
+                .collect(),
+            false => value.to_string(),
+        };
+
+        match self.fold_case {
+            true => decomposed.to_lowercase(),
+            false => decomposed,
+        }
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Normalization;
+
+    #[test]
+    fn none_keeps_value_unchanged() {
+        assert_eq!("Crème ﬁne", Normalization::NONE.normalize("Crème ﬁne"));
+    }
+
+    #[test]
+    fn each_step_can_be_applied_independently() {
+        let decompose = Normalization {
+            decompose: true,
+            ..Normalization::NONE
+        };
+        let fold_case = Normalization {
+            fold_case: true,
+            ..Normalization::NONE
+        };
+        let strip_diacritics = Normalization {
+            strip_diacritics: true,
+            ..Normalization::NONE
+        };
+
+        assert_eq!("Cre\u{300}me fine", decompose.normalize("Crème ﬁne"));
+        assert_eq!("crème ﬁne", fold_case.normalize("Crème ﬁne"));
+        assert_eq!("Creme fine", strip_diacritics.normalize("Crème ﬁne"));
+        assert_eq!("creme fine", Normalization::ALL.normalize("Crème ﬁne"));
+    }
+}
@@ -0,0 +1,216 @@
+use std::{borrow::Cow, cell::OnceCell, fmt::Display};
+
+use crate::transliteration::Transliterator;
+#[cfg(feature = "normalization")]
+use crate::Normalization;
+
+/// String values of the options of a list prompt, as matched against the
+/// filter input.
+///
+/// Values are computed on first access and cached, so that options that are
+/// never scored are never converted to strings.
+pub struct OptionStrings {
+    values: Vec<OnceCell<String>>,
+    #[cfg(feature = "normalization")]
+    normalization: Normalization,
+    #[cfg(feature = "normalization")]
+    normalized: Vec<OnceCell<String>>,
+    transliterator: Option<Box<dyn Transliterator>>,
+    transliterated: Vec<OnceCell<String>>,
+}
+
+fn lazy_cells(len: usize) -> Vec<OnceCell<String>> {
+    (0..len).map(|_| OnceCell::new()).collect()
+}
+
+impl OptionStrings {
+    pub fn new(len: usize, transliterator: Option<Box<dyn Transliterator>>) -> Self {
+        let transliterated = match transliterator {
+            Some(_) => lazy_cells(len),
+            None => vec![],
+        };
+
+        Self {
+            values: lazy_cells(len),
+            #[cfg(feature = "normalization")]
+            normalization: Normalization::NONE,
+            #[cfg(feature = "normalization")]
+            normalized: vec![],
+            transliterator,
+            transliterated,
+        }
+    }
+
+    #[cfg(feature = "normalization")]
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        if !normalization.is_identity() {
+            self.normalized = lazy_cells(self.values.len());
+        }
+        self.normalization = normalization;
+        self
+    }
+
+    /// String value of the option, as displayed to the user.
+    pub fn value<T: Display>(&self, options: &[T], index: usize) -> &str {
+        self.values
+            .get(index)
+            .unwrap()
+            .get_or_init(|| options.get(index).unwrap().to_string())
+    }
+
+    /// String value of the option as passed to the scorer.
+    pub fn scoring_value<T: Display>(&self, options: &[T], index: usize) -> &str {
+        #[cfg(feature = "normalization")]
+        if let Some(normalized) = self.normalized.get(index) {
+            return normalized
+                .get_or_init(|| self.normalization.normalize(self.value(options, index)));
+        }
+
+        self.value(options, index)
+    }
+
+    /// Transliterated string value of the option as passed to the scorer,
+    /// if a transliterator is set.
+    pub fn transliterated_value<T: Display>(&self, options: &[T], index: usize) -> Option<&str> {
+        let transliterator = self.transliterator.as_ref()?;
+        let cell = self.transliterated.get(index)?;
+
+        Some(cell.get_or_init(|| {
+            self.normalize(transliterator.transliterate(self.value(options, index)))
+        }))
+    }
+
+    /// Filter input as passed to the scorer.
+    #[cfg_attr(not(feature = "normalization"), allow(clippy::unused_self))]
+    pub fn scoring_filter<'s>(&self, filter: &'s str) -> Cow<'s, str> {
+        #[cfg(feature = "normalization")]
+        if !self.normalization.is_identity() {
+            return Cow::Owned(self.normalization.normalize(filter));
+        }
+
+        Cow::Borrowed(filter)
+    }
+
+    #[cfg_attr(not(feature = "normalization"), allow(clippy::unused_self))]
+    fn normalize(&self, value: String) -> String {
+        #[cfg(feature = "normalization")]
+        if !self.normalization.is_identity() {
+            return self.normalization.normalize(&value);
+        }
+
+        value
+    }
+}
+
+/// Maximum number of options scored on a key press or tick when
+/// virtualization is enabled.
+pub const SCORING_BUDGET: usize = 10_000;
+
+/// Scoring of the options of a list prompt against a filter, done in chunks
+/// of at most [SCORING_BUDGET] options so that a key press never scores a
+/// whole very large list. The options left are scored on the next ticks.
+pub struct ScoringPass {
+    filter: String,
+    remaining: Vec<usize>,
+    scores: Vec<(usize, i64)>,
+}
+
+impl ScoringPass {
+    pub fn new(filter: String, candidates: Vec<usize>) -> Self {
+        Self {
+            filter,
+            remaining: candidates,
+            scores: vec![],
+        }
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Scores the next chunk of options, keeping the ones that match.
+    pub fn advance<F>(&mut self, mut score: F)
+    where
+        F: FnMut(&str, usize) -> Option<i64>,
+    {
+        let chunk = self.remaining.len().min(SCORING_BUDGET);
+        for i in self.remaining.drain(..chunk) {
+            if let Some(score) = score(&self.filter, i) {
+                self.scores.push((i, score));
+            }
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.remaining.is_empty()
+    }
+
+    /// Options matched so far, with their scores.
+    pub fn scores(&self) -> &[(usize, i64)] {
+        &self.scores
+    }
+
+    /// Options to score for a filter extending the one of this pass, which
+    /// are the ones it matched so far and the ones it didn't score yet.
+    pub fn into_candidates(self) -> Vec<usize> {
+        let mut candidates: Vec<usize> = self.scores.into_iter().map(|(i, _)| i).collect();
+        candidates.extend(self.remaining);
+        candidates
+    }
+
+    pub fn into_scores(self) -> (String, Vec<(usize, i64)>) {
+        (self.filter, self.scores)
+    }
+}
+
+/// Maximum number of filters whose scores are cached with incremental
+/// scoring. The oldest ones, which are also the shortest and match the most
+/// options, are dropped first.
+pub const SCORE_CACHE_DEPTH: usize = 8;
+
+/// Scored options for each filter typed so far, each filter being a prefix
+/// of the next one.
+#[derive(Default)]
+pub struct ScoreCache {
+    entries: Vec<(String, Vec<(usize, i64)>)>,
+}
+
+impl ScoreCache {
+    /// Drops the scores of filters that are no longer a prefix of the
+    /// current one, which are stale.
+    pub fn retain_prefixes_of(&mut self, filter: &str) {
+        self.entries
+            .retain(|(cached, _)| filter.starts_with(cached.as_str()));
+    }
+
+    /// Scores of the longest cached filter.
+    pub fn last(&self) -> Option<&(String, Vec<(usize, i64)>)> {
+        self.entries.last()
+    }
+
+    pub fn push(&mut self, entry: (String, Vec<(usize, i64)>)) {
+        if self.entries.len() == SCORE_CACHE_DEPTH {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScoreCache, SCORE_CACHE_DEPTH};
+
+    #[test]
+    fn score_cache_drops_the_shortest_filters_first() {
+        let mut cache = ScoreCache::default();
+        for len in 0..=SCORE_CACHE_DEPTH + 2 {
+            cache.push(("a".repeat(len), vec![(len, 0)]));
+        }
+
+        cache.retain_prefixes_of("aaaa");
+        assert_eq!(Some(&("aaaa".to_string(), vec![(4, 0)])), cache.last());
+
+        cache.retain_prefixes_of("aa");
+        assert!(cache.last().is_none());
+    }
+}
@@ -25,8 +25,9 @@ impl InnerAction for PasswordPromptAction {
                 Self::ToggleDisplayMode
             }
             key => match InputAction::from_key(key, &()) {
+                // Passwords are kept out of the system clipboard.
+                Some(InputAction::Copy) | None => return None,
                 Some(action) => Self::ValueInput(action),
-                None => return None,
             },
         };
 
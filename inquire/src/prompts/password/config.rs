@@ -5,6 +5,8 @@ use crate::{Password, PasswordDisplayMode};
 pub struct PasswordConfig {
     /// Whether to allow the user to toggle the display mode of the password.
     pub enable_display_toggle: bool,
+    /// Whether the display toggle cycles through all display modes.
+    pub cycle_display_modes: bool,
     /// The initial display mode of the password.
     pub display_mode: PasswordDisplayMode,
 }
@@ -13,6 +15,7 @@ impl From<&Password<'_>> for PasswordConfig {
     fn from(value: &Password<'_>) -> Self {
         Self {
             enable_display_toggle: value.enable_display_toggle,
+            cycle_display_modes: value.cycle_display_modes,
             display_mode: value.display_mode,
         }
     }
@@ -0,0 +1,96 @@
+use ::keyring::{Entry, Error};
+
+use crate::{error::InquireResult, InquireError};
+
+/// Entry of the credential store of the operating system, such as the macOS
+/// Keychain or the Windows Credential Manager, used by a
+/// [Password](crate::Password) prompt.
+///
+/// When a credential is already stored under the entry, the user can submit
+/// an empty input to use it instead of typing the secret again, which is
+/// told by a help message. Otherwise, the submitted answer is stored under
+/// the entry, so that it can be reused the next time.
+///
+/// The platform stores are enabled through the features of the
+/// [keyring](https://docs.rs/keyring) crate, e.g. `apple-native` or
+/// `windows-native`, which must be enabled by the application. Without any
+/// of them, credentials are only kept in memory.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Password, PasswordKeyring};
+///
+/// let token = Password::new("API token:")
+///     .without_confirmation()
+///     .with_keyring(PasswordKeyring::new("my-cli", "alice@example.com"))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PasswordKeyring<'a> {
+    /// Service the credential belongs to, usually the name of the application.
+    pub service: &'a str,
+
+    /// Account the credential belongs to, e.g. a user name.
+    pub account: &'a str,
+
+    /// Whether answers typed by the user are stored under the entry.
+    pub store_answer: bool,
+
+    /// Help message displayed when a credential is stored under the entry.
+    pub hint: &'a str,
+}
+
+impl<'a> PasswordKeyring<'a> {
+    /// Default help message displayed when a credential is stored.
+    pub const DEFAULT_HINT: &'static str = "Leave empty to use the stored credential";
+
+    /// Creates a keyring entry for the provided service and account, storing
+    /// the answers typed by the user.
+    pub fn new(service: &'a str, account: &'a str) -> Self {
+        Self {
+            service,
+            account,
+            store_answer: true,
+            hint: Self::DEFAULT_HINT,
+        }
+    }
+
+    /// Sets whether answers typed by the user are stored under the entry.
+    /// When disabled, a stored credential is only read.
+    pub fn with_store_answer(mut self, store_answer: bool) -> Self {
+        self.store_answer = store_answer;
+        self
+    }
+
+    /// Sets the help message displayed when a credential is stored.
+    pub fn with_hint(mut self, hint: &'a str) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Credential stored under the entry, if any.
+    pub(crate) fn stored_credential(&self) -> InquireResult<Option<String>> {
+        match self.entry()?.get_password() {
+            Ok(credential) => Ok(Some(credential)),
+            Err(Error::NoEntry) => Ok(None),
+            Err(err) => Err(InquireError::Custom(Box::new(err))),
+        }
+    }
+
+    /// Stores an answer under the entry, if enabled.
+    pub(crate) fn store(&self, answer: &str) -> InquireResult<()> {
+        if !self.store_answer {
+            return Ok(());
+        }
+
+        self.entry()?
+            .set_password(answer)
+            .map_err(|err| InquireError::Custom(Box::new(err)))
+    }
+
+    fn entry(&self) -> InquireResult<Entry> {
+        Entry::new(self.service, self.account).map_err(|err| InquireError::Custom(Box::new(err)))
+    }
+}
@@ -1,24 +1,64 @@
 mod action;
 mod config;
+#[cfg(feature = "keyring")]
+mod keychain;
 mod prompt;
 #[cfg(test)]
-#[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+#[cfg(feature = "keyring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keyring")))]
+pub use keychain::PasswordKeyring;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    key_bindings::KeyBindings,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, PasswordBackend, RenderConfig},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{prompt_backend, PasswordBackend, RenderConfig},
     validator::StringValidator,
 };
 
 use self::prompt::PasswordPrompt;
 
+/// Runs `$run` on the prompt built from a password prompt, offering the
+/// credential stored in its keyring, if any, and storing the answer there
+/// when it differs from the stored one.
+macro_rules! prompt_with_keyring {
+    ($password:expr, |$prompt:ident| $run:expr) => {{
+        let password = $password;
+
+        #[cfg(feature = "keyring")]
+        if let Some(keyring) = password.keyring {
+            let stored = keyring.stored_credential()?;
+            let $prompt = PasswordPrompt::from(password)
+                .with_stored_credential(stored.as_deref(), keyring.hint);
+
+            let result = match $run {
+                Ok(answer) if stored.as_deref() != Some(answer.as_str()) => {
+                    keyring.store(&answer).map(|()| answer)
+                }
+                answer => answer,
+            };
+
+            #[cfg(feature = "zeroize")]
+            if let Some(mut stored) = stored {
+                zeroize::Zeroize::zeroize(&mut stored);
+            }
+
+            return result;
+        }
+
+        let $prompt = PasswordPrompt::from(password);
+        $run
+    }};
+}
+
 /// Display modes of the text input of a password prompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PasswordDisplayMode {
@@ -47,6 +87,7 @@ pub enum PasswordDisplayMode {
 /// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to toggle between the standard display mode set and the full display mode.
 ///   - If you have set the standard display mode to hidden (which is also the default) or masked, the user can press `Ctrl+R` to change the display mode to `Full`, and `Ctrl+R` again to change it back to the standard one.
 ///   - Obviously, if you have set the standard display mode to `Full`, pressing `Ctrl+R` won't cause any changes.
+///   - With `with_display_mode_cycling()`, `Ctrl+R` instead cycles through the hidden, masked and full display modes, in this order.
 /// - **Confirmation**: By default, the password will have a confirmation flow where the user will be asked for the input twice and the two responses will be compared. If they differ, an error message is shown and the user is prompted again.
 ///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_custom_confirmation_message()` method.
 ///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method.
@@ -107,6 +148,11 @@ pub struct Password<'a> {
     /// Whether to allow the user to toggle the display of the current password input by pressing the Ctrl+R hotkey.
     pub enable_display_toggle: bool,
 
+    /// Whether the display toggle cycles through the hidden, masked and full
+    /// display modes, instead of switching between the standard display mode
+    /// and the full one.
+    pub cycle_display_modes: bool,
+
     /// Whether to ask for input twice to see if the provided passwords are the same.
     pub enable_confirmation: bool,
 
@@ -118,15 +164,25 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Entry of the credential store of the operating system from which a
+    /// stored credential is offered, and under which the answer is stored.
+    #[cfg(feature = "keyring")]
+    pub keyring: Option<PasswordKeyring<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<PasswordPromptAction>,
 }
 
 impl<'a> Password<'a> {
@@ -142,6 +198,9 @@ impl<'a> Password<'a> {
     /// Default value for the allow display toggle variable.
     pub const DEFAULT_ENABLE_DISPLAY_TOGGLE: bool = false;
 
+    /// Default value for the cycle display modes variable.
+    pub const DEFAULT_CYCLE_DISPLAY_MODES: bool = false;
+
     /// Default value for the enable confirmation variable.
     pub const DEFAULT_ENABLE_CONFIRMATION: bool = true;
 
@@ -156,11 +215,16 @@ impl<'a> Password<'a> {
             custom_confirmation_error_message: None,
             enable_confirmation: Self::DEFAULT_ENABLE_CONFIRMATION,
             enable_display_toggle: Self::DEFAULT_ENABLE_DISPLAY_TOGGLE,
+            cycle_display_modes: Self::DEFAULT_CYCLE_DISPLAY_MODES,
             display_mode: Self::DEFAULT_DISPLAY_MODE,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            #[cfg(feature = "keyring")]
+            keyring: None,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -176,6 +240,41 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Enables display toggling, with the Ctrl+R hotkey cycling through the
+    /// hidden, masked and full display modes, e.g. to let the user check
+    /// a complicated secret before submitting it.
+    pub fn with_display_mode_cycling(mut self) -> Self {
+        self.enable_display_toggle = true;
+        self.cycle_display_modes = true;
+        self
+    }
+
+    /// Enables the confirmation step of the prompt, asking for the password
+    /// a second time with the provided message. When the two answers differ,
+    /// the provided error message is displayed and the user is asked for the
+    /// password again from the start.
+    pub fn with_confirmation(mut self, message: &'a str, error_message: &'a str) -> Self {
+        self.enable_confirmation = true;
+        self.custom_confirmation_message = Some(message);
+        self.custom_confirmation_error_message = Some(error_message);
+        self
+    }
+
+    /// Sets the entry of the credential store of the operating system used
+    /// by the prompt.
+    ///
+    /// When a credential is stored under the entry, submitting an empty input
+    /// returns it, skipping validators and the confirmation step. Otherwise,
+    /// the submitted answer is stored under the entry.
+    ///
+    /// See [`PasswordKeyring`] for more details.
+    #[cfg(feature = "keyring")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "keyring")))]
+    pub fn with_keyring(mut self, keyring: PasswordKeyring<'a>) -> Self {
+        self.keyring = Some(keyring);
+        self
+    }
+
     /// Disables the confirmation step of the prompt.
     pub fn without_confirmation(mut self) -> Self {
         self.enable_confirmation = false;
@@ -238,17 +337,35 @@ impl<'a> Password<'a> {
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<PasswordPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -269,15 +386,82 @@ impl<'a> Password<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`Password::prompt`], returning the answer wrapped in
+    /// [`Zeroizing`](crate::Zeroizing), which overwrites it with zeroes when
+    /// dropped.
+    ///
+    /// The buffers used by the prompt to hold the input are wiped when it
+    /// ends, be it submitted or canceled, so that the password doesn't linger
+    /// in the memory of long-running applications. Copies made by the
+    /// application, e.g. a custom formatter or validators receiving the
+    /// answer, are not covered.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Password;
+    ///
+    /// let secret = Password::new("Passphrase:").prompt_secret()?;
+    /// let key_material = secret.as_bytes();
+    /// # let _ = key_material;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn prompt_secret(self) -> InquireResult<zeroize::Zeroizing<String>> {
+        self.prompt().map(zeroize::Zeroizing::new)
+    }
+
+    /// Same as [`Password::prompt_skippable`], returning the answer wrapped in
+    /// [`Zeroizing`](crate::Zeroizing). See [`Password::prompt_secret`].
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    pub fn prompt_secret_skippable(self) -> InquireResult<Option<zeroize::Zeroizing<String>>> {
+        match self.prompt_secret() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Async version of [prompt](Self::prompt), awaiting the password typed
+    /// by the user. See [Text::prompt_async](crate::Text::prompt_async) for
+    /// more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<String> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+
+        prompt_with_keyring!(self, |prompt| {
+            crate::prompts::prompt_async::prompt_async(prompt, &mut backend).await
+        })
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the password typed by the user. The keyring, if set, is not used. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, String>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            PasswordPrompt::from(self),
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
     pub(crate) fn prompt_with_backend<B: PasswordBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
-        PasswordPrompt::from(self).prompt(backend)
+        prompt_with_keyring!(self, |prompt| prompt.prompt(backend))
     }
 }
@@ -2,10 +2,11 @@ use crate::{
     error::InquireResult,
     formatter::StringFormatter,
     input::Input,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     prompts::prompt::{ActionResult, Prompt},
     ui::PasswordBackend,
     validator::{ErrorMessage, StringValidator, Validation},
-    InquireError, Password, PasswordDisplayMode,
+    InputAction, InquireError, Password, PasswordDisplayMode,
 };
 
 use super::{action::PasswordPromptAction, config::PasswordConfig};
@@ -32,7 +33,13 @@ pub struct PasswordPrompt<'a> {
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    #[cfg(feature = "keyring")]
+    stored_credential: Option<&'a str>,
     error: Option<ErrorMessage>,
+    key_bindings: KeyBindings<PasswordPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
@@ -58,7 +65,13 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             formatter: so.formatter,
             validators: so.validators,
             input: Input::new(),
+            #[cfg(feature = "keyring")]
+            stored_credential: None,
             error: None,
+            key_bindings: so.key_bindings,
+            key_handler: so.settings.key_handler,
+            escape_behavior: so.settings.escape_behavior,
+            cancel_guard: so.settings.cancel_guard,
         }
     }
 }
@@ -70,6 +83,18 @@ impl<'a> From<&'a str> for Password<'a> {
 }
 
 impl<'a> PasswordPrompt<'a> {
+    /// Offers a credential read from the keyring, returned when the user
+    /// submits an empty input. The hint is displayed unless there is already
+    /// a help message.
+    #[cfg(feature = "keyring")]
+    pub fn with_stored_credential(mut self, credential: Option<&'a str>, hint: &'a str) -> Self {
+        if credential.is_some() && self.help_message.is_none() {
+            self.help_message = Some(hint);
+        }
+        self.stored_credential = credential;
+        self
+    }
+
     fn active_input_mut(&mut self) -> &mut Input {
         if let Some(c) = &mut self.confirmation {
             if self.confirmation_stage {
@@ -81,9 +106,14 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
-        let new_mode = match self.current_mode {
-            PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked => PasswordDisplayMode::Full,
-            PasswordDisplayMode::Full => self.config.display_mode,
+        let new_mode = match (self.config.cycle_display_modes, self.current_mode) {
+            (true, PasswordDisplayMode::Hidden) => PasswordDisplayMode::Masked,
+            (true, PasswordDisplayMode::Masked) => PasswordDisplayMode::Full,
+            (true, PasswordDisplayMode::Full) => PasswordDisplayMode::Hidden,
+            (false, PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked) => {
+                PasswordDisplayMode::Full
+            }
+            (false, PasswordDisplayMode::Full) => self.config.display_mode,
         };
 
         if new_mode != self.current_mode {
@@ -95,7 +125,7 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn confirmation_step(&mut self) -> ConfirmationStepResult {
-        let cur_answer = self.cur_answer().to_owned();
+        let cur_answer = self.input.content();
         match &mut self.confirmation {
             None => ConfirmationStepResult::NoConfirmationRequired,
             Some(confirmation) => {
@@ -104,13 +134,13 @@ impl<'a> PasswordPrompt<'a> {
                         ConfirmationStepResult::ConfirmationValidated
                     } else {
                         self.confirmation_stage = false;
-                        confirmation.input.clear();
+                        discard(&mut confirmation.input);
                         ConfirmationStepResult::ConfirmationInvalidated(ErrorMessage::Custom(
                             confirmation.error_message.to_owned(),
                         ))
                     }
                 } else {
-                    confirmation.input.clear();
+                    discard(&mut confirmation.input);
                     self.confirmation_stage = true;
 
                     ConfirmationStepResult::ConfirmationPending
@@ -136,6 +166,27 @@ impl<'a> PasswordPrompt<'a> {
     }
 }
 
+// Wipes both inputs once the prompt is over, whether it was submitted or
+// canceled. The answer returned on submission is a separate copy.
+#[cfg(feature = "zeroize")]
+impl Drop for PasswordPrompt<'_> {
+    fn drop(&mut self) {
+        self.input.wipe();
+        if let Some(confirmation) = &mut self.confirmation {
+            confirmation.input.wipe();
+        }
+    }
+}
+
+/// Clears an input holding a secret, overwriting it with zeroes first when
+/// the `zeroize` feature is enabled.
+fn discard(input: &mut Input) {
+    #[cfg(feature = "zeroize")]
+    input.wipe();
+    #[cfg(not(feature = "zeroize"))]
+    input.clear();
+}
+
 impl<'a, Backend> Prompt<Backend> for PasswordPrompt<'a>
 where
     Backend: PasswordBackend,
@@ -152,14 +203,43 @@ where
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<PasswordPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(
+            self,
+            PasswordPromptAction::ValueInput(InputAction::Clear),
+        )
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
 
+    /// Canceling the confirmation only goes back to the first input, which
+    /// is kept.
+    fn has_input(&self) -> bool {
+        !self.confirmation_stage && !self.input.is_empty()
+    }
+
     fn pre_cancel(&mut self) -> InquireResult<bool> {
         if let Some(confirmation) = &mut self.confirmation {
             if self.confirmation_stage {
-                confirmation.input.clear();
+                discard(&mut confirmation.input);
                 self.confirmation_stage = false;
                 return Ok(false);
             }
@@ -168,26 +248,43 @@ where
         Ok(true)
     }
 
+    /// Answers given as text are not confirmed.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.input = Input::new_with(answer);
+        self.confirmation = None;
+        self.confirmation_stage = false;
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
+        #[cfg(feature = "keyring")]
+        if let Some(credential) = self.stored_credential {
+            if !self.confirmation_stage && self.cur_answer().is_empty() {
+                return Ok(Some(credential.to_owned()));
+            }
+        }
+
         if let Validation::Invalid(msg) = self.validate_current_answer()? {
             self.error = Some(msg);
             if self.config.display_mode == PasswordDisplayMode::Hidden {
-                self.input.clear();
+                discard(&mut self.input);
             }
             return Ok(None);
         }
 
         let confirmation = self.confirmation_step();
 
-        let cur_answer = self.cur_answer().to_owned();
-
         let result = match confirmation {
             ConfirmationStepResult::NoConfirmationRequired
-            | ConfirmationStepResult::ConfirmationValidated => Some(cur_answer),
+            | ConfirmationStepResult::ConfirmationValidated => Some(self.cur_answer().to_owned()),
             ConfirmationStepResult::ConfirmationPending => None,
             ConfirmationStepResult::ConfirmationInvalidated(message) => {
                 self.error = Some(message);
-                self.input.clear();
+                discard(&mut self.input);
                 None
             }
         };
@@ -254,3 +254,145 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+#[test]
+fn display_mode_cycling_goes_through_masked_and_full_modes() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut events: Vec<Key> = text_to_events!("ab");
+    events.push(Key::Char('r', KeyModifiers::CONTROL));
+    events.push(Key::Char('r', KeyModifiers::CONTROL));
+    events.push(Key::Char('r', KeyModifiers::CONTROL));
+    events.push(Key::Char('c', KeyModifiers::NONE));
+    events.push(Key::Enter);
+
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend =
+            Backend::new(events.into_iter(), terminal, RenderConfig::default()).unwrap();
+
+        let ans = Password::new("")
+            .without_confirmation()
+            .with_display_mode_cycling()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!("abc", ans);
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("**"));
+    assert!(rendered("ab"));
+    assert!(!rendered("abc"));
+}
+
+#[test]
+fn with_confirmation_displays_custom_messages_and_asks_again_on_mismatch() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut events: Vec<Key> = text_to_events!("anor");
+    events.push(Key::Enter);
+    events.append(&mut text_to_events!("anor2"));
+    events.push(Key::Enter);
+    events.append(&mut text_to_events!("anor"));
+    events.push(Key::Enter);
+    events.append(&mut text_to_events!("anor"));
+    events.push(Key::Enter);
+
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend =
+            Backend::new(events.into_iter(), terminal, RenderConfig::default()).unwrap();
+
+        let ans = Password::new("Password:")
+            .without_confirmation()
+            .with_confirmation("Repeat password:", "They differ")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!("anor", ans);
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content.contains(text)))
+    };
+    assert!(rendered("Repeat password:"));
+    assert!(rendered("They differ"));
+}
+
+#[cfg(feature = "keyring")]
+mod keyring {
+    use super::super::prompt::PasswordPrompt;
+    use crate::{
+        prompts::prompt::Prompt,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, Key, KeyModifiers, RenderConfig},
+        Password, PasswordKeyring,
+    };
+    use std::collections::VecDeque;
+
+    fn prompt_with_stored_credential(events: Vec<Key>) -> (String, VecDeque<MockTerminalToken>) {
+        let mut output = VecDeque::new();
+        let ans = {
+            let terminal = MockTerminal::new(&mut output);
+            let mut backend =
+                Backend::new(events.into_iter(), terminal, RenderConfig::default()).unwrap();
+
+            PasswordPrompt::from(Password::new("Token:"))
+                .with_stored_credential(Some("stored"), PasswordKeyring::DEFAULT_HINT)
+                .prompt(&mut backend)
+                .unwrap()
+        };
+        (ans, output)
+    }
+
+    #[test]
+    fn empty_input_submits_stored_credential_without_confirmation() {
+        let (ans, output) = prompt_with_stored_credential(vec![Key::Enter]);
+
+        assert_eq!("stored", ans);
+        assert!(output.iter().any(|token| matches!(
+            token,
+            MockTerminalToken::Text(t) if t.content == PasswordKeyring::DEFAULT_HINT
+        )));
+    }
+
+    #[test]
+    fn typed_input_replaces_stored_credential() {
+        let mut events: Vec<Key> = text_to_events!("new");
+        events.push(Key::Enter);
+        events.append(&mut text_to_events!("new"));
+        events.push(Key::Enter);
+
+        let (ans, _) = prompt_with_stored_credential(events);
+
+        assert_eq!("new", ans);
+    }
+
+    #[test]
+    fn answer_is_returned_when_nothing_is_stored() {
+        let mut backend = crate::prompts::test::fake_backend(text_to_events!("secret\n"));
+
+        let ans = Password::new("Token:")
+            .without_confirmation()
+            .with_keyring(PasswordKeyring::new("inquire-tests", "nobody"))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("secret", ans);
+    }
+}
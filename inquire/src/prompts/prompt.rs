@@ -1,6 +1,22 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{
+    cancellation::{CancellationToken, CANCELLATION_POLL_INTERVAL},
+    error::InquireResult,
+    event::{self, PromptEvent},
+    feedback::{self, FeedbackEvent},
+    input::InputActionResult,
+    key_bindings::{
+        Binding, CancelGuard, EscapeBehavior, Handled, KeyBindings, KeyHandler, KeySequence,
+        PromptStateView,
+    },
+    transcript::{self, TranscriptOutcome},
+    ui::{AnswerRedaction, CommonBackend, Key, PresetAnswer, RenderAfterSubmit},
+    validator::ErrorMessage,
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
@@ -17,6 +33,7 @@ pub enum ActionResult {
 }
 
 impl ActionResult {
+    /// Combines two action results, requiring a redraw if either of them does.
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
             (Self::NeedsRedraw, _) | (_, Self::NeedsRedraw) => Self::NeedsRedraw,
@@ -40,14 +57,128 @@ impl From<InputActionResult> for ActionResult {
     }
 }
 
+/// Opt-in hook for prompts that render a paginated list of items, such as
+/// options or suggestions.
+///
+/// Rendering a page where items wrap into multiple lines might result in a
+/// frame taller than the terminal, which corrupts the UI once the terminal
+/// starts scrolling. Prompts that expose their page size through this trait
+/// get their frames checked before being flushed: oversized frames are
+/// aborted and re-rendered with a smaller page size until they fit.
+pub trait AdaptivePagePrompt {
+    /// Current number of items rendered per page.
+    fn page_size(&self) -> usize;
+
+    /// Updates the number of items rendered per page.
+    fn set_page_size(&mut self, page_size: usize);
+
+    /// Hook called after the page size has been reduced, allowing the prompt
+    /// to reconcile any state that depends on it, e.g. clamping cursor indices.
+    fn reconcile_after_resize(&mut self) {}
+}
+
 /// Shared behavior among all different prompt types.
+///
+/// This trait can also be implemented by user-defined prompts, which then get
+/// the same input handling, keybindings, rendering and render config machinery
+/// used by the built-in prompts.
+///
+/// A prompt defines its specialized actions through an [`InnerAction`] type,
+/// parsed from key events according to the prompt configuration. Submission,
+/// cancellation and interruption keys are handled for all prompts in
+/// [`Action::from_key`].
+///
+/// # Example
+///
+/// A prompt to pick a number by pressing the arrow keys:
+///
+/// ```no_run
+/// use inquire::{
+///     error::InquireResult,
+///     ui::{default_backend, CommonBackend, Key, KeyModifiers, RenderConfig, Styled},
+///     ActionResult, InnerAction, Prompt,
+/// };
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// enum CounterAction {
+///     Increment,
+///     Decrement,
+/// }
+///
+/// impl InnerAction for CounterAction {
+///     type Config = ();
+///
+///     fn from_key(key: Key, _config: &()) -> Option<Self> {
+///         match key {
+///             Key::Up(KeyModifiers::NONE) => Some(Self::Increment),
+///             Key::Down(KeyModifiers::NONE) => Some(Self::Decrement),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// struct CounterPrompt {
+///     message: String,
+///     value: i64,
+/// }
+///
+/// impl<B: CommonBackend> Prompt<B> for CounterPrompt {
+///     type Config = ();
+///     type InnerAction = CounterAction;
+///     type Output = i64;
+///
+///     fn message(&self) -> &str {
+///         &self.message
+///     }
+///
+///     fn config(&self) -> &() {
+///         &()
+///     }
+///
+///     fn format_answer(&self, answer: &i64) -> String {
+///         answer.to_string()
+///     }
+///
+///     fn submit(&mut self) -> InquireResult<Option<i64>> {
+///         Ok(Some(self.value))
+///     }
+///
+///     fn handle(&mut self, action: CounterAction) -> InquireResult<ActionResult> {
+///         match action {
+///             CounterAction::Increment => self.value += 1,
+///             CounterAction::Decrement => self.value -= 1,
+///         }
+///         Ok(ActionResult::NeedsRedraw)
+///     }
+///
+///     fn render(&self, backend: &mut B) -> InquireResult<()> {
+///         backend.render_prompt_message(&self.message)?;
+///         backend.render_text(Styled::new(&self.value.to_string()))?;
+///         backend.render_text(Styled::new("\n"))?;
+///         backend.render_help_message("↑↓ to change the value, enter to submit")?;
+///         Ok(())
+///     }
+/// }
+///
+/// let prompt = CounterPrompt {
+///     message: String::from("How many cookies?"),
+///     value: 3,
+/// };
+///
+/// let mut backend = default_backend(RenderConfig::default())?;
+/// let cookies = prompt.prompt(&mut backend)?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
 pub trait Prompt<Backend>
 where
     Backend: CommonBackend,
     Self: Sized,
 {
+    /// Configuration of the prompt, used to parse key events into actions.
     type Config;
+    /// Specialized actions handled by the prompt.
     type InnerAction: InnerAction<Config = Self::Config>;
+    /// Type of the answer returned by the prompt.
     type Output;
 
     /// Prompt header rendered to the user.
@@ -61,6 +192,55 @@ where
     /// actions to the prompt.
     fn config(&self) -> &Self::Config;
 
+    /// Derives the action triggered by a key event.
+    ///
+    /// By default, it is derived from the key and the prompt configuration
+    /// with [`Action::from_key`]. Prompts can override it to bind keys
+    /// that depend on their state.
+    fn action_from_key(&mut self, key: Key) -> Option<Action<Self::InnerAction>> {
+        Action::from_key(key, self.config())
+    }
+
+    /// Clears the text typed by the user, when pressing Escape is set to
+    /// [`EscapeBehavior::ClearInput`]. Returns [`ActionResult::Clean`] when
+    /// there was nothing to clear, in which case the prompt is canceled.
+    ///
+    /// By default, prompts have nothing to clear.
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
+    /// Key bindings of the prompt, overriding the global ones and the
+    /// default ones parsed by [`action_from_key`](Prompt::action_from_key).
+    ///
+    /// None by default.
+    fn key_bindings(&self) -> Option<&KeyBindings<Self::InnerAction>> {
+        None
+    }
+
+    /// Handler called with the keys pressed by the user before they are
+    /// parsed into actions, set with `with_key_handler`.
+    ///
+    /// None by default.
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        None
+    }
+
+    /// What pressing Escape does, set with `with_escape_behavior`.
+    ///
+    /// [`EscapeBehavior::Cancel`] by default.
+    fn escape_behavior(&self) -> EscapeBehavior {
+        EscapeBehavior::Cancel
+    }
+
+    /// Guard against canceling the prompt by accident, set with
+    /// `with_cancel_guard`.
+    ///
+    /// [`CancelGuard::None`] by default.
+    fn cancel_guard(&self) -> CancelGuard {
+        CancelGuard::None
+    }
+
     /// Hook called when a prompt is finished. Returns a string
     /// to be rendered to the user as the final submission to the prompt.
     ///
@@ -69,12 +249,76 @@ where
     /// * `answer` - Answer returned by the prompt.
     fn format_answer(&self, answer: &Self::Output) -> String;
 
+    /// Renders the final state of the prompt once it is submitted.
+    ///
+    /// By default, it renders the prompt message along with the answer
+    /// formatted by [`format_answer`](Prompt::format_answer).
+    fn render_answer(&self, answer: &Self::Output, backend: &mut Backend) -> InquireResult<()> {
+        let formatted = self.format_answer(answer);
+        backend.render_prompt_with_answer(self.message(), &formatted)?;
+        Ok(())
+    }
+
     /// Hook called when a prompt is first started, before the first
     /// draw happens.
     fn setup(&mut self) -> InquireResult<()> {
         Ok(())
     }
 
+    /// Text typed by the user so far, if any.
+    ///
+    /// It is provided to [`CanceledPrompt::Custom`](crate::ui::CanceledPrompt::Custom)
+    /// formatters when the prompt is canceled. Prompts handling sensitive
+    /// input should keep the default implementation.
+    fn partial_input(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether the user entered something that canceling the prompt would
+    /// discard, in which case the [`CancelGuard`] of the prompt, if any,
+    /// holds the cancellation back until the user confirms it.
+    ///
+    /// By default, whether [`partial_input`](Prompt::partial_input) holds
+    /// any text.
+    fn has_input(&self) -> bool {
+        self.partial_input().is_some_and(|input| !input.is_empty())
+    }
+
+    /// Current state of the prompt, described by the events leading to it.
+    ///
+    /// When an `on_change` hook is set, the state is compared before and
+    /// after each action, and the hook is called with the events that were
+    /// not part of the previous state. By default, the state holds the
+    /// text returned by [`partial_input`](Prompt::partial_input), if any.
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        self.partial_input()
+            .map(|input| PromptEvent::InputChanged(input.to_owned()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Fills in an answer given as text, as if typed by the user, before
+    /// it is submitted without user interaction. See
+    /// [`InputReader::read_preset_answer`](crate::ui::InputReader::read_preset_answer).
+    ///
+    /// Returns [`InquireError::InvalidAnswer`] when the text can't be an
+    /// answer to the prompt, which is the case for all text by default.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        Err(InquireError::InvalidAnswer(format!(
+            "\"{}\" can't be answered with \"{}\"",
+            self.message(),
+            answer
+        )))
+    }
+
+    /// Error message displayed after the last rejected submission, if any.
+    ///
+    /// It is used to report why an answer submitted without user
+    /// interaction was rejected.
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        None
+    }
+
     /// Hook called when an input to cancel the prompt is triggered.
     ///
     /// Returns whether the prompt can be terminated.
@@ -111,57 +355,509 @@ where
     /// by the top-level prompt method.
     fn render(&self, backend: &mut Backend) -> InquireResult<()>;
 
+    /// Interval at which [`tick`](Prompt::tick) is called while waiting for
+    /// key events, if the prompt needs to be woken up periodically.
+    ///
+    /// Input readers that can't wait with a timeout block until the next key
+    /// event, in which case ticks only happen between key presses.
+    fn tick_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Hook called periodically when [`tick_interval`](Prompt::tick_interval)
+    /// is set, e.g. to pick up results of work done in the background.
+    ///
+    /// It is called whenever no key event was received during the interval,
+    /// and before each received key event is handled.
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
+    /// Returns the adaptive page sizing hook of the prompt, if it
+    /// opts in to it. See [`AdaptivePagePrompt`] for more details.
+    fn adaptive_page(&mut self) -> Option<&mut dyn AdaptivePagePrompt> {
+        None
+    }
+
+    /// Renders the prompt UI and flushes it to the terminal.
+    ///
+    /// For prompts that opt in to [`AdaptivePagePrompt`], frames taller than
+    /// the terminal are aborted before being flushed and re-rendered with a
+    /// smaller page size.
+    fn redraw(&mut self, backend: &mut Backend) -> InquireResult<()> {
+        #[cfg(feature = "instrument")]
+        let render_span = crate::instrument::span(crate::instrument::Phase::Render);
+
+        loop {
+            backend.frame_setup()?;
+
+            if self.error_message().is_none() {
+                if let Some(error) = backend.initial_error().cloned() {
+                    backend.render_error_message(&error)?;
+                }
+            }
+
+            self.render(backend)?;
+
+            if let Some(notice) = backend.pending_cancel().and(self.cancel_guard().notice()) {
+                backend.render_error_message(&ErrorMessage::Custom(notice.to_owned()))?;
+            }
+
+            let page_size = match self.adaptive_page() {
+                Some(adaptive) => adaptive.page_size().max(1),
+                None => break,
+            };
+
+            // Use the height that would actually be flushed (max of last/current)
+            // to avoid scrolling when clearing a previously taller frame.
+            let frame_h = backend.current_flush_height().unwrap_or(0);
+            let term_h = backend.current_terminal_height().unwrap_or(u16::MAX);
+
+            if frame_h <= term_h || page_size <= 1 {
+                break;
+            }
+
+            // Oversized render: abort without flushing and reduce page size.
+            backend.frame_abort()?;
+
+            let mut new_size = (page_size as u32)
+                .saturating_mul(term_h.max(1) as u32)
+                .checked_div(frame_h.max(1) as u32)
+                .unwrap_or(1) as usize;
+
+            if new_size >= page_size {
+                new_size = page_size.saturating_sub(1).max(1);
+            }
+
+            if let Some(adaptive) = self.adaptive_page() {
+                adaptive.set_page_size(new_size);
+                adaptive.reconcile_after_resize();
+            }
+        }
+
+        #[cfg(feature = "instrument")]
+        drop(render_span);
+
+        backend.frame_finish(false)?;
+
+        Ok(())
+    }
+
     /// Top-level implementation of a prompt's flow.
     ///
     /// This should not be reimplemented by types that implement this trait,
     /// unless the situation really warrants it.
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
+        let started_at = SystemTime::now();
+
+        if let Some(answer) = backend.read_preset_answer()? {
+            return self.submit_preset_answer(answer, backend);
+        }
 
         let mut last_handle = ActionResult::NeedsRedraw;
+        let mut key_sequence = KeySequence::default();
         let final_answer = loop {
+            if backend
+                .cancellation()
+                .is_some_and(CancellationToken::is_canceled)
+            {
+                return cancel_by_token(&mut self, backend, started_at);
+            }
+
             if last_handle.needs_redraw() {
-                backend.frame_setup()?;
-                self.render(backend)?;
-                backend.frame_finish(false)?;
+                self.redraw(backend)?;
                 last_handle = ActionResult::Clean;
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            // keys are polled to check the cancellation token in the meantime,
+            // and to apply the first key of a sequence once it times out
+            let interval = [
+                self.tick_interval(),
+                backend.cancellation().map(|_| CANCELLATION_POLL_INTERVAL),
+                key_sequence.remaining(),
+            ]
+            .iter()
+            .flatten()
+            .min()
+            .copied();
 
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
+            let key = match interval {
+                Some(interval) => {
+                    let key = backend.poll_key(interval)?;
+                    if self.tick_interval().is_some() {
+                        last_handle = last_handle.merge(self.tick()?);
                     }
-                    Action::Cancel => {
-                        let pre_cancel_result = self.pre_cancel()?;
-
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(self.message())?;
-                            backend.frame_finish(true)?;
-                            return Err(InquireError::OperationCanceled);
+
+                    let Some(key) = key else {
+                        match flush_key_sequence(&mut self, backend, &mut key_sequence, started_at)?
+                        {
+                            KeyOutcome::Handled(handle) => last_handle = last_handle.merge(handle),
+                            KeyOutcome::Submitted(answer) => break answer,
                         }
+                        continue;
+                    };
+                    key
+                }
+                None => backend.read_key()?,
+            };
 
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
-                };
+            match handle_key(&mut self, key, backend, &mut key_sequence, started_at)? {
+                KeyOutcome::Handled(handle) => last_handle = last_handle.merge(handle),
+                KeyOutcome::Submitted(answer) => break answer,
             }
         };
 
-        let formatted = self.format_answer(&final_answer);
+        finish(&self, final_answer, backend, started_at)
+    }
 
-        backend.frame_setup()?;
-        backend.render_prompt_with_answer(self.message(), &formatted)?;
-        backend.frame_finish(true)?;
+    /// Submits an answer given without user interaction, rendering only the
+    /// final answer.
+    fn submit_preset_answer(
+        mut self,
+        answer: PresetAnswer,
+        backend: &mut Backend,
+    ) -> InquireResult<Self::Output> {
+        let started_at = SystemTime::now();
+
+        // an answer rejected by a form can't be changed without the user
+        if let Some(error) = backend.initial_error() {
+            return Err(rejected_answer(self.message(), Some(error)));
+        }
+
+        if let PresetAnswer::Text(text) = &answer {
+            self.fill_answer(text)?;
+        }
 
-        Ok(final_answer)
+        let Some(final_answer) = submit(&mut self, backend)? else {
+            return Err(rejected_answer(self.message(), self.error_message()));
+        };
+
+        finish(&self, final_answer, backend, started_at)
+    }
+}
+
+/// Error returned when an answer submitted without user interaction is
+/// rejected, with the error message displayed by the prompt, if any.
+fn rejected_answer(message: &str, error: Option<&ErrorMessage>) -> InquireError {
+    let reason = match error {
+        Some(ErrorMessage::Custom(error)) => error.clone(),
+        _ => format!("\"{message}\" rejected the answer"),
+    };
+    InquireError::InvalidAnswer(reason)
+}
+
+/// Outcome of handling a key event in the loop of a prompt.
+pub(crate) enum KeyOutcome<T> {
+    /// The key was handled, and the prompt keeps waiting for an answer.
+    Handled(ActionResult),
+
+    /// The key submitted an answer accepted by the prompt.
+    Submitted(T),
+}
+
+/// Handles a key event read by the loop of a prompt, parsing it into an
+/// action and running it. Keys that may start a bound sequence are kept in
+/// `key_sequence` until the next one.
+pub(crate) fn handle_key<P, B>(
+    prompt: &mut P,
+    key: Key,
+    backend: &mut B,
+    key_sequence: &mut KeySequence,
+    started_at: SystemTime,
+) -> InquireResult<KeyOutcome<P::Output>>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    let handled = prompt.key_handler().and_then(|key_handler| {
+        let state = prompt.observable_state();
+        let mut view = PromptStateView::new(prompt.message(), prompt.partial_input(), &state);
+        match key_handler(key, &mut view) {
+            Handled::Yes => Some(view.action()),
+            Handled::No => None,
+        }
+    });
+
+    // cancellations asked by the key handler are deliberate and not guarded
+    let guarded = handled.is_none();
+    let bindings = match handled {
+        Some(Some(action)) => vec![Binding::Action(action)],
+        Some(None) => return Ok(KeyOutcome::Handled(ActionResult::NeedsRedraw)),
+        None => key_sequence.feed(key, prompt.key_bindings()),
+    };
+
+    let mut handle = ActionResult::Clean;
+
+    // any key dismisses a pending cancellation, after confirming it when asked
+    let pending_cancel = backend.pending_cancel();
+    if pending_cancel.is_some() {
+        backend.set_pending_cancel(None);
+        handle = ActionResult::NeedsRedraw;
+
+        if prompt.cancel_guard() == CancelGuard::Confirm {
+            match key {
+                Key::Char('y' | 'Y', _) => {
+                    return run_action(prompt, Action::Cancel, backend, started_at);
+                }
+                Key::Char('n' | 'N', _) | Key::Escape => return Ok(KeyOutcome::Handled(handle)),
+                _ => {}
+            }
+        }
+    }
+
+    apply_bindings(
+        prompt,
+        bindings,
+        guarded,
+        pending_cancel,
+        handle,
+        backend,
+        started_at,
+    )
+}
+
+/// Applies the first key of a bound sequence once the sequence timed out
+/// without the user pressing its second key, as if the key was not the start
+/// of any sequence.
+pub(crate) fn flush_key_sequence<P, B>(
+    prompt: &mut P,
+    backend: &mut B,
+    key_sequence: &mut KeySequence,
+    started_at: SystemTime,
+) -> InquireResult<KeyOutcome<P::Output>>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    let bindings = key_sequence.flush_expired(prompt.key_bindings());
+    let pending_cancel = backend.pending_cancel();
+
+    apply_bindings(
+        prompt,
+        bindings,
+        true,
+        pending_cancel,
+        ActionResult::Clean,
+        backend,
+        started_at,
+    )
+}
+
+/// Parses the bindings of the keys pressed by the user into actions and runs
+/// them, holding back cancellations according to the cancel guard of the
+/// prompt when `guarded` is set.
+fn apply_bindings<P, B>(
+    prompt: &mut P,
+    bindings: Vec<Binding<P::InnerAction>>,
+    guarded: bool,
+    pending_cancel: Option<Instant>,
+    mut handle: ActionResult,
+    backend: &mut B,
+    started_at: SystemTime,
+) -> InquireResult<KeyOutcome<P::Output>>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    for binding in bindings {
+        let action = match binding {
+            Binding::Action(action) => Some(action),
+            Binding::Key(Key::Escape) => match prompt.action_from_key(Key::Escape) {
+                Some(Action::Cancel) => match prompt.escape_behavior() {
+                    EscapeBehavior::Cancel => Some(Action::Cancel),
+                    EscapeBehavior::ClearInput => {
+                        let cleared = prompt.clear_input()?;
+                        handle = handle.merge(cleared);
+                        (!cleared.needs_redraw()).then_some(Action::Cancel)
+                    }
+                    EscapeBehavior::Ignore => None,
+                },
+                action => action,
+            },
+            Binding::Key(key) => prompt.action_from_key(key),
+            Binding::Unbound => None,
+        };
+        let Some(action) = action else {
+            continue;
+        };
+
+        let confirmed =
+            || cancel_confirmed(prompt.cancel_guard(), prompt.has_input(), pending_cancel);
+        if guarded && action == Action::Cancel && !confirmed() {
+            backend.set_pending_cancel(Some(Instant::now()));
+            handle = ActionResult::NeedsRedraw;
+            continue;
+        }
+
+        match run_action(prompt, action, backend, started_at)? {
+            KeyOutcome::Handled(result) => handle = handle.merge(result),
+            submitted @ KeyOutcome::Submitted(_) => return Ok(submitted),
+        }
     }
+
+    Ok(KeyOutcome::Handled(handle))
+}
+
+/// Whether a request to cancel a prompt can go through its cancel guard,
+/// given whether the user entered something in the prompt and when the
+/// previous request held back by the guard was made, if any.
+fn cancel_confirmed(guard: CancelGuard, has_input: bool, pending_cancel: Option<Instant>) -> bool {
+    match guard {
+        CancelGuard::None => true,
+        _ if !has_input => true,
+        CancelGuard::PressTwice(window) => {
+            pending_cancel.is_some_and(|requested_at| requested_at.elapsed() <= window)
+        }
+        CancelGuard::Confirm => false,
+    }
+}
+
+/// Runs an action parsed from a key event.
+fn run_action<P, B>(
+    prompt: &mut P,
+    action: Action<P::InnerAction>,
+    backend: &mut B,
+    started_at: SystemTime,
+) -> InquireResult<KeyOutcome<P::Output>>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    let handle = match action {
+        Action::Submit => {
+            if let Some(answer) = submit(prompt, backend)? {
+                feedback::emit(FeedbackEvent::Submit);
+                return Ok(KeyOutcome::Submitted(answer));
+            }
+            feedback::emit(FeedbackEvent::Error);
+            ActionResult::NeedsRedraw
+        }
+        Action::Cancel => {
+            let pre_cancel_result = prompt.pre_cancel()?;
+
+            if pre_cancel_result {
+                return cancel(prompt, backend, started_at).map(KeyOutcome::Submitted);
+            }
+
+            ActionResult::NeedsRedraw
+        }
+        Action::Interrupt => {
+            transcript::record(prompt.message(), started_at, || {
+                TranscriptOutcome::Interrupted
+            });
+            return Err(InquireError::OperationInterrupted);
+        }
+        Action::Inner(inner_action) => {
+            let previous_state = backend.on_change().map(|_| prompt.observable_state());
+            let result = prompt.handle(inner_action)?;
+
+            if let (Some(on_change), Some(previous_state)) = (backend.on_change(), previous_state) {
+                event::notify(on_change, &previous_state, &prompt.observable_state());
+            }
+
+            result
+        }
+    };
+
+    Ok(KeyOutcome::Handled(handle))
+}
+
+/// Submits the current input of a prompt. When the full prompt is to be kept
+/// on screen afterwards, its final frame is rendered beforehand, as prompts
+/// may give away their state when submitting, and is discarded if the answer
+/// is rejected or the submission fails.
+fn submit<P, B>(prompt: &mut P, backend: &mut B) -> InquireResult<Option<P::Output>>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    if backend.render_after_submit() != RenderAfterSubmit::Full {
+        return prompt.submit();
+    }
+
+    backend.frame_setup()?;
+    let answer = prompt.render(backend).and_then(|()| prompt.submit());
+
+    match answer {
+        Ok(Some(_)) => {}
+        Ok(None) => backend.frame_abort()?,
+        // the error of the submission is more relevant than the one of the abort
+        Err(_) => {
+            let _ = backend.frame_abort();
+        }
+    }
+
+    answer
+}
+
+/// Records and renders a canceled prompt, before returning
+/// [`InquireError::OperationCanceled`].
+pub(crate) fn cancel<P, B>(
+    prompt: &P,
+    backend: &mut B,
+    started_at: SystemTime,
+) -> InquireResult<P::Output>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    feedback::emit(FeedbackEvent::Cancel);
+    transcript::record(prompt.message(), started_at, || TranscriptOutcome::Canceled);
+
+    backend.frame_setup()?;
+    backend.render_canceled_prompt(prompt.message(), prompt.partial_input())?;
+    backend.frame_finish(true)?;
+
+    Err(InquireError::OperationCanceled)
+}
+
+/// Cancels a prompt whose cancellation token was canceled. The
+/// [`pre_cancel`](Prompt::pre_cancel) hook of the prompt is run, e.g. to save
+/// its state, but can't hold the cancellation back.
+pub(crate) fn cancel_by_token<P, B>(
+    prompt: &mut P,
+    backend: &mut B,
+    started_at: SystemTime,
+) -> InquireResult<P::Output>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    prompt.pre_cancel()?;
+    cancel(prompt, backend, started_at)
+}
+
+/// Records and renders the answer submitted to a prompt, before returning it.
+pub(crate) fn finish<P, B>(
+    prompt: &P,
+    answer: P::Output,
+    backend: &mut B,
+    started_at: SystemTime,
+) -> InquireResult<P::Output>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    transcript::record(prompt.message(), started_at, || {
+        let displayed = match backend.answer_redaction() {
+            None => prompt.format_answer(&answer),
+            Some(AnswerRedaction::Hidden) => String::new(),
+            Some(AnswerRedaction::Placeholder(placeholder)) => placeholder.to_owned(),
+        };
+        TranscriptOutcome::Answered(displayed)
+    });
+
+    match backend.render_after_submit() {
+        RenderAfterSubmit::Clear => backend.frame_setup()?,
+        RenderAfterSubmit::AnswerLine => {
+            backend.frame_setup()?;
+            prompt.render_answer(&answer, backend)?;
+        }
+        // the final frame was rendered before submitting
+        RenderAfterSubmit::Full => {}
+    }
+    backend.frame_finish(true)?;
+
+    Ok(answer)
 }
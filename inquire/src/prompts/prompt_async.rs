@@ -0,0 +1,218 @@
+//! Async counterpart of the loop of [Prompt::prompt], reading key events
+//! from a stream instead of blocking the thread until they are available.
+
+use std::{future::poll_fn, io, pin::Pin, task::Poll, time::SystemTime};
+
+use futures_core::Stream;
+
+use crate::{
+    error::InquireResult,
+    key_bindings::KeySequence,
+    terminal::crossterm::CrosstermKeyStream,
+    ui::{CommonBackend, Key},
+};
+
+use super::prompt::{cancel_by_token, finish, handle_key, ActionResult, KeyOutcome, Prompt};
+
+/// Next event awaited by the prompt loop.
+enum Event {
+    Key(Option<InquireResult<Key>>),
+    Canceled,
+}
+
+/// Runs a prompt like [Prompt::prompt], awaiting the keys pressed by the
+/// user in the terminal.
+pub(crate) async fn prompt_async<P, B>(prompt: P, backend: &mut B) -> InquireResult<P::Output>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+{
+    prompt_with_keys(prompt, backend, &mut CrosstermKeyStream::new()).await
+}
+
+/// Runs a prompt like [Prompt::prompt], awaiting key events from the stream.
+///
+/// Ticks of prompts that set a [tick interval](Prompt::tick_interval) only
+/// happen before each key event is handled, as there is no timer available
+/// independently of the async runtime. For the same reason, the first key of
+/// a bound sequence waits for the next key event even once it timed out.
+pub(crate) async fn prompt_with_keys<P, B, S>(
+    mut prompt: P,
+    backend: &mut B,
+    keys: &mut S,
+) -> InquireResult<P::Output>
+where
+    B: CommonBackend,
+    P: Prompt<B>,
+    S: Stream<Item = InquireResult<Key>> + Unpin,
+{
+    prompt.setup()?;
+    let started_at = SystemTime::now();
+
+    if let Some(answer) = backend.read_preset_answer()? {
+        return prompt.submit_preset_answer(answer, backend);
+    }
+
+    // the token is cloned so that it can be polled while the backend is
+    // borrowed by the prompt
+    let cancellation = backend.cancellation().cloned();
+
+    let mut last_handle = ActionResult::NeedsRedraw;
+    let mut key_sequence = KeySequence::default();
+    let final_answer = loop {
+        if last_handle.needs_redraw() {
+            prompt.redraw(backend)?;
+            last_handle = ActionResult::Clean;
+        }
+
+        let event = poll_fn(|cx| {
+            if let Some(token) = &cancellation {
+                if token.poll_canceled(cx).is_ready() {
+                    return Poll::Ready(Event::Canceled);
+                }
+            }
+            Pin::new(&mut *keys).poll_next(cx).map(Event::Key)
+        })
+        .await;
+
+        let key = match event {
+            Event::Key(Some(key)) => key?,
+            Event::Key(None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "The stream of key events ended before the prompt was answered",
+                )
+                .into())
+            }
+            Event::Canceled => return cancel_by_token(&mut prompt, backend, started_at),
+        };
+
+        if prompt.tick_interval().is_some() {
+            last_handle = last_handle.merge(prompt.tick()?);
+        }
+
+        match handle_key(&mut prompt, key, backend, &mut key_sequence, started_at)? {
+            KeyOutcome::Handled(handle) => last_handle = last_handle.merge(handle),
+            KeyOutcome::Submitted(answer) => break answer,
+        }
+    };
+
+    finish(&prompt, final_answer, backend, started_at)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        future::Future,
+        io,
+        pin::{pin, Pin},
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    use futures_core::Stream;
+
+    use crate::{
+        error::{InquireError, InquireResult},
+        prompts::text::prompt::TextPrompt,
+        terminal::test::NullTerminal,
+        ui::{test::FakeBackend, CustomBackend, Key},
+        CancellationToken, Text,
+    };
+
+    use super::prompt_with_keys;
+
+    struct Keys(std::vec::IntoIter<Key>);
+
+    impl Stream for Keys {
+        type Item = InquireResult<Key>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.next().map(Ok))
+        }
+    }
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Polls the future until it completes, which happens without waiting
+    /// as the keys of the tests are always ready.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn prompt_text(keys: Vec<Key>) -> (InquireResult<String>, FakeBackend) {
+        let mut backend = FakeBackend::new(vec![]);
+        let prompt = TextPrompt::from(Text::new("Name:"));
+        let answer = block_on(prompt_with_keys(
+            prompt,
+            &mut backend,
+            &mut Keys(keys.into_iter()),
+        ));
+        (answer, backend)
+    }
+
+    #[test]
+    fn keys_are_awaited_until_the_answer_is_submitted() {
+        let mut keys = Key::char_keys_from_str("Ana");
+        keys.push(Key::Enter);
+
+        let (answer, backend) = prompt_text(keys);
+
+        assert_eq!("Ana", answer.unwrap());
+        assert!(backend.frames().len() > 1);
+    }
+
+    #[test]
+    fn prompt_is_canceled_by_the_stream() {
+        let (answer, _) = prompt_text(vec![Key::Escape]);
+
+        assert!(matches!(answer, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn prompt_is_canceled_by_the_token_while_awaiting_keys() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let mut backend = FakeBackend::new(vec![]);
+        backend.cancellation = Some(token);
+        let prompt = TextPrompt::from(Text::new("Name:"));
+        let answer = block_on(prompt_with_keys(
+            prompt,
+            &mut backend,
+            &mut Keys(vec![].into_iter()),
+        ));
+
+        assert!(matches!(answer, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn prompts_rendered_to_custom_backends_fail() {
+        let backend = CustomBackend::new(|| Ok((vec![Key::Enter].into_iter(), NullTerminal)));
+
+        let answer = block_on(Text::new("Name:").with_backend(backend).prompt_async());
+
+        assert!(matches!(answer, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn stream_ending_before_the_answer_fails() {
+        let (answer, _) = prompt_text(Key::char_keys_from_str("An"));
+
+        assert!(
+            matches!(answer, Err(InquireError::IO(err)) if err.kind() == io::ErrorKind::UnexpectedEof)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ui::{Key, KeyModifiers},
-    InnerAction, InputAction,
+    InnerAction, InputAction, Layout,
 };
 
 use super::config::SelectConfig;
@@ -14,6 +14,10 @@ pub enum SelectPromptAction {
     MoveUp,
     /// Moves the cursor to the option below.
     MoveDown,
+    /// Moves the cursor to the previous option, on the left in the grid layout.
+    MoveLeft,
+    /// Moves the cursor to the next option, on the right in the grid layout.
+    MoveRight,
     /// Moves the cursor to the page above.
     PageUp,
     /// Moves the cursor to the page below.
@@ -40,6 +44,14 @@ impl InnerAction for SelectPromptAction {
             }
         }
 
+        if let Layout::Grid { .. } = config.layout {
+            match key {
+                Key::Left(KeyModifiers::NONE) => return Some(Self::MoveLeft),
+                Key::Right(KeyModifiers::NONE) => return Some(Self::MoveRight),
+                _ => {}
+            }
+        }
+
         let action = match key {
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
             Key::PageUp(_) => Self::PageUp,
@@ -0,0 +1,22 @@
+use crate::list_option::ListOption;
+
+use super::{intent::Intent, matching::OptionMatch, metadata::SelectMetadata};
+
+/// Answer of a [Select](crate::Select) prompt along with the details of how
+/// it was submitted, returned by
+/// [Select::prompt_with_details](crate::Select::prompt_with_details).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectAnswer<'a, T> {
+    /// Selected option, along with its index in the original list.
+    pub option: ListOption<T>,
+
+    /// How the selected option matched the filter typed by the user, `None`
+    /// when the user did not type a filter or filtering is disabled.
+    pub matched: Option<OptionMatch>,
+
+    /// Filter input and cursor positions when the option was submitted.
+    pub metadata: SelectMetadata,
+
+    /// Intent with which the option was submitted.
+    pub intent: Intent<'a>,
+}
@@ -1,4 +1,4 @@
-use crate::Select;
+use crate::{Layout, Select};
 
 /// Configuration settings used in the execution of a SelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,6 +7,8 @@ pub struct SelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Layout of the list of options.
+    pub layout: Layout,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
 }
@@ -14,8 +16,9 @@ pub struct SelectConfig {
 impl<T> From<&Select<'_, T>> for SelectConfig {
     fn from(value: &Select<'_, T>) -> Self {
         Self {
-            vim_mode: value.vim_mode,
+            vim_mode: value.vim_mode && !value.vim_filter_mode,
             page_size: value.page_size,
+            layout: value.layout,
             reset_cursor: value.reset_cursor,
         }
     }
@@ -0,0 +1,217 @@
+use std::fmt::Display;
+
+use crate::{
+    ui::{IndexPrefix, GRID_COLUMN_SPACING},
+    utils::{self, int_log10},
+    Layout, Select,
+};
+
+/// Diagnostics returned by [Select::check_fit](crate::Select::check_fit),
+/// describing how the configured prompt compares to a given terminal size.
+///
+/// Widths are measured in terminal columns and heights in rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FitReport {
+    /// Width of the terminal the prompt was checked against.
+    pub terminal_width: u16,
+
+    /// Height of the terminal the prompt was checked against.
+    pub terminal_height: u16,
+
+    /// Width of the widest line of the prompt message, including the prompt
+    /// prefix, or the indentation of the lines following the first one, and
+    /// the starting filter input, if any, placed after the last line.
+    pub prompt_width: usize,
+
+    /// Index of the option with the widest rendered line, `None` when
+    /// there are no options.
+    pub widest_option_index: Option<usize>,
+
+    /// Width of the widest rendered option line, including option prefixes.
+    ///
+    /// In the grid layout, this is the width of a full row of options.
+    pub widest_option_width: usize,
+
+    /// Height of the tallest page, including the help message line and the
+    /// prompt lines, with options and prompt lines wrapped to the terminal
+    /// width.
+    pub tallest_page_height: usize,
+}
+
+impl FitReport {
+    /// Whether every line of the prompt fits in the terminal width,
+    /// meaning no line will be wrapped when rendered.
+    pub fn fits_width(&self) -> bool {
+        let width = usize::from(self.terminal_width);
+        self.prompt_width <= width && self.widest_option_width <= width
+    }
+
+    /// Whether the tallest page fits in the terminal height.
+    ///
+    /// When it does not, the prompt still works, as the page size is
+    /// reduced at runtime to fit the terminal.
+    pub fn fits_height(&self) -> bool {
+        self.tallest_page_height <= usize::from(self.terminal_height)
+    }
+
+    /// Whether the prompt fits in the terminal in both dimensions.
+    pub fn fits(&self) -> bool {
+        self.fits_width() && self.fits_height()
+    }
+}
+
+pub(super) fn check_fit<T>(select: &Select<'_, T>, width: u16, height: u16) -> FitReport
+where
+    T: Display,
+{
+    let render_config = &select.render_config;
+    let text_width = |text: &str| utils::text_width(text, render_config.ambiguous_width);
+
+    // lines following the first one are aligned with it, as rendered
+    let indent = text_width(render_config.prompt_prefix.content) + 1;
+    let mut prompt_lines = select
+        .message
+        .split('\n')
+        .map(|line| indent + text_width(line))
+        .collect::<Vec<usize>>();
+    if let Some(last) = prompt_lines.last_mut() {
+        *last += 1 + text_width(select.starting_filter_input.unwrap_or_default());
+    }
+
+    let prompt_width = prompt_lines.iter().copied().max().unwrap_or(0);
+    let prompt_height = prompt_lines
+        .iter()
+        .map(|line_width| wrapped_height(*line_width, usize::from(width), indent))
+        .sum::<usize>();
+
+    let option_prefix_width = [
+        text_width(render_config.highlighted_option_prefix.content),
+        text_width(render_config.scroll_up_prefix.content),
+        text_width(render_config.scroll_down_prefix.content),
+        1,
+    ]
+    .iter()
+    .copied()
+    .max()
+    .unwrap_or(1);
+
+    let index_prefix_width = |index: usize| {
+        let index = index.saturating_add(1);
+        let digits = match render_config.option_index_prefix {
+            IndexPrefix::None => return 0,
+            IndexPrefix::Simple => int_log10(index),
+            IndexPrefix::SpacePadded | IndexPrefix::ZeroPadded => {
+                int_log10(select.options.len().saturating_add(1))
+            }
+        };
+        // digits, closing parenthesis and separator
+        digits + 2
+    };
+
+    let custom_prefix_width = match select.option_prefix {
+        Some(option_prefix) => {
+            let widest = select
+                .options
+                .iter()
+                .map(|option| text_width(&option_prefix(option)))
+                .max()
+                .unwrap_or(0);
+            // prefix column and its separator
+            widest + 1
+        }
+        None => 0,
+    };
+
+    let icon_width = match select.option_icon {
+        Some(option_icon) => {
+            let widest = select
+                .options
+                .iter()
+                .map(|option| text_width(option_icon(option).content(render_config.icon_fallback)))
+                .max()
+                .unwrap_or(0);
+            // icon column and its separator
+            widest + 1
+        }
+        None => 0,
+    };
+
+    let option_widths = select
+        .options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            option_prefix_width
+                + 1
+                + index_prefix_width(i)
+                + icon_width
+                + custom_prefix_width
+                + text_width(&option.to_string())
+        })
+        .collect::<Vec<usize>>();
+
+    let widest_option_index = option_widths
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, width)| **width)
+        .map(|(i, _)| i);
+    let widest_option_width = option_widths.iter().copied().max().unwrap_or(0);
+
+    let terminal_width = usize::from(width);
+    let (widest_option_width, row_heights) = match select.layout {
+        Layout::List => (
+            widest_option_width,
+            option_widths
+                .iter()
+                .map(|option_width| wrapped_height(*option_width, terminal_width, 0))
+                .collect::<Vec<usize>>(),
+        ),
+        Layout::Grid { columns } => {
+            let columns = columns.max(1);
+            let row_width = |cells: usize| {
+                widest_option_width * cells + cells.saturating_sub(1) * GRID_COLUMN_SPACING
+            };
+            (
+                row_width(columns.min(select.options.len())),
+                option_widths
+                    .chunks(columns)
+                    .map(|row| wrapped_height(row_width(row.len()), terminal_width, 0))
+                    .collect(),
+            )
+        }
+    };
+
+    // rows of options wider than the terminal are wrapped, so the tallest
+    // page is the window of rows taking the most lines
+    let tallest_rows_height = row_heights
+        .windows(select.page_size.clamp(1, row_heights.len().max(1)))
+        .map(|window| window.iter().sum::<usize>())
+        .max()
+        .unwrap_or(0);
+
+    let help_message_height = usize::from(select.help_message.is_some());
+    let tallest_page_height = prompt_height + tallest_rows_height + help_message_height;
+
+    FitReport {
+        terminal_width: width,
+        terminal_height: height,
+        prompt_width,
+        widest_option_index,
+        widest_option_width,
+        tallest_page_height,
+    }
+}
+
+/// Rows taken by a line of the given width, continued on indented rows when
+/// it's wider than the terminal.
+fn wrapped_height(line_width: usize, width: usize, indent: usize) -> usize {
+    let width = width.max(1);
+    let continuation_width = match indent < width / 2 {
+        true => width - indent,
+        false => width,
+    };
+
+    1 + line_width
+        .saturating_sub(width)
+        .div_ceil(continuation_width)
+}
@@ -0,0 +1,15 @@
+/// Intent with which the user submitted a [Select](crate::Select) prompt,
+/// returned by [`Select::prompt_with_intent`](crate::Select::prompt_with_intent).
+///
+/// Besides the regular submit key, a prompt can have additional keys bound
+/// with [`Select::with_intent`](crate::Select::with_intent), each submitting
+/// the highlighted option with a distinct meaning, such as opening it in a new
+/// window or deleting it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Intent<'a> {
+    /// The prompt was submitted with the regular submit key (`enter`).
+    Submit,
+
+    /// The prompt was submitted with a key bound to the intent of the given name.
+    Custom(&'a str),
+}
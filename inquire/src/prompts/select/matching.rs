@@ -0,0 +1,89 @@
+use std::ops::Range;
+
+/// Match metadata of the option selected in a [Select](crate::Select) prompt,
+/// returned by [Select::prompt_with_match](crate::Select::prompt_with_match)
+/// when the user filtered the options before submitting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionMatch {
+    /// Filter input typed by the user when the option was submitted.
+    pub filter: String,
+
+    /// Score given by the prompt's scorer to the selected option.
+    pub score: i64,
+
+    /// Byte ranges of the option's string value matched by the filter.
+    ///
+    /// Spans are computed by matching the filter against the string value,
+    /// independently of the scorer: fuzzily when the `fuzzy` feature is enabled,
+    /// or as a case-insensitive substring otherwise. They are empty when the
+    /// filter does not match the string value, e.g. due to a custom scorer.
+    pub spans: Vec<Range<usize>>,
+}
+
+#[cfg(feature = "fuzzy")]
+pub(super) fn match_spans(string_value: &str, filter: &str) -> Vec<Range<usize>> {
+    use fuzzy_matcher::FuzzyMatcher;
+
+    let matched_chars = match super::DEFAULT_MATCHER.fuzzy_indices(string_value, filter) {
+        Some((_score, indices)) => indices,
+        None => return vec![],
+    };
+
+    let mut spans: Vec<Range<usize>> = vec![];
+
+    for (char_index, (byte_index, c)) in string_value.char_indices().enumerate() {
+        if !matched_chars.contains(&char_index) {
+            continue;
+        }
+
+        let end = byte_index + c.len_utf8();
+        match spans.last_mut() {
+            Some(span) if span.end == byte_index => span.end = end,
+            _ => spans.push(byte_index..end),
+        }
+    }
+
+    spans
+}
+
+#[cfg(not(feature = "fuzzy"))]
+pub(super) fn match_spans(string_value: &str, filter: &str) -> Vec<Range<usize>> {
+    for (start, _) in string_value.char_indices() {
+        let mut expected = filter.chars().flat_map(char::to_lowercase).peekable();
+        let remaining = string_value.get(start..).unwrap_or_default();
+
+        for (offset, c) in remaining.char_indices() {
+            if !c.to_lowercase().all(|lc| expected.next() == Some(lc)) {
+                break;
+            }
+
+            if expected.peek().is_none() {
+                let end = start + offset + c.len_utf8();
+                return std::iter::once(start..end).collect();
+            }
+        }
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod test {
+    use super::match_spans;
+
+    #[test]
+    fn spans_are_byte_ranges_of_the_string_value() {
+        assert_eq!(vec![4..7], match_spans("New York", "yor"));
+        assert_eq!(vec![1..4], match_spans("São Paulo", "ão"));
+        assert_eq!(
+            Vec::<std::ops::Range<usize>>::new(),
+            match_spans("Lisbon", "xyz")
+        );
+    }
+
+    #[cfg(feature = "fuzzy")]
+    #[test]
+    fn fuzzy_spans_merge_adjacent_characters() {
+        assert_eq!(vec![0..2, 4..5], match_spans("San Diego", "sad"));
+    }
+}
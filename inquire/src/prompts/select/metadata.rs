@@ -0,0 +1,16 @@
+/// State of a [Select](crate::Select) prompt at the moment an option was
+/// submitted, returned by [Select::prompt_with_details](crate::Select::prompt_with_details).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectMetadata {
+    /// Filter input typed by the user, empty when no filter was typed or
+    /// filtering is disabled.
+    pub filter: String,
+
+    /// Position of the text cursor in the filter input, counted in
+    /// grapheme clusters.
+    pub filter_cursor: usize,
+
+    /// Position of the submitted option in the list displayed to the user,
+    /// i.e. after filtering and sorting.
+    pub list_position: usize,
+}
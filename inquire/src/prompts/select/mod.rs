@@ -1,24 +1,43 @@
 mod action;
+mod answer;
 mod config;
+mod fit;
+mod intent;
+mod matching;
+mod metadata;
 mod prompt;
+mod state;
 #[cfg(test)]
-#[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use answer::SelectAnswer;
+pub use fit::FitReport;
+pub use intent::Intent;
+pub use matching::OptionMatch;
+pub use metadata::SelectMetadata;
+pub use state::SelectState;
 use std::fmt::Display;
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
+    key_bindings::KeyBindings,
     list_option::ListOption,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    prompts::{prompt::Prompt, BackgroundScoring, Layout},
+    transliteration::Transliterator,
+    type_aliases::{OptionIcon, OptionPrefix, Scorer, Sorter},
+    ui::{prompt_backend, Key, PromptBackend, RenderConfig, SelectBackend, Viewport},
+    utils::natural_cmp,
 };
 
+#[cfg(feature = "normalization")]
+use crate::Normalization;
+
 use self::prompt::SelectPrompt;
 
 #[cfg(feature = "fuzzy")]
@@ -32,7 +51,7 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
 ///
-/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the selected option (`Vec` element) after the user submits.
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the selected option (`Vec` element) after the user submits. To avoid moving or cloning large collections, use [`Select::new_borrowed`] to borrow the options from a slice instead.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
 /// This prompt does not support custom validators because of its nature. A submission always selects exactly one of the options. If this option was not supposed to be selected or is invalid in some way, it probably should not be included in the options list.
@@ -45,12 +64,21 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Starting state**: Restores the filter input and cursor positions of a previous prompt. See [SelectState].
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected option string value by default.
+/// - **Option prefix**: Function that generates a custom prefix for each option, such as a status emoji or file-type icon, rendered in a dedicated column before the option. None by default.
+/// - **Icons**: Function that returns an [Icon](crate::ui::Icon) for each option, rendered in a dedicated column before the option and its prefix. Icons have an ASCII fallback, used when enabled in the `RenderConfig`. None by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Layout**: Whether options are displayed one per line or in a multi-column grid. See [Layout].
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Transliterator**: Converts the string values of the options to another script before scoring, so that e.g. typing "moskva" matches "Москва". None by default. See [Transliterator].
+/// - **Virtualization**: Optimizes filtering of very large option lists, at the cost of assumptions about the scorer. Disabled by default. See [`Select::with_virtualization`].
+/// - **Sorter function**: Function that defines the order of options when no filter is applied. By default, options are displayed in the order they were provided.
+/// - **Normalization**: Unicode normalization applied to the filter input and option strings before scoring, e.g. so that "jose" matches "José". None by default. Requires the `normalization` feature.
+/// - **Viewport**: Region of the terminal screen the prompt is rendered into. By default, the prompt is rendered inline at the current cursor position.
 ///
 /// # Example
 ///
@@ -84,16 +112,31 @@ pub struct Select<'a, T> {
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Layout of the options displayed to the user.
+    ///
+    /// In the grid layout, the page size is the number of rows displayed at once.
+    pub layout: Layout,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
 
+    /// Whether modal vi keybindings are enabled. When enabled, the prompt
+    /// starts in normal mode, where the user navigates through the options
+    /// with `j`, `k`, `gg` and `G`, and `/` focuses the filter input until
+    /// escape is pressed.
+    pub vim_filter_mode: bool,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
+    /// State restored from a previous prompt, taking precedence over
+    /// `starting_cursor` and `starting_filter_input`.
+    pub starting_state: Option<SelectState>,
+
     /// Reset cursor position to first option on filter input change.
     /// Defaults to true.
     pub reset_cursor: bool,
@@ -107,18 +150,74 @@ pub struct Select<'a, T> {
     /// options.
     pub scorer: Scorer<'a, T>,
 
+    /// Function used to sort the options for display when no filter is applied.
+    ///
+    /// When `None`, options are displayed in the order they were provided.
+    pub sorter: Option<Sorter<'a, T>>,
+
+    /// Transliterator applied to the string values of the options. When set,
+    /// options are scored against both their original and transliterated
+    /// string values, and the best score is kept.
+    pub transliterator: Option<Box<dyn Transliterator>>,
+
+    /// Whether filtering is optimized for very large option lists.
+    ///
+    /// See [`Select::with_virtualization`] for more details.
+    pub virtualized: bool,
+
+    /// Whether the scorer only narrows down the matches when characters are
+    /// appended to the filter input.
+    ///
+    /// Enabled by default, and disabled by [`Select::with_scorer`]. See
+    /// [`Select::with_incremental_scoring`] for more details.
+    pub incremental_scoring: bool,
+
+    /// Settings to score options on a worker thread instead of on every key
+    /// press, if enabled.
+    ///
+    /// See [`Select::with_background_scoring`] for more details.
+    pub background_scoring: Option<BackgroundScoring>,
+
+    /// Normalization applied to the filter input and the string values of
+    /// the options before they are passed to the scorer.
+    #[cfg(feature = "normalization")]
+    pub normalization: Normalization,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Function that generates a custom prefix for each option, rendered in a
+    /// dedicated column before the option's string value.
+    pub option_prefix: Option<OptionPrefix<'a, T>>,
+
+    /// Function that returns the icon of each option, rendered in a dedicated
+    /// column before the option and its custom prefix.
+    pub option_icon: Option<OptionIcon<'a, T>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Region of the terminal screen the prompt is rendered into.
+    ///
+    /// Defaults to `None`, which renders the prompt inline, starting at the
+    /// current cursor position and spanning the whole terminal width.
+    pub viewport: Option<Viewport>,
+
+    /// Additional keys submitting the prompt, each bound to the name of an
+    /// intent returned by [`Select::prompt_with_intent`].
+    pub intents: Vec<(Key, &'a str)>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<SelectPromptAction>,
 }
 
 impl<'a, T> Select<'a, T>
@@ -169,6 +268,8 @@ where
             DEFAULT_MATCHER.fuzzy_match(string_value, input)
         };
 
+    /// Default scoring function, which keeps the options whose string value
+    /// contains the input, ignoring case, all with the same score.
     #[cfg(not(feature = "fuzzy"))]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
@@ -179,12 +280,51 @@ where
             }
         };
 
+    /// Sorter that orders options alphabetically by their string values, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use inquire::Select;
+    ///
+    /// let sorter = Select::<&str>::ALPHABETICAL_SORTER;
+    /// assert_eq!(Ordering::Less, sorter(&"apple", &"Banana"));
+    /// assert_eq!(Ordering::Greater, sorter(&"item2", &"item10"));
+    /// ```
+    pub const ALPHABETICAL_SORTER: Sorter<'a, T> = &|a, b| {
+        a.to_string()
+            .to_lowercase()
+            .cmp(&b.to_string().to_lowercase())
+    };
+
+    /// Sorter that orders options in natural order by their string values, ignoring case,
+    /// where numbers are compared by their value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use inquire::Select;
+    ///
+    /// let sorter = Select::<&str>::NATURAL_SORTER;
+    /// assert_eq!(Ordering::Less, sorter(&"item2", &"Item10"));
+    /// ```
+    pub const NATURAL_SORTER: Sorter<'a, T> =
+        &|a, b| natural_cmp(&a.to_string().to_lowercase(), &b.to_string().to_lowercase());
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
+    /// Default layout, one option per line.
+    pub const DEFAULT_LAYOUT: Layout = Layout::List;
+
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
+    /// Default value of vim filter mode.
+    pub const DEFAULT_VIM_FILTER_MODE: bool = false;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
@@ -192,6 +332,13 @@ where
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
 
+    /// Default virtualization behaviour, disabled.
+    pub const DEFAULT_VIRTUALIZED: bool = false;
+
+    /// Default incremental scoring behaviour, enabled, since the default
+    /// scorer only narrows down its matches as the filter is extended.
+    pub const DEFAULT_INCREMENTAL_SCORING: bool = true;
+
     /// Default filter input enabled behaviour.
     /// Defaults to true.
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
@@ -200,6 +347,10 @@ where
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
 
+    /// Default help message used by [`Select::view`].
+    pub const DEFAULT_VIEWER_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, type to filter, esc to close");
+
     /// Creates a [Select] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
         Self {
@@ -207,14 +358,30 @@ where
             options,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            layout: Self::DEFAULT_LAYOUT,
+            vim_mode: crate::config::get_vim_mode(),
+            vim_filter_mode: Self::DEFAULT_VIM_FILTER_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             scorer: Self::DEFAULT_SCORER,
+            sorter: None,
+            transliterator: None,
+            virtualized: Self::DEFAULT_VIRTUALIZED,
+            incremental_scoring: Self::DEFAULT_INCREMENTAL_SCORING,
+            background_scoring: None,
+            #[cfg(feature = "normalization")]
+            normalization: Normalization::NONE,
             formatter: Self::DEFAULT_FORMATTER,
+            option_prefix: None,
+            option_icon: None,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
             starting_filter_input: None,
+            starting_state: None,
+            viewport: None,
+            intents: vec![],
         }
     }
 
@@ -236,15 +403,130 @@ where
         self
     }
 
-    /// Enables or disables vim_mode.
+    /// Sets the layout of the options.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Enables or disables vim_mode, overriding the global setting of
+    /// [set_global_vim_mode](crate::set_global_vim_mode).
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
+    /// Enables or disables modal vi keybindings, taking precedence over
+    /// [`Select::with_vim_mode`].
+    ///
+    /// The prompt starts in normal mode, where keys move the cursor instead
+    /// of being typed into the filter input. Pressing `/` switches to insert
+    /// mode to type a filter, and escape switches back to normal mode.
+    pub fn with_vim_filter_mode(mut self, vim_filter_mode: bool) -> Self {
+        self.vim_filter_mode = vim_filter_mode;
+        self
+    }
+
     /// Sets the scoring function.
+    ///
+    /// Incremental scoring is disabled, as custom scorers might not hold the
+    /// assumptions it relies on. If yours does, re-enable it with
+    /// [`Select::with_incremental_scoring`] after setting the scorer.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
+        self.incremental_scoring = false;
+        self
+    }
+
+    /// Enables or disables incremental scoring.
+    ///
+    /// Scores are cached for each filter typed so far. When characters are
+    /// appended to the filter, only the options matched by the previous one
+    /// are scored again, and deleting characters restores the cached scores
+    /// of the shorter filter without calling the scorer.
+    ///
+    /// The scorer must be monotonic for this to be correct: if it rejects an
+    /// option for a filter, it must also reject it for every filter starting
+    /// with that one. This holds for the default scorer and for substring
+    /// matching, but not for scorers matching e.g. whole words or regular
+    /// expressions. With a scorer that isn't monotonic, options matching the
+    /// current filter can be missing from the list.
+    ///
+    /// The scores of the last 8 filters are cached.
+    pub fn with_incremental_scoring(mut self, incremental_scoring: bool) -> Self {
+        self.incremental_scoring = incremental_scoring;
+        self
+    }
+
+    /// Sets the Unicode normalization applied to the filter input and the
+    /// string values of the options before scoring, so that, for example,
+    /// typing "jose" matches "José" when diacritics are stripped.
+    ///
+    /// The scorer receives the normalized strings, while options are still
+    /// displayed as provided.
+    #[cfg(feature = "normalization")]
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Enables or disables virtualization, which keeps filtering responsive
+    /// on lists with hundreds of thousands of options.
+    ///
+    /// Options are always converted to strings lazily, only when they are
+    /// scored. When virtualization is enabled:
+    ///
+    /// - An empty filter matches all options without calling the scorer, so
+    ///   options that are never filtered are never converted to strings.
+    /// - At most 10,000 options are scored per key press. The matches found
+    ///   so far are displayed along with a "filtering…" indicator, and the
+    ///   other options are scored while the user doesn't type.
+    ///
+    /// Combined with [`Select::with_incremental_scoring`], extending the filter
+    /// only scores again the options matched by the previous one, including
+    /// the ones matched so far when it was still being scored.
+    pub fn with_virtualization(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
+
+    /// Scores options on a worker thread, so that typing stays responsive
+    /// with expensive scorers or very large lists.
+    ///
+    /// Filter changes are debounced and sent to the worker, and the list is
+    /// updated when the results arrive, showing a "filtering…" indicator in
+    /// the meantime. The scorer set with [`Select::with_scorer`] and the
+    /// virtualization setting are not used when background scoring is enabled.
+    ///
+    /// See [`BackgroundScoring`] for more details.
+    pub fn with_background_scoring(mut self, scoring: BackgroundScoring) -> Self {
+        self.background_scoring = Some(scoring);
+        self
+    }
+
+    /// Sets the transliterator, used to match options written in non-Latin
+    /// scripts with input typed in Latin characters, e.g. "beijing" for "北京".
+    ///
+    /// Options are scored against both their original and transliterated
+    /// string values, keeping the best score. With the `transliteration`
+    /// feature, [AsciiTransliterator](crate::transliteration::AsciiTransliterator)
+    /// is available as a default implementation.
+    pub fn with_transliterator<TL>(mut self, transliterator: TL) -> Self
+    where
+        TL: Transliterator + 'static,
+    {
+        self.transliterator = Some(Box::new(transliterator));
+        self
+    }
+
+    /// Sets the sorter, which defines the display order of the options
+    /// when no filter is applied. While filtering, options are still
+    /// ordered by the scorer.
+    ///
+    /// Unlike sorting the options beforehand, the index of each option in
+    /// the original list is preserved in the answer.
+    pub fn with_sorter(mut self, sorter: Sorter<'a, T>) -> Self {
+        self.sorter = Some(sorter);
         self
     }
 
@@ -254,7 +536,30 @@ where
         self
     }
 
-    /// Sets the starting cursor index.
+    /// Sets the function that generates a custom prefix for each option,
+    /// such as a status emoji or a file-type icon.
+    ///
+    /// Prefixes are rendered in a dedicated column, independent of the options'
+    /// string values, and are not considered when filtering.
+    pub fn with_option_prefix(mut self, option_prefix: OptionPrefix<'a, T>) -> Self {
+        self.option_prefix = Some(option_prefix);
+        self
+    }
+
+    /// Sets the function that returns the icon of each option, such as an
+    /// emoji or a nerd-font glyph with an ASCII fallback.
+    ///
+    /// Icons are rendered in a dedicated column, aligned across the options
+    /// of a page, and are not taken into account when filtering. Whether the
+    /// glyph or the fallback is rendered is defined by
+    /// [`RenderConfig::icon_fallback`](crate::ui::RenderConfig::icon_fallback).
+    pub fn with_icons(mut self, option_icon: OptionIcon<'a, T>) -> Self {
+        self.option_icon = Some(option_icon);
+        self
+    }
+
+    /// Sets the starting cursor index, the index of the highlighted option in
+    /// the list of options, wherever the sorter displays it.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
     /// and starting_filter_input is set to something other than None.
@@ -269,6 +574,15 @@ where
         self
     }
 
+    /// Restores the filter input and cursor positions from the state of a
+    /// previous prompt, overriding the starting cursor and filter input.
+    ///
+    /// The cursor is clamped to the options displayed after the filter is applied.
+    pub fn with_state(mut self, state: SelectState) -> Self {
+        self.starting_state = Some(state);
+        self
+    }
+
     /// Sets the reset_cursor behaviour. Defaults to true.
     ///
     /// When there's an input change that results in a different list of options being displayed,
@@ -290,17 +604,85 @@ where
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<SelectPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
+    /// Renders the prompt inside the provided region of the terminal screen,
+    /// instead of inline at the current cursor position.
+    ///
+    /// The prompt is wrapped and clipped to the viewport dimensions, and the page
+    /// size is reduced if needed so that the options fit in the viewport height.
+    /// Content outside of the viewport is never written to.
+    pub fn with_viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Binds a key that submits the highlighted option with the given intent,
+    /// e.g. `d` to delete it, instead of the regular submission with `enter`.
+    /// The intent is returned by [`Select::prompt_with_intent`]
+    /// and [`Select::prompt_with_details`].
+    ///
+    /// Bound keys take precedence over every other action, including typing
+    /// into the filter input, so printable characters are best bound when
+    /// filtering is disabled. Note that most terminals send the same key
+    /// event for `enter` and `ctrl+enter`, so they can't be told apart.
+    pub fn with_intent(mut self, key: Key, intent: &'a str) -> Self {
+        self.intents.push((key, intent));
+        self
+    }
+
+    /// Checks whether the prompt, as currently configured, fits in a terminal
+    /// of the given width and height, without rendering anything.
+    ///
+    /// The returned [FitReport] describes the widest option line and the tallest
+    /// page, which applications can log or use to adjust the configuration,
+    /// e.g. lowering the page size or shortening options, before prompting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Pick a fruit", vec!["Apple", "Banana", "Cherry"]);
+    ///
+    /// let report = select.check_fit(80, 24);
+    /// assert!(report.fits());
+    ///
+    /// let report = select.check_fit(8, 3);
+    /// assert!(!report.fits_width());
+    /// assert!(!report.fits_height());
+    /// ```
+    pub fn check_fit(&self, width: u16, height: u16) -> FitReport {
+        fit::check_fit(self, width, height)
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -346,21 +728,269 @@ where
         }
     }
 
+    /// Displays the options in a read-only viewer, which the user can
+    /// navigate and filter but not submit, closing it with the cancel key
+    /// (`esc`).
+    ///
+    /// This is useful to let the user browse a list of results when no
+    /// choice is required. If the help message wasn't changed, a default one
+    /// mentioning how to close the viewer is displayed instead of the one
+    /// of regular prompts.
+    ///
+    /// Returns `Ok(())` once the viewer is closed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let results = vec!["src/main.rs:12", "src/lib.rs:40", "tests/cli.rs:7"];
+    /// Select::new("Search results:", results).view()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn view(self) -> InquireResult<()> {
+        let mut backend = self.backend()?;
+        self.view_with_backend(&mut backend)
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = self.backend()?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [SelectAnswer] holding the selected option along with the
+    /// details of how it was submitted:
+    ///
+    /// - [`matched`](SelectAnswer::matched): an [OptionMatch] describing how
+    ///   the option matched the filter typed by the user, useful for
+    ///   downstream ranking or learning systems.
+    /// - [`metadata`](SelectAnswer::metadata): the filter input and cursor
+    ///   positions, which can be turned into a [SelectState] to resume a
+    ///   following prompt with [`Select::with_state`].
+    /// - [`intent`](SelectAnswer::intent): [`Intent::Submit`] for the regular
+    ///   submit key, or the intent bound to the pressed key with
+    ///   [`Select::with_intent`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{
+    ///     ui::{Key, KeyModifiers},
+    ///     Intent, Select, SelectState,
+    /// };
+    ///
+    /// let mut state = SelectState::default();
+    ///
+    /// loop {
+    ///     let files = vec!["notes.md", "todo.md", "draft.md"];
+    ///     let answer = Select::new("File:", files)
+    ///         .with_state(state)
+    ///         .with_intent(Key::Char('d', KeyModifiers::CONTROL), "delete")
+    ///         .with_help_message("enter to open, ctrl-d to delete")
+    ///         .prompt_with_details()?;
+    ///
+    ///     match answer.intent {
+    ///         Intent::Custom("delete") => println!("Deleting {}", answer.option),
+    ///         _ => println!("Opening {}", answer.option),
+    ///     }
+    ///     if let Some(matched) = answer.matched {
+    ///         println!("Matched {:?} with score {}", matched.filter, matched.score);
+    ///     }
+    ///
+    ///     state = answer.metadata.into();
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_details(self) -> InquireResult<SelectAnswer<'a, T>> {
+        let mut backend = self.backend()?;
+        self.prompt_with_details_and_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user,
+    /// along with an [OptionMatch] describing how the selected option matched
+    /// the filter typed by the user. The match is `None` when the user did not
+    /// type a filter or filtering is disabled.
+    ///
+    /// This is useful for downstream ranking or learning systems that want
+    /// to use the search signal that led to the selection.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let options = vec!["Banana", "Apple", "Strawberry"];
+    /// let (answer, option_match) = Select::new("Fruit:", options).prompt_with_match()?;
+    ///
+    /// if let Some(option_match) = option_match {
+    ///     println!(
+    ///         "{} matched {:?} with score {}",
+    ///         answer, option_match.filter, option_match.score
+    ///     );
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_match(self) -> InquireResult<(ListOption<T>, Option<OptionMatch>)> {
+        let mut backend = self.backend()?;
+        self.prompt_with_match_and_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user,
+    /// along with the [Intent] with which it was submitted: [`Intent::Submit`]
+    /// for the regular submit key, or the intent bound to the pressed key with
+    /// [`Select::with_intent`]. See [`Select::prompt_with_details`] for more
+    /// details about the answer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{
+    ///     ui::{Key, KeyModifiers},
+    ///     Intent, Select,
+    /// };
+    ///
+    /// let files = vec!["notes.md", "todo.md", "draft.md"];
+    /// let (file, intent) = Select::new("File:", files)
+    ///     .without_filtering()
+    ///     .with_intent(Key::Char('d', KeyModifiers::NONE), "delete")
+    ///     .with_help_message("enter to open, d to delete")
+    ///     .prompt_with_intent()?;
+    ///
+    /// match intent {
+    ///     Intent::Custom("delete") => println!("Deleting {}", file),
+    ///     _ => println!("Opening {}", file),
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_intent(self) -> InquireResult<(ListOption<T>, Intent<'a>)> {
+        let mut backend = self.backend()?;
+        self.prompt_with_intent_and_backend(&mut backend)
+    }
+
+    /// Async version of [prompt](Self::prompt), awaiting the option selected
+    /// by the user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<T> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        backend.set_viewport(self.viewport);
+        let answer =
+            crate::prompts::prompt_async::prompt_async(SelectPrompt::new(self)?, &mut backend)
+                .await?;
+        Ok(answer.option.value)
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with the option selected by the user. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, T>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            SelectPrompt::new(self)?,
+            render_config,
+            settings,
+            |answer| answer.option.value,
+        )
+    }
+
+    /// Backend rendering the prompt to the terminal, clipped to its viewport.
+    fn backend(&self) -> InquireResult<PromptBackend<'a>> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        backend.set_viewport(self.viewport);
+        Ok(backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
+        SelectPrompt::new(self)?
+            .prompt(backend)
+            .map(|answer| answer.option)
+    }
+
+    pub(crate) fn view_with_backend<B: SelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<()> {
+        if self.help_message == Self::DEFAULT_HELP_MESSAGE {
+            self.help_message = Self::DEFAULT_VIEWER_HELP_MESSAGE;
+        }
+
+        match SelectPrompt::new(self)?.with_viewer_mode().prompt(backend) {
+            Ok(_) | Err(InquireError::OperationCanceled) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(crate) fn prompt_with_details_and_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<SelectAnswer<'a, T>> {
         SelectPrompt::new(self)?.prompt(backend)
     }
+
+    pub(crate) fn prompt_with_match_and_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(ListOption<T>, Option<OptionMatch>)> {
+        self.prompt_with_details_and_backend(backend)
+            .map(|answer| (answer.option, answer.matched))
+    }
+
+    pub(crate) fn prompt_with_intent_and_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(ListOption<T>, Intent<'a>)> {
+        self.prompt_with_details_and_backend(backend)
+            .map(|answer| (answer.option, answer.intent))
+    }
+}
+
+impl<'a, T> Select<'a, &'a T>
+where
+    T: Display,
+{
+    /// Creates a [Select] that borrows its options from the provided slice,
+    /// along with default configuration values.
+    ///
+    /// The options are neither cloned nor moved into the prompt: the answer
+    /// holds a reference to the selected element, along with its index in
+    /// the slice. This is useful for large or shared collections that the
+    /// caller still needs after the prompt.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let fruits = vec![String::from("Banana"), String::from("Apple")];
+    ///
+    /// let answer = Select::new_borrowed("Fruit:", &fruits).prompt()?;
+    /// println!("Picked {} out of {} fruits", answer, fruits.len());
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_borrowed(message: &'a str, options: &'a [T]) -> Self {
+        Self::new(message, options.iter().collect())
+    }
 }
@@ -1,31 +1,67 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{cmp::Reverse, fmt::Display, time::Duration};
 
 use crate::{
     error::InquireResult,
+    event::PromptEvent,
     formatter::OptionFormatter,
     input::{Input, InputActionResult},
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     list_option::ListOption,
-    prompts::action::Action,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    prompts::{
+        background_scoring::{ScoringWorker, FILTERING_MESSAGE, TICK_INTERVAL},
+        option_strings::{OptionStrings, ScoreCache, ScoringPass},
+        prompt::{ActionResult, AdaptivePagePrompt, Prompt},
+        vi::{ViKeys, ViMode},
+        Action,
+    },
+    type_aliases::{OptionIcon, OptionPrefix, Scorer, Sorter},
+    ui::{Icon, Key, SelectBackend},
+    utils::{paginate, paginate_grid},
+    InputAction, InquireError, Layout, Select,
 };
 
-use super::{action::SelectPromptAction, config::SelectConfig};
+use super::{
+    action::SelectPromptAction,
+    answer::SelectAnswer,
+    config::SelectConfig,
+    intent::Intent,
+    matching::{match_spans, OptionMatch},
+    metadata::SelectMetadata,
+};
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
     options: Vec<T>,
-    string_options: Vec<String>,
+    option_strings: OptionStrings,
     scored_options: Vec<usize>,
+    scores: Vec<i64>,
     help_message: Option<&'a str>,
     cursor_index: usize,
     input: Option<Input>,
     scorer: Scorer<'a, T>,
+    virtualized: bool,
+    incremental_scoring: bool,
+    /// Only used with incremental scoring.
+    score_cache: ScoreCache,
+    /// Scoring of the current filter continued on the next ticks, when it
+    /// exceeds the budget of a key press. Only used with virtualization.
+    scoring_pass: Option<ScoringPass>,
+    scoring_worker: Option<ScoringWorker>,
+    sorter: Option<Sorter<'a, T>>,
+    option_prefix: Option<OptionPrefix<'a, T>>,
+    option_icon: Option<OptionIcon<'a, T>>,
     formatter: OptionFormatter<'a, T>,
+    restored_cursor: Option<usize>,
+    viewer_mode: bool,
+    intents: Vec<(Key, &'a str)>,
+    /// Index of the intent the prompt is being submitted with, if any.
+    intent: Option<usize>,
+    vi: Option<ViKeys>,
+    key_bindings: KeyBindings<SelectPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -47,30 +83,100 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        if let Layout::Grid { columns: 0 } = so.layout {
+            return Err(InquireError::InvalidConfiguration(
+                "Grid layout must have at least one column".into(),
+            ));
+        }
 
-        let input = match so.filter_input_enabled {
-            true => Some(Input::new_with(
+        let config = (&so).into();
+        let mut scored_options = (0..so.options.len()).collect::<Vec<usize>>();
+        if let Some(sorter) = so.sorter {
+            scored_options
+                .sort_by(|a, b| sorter(so.options.get(*a).unwrap(), so.options.get(*b).unwrap()));
+        }
+        let scores = vec![0; so.options.len()];
+
+        let option_strings = OptionStrings::new(so.options.len(), so.transliterator);
+        #[cfg(feature = "normalization")]
+        let option_strings = option_strings.with_normalization(so.normalization);
+
+        let options = &so.options;
+        let scoring_worker = so.background_scoring.as_ref().map(|scoring| {
+            let values = (0..options.len())
+                .map(|i| option_strings.scoring_value(options, i).to_owned())
+                .collect();
+            let transliterated = (0..options.len())
+                .map(|i| {
+                    option_strings
+                        .transliterated_value(options, i)
+                        .map(str::to_owned)
+                })
+                .collect();
+
+            ScoringWorker::spawn(scoring, values, transliterated)
+        });
+
+        let input = match (so.filter_input_enabled, &so.starting_state) {
+            (true, Some(state)) => {
+                let input = Input::new_with(state.filter.as_str());
+                let cursor = state.filter_cursor.min(input.length());
+                Some(input.with_cursor(cursor))
+            }
+            (true, None) => Some(Input::new_with(
                 so.starting_filter_input.unwrap_or_default(),
             )),
-            false => None,
+            (false, _) => None,
         };
+        let restored_cursor = so.starting_state.as_ref().map(|state| state.cursor_index);
+
+        // the starting cursor is the index of an option, which the sorter
+        // may have moved to another row.
+        let starting_cursor = so.starting_cursor;
+        let cursor_index = scored_options
+            .iter()
+            .position(|i| *i == starting_cursor)
+            .unwrap_or(starting_cursor);
 
         Ok(Self {
             message: so.message,
-            config: (&so).into(),
+            config,
             options: so.options,
-            string_options,
+            option_strings,
             scored_options,
+            scores,
             help_message: so.help_message,
-            cursor_index: so.starting_cursor,
+            cursor_index,
             input,
             scorer: so.scorer,
+            virtualized: so.virtualized,
+            incremental_scoring: so.incremental_scoring,
+            score_cache: ScoreCache::default(),
+            scoring_pass: None,
+            scoring_worker,
+            sorter: so.sorter,
+            option_prefix: so.option_prefix,
+            option_icon: so.option_icon,
             formatter: so.formatter,
+            restored_cursor,
+            viewer_mode: false,
+            intents: so.intents,
+            intent: None,
+            vi: so.vim_filter_mode.then(|| ViKeys::new(ViMode::Normal)),
+            key_bindings: so.key_bindings,
+            key_handler: so.settings.key_handler,
+            escape_behavior: so.settings.escape_behavior,
+            cancel_guard: so.settings.cancel_guard,
         })
     }
 
+    /// Disables submission, so that the prompt can only be closed with the
+    /// cancel key.
+    pub fn with_viewer_mode(mut self) -> Self {
+        self.viewer_mode = true;
+        self
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let new_position = if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
@@ -100,6 +206,52 @@ where
         self.update_cursor_position(new_position)
     }
 
+    fn move_cursor_row_up(&mut self) -> ActionResult {
+        match self.cursor_index.checked_sub(self.row_length()) {
+            Some(new_position) => self.update_cursor_position(new_position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn move_cursor_row_down(&mut self) -> ActionResult {
+        let row_length = self.row_length();
+        let last_row = self.scored_options.len().saturating_sub(1) / row_length;
+
+        if self.cursor_index / row_length < last_row {
+            self.move_cursor_down(row_length, false)
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn option_icons(&self, options: &[ListOption<&T>]) -> Vec<Icon> {
+        match self.option_icon {
+            Some(option_icon) => options
+                .iter()
+                .map(|option| option_icon(option.value))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn option_prefixes(&self, options: &[ListOption<&T>]) -> Vec<String> {
+        match self.option_prefix {
+            Some(option_prefix) => options
+                .iter()
+                .map(|option| option_prefix(option.value))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Number of options displayed in each row of the current layout.
+    fn row_length(&self) -> usize {
+        match self.config.layout {
+            Layout::List => 1,
+            Layout::Grid { columns } => columns,
+        }
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -113,35 +265,161 @@ where
         self.scored_options.get(self.cursor_index).is_some()
     }
 
-    fn get_final_answer(&mut self) -> ListOption<T> {
+    fn get_final_answer(&mut self) -> SelectAnswer<'a, T> {
         // should only be called after current cursor index is validated
         // on has_answer_highlighted
 
         let index = *self.scored_options.get(self.cursor_index).unwrap();
+
+        let matched = match &self.input {
+            Some(input) if !input.is_empty() => Some(OptionMatch {
+                filter: input.content().to_string(),
+                score: self.scores.get(self.cursor_index).copied().unwrap_or(0),
+                spans: match_spans(
+                    self.option_strings.value(&self.options, index),
+                    input.content(),
+                ),
+            }),
+            _ => None,
+        };
+
+        let metadata = SelectMetadata {
+            filter: self
+                .input
+                .as_ref()
+                .map(|input| input.content().to_string())
+                .unwrap_or_default(),
+            filter_cursor: self.input.as_ref().map(Input::cursor).unwrap_or(0),
+            list_position: self.cursor_index,
+        };
+
+        let intent = self
+            .intent
+            .and_then(|i| self.intents.get(i))
+            .map(|(_, name)| Intent::Custom(name))
+            .unwrap_or(Intent::Submit);
+
         let value = self.options.swap_remove(index);
 
-        ListOption::new(index, value)
+        SelectAnswer {
+            option: ListOption::new(index, value),
+            matched,
+            metadata,
+            intent,
+        }
     }
 
     fn run_scorer(&mut self) {
+        #[cfg(feature = "instrument")]
+        let _span = crate::instrument::span(crate::instrument::Phase::Scoring);
+
         let content = match &self.input {
-            Some(input) => input.content(),
+            Some(input) => self.option_strings.scoring_filter(input.content()),
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+        if let Some(worker) = self.scoring_worker.as_mut() {
+            if !content.is_empty() {
+                worker.request(content.into_owned());
+                return;
+            }
+
+            // An empty filter matches everything, no need to wait for the worker.
+            worker.cancel();
+            self.apply_scores((0..self.options.len()).map(|i| (i, 0)).collect(), true);
+            return;
+        }
+
+        let content = content.into_owned();
+        let incremental = self.incremental_scoring;
+
+        self.score_cache.retain_prefixes_of(&content);
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        if let Some((filter, scores)) = self.score_cache.last() {
+            if *filter == content {
+                let scores = scores.clone();
+                self.apply_scores(scores, content.is_empty());
+                return;
+            }
+        }
+
+        // A filter that extends a cached one can only narrow down its
+        // matches, so there is no need to score the other options. The same
+        // goes for the options matched so far by an unfinished pass.
+        let unfinished_pass = self
+            .scoring_pass
+            .take()
+            .filter(|pass| incremental && content.starts_with(pass.filter()));
+        let candidates = match (unfinished_pass, self.score_cache.last()) {
+            (Some(pass), _) => pass.into_candidates(),
+            (None, Some((_, previous))) => previous.iter().map(|(i, _)| *i).collect(),
+            (None, None) => (0..self.options.len()).collect::<Vec<usize>>(),
+        };
+
+        let options = match self.virtualized {
+            true if content.is_empty() => candidates.into_iter().map(|i| (i, 0)).collect(),
+            true => {
+                self.continue_scoring(ScoringPass::new(content, candidates));
+                return;
+            }
+            false => candidates
+                .into_iter()
+                .filter_map(|i| self.score_option(&content, i).map(|score| (i, score)))
+                .collect::<Vec<(usize, i64)>>(),
+        };
+
+        let filter_is_empty = content.is_empty();
+        if incremental {
+            self.score_cache.push((content, options.clone()));
+        }
+        self.apply_scores(options, filter_is_empty);
+    }
+
+    /// Best score of the option against the filter, among its string value
+    /// and its transliterated one.
+    fn score_option(&self, filter: &str, i: usize) -> Option<i64> {
+        let opt = self.options.get(i).unwrap();
+        let value = self.option_strings.scoring_value(&self.options, i);
+        let score = (self.scorer)(filter, opt, value, i);
+        let transliterated_score = self
+            .option_strings
+            .transliterated_value(&self.options, i)
+            .and_then(|value| (self.scorer)(filter, opt, value, i));
+
+        score.max(transliterated_score)
+    }
+
+    /// Scores the next chunk of options of the pass and displays the matches
+    /// found so far, keeping the pass to be continued on the next tick until
+    /// all options are scored.
+    fn continue_scoring(&mut self, mut pass: ScoringPass) {
+        pass.advance(|filter, i| self.score_option(filter, i));
+
+        let scores = pass.scores().to_vec();
+        match pass.is_done() {
+            true if self.incremental_scoring => self.score_cache.push(pass.into_scores()),
+            true => {}
+            false => self.scoring_pass = Some(pass),
+        }
+        self.apply_scores(scores, false);
+    }
+
+    /// Replaces the filtered options with the provided scored ones, sorting
+    /// them by score, or with the sorter if the filter is empty.
+    ///
+    /// Options with the same score keep their original order, so that the
+    /// list is the same regardless of whether the scores were cached or
+    /// computed in the background.
+    fn apply_scores(&mut self, mut options: Vec<(usize, i64)>, filter_is_empty: bool) {
+        match self.sorter {
+            Some(sorter) if filter_is_empty => options.sort_by(|(a, _), (b, _)| {
+                sorter(self.options.get(*a).unwrap(), self.options.get(*b).unwrap())
+            }),
+            _ => options.sort_unstable_by_key(|(idx, score)| (Reverse(*score), *idx)),
+        }
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        self.scores = options.iter().map(|(_, score)| *score).collect();
 
         if self.scored_options == new_scored_options {
             return;
@@ -164,121 +442,139 @@ where
 {
     type Config = SelectConfig;
     type InnerAction = SelectPromptAction;
-    type Output = ListOption<T>;
+    type Output = SelectAnswer<'a, T>;
 
     fn message(&self) -> &str {
         self.message
     }
 
+    fn partial_input(&self) -> Option<&str> {
+        self.input.as_ref().map(Input::content)
+    }
+
+    fn observable_state(&self) -> Vec<PromptEvent> {
+        let input = self.input.as_ref().map(|input| input.content().to_owned());
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+
+        input
+            .map(PromptEvent::InputChanged)
+            .into_iter()
+            .chain(highlighted.map(PromptEvent::HighlightChanged))
+            .collect()
+    }
+
     fn config(&self) -> &SelectConfig {
         &self.config
     }
 
-    fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+    fn key_bindings(&self) -> Option<&KeyBindings<SelectPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(self, SelectPromptAction::FilterInput(InputAction::Clear))
+    }
+
+    fn action_from_key(&mut self, key: Key) -> Option<Action<SelectPromptAction>> {
+        self.intent = self.intents.iter().position(|(k, _)| *k == key);
+        let action = if self.intent.is_some() {
+            Action::Submit
+        } else {
+            let key = match &mut self.vi {
+                Some(vi) => vi.translate(key)?,
+                None => key,
+            };
+            Action::from_key(key, &self.config)?
+        };
+
+        // the viewer can't be submitted, so submit keys do nothing instead
+        // of being reported as a failed submission.
+        match action {
+            Action::Submit if self.viewer_mode => None,
+            action => Some(action),
+        }
+    }
+
+    fn format_answer(&self, answer: &SelectAnswer<'a, T>) -> String {
+        (self.formatter)(answer.option.as_ref())
     }
 
     fn setup(&mut self) -> InquireResult<()> {
         self.run_scorer();
+
+        if let Some(cursor_index) = self.restored_cursor.take() {
+            let last_index = self.scored_options.len().saturating_sub(1);
+            self.cursor_index = cursor_index.min(last_index);
+        }
+
         Ok(())
     }
 
-    fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
-        // NOTE ABOUT OVERRIDING `Prompt::prompt`
-        //
-        // The base `Prompt::prompt` implementation (see `prompts/prompt.rs`) includes an
-        // explicit guideline to only override it with a strong reason. This override
-        // exists to implement an *adaptive page size* workaround for terminals where
-        // wrapped multi-line options can cause the rendered list to exceed the visible
-        // screen height and corrupt the UI.
-        //
-        // Why this cannot live in the base `Prompt::prompt` today:
-        //
-        // 1) **Prompt-specific knob**: Adaptive sizing needs to shrink
-        //    `self.config.page_size`. The base prompt is generic over `Self::Config`
-        //    and has no required interface to read/modify a "page size" field. Without
-        //    adding new trait requirements or hooks, the base prompt can't safely
-        //    mutate prompt-specific configuration.
-        //
-        // 2) **Preflight + abort**: The workaround must render into an in-memory frame,
-        //    compare the *flush height* (max of last/current frame heights) against
-        //    terminal height, and if oversized, abort without flushing and retry with a
-        //    smaller page size. The base prompt previously had no "abort and retry"
-        //    control flow, so we need to own the redraw path here.
-        //
-        // 3) **State correction**: After shrinking, we may need to clamp cursor/selection
-        //    indices and re-paginate. This adjustment is prompt-specific; the base
-        //    prompt cannot assume how to reconcile internal state for all prompt types.
-        //
-        // Future direction:
-        // If we want to move adaptive sizing into the base prompt for broader coverage,
-        // we should introduce a minimal opt-in trait/hook (e.g., "AdaptivePagePrompt")
-        // that exposes:
-        //   - current page size
-        //   - ability to set a smaller page size
-        //   - a hook to reconcile state after resize
-        // and have the default loop perform preflight/abort/retry only for prompts that
-        // implement that trait. Until such an abstraction exists, this local override
-        // is the smallest safe, semver-friendly fix.
-        //
-        <Self as Prompt<Backend>>::setup(&mut self)?;
-
-        let mut last_handle = ActionResult::NeedsRedraw;
-        let final_answer = loop {
-            if last_handle.needs_redraw() {
-                self.redraw_with_adaptive_page_size(backend)?;
-                last_handle = ActionResult::Clean;
-            }
+    fn tick_interval(&self) -> Option<Duration> {
+        match self.scoring_worker.is_some() || self.scoring_pass.is_some() {
+            true => Some(TICK_INTERVAL),
+            false => None,
+        }
+    }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, <Self as Prompt<Backend>>::config(&self));
-
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) =
-                            <Self as Prompt<Backend>>::submit(&mut self)?
-                        {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Cancel => {
-                        let pre_cancel_result =
-                            <Self as Prompt<Backend>>::pre_cancel(&mut self)?;
-
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(
-                                <Self as Prompt<Backend>>::message(&self),
-                            )?;
-                            backend.frame_finish(true)?;
-                            return Err(InquireError::OperationCanceled);
-                        }
-
-                        ActionResult::NeedsRedraw
-                    }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => {
-                        <Self as Prompt<Backend>>::handle(&mut self, inner_action)?
-                    }
-                };
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        if let Some(pass) = self.scoring_pass.take() {
+            self.continue_scoring(pass);
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        match self.scoring_worker.as_mut().and_then(ScoringWorker::poll) {
+            Some(scores) => {
+                self.apply_scores(scores, false);
+                Ok(ActionResult::NeedsRedraw)
             }
-        };
+            None => Ok(ActionResult::Clean),
+        }
+    }
 
-        let formatted = <Self as Prompt<Backend>>::format_answer(&self, &final_answer);
+    /// Selects the option displayed exactly as the answer, ignoring case
+    /// when no option matches exactly.
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        let answer = answer.trim();
+        let find = |matches: &dyn Fn(&str) -> bool| {
+            (0..self.options.len()).find(|i| matches(self.option_strings.value(&self.options, *i)))
+        };
 
-        backend.frame_setup()?;
-        backend.render_prompt_with_answer(
-            <Self as Prompt<Backend>>::message(&self),
-            &formatted,
-        )?;
-        backend.frame_finish(true)?;
+        let index = find(&|option| option == answer)
+            .or_else(|| find(&|option| option.eq_ignore_ascii_case(answer)))
+            .ok_or_else(|| {
+                InquireError::InvalidAnswer(format!("\"{}\" is not one of the options", answer))
+            })?;
 
-        Ok(final_answer)
+        // the option may have been filtered out of the list
+        if !self.scored_options.contains(&index) {
+            self.scored_options.push(index);
+        }
+        self.cursor_index = self
+            .scored_options
+            .iter()
+            .position(|i| *i == index)
+            .unwrap_or_default();
+        Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+    fn submit(&mut self) -> InquireResult<Option<SelectAnswer<'a, T>>> {
+        if self.viewer_mode {
+            return Ok(None);
+        }
+
         let answer = match self.has_answer_highlighted() {
             true => Some(self.get_final_answer()),
             false => None,
@@ -289,10 +585,22 @@ where
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
-            SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            SelectPromptAction::MoveUp => match self.config.layout {
+                Layout::List => self.move_cursor_up(1, true),
+                Layout::Grid { .. } => self.move_cursor_row_up(),
+            },
+            SelectPromptAction::MoveDown => match self.config.layout {
+                Layout::List => self.move_cursor_down(1, true),
+                Layout::Grid { .. } => self.move_cursor_row_down(),
+            },
+            SelectPromptAction::MoveLeft => self.move_cursor_up(1, true),
+            SelectPromptAction::MoveRight => self.move_cursor_down(1, true),
+            SelectPromptAction::PageUp => {
+                self.move_cursor_up(self.config.page_size * self.row_length(), false)
+            }
+            SelectPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size * self.row_length(), false)
+            }
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
 
@@ -325,60 +633,62 @@ where
             .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
             .collect::<Vec<ListOption<&T>>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
-
-        backend.render_options(page)?;
+        match self.config.layout {
+            Layout::List => {
+                let mut page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+                page.prefixes = self.option_prefixes(page.content);
+                page.icons = self.option_icons(page.content);
+                backend.render_options(page)?;
+            }
+            Layout::Grid { columns } => {
+                let mut page = paginate_grid(
+                    columns,
+                    self.config.page_size,
+                    &choices,
+                    Some(self.cursor_index),
+                );
+                page.prefixes = self.option_prefixes(page.content);
+                page.icons = self.option_icons(page.content);
+                backend.render_options_grid(page, columns)?;
+            }
+        }
 
-        if let Some(help_message) = self.help_message {
+        let filtering = self.scoring_pass.is_some()
+            || self
+                .scoring_worker
+                .as_ref()
+                .is_some_and(ScoringWorker::is_busy);
+        if filtering {
+            backend.render_help_message(FILTERING_MESSAGE)?;
+        } else if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
         }
 
         Ok(())
     }
+
+    fn adaptive_page(&mut self) -> Option<&mut dyn AdaptivePagePrompt> {
+        Some(self)
+    }
 }
 
-impl<'a, T> SelectPrompt<'a, T>
+impl<'a, T> AdaptivePagePrompt for SelectPrompt<'a, T>
 where
     T: Display,
 {
-    fn redraw_with_adaptive_page_size<Backend>(&mut self, backend: &mut Backend) -> InquireResult<()>
-    where
-        Backend: SelectBackend,
-    {
-        let mut page_size = self.config.page_size.max(1);
-
-        loop {
-            backend.frame_setup()?;
-            self.render(backend)?;
-
-            // Use the height that would actually be flushed (max of last/current)
-            // to avoid scrolling when clearing a previously taller frame.
-            let frame_h = backend.current_flush_height().unwrap_or(0);
-            let term_h = backend.current_terminal_height().unwrap_or(u16::MAX);
-
-            if frame_h <= term_h || page_size <= 1 {
-                backend.frame_finish(false)?;
-                break;
-            }
-
-            // Oversized render: abort without flushing and reduce page size.
-            backend.frame_abort()?;
-
-            let mut new_size = (page_size as u32)
-                .saturating_mul(term_h.max(1) as u32)
-                .checked_div(frame_h.max(1) as u32)
-                .unwrap_or(1) as usize;
-
-            if new_size >= page_size {
-                new_size = page_size.saturating_sub(1).max(1);
-            }
+    fn page_size(&self) -> usize {
+        self.config.page_size
+    }
 
-            page_size = new_size;
-            self.config.page_size = page_size;
-            // Ensure cursor stays within bounds after size change.
-            let _ = self.update_cursor_position(self.cursor_index.min(self.scored_options.len().saturating_sub(1)));
-        }
+    fn set_page_size(&mut self, page_size: usize) {
+        self.config.page_size = page_size;
+    }
 
-        Ok(())
+    fn reconcile_after_resize(&mut self) {
+        // Ensure cursor stays within bounds after size change.
+        let _ = self.update_cursor_position(
+            self.cursor_index
+                .min(self.scored_options.len().saturating_sub(1)),
+        );
     }
 }
@@ -0,0 +1,53 @@
+use super::metadata::SelectMetadata;
+
+/// Snapshot of the interactive state of a [Select](crate::Select) prompt,
+/// used to resume a prompt where a previous one left off.
+///
+/// This is useful when running a prompt in a loop, e.g. pick an option, act
+/// on it and pick again, without making the user retype their filter. The
+/// state is restored with [Select::with_state](crate::Select::with_state),
+/// and the one of a submitted prompt is built from the
+/// [SelectMetadata] returned by
+/// [Select::prompt_with_details](crate::Select::prompt_with_details).
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{Select, SelectState};
+///
+/// let mut state = SelectState::default();
+///
+/// loop {
+///     let options = vec!["Banana", "Apple", "Strawberry"];
+///     let answer = Select::new("Fruit:", options)
+///         .with_state(state)
+///         .prompt_with_details()?;
+///
+///     println!("Picked {}, filter was {:?}", answer.option, answer.metadata.filter);
+///     state = answer.metadata.into();
+/// }
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectState {
+    /// Content of the filter input.
+    pub filter: String,
+
+    /// Position of the text cursor in the filter input, counted in
+    /// grapheme clusters.
+    pub filter_cursor: usize,
+
+    /// Position of the highlighted option in the list displayed to the user,
+    /// i.e. after filtering and sorting.
+    pub cursor_index: usize,
+}
+
+impl From<SelectMetadata> for SelectState {
+    fn from(metadata: SelectMetadata) -> Self {
+        Self {
+            filter: metadata.filter,
+            filter_cursor: metadata.filter_cursor,
+            cursor_index: metadata.list_position,
+        }
+    }
+}
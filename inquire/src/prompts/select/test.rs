@@ -3,8 +3,9 @@ use crate::{
     list_option::ListOption,
     terminal::test::match_text,
     test::fake_backend,
+    type_aliases::Scorer,
     ui::{Key, KeyModifiers},
-    Select,
+    InquireError, Intent, Layout, Select, SelectState,
 };
 
 #[test]
@@ -24,6 +25,34 @@ fn closure_formatter() {
     assert_eq!(ListOption::new(1, 2), ans);
 }
 
+#[test]
+fn vim_mode_moves_with_jk_and_filters_with_other_keys() {
+    let mut keys = Key::char_keys_from_str("jj3jk");
+    keys.push(Key::Enter);
+    let mut backend = fake_backend(keys);
+
+    let ans = Select::new("Question", vec![1, 2, 3, 4, 13])
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn vim_filter_mode_moves_in_normal_mode_and_filters_after_slash() {
+    let mut keys = Key::char_keys_from_str("jjggGk/3");
+    keys.extend([Key::Escape, Key::Char('j', KeyModifiers::NONE), Key::Enter]);
+    let mut backend = fake_backend(keys);
+
+    let ans = Select::new("Question", vec![1, 2, 3, 4])
+        .with_vim_filter_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
 #[test]
 // Anti-regression test: https://github.com/mikaelmello/inquire/issues/29
 fn enter_arrow_on_empty_list_does_not_panic() {
@@ -82,6 +111,7 @@ fn starting_cursor_is_respected() {
 }
 
 #[test]
+#[cfg(feature = "fuzzy")]
 fn naive_assert_fuzzy_match_as_default_scorer() {
     let mut backend = fake_backend(vec![
         Key::Char('w', KeyModifiers::NONE),
@@ -188,3 +218,852 @@ fn first_option_renders_on_new_line_without_filtering() {
     match_text(&mut output, "\r");
     match_text(&mut output, "\n");
 }
+
+#[test]
+fn page_size_is_reduced_to_fit_terminal_height() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(80, 5).unwrap());
+
+    let options = (0..10).map(|i| format!("Option {i}")).collect();
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::default(),
+        )
+        .unwrap();
+        let ans = Select::new("Question", options)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!(ListOption::new(0, String::from("Option 0")), ans);
+    }
+
+    // prompt + help message leave room for only 3 options in 5 rows.
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("Option 2"));
+    assert!(!rendered("Option 3"));
+}
+
+#[test]
+fn grid_layout_moves_cursor_in_two_dimensions() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = (0..10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_layout(Layout::Grid { columns: 4 })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 1), ans);
+}
+
+#[test]
+fn grid_layout_down_stays_on_last_row() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = (0..10).collect();
+
+    let ans = Select::new("Question", options)
+        .with_layout(Layout::Grid { columns: 3 })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // 3 -> 4 -> 7 -> 9 (last option, row below is partial) -> stays
+    assert_eq!(ListOption::new(9, 9), ans);
+}
+
+#[test]
+fn grid_layout_without_columns_is_invalid() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_layout(Layout::Grid { columns: 0 })
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn check_fit_reports_widest_option_and_tallest_page() {
+    let options = vec!["a", "a much longer option", "b", "c"];
+
+    let select = Select::new("Question", options)
+        .with_page_size(3)
+        .without_help_message();
+
+    let report = select.check_fit(20, 10);
+
+    assert_eq!(Some(1), report.widest_option_index);
+    assert_eq!(22, report.widest_option_width);
+    // the longer option is wrapped to a second row
+    assert_eq!(5, report.tallest_page_height);
+    assert!(!report.fits_width());
+    assert!(report.fits_height());
+}
+
+#[test]
+fn check_fit_accounts_for_options_wrapped_to_the_terminal_width() {
+    let long = "x".repeat(50);
+    let options = vec!["a", long.as_str(), "b", "c", "d", "e"];
+
+    let select = Select::new("Question", options)
+        .with_page_size(5)
+        .without_help_message();
+
+    // the page holding the 52-column option takes 3 rows for it
+    let report = select.check_fit(20, 7);
+
+    assert_eq!(1 + 3 + 4, report.tallest_page_height);
+    assert!(!report.fits_height());
+    assert!(select.check_fit(60, 7).fits());
+}
+
+#[test]
+fn check_fit_accounts_for_multi_line_and_wrapped_messages() {
+    let select =
+        Select::new("Pick one\nof the options:", vec!["a", "b", "c"]).without_help_message();
+
+    // "? Pick one" and "  of the options: ", the latter wrapped to a second row
+    let report = select.check_fit(12, 10);
+
+    assert_eq!(18, report.prompt_width);
+    assert_eq!(6, report.tallest_page_height);
+}
+
+#[test]
+fn option_prefixes_are_padded_to_a_common_width() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(80, 10).unwrap());
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::empty(),
+        )
+        .unwrap();
+        let ans = Select::new("Question", vec!["docs", "src"])
+            .with_option_prefix(&|option| match *option {
+                "docs" => String::from("📁"),
+                _ => String::from("x"),
+            })
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!(ListOption::new(0, "docs"), ans);
+    }
+
+    let texts = output
+        .iter()
+        .filter_map(|token| match token {
+            MockTerminalToken::Text(text) => Some(text.content.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<&str>>();
+
+    // the icon is two columns wide, so the narrower one gets an extra space
+    assert!(texts.windows(5).any(|w| w == [">", " ", "📁", " ", "docs"]));
+    assert!(texts.windows(5).any(|w| w == [" ", " ", "x", "  ", "src"]));
+}
+
+#[test]
+fn prompt_with_details_returns_filter_match() {
+    let mut backend = fake_backend(vec![
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Lisbon", "New York", "Tokyo"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+
+    let option_match = ans.matched.expect("filter was typed");
+    assert_eq!(ListOption::new(1, "New York"), ans.option);
+    assert_eq!("yo", option_match.filter);
+    assert_eq!(vec![4..6], option_match.spans);
+}
+
+#[test]
+fn prompt_with_details_without_filter_has_no_match() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 1), ans.option);
+    assert_eq!(None, ans.matched);
+}
+
+#[test]
+fn prompt_with_match_returns_the_option_with_its_match() {
+    let mut backend = fake_backend(vec![
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let (ans, option_match) = Select::new("Question", vec!["Lisbon", "New York", "Tokyo"])
+        .prompt_with_match_and_backend(&mut backend)
+        .unwrap();
+
+    let option_match = option_match.expect("filter was typed");
+    assert_eq!(ListOption::new(1, "New York"), ans);
+    assert_eq!("yo", option_match.filter);
+    assert_eq!(vec![4..6], option_match.spans);
+}
+
+#[test]
+fn sorter_changes_display_order_but_keeps_original_indices() {
+    let mut backend = fake_backend(vec![Key::Home, Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["item10", "item2", "item1"];
+
+    let ans = Select::new("Question", options)
+        .with_sorter(Select::NATURAL_SORTER)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "item2"), ans);
+}
+
+#[test]
+fn starting_cursor_highlights_the_given_option_with_sorter() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["c", "a", "b"])
+        .with_sorter(Select::ALPHABETICAL_SORTER)
+        .with_starting_cursor(0)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "c"), ans);
+}
+
+#[test]
+fn scorer_order_is_used_while_filtering_with_sorter() {
+    let mut backend = fake_backend(vec![Key::Char('b', KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["ab", "b", "a"];
+
+    let ans = Select::new("Question", options)
+        .with_sorter(Select::ALPHABETICAL_SORTER)
+        .with_scorer(&|input, _option, value, _idx| {
+            value.contains(input).then(|| -(value.len() as i64))
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn duplicate_options_keep_their_original_indices_and_order() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["b", "a", "b", "a"];
+
+    let ans = Select::new("Question", options)
+        .with_sorter(Select::ALPHABETICAL_SORTER)
+        .with_scorer(&|input, _option, value, _idx| value.contains(input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, "a"), ans);
+}
+
+#[test]
+fn prompt_with_details_returns_filter_and_cursor_positions() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["apple", "banana", "apricot"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|input, _option, value, idx| value.contains(input).then(|| -(idx as i64)))
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    let metadata = ans.metadata;
+
+    assert_eq!(ListOption::new(2, "apricot"), ans.option);
+    assert_eq!("ap", metadata.filter);
+    assert_eq!(1, metadata.filter_cursor);
+    assert_eq!(1, metadata.list_position);
+}
+
+#[test]
+fn prompt_with_details_without_filter_has_empty_filter() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .without_filtering()
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    let metadata = ans.metadata;
+
+    assert_eq!(ListOption::new(1, 2), ans.option);
+    assert_eq!("", metadata.filter);
+    assert_eq!(0, metadata.filter_cursor);
+    assert_eq!(1, metadata.list_position);
+}
+
+#[test]
+fn metadata_restores_the_state_of_a_following_prompt() {
+    let options = || vec!["apple", "banana", "apricot", "avocado"];
+    let scorer: Scorer<'_, &str> =
+        &|input, _option, value, idx| value.contains(input).then(|| -(idx as i64));
+
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = Select::new("Question", options())
+        .with_scorer(scorer)
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    let state = SelectState::from(ans.metadata);
+
+    assert_eq!(ListOption::new(2, "apricot"), ans.option);
+    assert_eq!(
+        SelectState {
+            filter: String::from("ap"),
+            filter_cursor: 2,
+            cursor_index: 1,
+        },
+        state
+    );
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", options())
+        .with_scorer(scorer)
+        .with_state(state)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "apricot"), ans);
+}
+
+#[test]
+fn restored_cursor_is_clamped_to_filtered_options() {
+    let state = SelectState {
+        filter: String::from("an"),
+        filter_cursor: 10,
+        cursor_index: 5,
+    };
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", vec!["apple", "banana", "mango"])
+        .with_scorer(&|input, _option, value, idx| value.contains(input).then(|| -(idx as i64)))
+        .with_state(state)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "mango"), ans);
+}
+
+#[test]
+#[cfg(feature = "normalization")]
+fn normalization_lets_plain_filter_match_accented_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('o', KeyModifiers::NONE),
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Maria", "JOSÉ", "Joana"];
+
+    let ans = Select::new("Question", options)
+        .with_normalization(crate::Normalization::ALL)
+        .with_scorer(&|input, _option, value, _idx| (value == input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "JOSÉ"), ans);
+}
+
+#[test]
+fn new_borrowed_returns_reference_to_selected_option() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec![String::from("Lisbon"), String::from("Tokyo")];
+
+    let ans = Select::new_borrowed("Question", &options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+    assert!(std::ptr::eq(ans.value, options.get(1).unwrap()));
+}
+
+#[test]
+#[cfg(feature = "transliteration")]
+fn ascii_transliterator_matches_non_latin_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('i', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["東京", "北京", "Москва"];
+
+    let ans = Select::new("Question", options)
+        .with_transliterator(crate::transliteration::AsciiTransliterator)
+        .with_scorer(&|input, _option, value, _idx| {
+            value.to_lowercase().starts_with(input).then_some(0)
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "北京"), ans);
+}
+
+#[test]
+fn icons_are_rendered_before_prefixes_and_fall_back_to_ascii() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, Icon, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let render = |icon_fallback: bool| {
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(80, 10).unwrap());
+
+        {
+            let mut backend = Backend::new(
+                vec![Key::Char('s', KeyModifiers::NONE), Key::Enter].into_iter(),
+                terminal,
+                RenderConfig::empty().with_icon_fallback(icon_fallback),
+            )
+            .unwrap();
+            let ans = Select::new("Question", vec!["docs", "src"])
+                .with_icons(&|option| match *option {
+                    "docs" => Icon::new("📁", "d"),
+                    _ => Icon::new("🦀", "rs"),
+                })
+                .with_option_prefix(&|_| String::from("-"))
+                .with_scorer(&|input, _option, value, _idx| value.starts_with(input).then_some(0))
+                .without_help_message()
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+            assert_eq!(ListOption::new(1, "src"), ans);
+        }
+
+        output
+            .into_iter()
+            .filter_map(|token| match token {
+                MockTerminalToken::Text(text) => Some(text.content),
+                _ => None,
+            })
+            .collect::<Vec<String>>()
+    };
+
+    let glyphs = render(false);
+    assert!(glyphs
+        .windows(5)
+        .any(|w| w == ["📁", " ", "-", " ", "docs"]));
+    assert!(glyphs.windows(5).any(|w| w == ["🦀", " ", "-", " ", "src"]));
+
+    let fallbacks = render(true);
+    assert!(fallbacks
+        .windows(5)
+        .any(|w| w == ["d", "  ", "-", " ", "docs"]));
+    assert!(fallbacks
+        .windows(5)
+        .any(|w| w == ["rs", " ", "-", " ", "src"]));
+}
+
+#[test]
+fn virtualization_only_rescores_previous_matches_when_filter_is_extended() {
+    use std::cell::Cell;
+
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+
+    let ans = Select::new("Question", (0..1000).collect())
+        .with_virtualization(true)
+        .with_scorer(scorer)
+        .with_incremental_scoring(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.value.to_string().contains("12"));
+    // no calls for the empty filter, all options for "1", and only the
+    // 271 numbers containing a "1" for "12".
+    assert_eq!(1000 + 271, calls.get());
+}
+
+#[test]
+fn virtualization_without_incremental_scoring_rescores_all_options() {
+    use std::cell::Cell;
+
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+
+    let ans = Select::new("Question", (0..1000).collect())
+        .with_virtualization(true)
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.value.to_string().contains("12"));
+    assert_eq!(1000 + 1000, calls.get());
+}
+
+#[test]
+fn virtualization_caps_the_options_scored_per_key_press() {
+    use std::cell::Cell;
+
+    use crate::{
+        prompts::test::PollingInput,
+        terminal::test::NullTerminal,
+        ui::{Backend, RenderConfig},
+    };
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+    let prompt = || {
+        Select::new("Question", (0..25_000).collect())
+            .with_virtualization(true)
+            .with_scorer(scorer)
+    };
+
+    // the first 10,000 options are scored on the key press, and the next
+    // ones on the tick preceding the next key press
+    let mut backend = fake_backend(vec![Key::Char('1', KeyModifiers::NONE), Key::Enter]);
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(1, ans.value);
+    assert_eq!(20_000, calls.get());
+
+    // the other options are scored while the user doesn't type
+    calls.set(0);
+    let input = PollingInput::new(
+        vec![Key::Char('1', KeyModifiers::NONE)],
+        3,
+        vec![Key::End, Key::Enter],
+    );
+    let mut backend = Backend::new(input, NullTerminal, RenderConfig::default()).unwrap();
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(24_991, ans.value);
+    assert_eq!(25_000, calls.get());
+}
+
+#[test]
+fn virtualization_rescores_all_options_when_filter_is_shortened() {
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char('3', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["12", "31", "13"];
+
+    let ans = Select::new("Question", options)
+        .with_virtualization(true)
+        .with_scorer(&|input, _option, value, _idx| value.starts_with(input).then_some(0))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "13"), ans);
+}
+
+#[test]
+fn incremental_scoring_reuses_cached_scores_when_filter_is_shortened() {
+    use std::cell::Cell;
+
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+
+    let ans = Select::new("Question", (0..1000).collect())
+        .with_scorer(scorer)
+        .with_incremental_scoring(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.value.to_string().contains('1'));
+    // all options for the empty filter and for "1", only the 271 numbers
+    // containing a "1" for "12", and none when going back to "1".
+    assert_eq!(1000 + 1000 + 271, calls.get());
+}
+
+#[test]
+fn custom_scorer_disables_incremental_scoring() {
+    use std::cell::Cell;
+
+    let mut backend = fake_backend(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let calls = Cell::new(0);
+    let scorer: Scorer<'_, usize> = &|input, _option, value, _idx| {
+        calls.set(calls.get() + 1);
+        value.contains(input).then_some(0)
+    };
+
+    Select::new("Question", (0..1000).collect())
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(4 * 1000, calls.get());
+}
+
+#[test]
+fn background_scoring_updates_options_when_results_arrive() {
+    use crate::{
+        prompts::test::PollingInput,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+        BackgroundScoring,
+    };
+    use std::{collections::VecDeque, time::Duration};
+
+    let scoring = BackgroundScoring::new(|input, value, _idx| value.contains(input).then_some(0))
+        .with_debounce(Duration::ZERO);
+    let options = vec!["tokio", "serde", "serde_json", "rayon"];
+
+    let input = PollingInput::new(
+        vec![Key::Char('j', KeyModifiers::NONE)],
+        20,
+        vec![Key::Enter],
+    );
+
+    let mut output = VecDeque::new();
+    let ans = {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+        Select::new("Package", options)
+            .with_background_scoring(scoring)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(2, "serde_json"), ans);
+
+    let filtering_rendered = output.iter().any(
+        |token| matches!(token, MockTerminalToken::Text(t) if t.content.contains("filtering…")),
+    );
+    assert!(filtering_rendered);
+}
+
+#[test]
+fn background_scoring_restores_all_options_when_filter_is_cleared() {
+    use crate::{
+        prompts::test::PollingInput,
+        terminal::test::NullTerminal,
+        ui::{Backend, RenderConfig},
+        BackgroundScoring,
+    };
+    use std::time::Duration;
+
+    let scoring = BackgroundScoring::new(|input, value, _idx| value.contains(input).then_some(0))
+        .with_debounce(Duration::ZERO);
+    let options = vec!["tokio", "serde", "serde_json", "rayon"];
+
+    let input = PollingInput::new(
+        vec![Key::Char('j', KeyModifiers::NONE)],
+        20,
+        vec![Key::Backspace, Key::Down(KeyModifiers::NONE), Key::Enter],
+    );
+    let terminal = NullTerminal;
+    let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Package", options)
+        .with_background_scoring(scoring)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "serde"), ans);
+}
+
+#[test]
+#[cfg(feature = "instrument")]
+fn instrument_records_phases_of_each_frame() {
+    use crate::instrument::{self, Phase};
+
+    let mut backend = fake_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+
+    instrument::start_recording();
+    Select::new("Question", vec!["a", "b"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    let trace = instrument::stop_recording();
+
+    let phases = trace
+        .events()
+        .iter()
+        .map(|event| event.phase)
+        .collect::<Vec<_>>();
+
+    for phase in [
+        Phase::Render,
+        Phase::Flush,
+        Phase::InputWait,
+        Phase::Scoring,
+    ] {
+        assert!(phases.contains(&phase), "missing {:?}", phase);
+    }
+    assert!(trace.events().iter().any(|event| event.frame >= 2));
+    assert!(trace.to_chrome_json().contains("\"name\":\"scoring\""));
+}
+
+#[test]
+fn viewer_mode_ignores_submit_and_closes_on_cancel() {
+    let mut keys = vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Escape,
+    ]
+    .into_iter();
+
+    let terminal = crate::terminal::test::NullTerminal;
+    let mut backend =
+        crate::ui::Backend::new(keys.by_ref(), terminal, crate::ui::RenderConfig::default())
+            .unwrap();
+
+    let result = Select::new("Question", vec!["a", "b", "c"]).view_with_backend(&mut backend);
+
+    assert!(result.is_ok());
+    assert_eq!(None, keys.next());
+}
+
+#[test]
+fn intent_keys_submit_highlighted_option_with_their_intent() {
+    let select = || {
+        Select::new("Question", vec!["a", "b", "c"])
+            .without_filtering()
+            .with_intent(Key::Char('d', KeyModifiers::NONE), "delete")
+            .with_intent(Key::Char('o', KeyModifiers::CONTROL), "open-in-new")
+    };
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+    ]);
+    let ans = select()
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(1, "b"), ans.option);
+    assert_eq!(Intent::Custom("delete"), ans.intent);
+
+    let mut backend = fake_backend(vec![Key::Char('o', KeyModifiers::CONTROL)]);
+    let ans = select()
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(0, "a"), ans.option);
+    assert_eq!(Intent::Custom("open-in-new"), ans.intent);
+
+    let mut backend = fake_backend(vec![Key::Char('x', KeyModifiers::NONE), Key::Enter]);
+    let ans = select()
+        .prompt_with_details_and_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(0, "a"), ans.option);
+    assert_eq!(Intent::Submit, ans.intent);
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+    ]);
+    let ans = select()
+        .prompt_with_intent_and_backend(&mut backend)
+        .unwrap();
+    assert_eq!((ListOption::new(1, "b"), Intent::Custom("delete")), ans);
+}
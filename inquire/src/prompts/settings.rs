@@ -0,0 +1,407 @@
+use crate::{
+    cancellation::CancellationToken,
+    config::{NonInteractiveBehavior, OutputStream},
+    event::OnChange,
+    formatter::StyledAnswerFormatter,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyHandler},
+    terminal::CustomBackend,
+    ui::{AnswerRedaction, RenderAfterSubmit, StyledText},
+    validator::ErrorMessage,
+};
+
+/// Settings shared by all prompts, such as the terminal they are rendered to,
+/// what is left on the screen once they are submitted or how they are
+/// canceled.
+///
+/// Each prompt holds its settings in its `settings` field, and its builders
+/// for them, e.g. [Text::with_cancel_guard](crate::Text::with_cancel_guard),
+/// forward to the ones of this struct. Settings built once can be given to
+/// several prompts with their `with_settings` builder.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use inquire::{key_bindings::CancelGuard, Confirm, OutputStream, PromptSettings, Text};
+///
+/// let settings = PromptSettings::new()
+///     .with_output_stream(OutputStream::Stdout)
+///     .with_cancel_guard(CancelGuard::PressTwice(Duration::from_secs(1)));
+///
+/// let name = Text::new("What's your name?")
+///     .with_settings(settings.clone())
+///     .prompt()?;
+/// let proceed = Confirm::new("Proceed?").with_settings(settings).prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct PromptSettings<'a> {
+    /// What the prompt does when stdin or the terminal output is not a TTY.
+    /// When `None`, the global [NonInteractiveBehavior] is used, which fails
+    /// by default.
+    pub non_interactive_behavior: Option<NonInteractiveBehavior>,
+
+    /// Name of an environment variable that, when set, answers the prompt
+    /// with its value instead of waiting for user input.
+    pub env_override: Option<&'a str>,
+
+    /// Token to cancel the prompt from another thread or task, in which case
+    /// it returns [InquireError::OperationCanceled](crate::InquireError::OperationCanceled).
+    pub cancellation: Option<CancellationToken>,
+
+    /// Input reader and terminal used instead of the default ones. When
+    /// `None`, the global backend set with
+    /// [set_global_backend](crate::set_global_backend) is used, if any.
+    pub backend: Option<CustomBackend>,
+
+    /// Standard stream the prompt is rendered to. When `None`, the global
+    /// [OutputStream] is used, which is stderr by default.
+    pub output_stream: Option<OutputStream>,
+
+    /// Whether the prompt is rendered in the alternate screen of the
+    /// terminal, using its whole height. Defaults to false.
+    pub fullscreen: bool,
+
+    /// What is left on the screen once the prompt is submitted. Defaults to
+    /// the prompt message followed by the formatted answer.
+    pub render_after_submit: RenderAfterSubmit,
+
+    /// Replacement of the answer rendered once the prompt is submitted, for
+    /// sensitive answers. Defaults to `None`, rendering the formatted answer.
+    pub answer_redaction: Option<AnswerRedaction<'a>>,
+
+    /// Styled version of the prompt message, rendered instead of it when
+    /// they have the same content.
+    pub styled_message: Option<&'a StyledText>,
+
+    /// Styled version of the help message, rendered instead of it when they
+    /// have the same content.
+    pub styled_help_message: Option<&'a StyledText>,
+
+    /// Function styling the formatted answer rendered once the prompt is
+    /// submitted.
+    pub styled_answer_formatter: Option<StyledAnswerFormatter<'a>>,
+
+    /// Hook called with the changes made by the user while the prompt is
+    /// active. See [PromptEvent](crate::event::PromptEvent).
+    pub on_change: Option<OnChange<'a>>,
+
+    /// Handler called with the keys pressed by the user before they are
+    /// parsed into actions. See [KeyHandler].
+    pub key_handler: Option<KeyHandler<'a>>,
+
+    /// What pressing Escape does. See [EscapeBehavior].
+    pub escape_behavior: EscapeBehavior,
+
+    /// Guard against canceling the prompt by accident. See [CancelGuard].
+    pub cancel_guard: CancelGuard,
+
+    /// Error message displayed while the prompt has none of its own, e.g.
+    /// the one of a form validator asking a question again.
+    pub(crate) initial_error: Option<ErrorMessage>,
+}
+
+impl<'a> PromptSettings<'a> {
+    /// Creates the default settings of prompts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets what the prompt does when stdin or the terminal output is not
+    /// a TTY, e.g. returning its default answer when running in a script.
+    ///
+    /// Overrides the behavior set with
+    /// [set_global_non_interactive_behavior](crate::set_global_non_interactive_behavior).
+    pub fn with_non_interactive_behavior(mut self, behavior: NonInteractiveBehavior) -> Self {
+        self.non_interactive_behavior = Some(behavior);
+        self
+    }
+
+    /// Sets the name of an environment variable that answers the prompt when
+    /// it is set, e.g. `MYTOOL_REGION`, so that scripts can skip it.
+    ///
+    /// The value goes through the same parsing and validation as a typed
+    /// answer, failing with [InquireError::InvalidAnswer](crate::InquireError::InvalidAnswer)
+    /// when rejected. The prompt and its answer are still printed.
+    pub fn with_env_override(mut self, var: &'a str) -> Self {
+        self.env_override = Some(var);
+        self
+    }
+
+    /// Sets a token to cancel the prompt from another thread or task, e.g. on
+    /// a timeout. The prompt is then rendered as canceled and returns
+    /// [InquireError::OperationCanceled](crate::InquireError::OperationCanceled).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Sets the input reader and terminal used to display the prompt instead
+    /// of the default ones, e.g. to render it in an embedded terminal widget.
+    pub fn with_backend(mut self, backend: CustomBackend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Sets the standard stream the prompt is rendered to, e.g. stdout for
+    /// applications logging to stderr. Ignored when a custom backend is set.
+    ///
+    /// Overrides the stream set with
+    /// [set_global_output_stream](crate::set_global_output_stream).
+    pub fn with_output_stream(mut self, stream: OutputStream) -> Self {
+        self.output_stream = Some(stream);
+        self
+    }
+
+    /// Sets whether the prompt is rendered in the alternate screen of the
+    /// terminal, which is restored once the prompt is answered, leaving only
+    /// the answered prompt in the scrollback.
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets what is left on the screen once the prompt is submitted, e.g.
+    /// nothing at all to keep the terminal clean.
+    pub fn with_render_after_submit(mut self, render_after_submit: RenderAfterSubmit) -> Self {
+        self.render_after_submit = render_after_submit;
+        self
+    }
+
+    /// Sets what is rendered instead of the answer once the prompt is
+    /// submitted, regardless of the formatter.
+    pub fn with_answer_redaction(mut self, answer_redaction: AnswerRedaction<'a>) -> Self {
+        self.answer_redaction = Some(answer_redaction);
+        self
+    }
+
+    /// Sets the styled version of the prompt message, e.g. to emphasize part
+    /// of the question.
+    ///
+    /// The `with_styled_message` builders of the prompts also set their
+    /// message to its content.
+    pub fn with_styled_message(mut self, message: &'a StyledText) -> Self {
+        self.styled_message = Some(message);
+        self
+    }
+
+    /// Sets the styled version of the help message.
+    ///
+    /// The `with_styled_help_message` builders of the prompts also set their
+    /// help message to its content.
+    pub fn with_styled_help_message(mut self, message: &'a StyledText) -> Self {
+        self.styled_help_message = Some(message);
+        self
+    }
+
+    /// Sets the function styling the answer rendered once the prompt is
+    /// submitted, which receives the output of the formatter.
+    pub fn with_styled_answer_formatter(mut self, formatter: StyledAnswerFormatter<'a>) -> Self {
+        self.styled_answer_formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the hook called with the changes made by the user while the
+    /// prompt is active, e.g. to preview the highlighted option.
+    pub fn with_on_change(mut self, on_change: OnChange<'a>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Sets the handler called with the keys pressed by the user before the
+    /// prompt parses them into actions, e.g. to implement custom shortcuts.
+    pub fn with_key_handler(mut self, key_handler: KeyHandler<'a>) -> Self {
+        self.key_handler = Some(key_handler);
+        self
+    }
+
+    /// Sets what pressing Escape does, e.g. clearing the input before
+    /// canceling the prompt.
+    pub fn with_escape_behavior(mut self, escape_behavior: EscapeBehavior) -> Self {
+        self.escape_behavior = escape_behavior;
+        self
+    }
+
+    /// Sets the guard asking the user to confirm canceling the prompt once
+    /// they entered something, e.g. by pressing Escape twice.
+    pub fn with_cancel_guard(mut self, cancel_guard: CancelGuard) -> Self {
+        self.cancel_guard = cancel_guard;
+        self
+    }
+}
+
+/// Implements the builders of the [PromptSettings] of a prompt, which forward
+/// to the ones of its `settings` field.
+macro_rules! prompt_settings_builders {
+    ($lt:lifetime) => {
+        /// Sets all the [settings](crate::PromptSettings) shared by prompts at
+        /// once, replacing the ones set so far.
+        pub fn with_settings(mut self, settings: crate::PromptSettings<$lt>) -> Self {
+            self.settings = settings;
+            self
+        }
+
+        /// Sets what the prompt does when stdin or the terminal output is not
+        /// a TTY. See [PromptSettings::with_non_interactive_behavior](crate::PromptSettings::with_non_interactive_behavior).
+        pub fn with_non_interactive_behavior(
+            mut self,
+            behavior: crate::NonInteractiveBehavior,
+        ) -> Self {
+            self.settings = self.settings.with_non_interactive_behavior(behavior);
+            self
+        }
+
+        /// Sets the name of an environment variable answering the prompt. See
+        /// [PromptSettings::with_env_override](crate::PromptSettings::with_env_override).
+        pub fn with_env_override(mut self, var: &$lt str) -> Self {
+            self.settings = self.settings.with_env_override(var);
+            self
+        }
+
+        /// Sets a token to cancel the prompt from another thread or task. See
+        /// [PromptSettings::with_cancellation](crate::PromptSettings::with_cancellation).
+        pub fn with_cancellation(mut self, token: crate::CancellationToken) -> Self {
+            self.settings = self.settings.with_cancellation(token);
+            self
+        }
+
+        /// Sets the input reader and terminal used to display the prompt. See
+        /// [PromptSettings::with_backend](crate::PromptSettings::with_backend).
+        pub fn with_backend(mut self, backend: crate::ui::CustomBackend) -> Self {
+            self.settings = self.settings.with_backend(backend);
+            self
+        }
+
+        /// Sets the standard stream the prompt is rendered to. See
+        /// [PromptSettings::with_output_stream](crate::PromptSettings::with_output_stream).
+        pub fn with_output_stream(mut self, stream: crate::OutputStream) -> Self {
+            self.settings = self.settings.with_output_stream(stream);
+            self
+        }
+
+        /// Sets whether the prompt is rendered in the alternate screen. See
+        /// [PromptSettings::with_fullscreen](crate::PromptSettings::with_fullscreen).
+        pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+            self.settings = self.settings.with_fullscreen(fullscreen);
+            self
+        }
+
+        /// Sets what is left on the screen once the prompt is submitted. See
+        /// [PromptSettings::with_render_after_submit](crate::PromptSettings::with_render_after_submit).
+        pub fn with_render_after_submit(
+            mut self,
+            render_after_submit: crate::ui::RenderAfterSubmit,
+        ) -> Self {
+            self.settings = self.settings.with_render_after_submit(render_after_submit);
+            self
+        }
+
+        /// Sets what is rendered instead of the answer once the prompt is
+        /// submitted. See [PromptSettings::with_answer_redaction](crate::PromptSettings::with_answer_redaction).
+        pub fn with_answer_redaction(
+            mut self,
+            answer_redaction: crate::ui::AnswerRedaction<$lt>,
+        ) -> Self {
+            self.settings = self.settings.with_answer_redaction(answer_redaction);
+            self
+        }
+
+        /// Sets the prompt message from styled fragments, e.g. to emphasize
+        /// part of the question. See [PromptSettings::with_styled_message](crate::PromptSettings::with_styled_message).
+        pub fn with_styled_message(mut self, message: &$lt crate::ui::StyledText) -> Self {
+            self.message = message.as_str();
+            self.settings = self.settings.with_styled_message(message);
+            self
+        }
+
+        /// Sets the help message from styled fragments. See
+        /// [PromptSettings::with_styled_help_message](crate::PromptSettings::with_styled_help_message).
+        pub fn with_styled_help_message(mut self, message: &$lt crate::ui::StyledText) -> Self {
+            self.help_message = Some(message.as_str());
+            self.settings = self.settings.with_styled_help_message(message);
+            self
+        }
+
+        /// Sets the function styling the answer rendered once the prompt is
+        /// submitted. See [PromptSettings::with_styled_answer_formatter](crate::PromptSettings::with_styled_answer_formatter).
+        pub fn with_styled_answer_formatter(
+            mut self,
+            formatter: crate::formatter::StyledAnswerFormatter<$lt>,
+        ) -> Self {
+            self.settings = self.settings.with_styled_answer_formatter(formatter);
+            self
+        }
+
+        /// Sets the hook called with the changes made by the user while the
+        /// prompt is active. See [PromptSettings::with_on_change](crate::PromptSettings::with_on_change).
+        pub fn with_on_change(mut self, on_change: crate::event::OnChange<$lt>) -> Self {
+            self.settings = self.settings.with_on_change(on_change);
+            self
+        }
+
+        /// Sets the handler called with the keys pressed by the user. See
+        /// [PromptSettings::with_key_handler](crate::PromptSettings::with_key_handler).
+        pub fn with_key_handler(mut self, key_handler: crate::key_bindings::KeyHandler<$lt>) -> Self {
+            self.settings = self.settings.with_key_handler(key_handler);
+            self
+        }
+
+        /// Sets what pressing Escape does. See
+        /// [PromptSettings::with_escape_behavior](crate::PromptSettings::with_escape_behavior).
+        pub fn with_escape_behavior(
+            mut self,
+            escape_behavior: crate::key_bindings::EscapeBehavior,
+        ) -> Self {
+            self.settings = self.settings.with_escape_behavior(escape_behavior);
+            self
+        }
+
+        /// Sets the guard against canceling the prompt by accident. See
+        /// [PromptSettings::with_cancel_guard](crate::PromptSettings::with_cancel_guard).
+        pub fn with_cancel_guard(mut self, cancel_guard: crate::key_bindings::CancelGuard) -> Self {
+            self.settings = self.settings.with_cancel_guard(cancel_guard);
+            self
+        }
+    };
+}
+
+pub(crate) use prompt_settings_builders;
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        key_bindings::EscapeBehavior,
+        list_option::ListOption,
+        test::fake_backend,
+        ui::{Key, KeyModifiers},
+        Select, Text,
+    };
+
+    use super::PromptSettings;
+
+    #[test]
+    fn settings_can_be_shared_by_prompts() {
+        let settings = PromptSettings::new().with_escape_behavior(EscapeBehavior::Ignore);
+
+        let mut backend = fake_backend(vec![
+            Key::Char('a', KeyModifiers::NONE),
+            Key::Escape,
+            Key::Enter,
+        ]);
+        let ans = Text::new("Question")
+            .with_settings(settings.clone())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("a", ans);
+
+        let mut backend = fake_backend(vec![Key::Escape, Key::Enter]);
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_settings(settings)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(0, 1), ans);
+    }
+}
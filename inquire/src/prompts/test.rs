@@ -1,5 +1,7 @@
+use std::{collections::VecDeque, thread, time::Duration};
+
 use crate::{
-    terminal::crossterm::CrosstermTerminal,
+    terminal::test::NullTerminal,
     ui::{Backend, InputReader, Key, RenderConfig},
 };
 
@@ -17,7 +19,46 @@ where
     }
 }
 
-pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, CrosstermTerminal> {
-    let output = CrosstermTerminal::new_in_memory_output();
+pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, NullTerminal> {
+    let output = NullTerminal;
     Backend::new(input.into_iter(), output, RenderConfig::default()).unwrap()
 }
+
+/// Input reader that supports polling, where `None` entries simulate
+/// intervals in which the user pressed no key.
+pub struct PollingInput(pub VecDeque<Option<Key>>);
+
+impl PollingInput {
+    /// Input where `keys` are followed by `waits` idle intervals, and
+    /// then by `after`.
+    pub fn new(keys: Vec<Key>, waits: usize, after: Vec<Key>) -> Self {
+        let keys = keys.into_iter().map(Some);
+        let waits = (0..waits).map(|_| None);
+        let after = after.into_iter().map(Some);
+
+        Self(keys.chain(waits).chain(after).collect())
+    }
+}
+
+impl InputReader for PollingInput {
+    fn read_key(&mut self) -> crate::error::InquireResult<Key> {
+        while let Some(entry) = self.0.pop_front() {
+            if let Some(key) = entry {
+                return Ok(key);
+            }
+        }
+
+        panic!("EOF")
+    }
+
+    fn poll_key(&mut self, timeout: Duration) -> crate::error::InquireResult<Option<Key>> {
+        match self.0.pop_front() {
+            Some(Some(key)) => Ok(Some(key)),
+            Some(None) => {
+                thread::sleep(timeout);
+                Ok(None)
+            }
+            None => panic!("EOF"),
+        }
+    }
+}
@@ -12,8 +12,10 @@ pub enum TextPromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
     /// When a suggestion list exists, moves the cursor to the option above.
+    /// Otherwise, recalls the previous entry of the history, if any.
     MoveToSuggestionAbove,
-    /// When a suggestion list exists, moves the cursor to the option below.
+    /// When browsing the history, recalls the next entry. Otherwise, when a
+    /// suggestion list exists, moves the cursor to the option below.
     MoveToSuggestionBelow,
     /// When a suggestion list exists, moves the cursor to the page above.
     MoveToSuggestionPageUp,
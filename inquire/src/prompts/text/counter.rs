@@ -0,0 +1,140 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Unit in which an [InputCounter] measures the input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CountMeasure {
+    /// Unicode scalar values, as returned by [`str::chars`].
+    Chars,
+
+    /// Grapheme clusters, i.e. characters as perceived by the user. This is
+    /// how the `max_length` and `min_length` validators measure the input.
+    #[default]
+    Graphemes,
+
+    /// Bytes of the UTF-8 encoded input.
+    Bytes,
+
+    /// Words, as delimited by the Unicode word boundaries.
+    Words,
+}
+
+impl CountMeasure {
+    /// Measures the provided text.
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            Self::Chars => text.chars().count(),
+            Self::Graphemes => text.graphemes(true).count(),
+            Self::Bytes => text.len(),
+            Self::Words => text.unicode_words().count(),
+        }
+    }
+}
+
+/// Where an [InputCounter] is rendered.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CounterPlacement {
+    /// At the end of the input line, after the text typed by the user.
+    #[default]
+    EndOfLine,
+
+    /// In the help area, below the input and its suggestions.
+    HelpMessage,
+}
+
+/// Counter of a [Text](crate::Text) prompt, displaying the length of the
+/// input as the user types, optionally next to its maximum, e.g. `97/140`.
+///
+/// At the end of the input line, the counter is rendered with the style of
+/// the help message, switching to the style of error messages once the
+/// maximum is exceeded. It doesn't prevent the user from typing past the
+/// maximum: combine it with the `max_length` validator to reject such answers.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{max_length, CountMeasure, InputCounter, Text};
+///
+/// let status = Text::new("Status:")
+///     .with_counter(InputCounter::new(CountMeasure::Graphemes).with_max(140))
+///     .with_validator(max_length!(140))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InputCounter {
+    measure: CountMeasure,
+    max: Option<usize>,
+    placement: CounterPlacement,
+}
+
+impl InputCounter {
+    /// Creates a counter measuring the input in the provided unit, rendered
+    /// at the end of the input line.
+    pub fn new(measure: CountMeasure) -> Self {
+        Self {
+            measure,
+            max: None,
+            placement: CounterPlacement::default(),
+        }
+    }
+
+    /// Sets the maximum displayed next to the count.
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets where the counter is rendered.
+    pub fn with_placement(mut self, placement: CounterPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Where the counter is rendered.
+    pub fn placement(&self) -> CounterPlacement {
+        self.placement
+    }
+
+    /// Text of the counter for the provided input.
+    pub fn format(&self, input: &str) -> String {
+        let count = self.measure.count(input);
+
+        match self.max {
+            Some(max) => format!("{count}/{max}"),
+            None => count.to_string(),
+        }
+    }
+
+    /// Whether the provided input is longer than the maximum.
+    pub fn is_exceeded(&self, input: &str) -> bool {
+        match self.max {
+            Some(max) => self.measure.count(input) > max,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CountMeasure, InputCounter};
+
+    #[test]
+    fn measures_count_different_units() {
+        let text = "héllo wörld 👋🏽";
+
+        assert_eq!(14, CountMeasure::Chars.count(text));
+        assert_eq!(13, CountMeasure::Graphemes.count(text));
+        assert_eq!(22, CountMeasure::Bytes.count(text));
+        assert_eq!(2, CountMeasure::Words.count(text));
+    }
+
+    #[test]
+    fn counter_displays_max_and_detects_when_exceeded() {
+        let counter = InputCounter::new(CountMeasure::Chars).with_max(3);
+
+        assert_eq!("2/3", counter.format("ab"));
+        assert!(!counter.is_exceeded("abc"));
+        assert!(counter.is_exceeded("abcd"));
+        assert_eq!("4", InputCounter::new(CountMeasure::Chars).format("abcd"));
+    }
+}
@@ -1,26 +1,62 @@
 mod action;
 mod config;
-mod prompt;
+mod counter;
+mod placeholder;
+pub(crate) mod prompt;
 #[cfg(test)]
-#[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use counter::{CountMeasure, CounterPlacement, InputCounter};
+pub use placeholder::{PlaceholderRotation, RotatingPlaceholder};
 
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
 use crate::{
-    autocompletion::Autocomplete,
+    autocompletion::{Autocomplete, PathAutocomplete},
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    history::History,
+    key_bindings::KeyBindings,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, RenderConfig, TextBackend},
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    type_aliases::{InputFilter, InputTransform},
+    ui::{prompt_backend, RenderConfig, TextBackend},
     validator::StringValidator,
 };
 
 use self::prompt::TextPrompt;
 
 const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete, enter to submit";
+const DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT: &str = "→ or tab to accept suggestion, enter to submit";
+
+/// How the suggestions of the autocompleter are displayed in a [Text] prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SuggestionDisplay {
+    /// Suggestions are displayed in a list below the input.
+    List,
+
+    /// The top suggestion, or the highlighted one, is rendered inline after
+    /// the cursor in a dimmed style when it extends the current input, like
+    /// the autosuggestions of the fish shell. It is accepted with the right
+    /// arrow or the autocompletion hotkey.
+    Inline,
+
+    /// Suggestions are displayed in a list, and the top or highlighted one
+    /// is also rendered inline.
+    ListAndInline,
+}
+
+impl SuggestionDisplay {
+    pub(crate) fn shows_list(self) -> bool {
+        matches!(self, Self::List | Self::ListAndInline)
+    }
+
+    pub(crate) fn shows_inline(self) -> bool {
+        matches!(self, Self::Inline | Self::ListAndInline)
+    }
+}
 
 /// Standard text prompt that returns the user string input.
 ///
@@ -37,6 +73,7 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Suggestion display**: Whether suggestions are displayed in a list, inline after the cursor as ghost text, or both. See [SuggestionDisplay].
 ///
 /// ## Default behaviors
 ///
@@ -57,6 +94,8 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
 /// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
 ///
+/// Completers of command lines and other multi-word inputs can instead override `get_suggestions_at` and `get_completion_at`, which also receive the cursor position and may replace only a range of the input, such as the token under the cursor.
+///
 /// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
 ///
 /// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
@@ -90,6 +129,10 @@ pub struct Text<'a, 'b> {
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Placeholder cycling through several values, displayed instead of
+    /// [`placeholder`](Self::placeholder) when set.
+    pub rotating_placeholder: Option<RotatingPlaceholder<'a>>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
@@ -99,6 +142,12 @@ pub struct Text<'a, 'b> {
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete + 'b>>,
 
+    /// How the suggestions of the autocompleter are displayed.
+    pub suggestion_display: SuggestionDisplay,
+
+    /// History of previous answers, recalled with the up and down arrow keys.
+    pub history: Option<Box<dyn History + 'b>>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -110,15 +159,35 @@ pub struct Text<'a, 'b> {
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Whether vi keybindings are enabled. When enabled, the input starts in
+    /// insert mode and escape switches to normal mode, where keys such as
+    /// `h`, `l`, `w` or `x` move the cursor and edit the input.
+    pub vim_mode: bool,
+
+    /// Counter displaying the length of the input as the user types.
+    pub counter: Option<InputCounter>,
+
+    /// Function deciding whether a typed character is accepted, applied after
+    /// the [`input_transform`](Self::input_transform).
+    pub input_filter: Option<InputFilter<'a>>,
+
+    /// Function transforming each typed character before it is inserted.
+    pub input_transform: Option<InputTransform<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<TextPromptAction>,
 }
 
 impl<'a> Clone for Text<'a, 'static> {
@@ -128,12 +197,21 @@ impl<'a> Clone for Text<'a, 'static> {
             initial_value: self.initial_value,
             default: self.default,
             placeholder: self.placeholder,
+            rotating_placeholder: self.rotating_placeholder,
             help_message: self.help_message,
             formatter: self.formatter,
             autocompleter: self.autocompleter.clone(),
+            suggestion_display: self.suggestion_display,
+            history: self.history.clone(),
             validators: self.validators.clone(),
             page_size: self.page_size,
+            vim_mode: self.vim_mode,
+            counter: self.counter,
+            input_filter: self.input_filter,
+            input_transform: self.input_transform,
             render_config: self.render_config,
+            settings: self.settings.clone(),
+            key_bindings: self.key_bindings.clone(),
         }
     }
 }
@@ -151,19 +229,31 @@ impl<'a, 'b> Text<'a, 'b> {
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
+    /// Default display of suggestions, in a list below the input.
+    pub const DEFAULT_SUGGESTION_DISPLAY: SuggestionDisplay = SuggestionDisplay::List;
+
     /// Creates a [Text] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
             message,
             placeholder: None,
+            rotating_placeholder: None,
             initial_value: None,
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: crate::config::get_vim_mode(),
             autocompleter: None,
+            suggestion_display: Self::DEFAULT_SUGGESTION_DISPLAY,
+            history: None,
+            counter: None,
+            input_filter: None,
+            input_transform: None,
             render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
         }
     }
 
@@ -175,6 +265,10 @@ impl<'a, 'b> Text<'a, 'b> {
 
     /// Sets the initial value of the prompt's text input.
     ///
+    /// The value is loaded into the editable input with the cursor at its
+    /// end, so that the user can tweak it instead of typing it from scratch,
+    /// e.g. when editing an existing name.
+    ///
     /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
     ///
     /// [`with_default`]: Self::with_default
@@ -195,6 +289,13 @@ impl<'a, 'b> Text<'a, 'b> {
         self
     }
 
+    /// Sets a placeholder cycling through several values, such as example
+    /// inputs, replacing the static placeholder.
+    pub fn with_rotating_placeholder(mut self, placeholder: RotatingPlaceholder<'a>) -> Self {
+        self.rotating_placeholder = Some(placeholder);
+        self
+    }
+
     /// Sets a new autocompleter
     pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
     where
@@ -204,18 +305,80 @@ impl<'a, 'b> Text<'a, 'b> {
         self
     }
 
+    /// Sets the built-in file system path autocompleter, with its default
+    /// settings. To suggest hidden files or only directories, pass a
+    /// configured [`PathAutocomplete`] to [`Text::with_autocomplete`] instead.
+    pub fn with_path_autocomplete(self) -> Self {
+        self.with_autocomplete(PathAutocomplete::new())
+    }
+
+    /// Sets how the suggestions of the autocompleter are displayed.
+    ///
+    /// With [`SuggestionDisplay::Inline`], the top suggestion is rendered as
+    /// ghost text after the cursor and accepted with the right arrow or the
+    /// autocompletion hotkey, instead of being listed below the input.
+    pub fn with_suggestion_display(mut self, suggestion_display: SuggestionDisplay) -> Self {
+        self.suggestion_display = suggestion_display;
+        self
+    }
+
+    /// Sets the history of previous answers.
+    ///
+    /// When no suggestion is highlighted, the up and down arrow keys replace
+    /// the text input with the previous and next entries of the history. The
+    /// answer is recorded in the history when the prompt is submitted.
+    pub fn with_history<H>(mut self, history: H) -> Self
+    where
+        H: History + 'b,
+    {
+        self.history = Some(Box::new(history));
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets a counter displaying the length of the input as the user types,
+    /// e.g. `97/140`.
+    pub fn with_counter(mut self, counter: InputCounter) -> Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    /// Sets a filter deciding, as the user types, whether each character is
+    /// accepted in the input, e.g. to only allow digits. Rejected characters
+    /// are not inserted, instead of failing validation when submitting.
+    ///
+    /// Pasted text goes through the filter as well, while initial values and
+    /// recalled history entries don't.
+    pub fn with_input_filter(mut self, filter: InputFilter<'a>) -> Self {
+        self.input_filter = Some(filter);
+        self
+    }
+
+    /// Sets a function transforming each character typed in the input, e.g.
+    /// to uppercase it. The transform is applied before the input filter.
+    pub fn with_input_transform(mut self, transform: InputTransform<'a>) -> Self {
+        self.input_transform = Some(transform);
+        self
+    }
+
     /// Sets the page size
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
+    /// Enables or disables vi keybindings, overriding the global setting of
+    /// [set_global_vim_mode](crate::set_global_vim_mode).
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -249,17 +412,42 @@ impl<'a, 'b> Text<'a, 'b> {
 
     /// Sets the provided color theme to this prompt.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
-    ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
     pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
         self.render_config = render_config;
         self
     }
 
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are.
+    ///
+    /// ```no_run
+    /// # use inquire::{ui::Styled, Text};
+    /// let name = Text::new("Name:")
+    ///     .with_render_config_overrides(|config| config.with_prompt_prefix(Styled::new("$")))
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(mut self, key_bindings: KeyBindings<TextPromptAction>) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -280,11 +468,69 @@ impl<'a, 'b> Text<'a, 'b> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [prompt](Self::prompt), but awaits the keys pressed by the
+    /// user instead of blocking the thread, so that the prompt can be used in
+    /// async applications, e.g. raced against a shutdown signal.
+    ///
+    /// Dropping the returned future before it completes, such as when another
+    /// future wins the race, takes the terminal out of raw mode.
+    ///
+    /// The returned future is not `Send`, as prompts hold non thread-safe
+    /// references to their validators and formatters, so it should be awaited
+    /// in the task running it instead of being spawned.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [InquireError::InvalidConfiguration] when the prompt renders
+    /// to a custom backend, set with [with_backend](Self::with_backend) or
+    /// [set_global_backend](crate::set_global_backend), as only the keys
+    /// pressed in the terminal can be awaited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run() -> inquire::error::InquireResult<()> {
+    /// use inquire::Text;
+    ///
+    /// let name = Text::new("What's your name?").prompt_async().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<String> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        crate::prompts::prompt_async::prompt_async(TextPrompt::from(self), &mut backend).await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// instead of displaying it in the terminal right away.
+    ///
+    /// The application renders the returned [EmbeddedPrompt] in an area of
+    /// its layout and hands it the keys pressed by the user, until it
+    /// returns the answer. The prompt's backend settings, such as its
+    /// non-interactive behavior or custom backend, are ignored.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, String>>
+    where
+        'b: 'a,
+    {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            TextPrompt::from(self),
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
     pub(crate) fn prompt_with_backend<B: TextBackend>(
         self,
         backend: &mut B,
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+/// When a [RotatingPlaceholder] moves on to its next placeholder.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlaceholderRotation {
+    /// The placeholder changes every time the given duration elapses.
+    Interval(Duration),
+
+    /// The placeholder changes every time the prompt is redrawn in response
+    /// to a key press, e.g. when the user moves the cursor or clears the input.
+    OnRedraw,
+}
+
+#[derive(Copy, Clone)]
+enum Source<'a> {
+    Examples(&'a [&'a str]),
+    Callback(&'a dyn Fn(usize) -> String),
+}
+
+/// Placeholder of a [Text](crate::Text) prompt cycling through several
+/// values, such as example inputs showing the different formats accepted
+/// by the prompt.
+///
+/// The placeholders are either taken in turn from a list of examples, or
+/// produced by a callback receiving the current step of the rotation,
+/// starting at 0 and incremented every time the placeholder changes.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use inquire::{PlaceholderRotation, RotatingPlaceholder, Text};
+///
+/// let examples = ["tomorrow 9am", "next friday", "2024-05-17 14:30"];
+///
+/// let when = Text::new("When?")
+///     .with_rotating_placeholder(
+///         RotatingPlaceholder::from_examples(&examples)
+///             .with_rotation(PlaceholderRotation::Interval(Duration::from_secs(2))),
+///     )
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone)]
+pub struct RotatingPlaceholder<'a> {
+    source: Source<'a>,
+    rotation: PlaceholderRotation,
+}
+
+impl<'a> RotatingPlaceholder<'a> {
+    /// Default rotation, moving on to the next placeholder every 3 seconds.
+    pub const DEFAULT_ROTATION: PlaceholderRotation =
+        PlaceholderRotation::Interval(Duration::from_secs(3));
+
+    /// Creates a placeholder cycling through the provided examples, starting
+    /// over after the last one.
+    pub fn from_examples(examples: &'a [&'a str]) -> Self {
+        Self {
+            source: Source::Examples(examples),
+            rotation: Self::DEFAULT_ROTATION,
+        }
+    }
+
+    /// Creates a placeholder produced by the provided callback, which
+    /// receives the current step of the rotation.
+    pub fn from_fn(callback: &'a dyn Fn(usize) -> String) -> Self {
+        Self {
+            source: Source::Callback(callback),
+            rotation: Self::DEFAULT_ROTATION,
+        }
+    }
+
+    /// Sets when the placeholder moves on to the next one.
+    pub fn with_rotation(mut self, rotation: PlaceholderRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Rotation of the placeholder.
+    pub fn rotation(&self) -> PlaceholderRotation {
+        self.rotation
+    }
+
+    /// Placeholder displayed at the given step of the rotation.
+    pub fn at(&self, step: usize) -> Option<String> {
+        match self.source {
+            Source::Examples(examples) => step
+                .checked_rem(examples.len())
+                .and_then(|i| examples.get(i))
+                .map(|example| (*example).to_owned()),
+            Source::Callback(callback) => Some(callback(step)),
+        }
+    }
+}
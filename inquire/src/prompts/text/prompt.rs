@@ -1,19 +1,42 @@
-use std::cmp::min;
+use std::{
+    cmp::min,
+    time::{Duration, Instant},
+};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    autocompletion::{NoAutoCompletion, Replacement},
+    autocompletion::NoAutoCompletion,
     error::InquireResult,
     formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    history::{History, HistoryCursor},
+    input::{filter_char, Input, InputActionResult, LineDirection},
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::TextBackend,
+    prompts::{
+        prompt::{ActionResult, AdaptivePagePrompt, Prompt},
+        vi::{ViKeys, ViMode},
+        Action,
+    },
+    type_aliases::{InputFilter, InputTransform},
+    ui::{Key, TextBackend},
     utils::paginate,
     validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    Autocomplete, InputAction, InquireError, Text,
+};
+
+use super::{
+    action::TextPromptAction, config::TextConfig, CounterPlacement, InputCounter,
+    PlaceholderRotation, RotatingPlaceholder, SuggestionDisplay, DEFAULT_HELP_MESSAGE_WITH_AC,
+    DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT,
 };
 
-use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
+/// Frames of the indicator displayed while suggestions are loading.
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Interval between frames of the loading indicator, which is also the
+/// interval at which pending suggestions are polled.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
 
 pub struct TextPrompt<'a, 'b> {
     message: &'a str,
@@ -21,22 +44,38 @@ pub struct TextPrompt<'a, 'b> {
     default: Option<&'a str>,
     help_message: Option<&'a str>,
     input: Input,
+    input_filter: Option<InputFilter<'a>>,
+    input_transform: Option<InputTransform<'a>>,
+    counter: Option<InputCounter>,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator + 'b>>,
     error: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete + 'b>,
     suggested_options: Vec<String>,
     suggestion_cursor_index: Option<usize>,
+    suggestion_display: SuggestionDisplay,
+    loading_since: Option<Instant>,
+    spinner_frame: usize,
+    history: Option<Box<dyn History + 'b>>,
+    history_cursor: Option<HistoryCursor>,
+    rotating_placeholder: Option<RotatingPlaceholder<'a>>,
+    placeholder_step: usize,
+    placeholder_rotated_at: Instant,
+    vi: Option<ViKeys>,
+    key_bindings: KeyBindings<TextPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
 }
 
 impl<'a, 'b> From<Text<'a, 'b>> for TextPrompt<'a, 'b> {
     fn from(so: Text<'a, 'b>) -> Self {
-        let input = Input::new_with(so.initial_value.unwrap_or_default());
-        let input = if let Some(placeholder) = so.placeholder {
-            input.with_placeholder(placeholder)
-        } else {
-            input
+        let mut input = Input::new_with(so.initial_value.unwrap_or_default());
+        let placeholder = match so.rotating_placeholder {
+            Some(rotating_placeholder) => rotating_placeholder.at(0),
+            None => so.placeholder.map(str::to_owned),
         };
+        input.set_placeholder(placeholder);
 
         Self {
             message: so.message,
@@ -48,10 +87,26 @@ impl<'a, 'b> From<Text<'a, 'b>> for TextPrompt<'a, 'b> {
                 .autocompleter
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
             input,
+            input_filter: so.input_filter,
+            input_transform: so.input_transform,
+            counter: so.counter,
             error: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
+            suggestion_display: so.suggestion_display,
+            loading_since: None,
+            spinner_frame: 0,
             validators: so.validators,
+            history: so.history,
+            history_cursor: None,
+            rotating_placeholder: so.rotating_placeholder,
+            placeholder_step: 0,
+            placeholder_rotated_at: Instant::now(),
+            vi: so.vim_mode.then(|| ViKeys::new(ViMode::Insert)),
+            key_bindings: so.key_bindings,
+            key_handler: so.settings.key_handler,
+            escape_behavior: so.settings.escape_behavior,
+            cancel_guard: so.settings.cancel_guard,
         }
     }
 }
@@ -64,12 +119,102 @@ impl<'a, 'b> From<&'a str> for Text<'a, 'b> {
 
 impl<'a, 'b> TextPrompt<'a, 'b> {
     fn update_suggestions(&mut self) -> InquireResult<()> {
-        self.suggested_options = self.autocompleter.get_suggestions(self.input.content())?;
+        let cursor = self.input.pre_cursor().len();
+        self.suggested_options = self
+            .autocompleter
+            .get_suggestions_at(self.input.content(), cursor)?;
         self.suggestion_cursor_index = None;
 
+        // Keep the spinner going when the input changes while loading.
+        self.loading_since = match self.autocompleter.is_loading() {
+            true => Some(self.loading_since.unwrap_or_else(Instant::now)),
+            false => None,
+        };
+
         Ok(())
     }
 
+    /// Moves the rotating placeholder on to its next value, requiring a
+    /// redraw only if it is visible.
+    fn rotate_placeholder(&mut self) -> ActionResult {
+        let Some(rotating_placeholder) = self.rotating_placeholder else {
+            return ActionResult::Clean;
+        };
+
+        self.placeholder_step = self.placeholder_step.wrapping_add(1);
+        self.placeholder_rotated_at = Instant::now();
+        self.input
+            .set_placeholder(rotating_placeholder.at(self.placeholder_step));
+
+        match self.input.is_empty() {
+            true => ActionResult::NeedsRedraw,
+            false => ActionResult::Clean,
+        }
+    }
+
+    /// Time left until the rotating placeholder moves on to its next value,
+    /// when it rotates at a fixed interval.
+    fn placeholder_time_left(&self) -> Option<Duration> {
+        match self.rotating_placeholder.map(|p| p.rotation()) {
+            Some(PlaceholderRotation::Interval(interval)) => {
+                Some(interval.saturating_sub(self.placeholder_rotated_at.elapsed()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Picks up suggestions fetched in the background and advances the
+    /// loading indicator.
+    fn tick_suggestions(&mut self) -> InquireResult<ActionResult> {
+        if let Some(suggestions) = self.autocompleter.poll_suggestions()? {
+            self.suggested_options = suggestions;
+            self.suggestion_cursor_index = None;
+        }
+
+        let Some(since) = self.loading_since else {
+            return Ok(ActionResult::Clean);
+        };
+
+        if !self.autocompleter.is_loading() {
+            self.loading_since = None;
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        let frame = (since.elapsed().as_millis() / SPINNER_INTERVAL.as_millis()) as usize
+            % SPINNER_FRAMES.len();
+        Ok(self.update_spinner_frame(frame))
+    }
+
+    /// Replaces the input with the older or newer entry of the history,
+    /// returning whether there was one to recall.
+    fn recall_history_entry(&mut self, older: bool) -> InquireResult<bool> {
+        let cursor = match self.history_cursor.as_mut() {
+            Some(cursor) => cursor,
+            None => return Ok(false),
+        };
+
+        let entry = match older {
+            true => cursor.older(self.input.content()),
+            false => cursor.newer(),
+        };
+
+        match entry.map(str::to_owned) {
+            Some(entry) => {
+                let cursor = entry.graphemes(true).count();
+                self.input.replace_content(entry, cursor);
+                self.update_suggestions()?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_browsing_history(&self) -> bool {
+        self.history_cursor
+            .as_ref()
+            .is_some_and(HistoryCursor::is_browsing)
+    }
+
     fn get_highlighted_suggestion(&self) -> Option<&str> {
         if let Some(cursor) = self.suggestion_cursor_index {
             let suggestion = self.suggested_options.get(cursor).unwrap().as_ref();
@@ -117,40 +262,117 @@ impl<'a, 'b> TextPrompt<'a, 'b> {
         }
     }
 
-    fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
-        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
-        match self
+    fn update_spinner_frame(&mut self, frame: usize) -> ActionResult {
+        if frame != self.spinner_frame {
+            self.spinner_frame = frame;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    /// Asks the autocompleter for a completion of the input, returning the
+    /// completed content and the grapheme index of the cursor, if any.
+    fn complete(&mut self, suggestion: Option<String>) -> InquireResult<Option<(String, usize)>> {
+        let content = self.input.content();
+        let cursor = self.input.pre_cursor().len();
+
+        let replacement = match self
             .autocompleter
-            .get_completion(self.input.content(), suggestion)?
+            .get_completion_at(content, cursor, suggestion)?
+        {
+            Some(replacement) => replacement,
+            None => return Ok(None),
+        };
+
+        let (content, cursor) = replacement.apply(content).ok_or_else(|| {
+            InquireError::Custom(
+                format!(
+                    "Replacement range {:?} is out of bounds of the input",
+                    replacement.range
+                )
+                .into(),
+            )
+        })?;
+
+        let cursor = content
+            .get(..cursor)
+            .unwrap_or_default()
+            .graphemes(true)
+            .count();
+        Ok(Some((content, cursor)))
+    }
+
+    /// Remainder of the top or highlighted suggestion rendered inline after
+    /// the cursor, if it extends the current input.
+    fn ghost_text(&self) -> Option<&str> {
+        if !self.suggestion_display.shows_inline()
+            || self.loading_since.is_some()
+            || self.input.is_empty()
+            || self.input.cursor() != self.input.length()
         {
-            Replacement::Some(value) => {
-                self.input = Input::new_with(value);
+            return None;
+        }
+
+        let suggestion = match self.get_highlighted_suggestion() {
+            Some(suggestion) => suggestion,
+            None => self.suggested_options.first()?,
+        };
+
+        suggestion
+            .strip_prefix(self.input.content())
+            .filter(|rest| !rest.is_empty())
+    }
+
+    /// Appends the ghost text to the input, returning whether there was any.
+    fn accept_ghost_text(&mut self) -> InquireResult<bool> {
+        let content = match self.ghost_text() {
+            Some(ghost_text) => format!("{}{}", self.input.content(), ghost_text),
+            None => return Ok(false),
+        };
+
+        let cursor = content.graphemes(true).count();
+        self.input.replace_content(content, cursor);
+        self.update_suggestions()?;
+        Ok(true)
+    }
+
+    fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
+        let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
+        match self.complete(suggestion)? {
+            Some((content, cursor)) => {
+                self.input.replace_content(content, cursor);
                 Ok(ActionResult::NeedsRedraw)
             }
-            Replacement::None => Ok(ActionResult::Clean),
+            None => Ok(ActionResult::Clean),
         }
     }
 
-    fn get_current_answer(&self) -> &str {
+    fn get_current_answer(&mut self) -> InquireResult<String> {
         // If there is a highlighted suggestion, assume user wanted it as
-        // the answer.
-        if let Some(suggestion) = self.get_highlighted_suggestion() {
-            return suggestion;
+        // the answer. Token-aware autocompleters only replace a part of
+        // the input with it.
+        if let Some(suggestion) = self.get_highlighted_suggestion().map(|s| s.to_owned()) {
+            let answer = match self.complete(Some(suggestion.clone()))? {
+                Some((content, _)) => content,
+                None => suggestion,
+            };
+            return Ok(answer);
         }
 
         // Empty input with default values override any validators.
         if self.input.content().is_empty() {
             if let Some(val) = self.default {
-                return val;
+                return Ok(val.to_owned());
             }
         }
 
-        self.input.content()
+        Ok(self.input.content().to_owned())
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
         for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
+            match validator.validate(answer) {
                 Ok(Validation::Valid) => {}
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
@@ -173,21 +395,91 @@ where
         self.message
     }
 
+    fn partial_input(&self) -> Option<&str> {
+        Some(self.input.content())
+    }
+
     fn config(&self) -> &TextConfig {
         &self.config
     }
 
+    fn key_bindings(&self) -> Option<&KeyBindings<TextPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(self, TextPromptAction::ValueInput(InputAction::Clear))
+    }
+
+    fn action_from_key(&mut self, key: Key) -> Option<Action<TextPromptAction>> {
+        let key = match &mut self.vi {
+            Some(vi) => vi.translate(key)?,
+            None => key,
+        };
+        Action::from_key(key, &self.config)
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
 
     fn setup(&mut self) -> InquireResult<()> {
+        if let Some(history) = &self.history {
+            self.history_cursor = Some(HistoryCursor::new(history.entries()?));
+        }
+
         self.update_suggestions()
     }
 
+    fn tick_interval(&self) -> Option<Duration> {
+        let spinner = self.loading_since.map(|_| SPINNER_INTERVAL);
+        spinner
+            .into_iter()
+            .chain(self.placeholder_time_left())
+            .min()
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        let placeholder = match self.placeholder_time_left() {
+            Some(time_left) if time_left.is_zero() => self.rotate_placeholder(),
+            _ => ActionResult::Clean,
+        };
+
+        Ok(placeholder.merge(self.tick_suggestions()?))
+    }
+
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.input = Input::new_with(answer);
+        self.suggestion_cursor_index = None;
+        Ok(())
+    }
+
+    fn error_message(&self) -> Option<&ErrorMessage> {
+        self.error.as_ref()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
-        let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
+        let answer = self.get_current_answer()?;
+        let result = match self.validate_answer(&answer)? {
+            Validation::Valid => {
+                if let Some(history) = self.history.as_mut() {
+                    // the answer was submitted even if it can't be recorded
+                    let _ = history.record(&answer);
+                }
+                Some(answer)
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
                 None
@@ -199,21 +491,44 @@ where
 
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
+            TextPromptAction::ValueInput(InputAction::MoveCursor(_, LineDirection::Right))
+                if self.accept_ghost_text()? =>
+            {
+                ActionResult::NeedsRedraw
+            }
             TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+                let (transform, filter) = (self.input_transform, self.input_filter);
+                let result = self
+                    .input
+                    .handle_filtered(input_action, &|c| filter_char(c, transform, filter));
 
                 if let InputActionResult::ContentChanged = result {
+                    if let Some(cursor) = self.history_cursor.as_mut() {
+                        cursor.reset();
+                    }
                     self.update_suggestions()?;
                 }
 
                 result.into()
             }
-            TextPromptAction::MoveToSuggestionAbove => self.move_cursor_up(1),
-            TextPromptAction::MoveToSuggestionBelow => self.move_cursor_down(1),
+            TextPromptAction::MoveToSuggestionAbove => match self.suggestion_cursor_index.is_none()
+            {
+                true if self.recall_history_entry(true)? => ActionResult::NeedsRedraw,
+                _ => self.move_cursor_up(1),
+            },
+            TextPromptAction::MoveToSuggestionBelow => match self.is_browsing_history() {
+                true if self.recall_history_entry(false)? => ActionResult::NeedsRedraw,
+                _ => self.move_cursor_down(1),
+            },
             TextPromptAction::MoveToSuggestionPageUp => self.move_cursor_up(self.config.page_size),
             TextPromptAction::MoveToSuggestionPageDown => {
                 self.move_cursor_down(self.config.page_size)
             }
+            TextPromptAction::UseCurrentSuggestion
+                if self.suggestion_cursor_index.is_none() && self.accept_ghost_text()? =>
+            {
+                ActionResult::NeedsRedraw
+            }
             TextPromptAction::UseCurrentSuggestion => {
                 let result = self.use_current_suggestion()?;
 
@@ -225,6 +540,14 @@ where
             }
         };
 
+        let rotates_on_redraw = matches!(
+            self.rotating_placeholder.map(|p| p.rotation()),
+            Some(PlaceholderRotation::OnRedraw)
+        );
+        if rotates_on_redraw && result.needs_redraw() {
+            self.rotate_placeholder();
+        }
+
         Ok(result)
     }
 
@@ -235,7 +558,22 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        let inline_counter = self
+            .counter
+            .as_ref()
+            .filter(|counter| counter.placement() == CounterPlacement::EndOfLine);
+
+        let ghost_text = self.ghost_text();
+        match ghost_text {
+            Some(ghost_text) => backend.render_prompt_with_ghost_text(
+                prompt,
+                self.default,
+                &self.input,
+                ghost_text,
+                inline_counter,
+            )?,
+            None => backend.render_prompt(prompt, self.default, &self.input, inline_counter)?,
+        }
 
         let choices = self
             .suggested_options
@@ -250,14 +588,45 @@ where
             self.suggestion_cursor_index,
         );
 
-        backend.render_suggestions(page)?;
+        match self.loading_since {
+            Some(_) => {
+                let indicator = SPINNER_FRAMES.get(self.spinner_frame).unwrap();
+                backend.render_suggestions_loading(indicator)?;
+            }
+            None if self.suggestion_display.shows_list() => backend.render_suggestions(page)?,
+            None => {}
+        }
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
-        } else if !choices.is_empty() {
+        } else if !choices.is_empty() && self.suggestion_display.shows_list() {
             backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
+        } else if ghost_text.is_some() {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_GHOST_TEXT)?;
+        }
+
+        if let Some(counter) = self
+            .counter
+            .as_ref()
+            .filter(|counter| counter.placement() == CounterPlacement::HelpMessage)
+        {
+            backend.render_help_message(&counter.format(self.input.content()))?;
         }
 
         Ok(())
     }
+
+    fn adaptive_page(&mut self) -> Option<&mut dyn AdaptivePagePrompt> {
+        Some(self)
+    }
+}
+
+impl<'a, 'b> AdaptivePagePrompt for TextPrompt<'a, 'b> {
+    fn page_size(&self) -> usize {
+        self.config.page_size
+    }
+
+    fn set_page_size(&mut self, page_size: usize) {
+        self.config.page_size = page_size;
+    }
 }
@@ -111,3 +111,608 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+#[test]
+fn suggestion_page_size_is_reduced_to_fit_terminal_height() {
+    use crate::{
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(80, 5).unwrap());
+
+    let suggester = |_: &str| Ok((0..10).map(|i| format!("Suggestion {i}")).collect());
+
+    {
+        let mut backend = Backend::new(
+            vec![Key::Enter].into_iter(),
+            terminal,
+            RenderConfig::default(),
+        )
+        .unwrap();
+        let ans = Text::new("Question")
+            .with_autocomplete(suggester)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!("", ans);
+    }
+
+    // prompt + help message leave room for only 3 suggestions in 5 rows.
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("Suggestion 2"));
+    assert!(!rendered("Suggestion 3"));
+}
+
+#[test]
+fn canceled_prompt_formatter_receives_message_and_partial_input() {
+    use crate::{
+        error::InquireError,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, CanceledPrompt, RenderConfig, Styled},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output);
+
+    let mut events = text_to_events!("abc");
+    events.push(Key::Escape);
+
+    let render_config =
+        RenderConfig::default().with_canceled_prompt(CanceledPrompt::Custom(&|message, input| {
+            Styled::new(format!("{message} discarded {}", input.unwrap()))
+        }));
+
+    {
+        let mut backend = Backend::new(events.into_iter(), terminal, render_config).unwrap();
+        let ans = Text::new("Question").prompt_with_backend(&mut backend);
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("Question discarded abc"));
+    assert!(!rendered("<canceled>"));
+}
+
+#[test]
+fn hidden_canceled_prompt_renders_nothing() {
+    use crate::{
+        error::InquireError,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, CanceledPrompt, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    let terminal = MockTerminal::new(&mut output);
+
+    let render_config = RenderConfig::default().with_canceled_prompt(CanceledPrompt::Hidden);
+
+    {
+        let mut backend =
+            Backend::new(vec![Key::Escape].into_iter(), terminal, render_config).unwrap();
+        let ans = Text::new("Question").prompt_with_backend(&mut backend);
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    }
+
+    assert!(!output
+        .iter()
+        .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == "<canceled>")));
+}
+
+#[derive(Clone)]
+struct GitSubcommandCompleter;
+
+impl crate::Autocomplete for GitSubcommandCompleter {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+        self.get_suggestions_at(input, input.len())
+    }
+
+    fn get_suggestions_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+    ) -> Result<Vec<String>, crate::CustomUserError> {
+        let token = input
+            .get(crate::autocompletion::token_range(input, cursor))
+            .unwrap_or_default();
+
+        Ok(["checkout", "cherry-pick", "commit"]
+            .iter()
+            .filter(|cmd| !token.is_empty() && cmd.starts_with(token))
+            .map(|cmd| (*cmd).to_owned())
+            .collect())
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        _: Option<String>,
+    ) -> Result<crate::autocompletion::Replacement, crate::CustomUserError> {
+        Ok(None)
+    }
+
+    fn get_completion_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Option<crate::autocompletion::RangeReplacement>, crate::CustomUserError> {
+        let range = crate::autocompletion::token_range(input, cursor);
+
+        Ok(highlighted_suggestion
+            .map(|value| crate::autocompletion::RangeReplacement::new(range, value)))
+    }
+}
+
+text_test!(
+    token_aware_completion_replaces_token_under_cursor,
+    {
+        let mut events = text_to_events!("git ch");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Tab);
+        events.append(&mut text_to_events!(" -b"));
+        events.push(Key::Enter);
+        events
+    },
+    "git checkout -b",
+    default().with_autocomplete(GitSubcommandCompleter)
+);
+
+text_test!(
+    submitting_token_aware_suggestion_keeps_rest_of_input,
+    {
+        let mut events = text_to_events!("git x");
+        events.push(Key::Left(KeyModifiers::NONE));
+        events.push(Key::Left(KeyModifiers::NONE));
+        events.append(&mut text_to_events!(" ch"));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "git cherry-pick x",
+    default().with_autocomplete(GitSubcommandCompleter)
+);
+
+text_test!(
+    up_arrow_recalls_previous_history_entries,
+    vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ],
+    "first",
+    default().with_history(crate::history::MemoryHistory::with_entries(
+        10,
+        ["first", "second"]
+    ))
+);
+
+text_test!(
+    down_arrow_restores_draft_after_most_recent_history_entry,
+    {
+        let mut events = text_to_events!("dra");
+        events.push(Key::Up(KeyModifiers::NONE));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Char('f', KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "draf",
+    default().with_history(crate::history::MemoryHistory::with_entries(10, ["first"]))
+);
+
+#[test]
+fn submitted_answers_are_recorded_in_history() {
+    use crate::history::{History, MemoryHistory};
+
+    let history = MemoryHistory::default();
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("ls\n"));
+    default()
+        .with_history(history.clone())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut backend = crate::prompts::test::fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = default()
+        .with_history(history.clone())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("lsa", ans);
+    assert_eq!(vec!["ls", "lsa"], history.entries().unwrap());
+}
+
+#[test]
+fn answers_are_returned_when_the_history_fails_to_record_them() {
+    use crate::{history::History, CustomUserError};
+
+    #[derive(Clone)]
+    struct ReadOnlyHistory;
+
+    impl History for ReadOnlyHistory {
+        fn entries(&self) -> Result<Vec<String>, CustomUserError> {
+            Ok(vec![])
+        }
+
+        fn record(&mut self, _entry: &str) -> Result<(), CustomUserError> {
+            Err("read-only file system".into())
+        }
+    }
+
+    let mut backend = crate::prompts::test::fake_backend(text_to_events!("ls\n"));
+    let ans = default()
+        .with_history(ReadOnlyHistory)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ls", ans);
+}
+
+#[test]
+fn async_suggestions_are_displayed_when_they_arrive() {
+    use crate::{
+        autocompletion::AsyncAutocomplete,
+        prompts::test::PollingInput,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+    };
+    use std::{collections::VecDeque, thread, time::Duration};
+
+    let autocompleter = AsyncAutocomplete::new(|input| {
+        thread::sleep(Duration::from_millis(50));
+        Ok(vec![format!("{input}-core"), format!("{input}-derive")])
+    });
+
+    let input = PollingInput::new(
+        text_to_events!("se"),
+        5,
+        vec![
+            Key::Down(KeyModifiers::NONE),
+            Key::Down(KeyModifiers::NONE),
+            Key::Enter,
+        ],
+    );
+
+    let mut output = VecDeque::new();
+    let ans = {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+        default()
+            .with_autocomplete(autocompleter)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!("se-derive", ans);
+
+    let loading_rendered = output.iter().any(|token| {
+        matches!(token, MockTerminalToken::Text(t) if t.content.contains("loading suggestions…"))
+    });
+    assert!(loading_rendered);
+}
+
+#[test]
+fn async_suggestions_for_outdated_input_are_discarded() {
+    use crate::{
+        autocompletion::AsyncAutocomplete,
+        prompts::test::PollingInput,
+        terminal::test::NullTerminal,
+        ui::{Backend, RenderConfig},
+    };
+    use std::{thread, time::Duration};
+
+    let autocompleter = AsyncAutocomplete::new(|input| match input {
+        "s" => {
+            thread::sleep(Duration::from_millis(150));
+            Ok(vec!["stale".to_owned()])
+        }
+        input => Ok(vec![format!("{input}!")]),
+    });
+
+    let input = PollingInput::new(
+        text_to_events!("se"),
+        5,
+        vec![Key::Down(KeyModifiers::NONE), Key::Enter],
+    );
+    let terminal = NullTerminal;
+    let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+    let ans = default()
+        .with_autocomplete(autocompleter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("se!", ans);
+}
+
+fn name_suggester(input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+    Ok(["Andrew", "Angela", "Charles"]
+        .iter()
+        .filter(|name| name.starts_with(input))
+        .map(|name| (*name).to_owned())
+        .collect())
+}
+
+text_test!(
+    right_arrow_accepts_ghost_text,
+    {
+        let mut events = text_to_events!("An");
+        events.push(Key::Right(KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "Andrew",
+    default()
+        .with_autocomplete(name_suggester)
+        .with_suggestion_display(super::SuggestionDisplay::Inline)
+);
+
+text_test!(
+    tab_accepts_ghost_text_of_highlighted_suggestion,
+    {
+        let mut events = text_to_events!("An");
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Down(KeyModifiers::NONE));
+        events.push(Key::Tab);
+        events.push(Key::Char('!', KeyModifiers::NONE));
+        events.push(Key::Enter);
+        events
+    },
+    "Angela!",
+    default()
+        .with_autocomplete(name_suggester)
+        .with_suggestion_display(super::SuggestionDisplay::ListAndInline)
+);
+
+#[test]
+fn inline_suggestion_is_rendered_as_ghost_text_without_list() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend = Backend::new(
+            text_to_events!("Ch\n").into_iter(),
+            terminal,
+            RenderConfig::default(),
+        )
+        .unwrap();
+
+        let ans = default()
+            .with_autocomplete(name_suggester)
+            .with_suggestion_display(super::SuggestionDisplay::Inline)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!("Ch", ans);
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+    };
+    assert!(rendered("arles"));
+    assert!(!rendered("Charles"));
+}
+
+#[test]
+fn rotating_placeholder_cycles_examples_at_interval() {
+    use crate::{
+        prompts::test::PollingInput,
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+        PlaceholderRotation, RotatingPlaceholder,
+    };
+    use std::{collections::VecDeque, time::Duration};
+
+    let examples = ["tomorrow 9am", "next friday"];
+    let placeholder = RotatingPlaceholder::from_examples(&examples)
+        .with_rotation(PlaceholderRotation::Interval(Duration::from_millis(10)));
+
+    let input = PollingInput::new(vec![], 3, vec![Key::Enter]);
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend = Backend::new(input, terminal, RenderConfig::default()).unwrap();
+
+        default()
+            .with_rotating_placeholder(placeholder)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    for example in examples {
+        let rendered = output.iter().any(
+            |token| matches!(token, MockTerminalToken::Text(t) if t.content.contains(example)),
+        );
+        assert!(rendered, "{} was not rendered", example);
+    }
+}
+
+#[test]
+fn rotating_placeholder_advances_on_redraw() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+        PlaceholderRotation, RotatingPlaceholder,
+    };
+    use std::collections::VecDeque;
+
+    let callback = |step| format!("example {step}");
+    let placeholder =
+        RotatingPlaceholder::from_fn(&callback).with_rotation(PlaceholderRotation::OnRedraw);
+
+    let keys = vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ];
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend =
+            Backend::new(keys.into_iter(), terminal, RenderConfig::default()).unwrap();
+
+        default()
+            .with_rotating_placeholder(placeholder)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let rendered = |text: &str| {
+        output
+            .iter()
+            .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content.contains(text)))
+    };
+
+    assert!(rendered("example 0"));
+    // The placeholder isn't visible while the input has content.
+    assert!(!rendered("example 1"));
+    assert!(rendered("example 2"));
+}
+
+text_test!(
+    undo_restores_deleted_word,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Delete(KeyModifiers::CONTROL));
+        events.push(Key::Left(KeyModifiers::CONTROL));
+        events.push(Key::Delete(KeyModifiers::CONTROL));
+        events.push(Key::Char('z', KeyModifiers::CONTROL));
+        events.push(Key::Enter);
+        events
+    },
+    "hello world"
+);
+
+text_test!(
+    input_filter_rejects_characters_as_they_are_typed,
+    text_to_events!("a1b2 3\n"),
+    "123",
+    default().with_input_filter(&|c| c.is_ascii_digit())
+);
+
+text_test!(
+    input_transform_is_applied_before_filter,
+    text_to_events!("ab c-d\n"),
+    "ABC-D",
+    default()
+        .with_input_transform(&|c| c.to_ascii_uppercase())
+        .with_input_filter(&|c| !c.is_whitespace())
+);
+
+#[test]
+fn counter_is_rendered_at_end_of_line_or_in_help_area() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+        CountMeasure, CounterPlacement, InputCounter,
+    };
+    use std::collections::VecDeque;
+
+    for placement in [CounterPlacement::EndOfLine, CounterPlacement::HelpMessage] {
+        let mut output = VecDeque::new();
+        {
+            let terminal = MockTerminal::new(&mut output);
+            let mut backend = Backend::new(
+                text_to_events!("one two\n").into_iter(),
+                terminal,
+                RenderConfig::default(),
+            )
+            .unwrap();
+
+            let counter = InputCounter::new(CountMeasure::Words)
+                .with_max(5)
+                .with_placement(placement);
+            default()
+                .with_counter(counter)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+        }
+
+        let rendered = |text: &str| {
+            output
+                .iter()
+                .any(|token| matches!(token, MockTerminalToken::Text(t) if t.content == text))
+        };
+        assert!(rendered("1/5"));
+        assert!(rendered("2/5"));
+        assert_eq!(
+            placement == CounterPlacement::HelpMessage,
+            rendered("[") && rendered("]")
+        );
+    }
+}
+
+text_test!(
+    initial_value_is_editable_with_cursor_at_end,
+    {
+        let mut events = vec![Key::Backspace, Key::Backspace, Key::Backspace];
+        events.append(&mut text_to_events!("ny\n"));
+        events
+    },
+    "Johnny",
+    default().with_initial_value("Johnnie")
+);
+
+text_test!(
+    default_is_returned_when_initial_value_is_cleared,
+    vec![Key::Char('u', KeyModifiers::CONTROL), Key::Enter],
+    "Default",
+    default()
+        .with_initial_value("Initial")
+        .with_default("Default")
+);
+
+text_test!(
+    vim_mode_edits_the_input_in_normal_mode,
+    {
+        let mut events = text_to_events!("helo");
+        events.push(Key::Escape);
+        events.append(&mut text_to_events!("hil"));
+        events.push(Key::Escape);
+        events.append(&mut text_to_events!("0x"));
+        events.push(Key::Enter);
+        events
+    },
+    "ello",
+    default().with_vim_mode(true)
+);
+
+#[test]
+fn render_config_overrides_keep_other_fields() {
+    use crate::ui::{RenderConfig, Styled};
+
+    let prompt = default()
+        .with_render_config(RenderConfig::empty().with_answered_prompt_prefix(Styled::new("!")))
+        .with_render_config_overrides(|config| config.with_prompt_prefix(Styled::new("$")));
+
+    assert_eq!("$", prompt.render_config.prompt_prefix.content);
+    assert_eq!("!", prompt.render_config.answered_prompt_prefix.content);
+}
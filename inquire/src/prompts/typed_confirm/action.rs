@@ -0,0 +1,16 @@
+use crate::{ui::Key, InnerAction, InputAction};
+
+/// Set of actions for a TypedConfirmPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TypedConfirmPromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+}
+
+impl InnerAction for TypedConfirmPromptAction {
+    type Config = ();
+
+    fn from_key(key: Key, _config: &()) -> Option<Self> {
+        InputAction::from_key(key, &()).map(Self::ValueInput)
+    }
+}
@@ -0,0 +1,202 @@
+mod action;
+mod prompt;
+#[cfg(test)]
+mod test;
+
+pub use action::*;
+
+#[cfg(feature = "ratatui")]
+use crate::ratatui::EmbeddedPrompt;
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
+    key_bindings::KeyBindings,
+    prompts::prompt::Prompt,
+    prompts::settings::{prompt_settings_builders, PromptSettings},
+    ui::{prompt_backend, RenderConfig, TypedConfirmBackend},
+};
+
+use self::prompt::TypedConfirmPrompt;
+
+/// Prompt guarding destructive operations, where the user has to type an
+/// exact phrase, such as the name of the resource about to be deleted, to
+/// proceed.
+///
+/// The required phrase is displayed as dimmed ghost text after the input,
+/// and typed characters that don't match it are highlighted with the style
+/// of error messages as the user types.
+///
+/// The prompt returns `true` when the submitted input is exactly the phrase,
+/// and `false` otherwise, including when the input is empty.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::TypedConfirm;
+///
+/// let confirmed = TypedConfirm::new("Type the name of the database to delete it:", "production-db")
+///     .with_help_message("This action cannot be undone")
+///     .prompt()?;
+///
+/// if confirmed {
+///     println!("Deleting production-db...");
+/// }
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct TypedConfirm<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Phrase the user has to type to confirm.
+    pub phrase: &'a str,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the answer to be presented to the user as the
+    /// final rendering of the prompt.
+    pub formatter: BoolFormatter<'a>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
+    pub render_config: RenderConfig<'a>,
+
+    /// Settings shared by all prompts, e.g. how the prompt is canceled.
+    /// See [PromptSettings].
+    pub settings: PromptSettings<'a>,
+
+    /// Key bindings overriding the global and default ones for this prompt.
+    /// See [KeyBindings].
+    pub key_bindings: KeyBindings<TypedConfirmPromptAction>,
+}
+
+impl<'a> TypedConfirm<'a> {
+    /// Default formatter, set to [DEFAULT_BOOL_FORMATTER](crate::formatter::DEFAULT_BOOL_FORMATTER)
+    pub const DEFAULT_FORMATTER: BoolFormatter<'a> = DEFAULT_BOOL_FORMATTER;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Creates a [TypedConfirm] with the provided message and required phrase,
+    /// and default options.
+    pub fn new(message: &'a str, phrase: &'a str) -> Self {
+        Self {
+            message,
+            phrase,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            render_config: get_configuration(),
+            settings: PromptSettings::default(),
+            key_bindings: KeyBindings::new(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: BoolFormatter<'a>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: Colors of the config are removed or downgraded when the terminal doesn't
+    /// support them, e.g. when the NO_COLOR environment variable is set, unless its
+    /// `color_support` is set. See [ColorSupport](crate::ui::ColorSupport).
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Changes some fields of the prompt's render config, which is the
+    /// global one unless replaced with [with_render_config](Self::with_render_config),
+    /// keeping the others as they are. See
+    /// [Text::with_render_config_overrides](crate::Text::with_render_config_overrides).
+    pub fn with_render_config_overrides<F>(mut self, overrides: F) -> Self
+    where
+        F: FnOnce(RenderConfig<'a>) -> RenderConfig<'a>,
+    {
+        self.render_config = overrides(self.render_config);
+        self
+    }
+
+    prompt_settings_builders!('a);
+
+    /// Sets the key bindings of the prompt, overriding the global and the
+    /// default ones.
+    pub fn with_key_bindings(
+        mut self,
+        key_bindings: KeyBindings<TypedConfirmPromptAction>,
+    ) -> Self {
+        self.key_bindings = key_bindings;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<bool>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<bool> {
+        let mut backend = prompt_backend(self.render_config, self.settings.clone())?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Async version of [prompt](Self::prompt), awaiting the phrase typed by
+    /// the user. See [Text::prompt_async](crate::Text::prompt_async) for more details.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn prompt_async(self) -> InquireResult<bool> {
+        let mut backend =
+            crate::ui::async_prompt_backend(self.render_config, self.settings.clone())?;
+        crate::prompts::prompt_async::prompt_async(TypedConfirmPrompt::from(self), &mut backend)
+            .await
+    }
+
+    /// Turns the prompt into a widget rendered inside a ratatui application,
+    /// answered with whether the user typed the phrase. See [Text::embed](crate::Text::embed) for more
+    /// details.
+    #[cfg(feature = "ratatui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+    pub fn embed(self) -> InquireResult<EmbeddedPrompt<'a, bool>> {
+        let render_config = self.render_config;
+        let settings = self.settings.clone();
+        EmbeddedPrompt::new(
+            TypedConfirmPrompt::from(self),
+            render_config,
+            settings,
+            std::convert::identity,
+        )
+    }
+
+    pub(crate) fn prompt_with_backend<B: TypedConfirmBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<bool> {
+        TypedConfirmPrompt::from(self).prompt(backend)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    error::InquireResult,
+    formatter::BoolFormatter,
+    input::Input,
+    key_bindings::{CancelGuard, EscapeBehavior, KeyBindings, KeyHandler},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TypedConfirmBackend,
+    InputAction, TypedConfirm,
+};
+
+use super::action::TypedConfirmPromptAction;
+
+pub struct TypedConfirmPrompt<'a> {
+    message: &'a str,
+    phrase: &'a str,
+    help_message: Option<&'a str>,
+    formatter: BoolFormatter<'a>,
+    input: Input,
+    key_bindings: KeyBindings<TypedConfirmPromptAction>,
+    key_handler: Option<KeyHandler<'a>>,
+    escape_behavior: EscapeBehavior,
+    cancel_guard: CancelGuard,
+}
+
+impl<'a> From<TypedConfirm<'a>> for TypedConfirmPrompt<'a> {
+    fn from(co: TypedConfirm<'a>) -> Self {
+        Self {
+            message: co.message,
+            phrase: co.phrase,
+            help_message: co.help_message,
+            formatter: co.formatter,
+            input: Input::new(),
+            key_bindings: co.key_bindings,
+            key_handler: co.settings.key_handler,
+            escape_behavior: co.settings.escape_behavior,
+            cancel_guard: co.settings.cancel_guard,
+        }
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for TypedConfirmPrompt<'a>
+where
+    Backend: TypedConfirmBackend,
+{
+    type Config = ();
+    type InnerAction = TypedConfirmPromptAction;
+    type Output = bool;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &() {
+        &()
+    }
+
+    fn key_bindings(&self) -> Option<&KeyBindings<TypedConfirmPromptAction>> {
+        Some(&self.key_bindings)
+    }
+
+    fn key_handler(&self) -> Option<KeyHandler<'_>> {
+        self.key_handler
+    }
+
+    fn escape_behavior(&self) -> EscapeBehavior {
+        self.escape_behavior
+    }
+
+    fn cancel_guard(&self) -> CancelGuard {
+        self.cancel_guard
+    }
+
+    fn clear_input(&mut self) -> InquireResult<ActionResult> {
+        <Self as Prompt<Backend>>::handle(
+            self,
+            TypedConfirmPromptAction::ValueInput(InputAction::Clear),
+        )
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        (self.formatter)(*answer)
+    }
+
+    fn partial_input(&self) -> Option<&str> {
+        Some(self.input.content())
+    }
+
+    fn fill_answer(&mut self, answer: &str) -> InquireResult<()> {
+        self.input = Input::new_with(answer);
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<bool>> {
+        Ok(Some(self.input.content() == self.phrase))
+    }
+
+    fn handle(&mut self, action: TypedConfirmPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TypedConfirmPromptAction::ValueInput(input_action) => {
+                self.input.handle(input_action).into()
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_prompt_with_phrase(self.message, self.phrase, &self.input)?;
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use super::TypedConfirm;
+use crate::ui::{Key, KeyModifiers};
+
+macro_rules! text_to_events {
+    ($text:expr) => {{
+        $text
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .collect::<Vec<Key>>()
+    }};
+}
+
+macro_rules! typed_confirm_test {
+    ($name:ident,$input:expr,$output:expr) => {
+        #[test]
+        fn $name() {
+            let mut backend = crate::prompts::test::fake_backend($input);
+
+            let ans = TypedConfirm::new("Type the name to confirm:", "prod-db")
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+
+            assert_eq!($output, ans);
+        }
+    };
+}
+
+typed_confirm_test!(exact_phrase_confirms, text_to_events!("prod-db\n"), true);
+
+typed_confirm_test!(empty_input_does_not_confirm, vec![Key::Enter], false);
+
+typed_confirm_test!(prefix_does_not_confirm, text_to_events!("prod\n"), false);
+
+typed_confirm_test!(
+    phrase_is_case_sensitive,
+    text_to_events!("PROD-DB\n"),
+    false
+);
+
+typed_confirm_test!(
+    corrected_typo_confirms,
+    {
+        let mut events = text_to_events!("prid");
+        events.push(Key::Backspace);
+        events.push(Key::Backspace);
+        events.append(&mut text_to_events!("od-db\n"));
+        events
+    },
+    true
+);
+
+#[test]
+fn mismatches_are_highlighted_and_phrase_is_hinted() {
+    use crate::{
+        terminal::test::{MockTerminal, MockTerminalToken},
+        ui::{Backend, RenderConfig},
+    };
+    use std::collections::VecDeque;
+
+    let render_config = RenderConfig::default_colored();
+    let mut output = VecDeque::new();
+    {
+        let terminal = MockTerminal::new(&mut output);
+        let mut backend = Backend::new(
+            text_to_events!("pra\n").into_iter(),
+            terminal,
+            render_config,
+        )
+        .unwrap();
+
+        let ans = TypedConfirm::new("Confirm:", "prod-db")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert!(!ans);
+    }
+
+    let rendered = |text: &str, style| {
+        output.iter().any(|token| {
+            matches!(token, MockTerminalToken::Text(t) if t.content == text && t.style == style)
+        })
+    };
+    assert!(rendered("rod-db", render_config.ghost_text));
+    assert!(rendered("pr", render_config.text_input));
+    assert!(rendered("a", render_config.error_message.message));
+    assert!(!rendered("d-db", render_config.ghost_text));
+}
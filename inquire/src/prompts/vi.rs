@@ -0,0 +1,126 @@
+use crate::ui::{Key, KeyModifiers};
+
+/// Editing mode of a prompt with vi keybindings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ViMode {
+    /// Keys move the cursor, e.g. `h`, `j`, `k` and `l`.
+    Normal,
+    /// Keys are typed into the input, until escape is pressed.
+    Insert,
+}
+
+/// Translates the keys pressed in vi mode into the keys of the default
+/// keybindings, which prompts then parse into actions as usual.
+#[derive(Copy, Clone, Debug)]
+pub struct ViKeys {
+    mode: ViMode,
+    /// Whether `g` was pressed, waiting for a second one to jump to the
+    /// start.
+    pending_g: bool,
+}
+
+impl ViKeys {
+    pub fn new(mode: ViMode) -> Self {
+        Self {
+            mode,
+            pending_g: false,
+        }
+    }
+
+    /// Returns the key the prompt should handle in place of the pressed one,
+    /// or `None` if it was consumed, e.g. to switch modes.
+    pub fn translate(&mut self, key: Key) -> Option<Key> {
+        let pending_g = std::mem::take(&mut self.pending_g);
+
+        if self.mode == ViMode::Insert {
+            if key == Key::Escape {
+                self.mode = ViMode::Normal;
+                return None;
+            }
+            return Some(key);
+        }
+
+        let c = match key {
+            Key::Char(c, modifiers) if (modifiers - KeyModifiers::SHIFT).is_empty() => c,
+            Key::Backspace => return Some(Key::Left(KeyModifiers::NONE)),
+            key => return Some(key),
+        };
+
+        let key = match c {
+            'g' if pending_g => Key::Home,
+            'g' => {
+                self.pending_g = true;
+                return None;
+            }
+            'G' | '$' => Key::End,
+            '0' | '^' => Key::Home,
+            'h' => Key::Left(KeyModifiers::NONE),
+            'l' => Key::Right(KeyModifiers::NONE),
+            'j' => Key::Down(KeyModifiers::NONE),
+            'k' => Key::Up(KeyModifiers::NONE),
+            'w' => Key::Right(KeyModifiers::CONTROL),
+            'b' => Key::Left(KeyModifiers::CONTROL),
+            'x' => Key::Delete(KeyModifiers::NONE),
+            'D' => Key::Char('k', KeyModifiers::CONTROL),
+            'u' => Key::Char('z', KeyModifiers::CONTROL),
+            'i' | '/' => return self.insert(None),
+            'a' => return self.insert(Some(Key::Right(KeyModifiers::NONE))),
+            'I' => return self.insert(Some(Key::Home)),
+            'A' => return self.insert(Some(Key::End)),
+            ' ' => Key::Char(' ', KeyModifiers::NONE),
+            _ => return None,
+        };
+
+        Some(key)
+    }
+
+    fn insert(&mut self, key: Option<Key>) -> Option<Key> {
+        self.mode = ViMode::Insert;
+        key
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ui::{Key, KeyModifiers};
+
+    use super::{ViKeys, ViMode};
+
+    fn translate(vi: &mut ViKeys, keys: &str) -> Vec<Option<Key>> {
+        Key::char_keys_from_str(keys)
+            .into_iter()
+            .map(|key| vi.translate(key))
+            .collect()
+    }
+
+    #[test]
+    fn normal_mode_keys_move_the_cursor() {
+        let mut vi = ViKeys::new(ViMode::Normal);
+
+        assert_eq!(
+            vec![
+                Some(Key::Down(KeyModifiers::NONE)),
+                Some(Key::Up(KeyModifiers::NONE)),
+                None,
+                Some(Key::Home),
+                Some(Key::End),
+                None,
+            ],
+            translate(&mut vi, "jkggGz")
+        );
+        assert_eq!(ViMode::Normal, vi.mode);
+    }
+
+    #[test]
+    fn insert_mode_is_left_with_escape() {
+        let mut vi = ViKeys::new(ViMode::Normal);
+
+        assert_eq!(
+            vec![None, Some(Key::Char('j', KeyModifiers::NONE))],
+            translate(&mut vi, "/j")
+        );
+        assert_eq!(None, vi.translate(Key::Escape));
+        assert_eq!(ViMode::Normal, vi.mode);
+        assert_eq!(Some(Key::Escape), vi.translate(Key::Escape));
+    }
+}
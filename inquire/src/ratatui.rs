@@ -0,0 +1,678 @@
+//! Integration with [ratatui](https://ratatui.rs) applications.
+//!
+//! Prompts usually take over the terminal until they are answered, which
+//! doesn't play well with TUIs that own the whole screen. The prompts of
+//! this crate can instead be turned into an [`EmbeddedPrompt`], e.g. with
+//! [Text::embed](crate::Text::embed), a widget rendered in an area of the
+//! application's layout and driven by the key events the application reads.
+//!
+//! Validation, filtering, autocompletion and editing behave as usual, only
+//! the rendering and the event loop are left to the application.
+//!
+//! This module depends on `ratatui-core`, which requires a more recent Rust
+//! version than the rest of the crate, currently 1.88.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{ratatui::EmbeddedPrompt, ui::Key, Text};
+//! use ratatui_core::{buffer::Buffer, layout::Rect};
+//!
+//! let mut prompt: EmbeddedPrompt<String> = Text::new("What's your name?").embed()?;
+//! let area = Rect::new(0, 0, 40, 5);
+//! let mut buf = Buffer::empty(area);
+//!
+//! // in the application's event loop
+//! prompt.render(area, &mut buf);
+//! if let Some(name) = prompt.handle_key(Key::Enter)? {
+//!     println!("Hello {name}");
+//! }
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    cell::RefCell,
+    convert::TryFrom,
+    io::Result,
+    rc::Rc,
+    time::{Duration, SystemTime},
+};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color as TuiColor, Modifier, Style},
+    widgets::Widget,
+};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    key_bindings::KeySequence,
+    prompts::{
+        prompt::{finish, flush_key_sequence, handle_key, KeyOutcome, Prompt},
+        PromptSettings,
+    },
+    terminal::{emulated::EmulatedScreen, Terminal, TerminalSize},
+    ui::{
+        Attributes, Backend, Color, InputReader, Key, RenderConfig, StyleSheet, Styled, Viewport,
+    },
+};
+
+/// Backend of embedded prompts, whose keys are given by the application
+/// instead of being read by the backend.
+pub(crate) type EmbeddedBackend<'a> = Backend<'a, NoInput, EmbeddedTerminal>;
+
+/// Prompt rendered as a ratatui widget, answered with the key events handed
+/// to [`handle_key`](Self::handle_key).
+///
+/// The prompt fills the area it is rendered to, wrapping its content to the
+/// area's width and shrinking its list of options to fit its height. Once
+/// answered or canceled, it keeps displaying its final state, e.g. the
+/// submitted answer, and ignores further keys.
+pub struct EmbeddedPrompt<'a, T> {
+    driver: Box<dyn Driver<T> + 'a>,
+    screen: Rc<RefCell<ScreenState>>,
+    needs_redraw: bool,
+    finished: bool,
+    error: Option<InquireError>,
+    area: Rect,
+}
+
+impl<'a, T> EmbeddedPrompt<'a, T> {
+    #[allow(clippy::large_types_passed_by_value)]
+    pub(crate) fn new<P, F>(
+        mut prompt: P,
+        render_config: RenderConfig<'a>,
+        settings: PromptSettings<'a>,
+        map: F,
+    ) -> InquireResult<Self>
+    where
+        P: Prompt<EmbeddedBackend<'a>> + 'a,
+        F: FnMut(P::Output) -> T + 'a,
+    {
+        prompt.setup()?;
+
+        let screen = Rc::new(RefCell::new(ScreenState::default()));
+        let terminal = EmbeddedTerminal {
+            screen: Rc::clone(&screen),
+        };
+        let mut backend = Backend::new(NoInput, terminal, render_config)?;
+        backend.apply_settings(settings);
+        // the viewport is clipped to the size of the area
+        backend.set_viewport(Some(Viewport::new(0, 0, u16::MAX, u16::MAX)));
+
+        let driver = Session {
+            prompt,
+            backend,
+            map,
+            key_sequence: KeySequence::default(),
+            started_at: SystemTime::now(),
+        };
+
+        Ok(Self {
+            driver: Box::new(driver),
+            screen,
+            needs_redraw: true,
+            finished: false,
+            error: None,
+            area: Rect::default(),
+        })
+    }
+
+    /// Handles a key pressed by the user, returning the answer once it is
+    /// submitted.
+    ///
+    /// With the `crossterm` feature, the key events read by ratatui
+    /// applications can be converted with `Key::from(key_event)`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [InquireError::OperationCanceled] or
+    /// [InquireError::OperationInterrupted] when the user cancels or
+    /// interrupts the prompt, and with any error raised while rendering the
+    /// prompt since the last call.
+    pub fn handle_key(&mut self, key: Key) -> InquireResult<Option<T>> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        if self.finished {
+            return Ok(None);
+        }
+
+        let outcome = self.driver.handle_key(key);
+        self.finish_with(outcome)
+    }
+
+    /// Marks the prompt as finished once the outcome of a key submitted an
+    /// answer or failed.
+    fn finish_with(&mut self, outcome: InquireResult<KeyOutcome<T>>) -> InquireResult<Option<T>> {
+        match outcome {
+            Ok(KeyOutcome::Handled(result)) => {
+                self.needs_redraw |= result.needs_redraw();
+                Ok(None)
+            }
+            Ok(KeyOutcome::Submitted(answer)) => {
+                self.finished = true;
+                Ok(Some(answer))
+            }
+            Err(err) => {
+                self.finished = true;
+                Err(err)
+            }
+        }
+    }
+
+    /// Interval at which the application should call [`tick`](Self::tick)
+    /// while the prompt is displayed, for prompts that update in the
+    /// background, e.g. with asynchronous autocompletion, or that wait for
+    /// the second key of a bound sequence. `None` if the prompt doesn't need
+    /// to be ticked.
+    pub fn tick_interval(&self) -> Option<Duration> {
+        match self.finished {
+            true => None,
+            false => self.driver.tick_interval(),
+        }
+    }
+
+    /// Lets the prompt process the updates made in the background since the
+    /// last tick, and apply the first key of a bound sequence that timed out,
+    /// returning the answer if that key submitted it.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`handle_key`](Self::handle_key) does.
+    pub fn tick(&mut self) -> InquireResult<Option<T>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let outcome = self.driver.tick();
+        self.finish_with(outcome)
+    }
+
+    /// Whether the prompt was answered, canceled or interrupted.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Position of the cursor in the area the prompt was last rendered to,
+    /// if the prompt is waiting for text input. Applications usually pass
+    /// it to `Frame::set_cursor_position` after rendering the prompt.
+    pub fn cursor_position(&self) -> Option<Position> {
+        let screen = self.screen.borrow();
+        if self.finished || !screen.cursor_visible {
+            return None;
+        }
+
+        let (row, col) = screen.screen.cursor();
+        let (row, col) = (u16::try_from(row).ok()?, u16::try_from(col).ok()?);
+        if row >= self.area.height || col >= self.area.width {
+            return None;
+        }
+
+        Some(Position::new(self.area.x + col, self.area.y + row))
+    }
+
+    /// Renders the prompt into the area of the buffer.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let Some(size) = TerminalSize::new(area.width, area.height) else {
+            return;
+        };
+        self.area = area;
+
+        if self.screen.borrow_mut().resize(size) {
+            self.needs_redraw = true;
+        }
+
+        if self.needs_redraw && !self.finished {
+            self.needs_redraw = false;
+            if let Err(err) = self.driver.redraw() {
+                self.error = Some(err);
+            }
+        }
+
+        let screen = self.screen.borrow();
+        for (y, row) in (area.y..area.bottom()).zip(screen.screen.rows()) {
+            for (x, cell) in (area.x..area.right()).zip(row) {
+                let target = &mut buf[(x, y)];
+                if cell.text.is_empty() {
+                    // second column of a wide character
+                    target.reset();
+                    continue;
+                }
+                target.set_symbol(&cell.text).set_style(style(cell.style));
+            }
+        }
+    }
+}
+
+impl<T> Widget for &mut EmbeddedPrompt<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        EmbeddedPrompt::render(self, area, buf);
+    }
+}
+
+/// Prompt being run along with its backend, hiding their types from
+/// [`EmbeddedPrompt`].
+trait Driver<T> {
+    fn redraw(&mut self) -> InquireResult<()>;
+    /// Handles the key, rendering the final answer if it was submitted.
+    fn handle_key(&mut self, key: Key) -> InquireResult<KeyOutcome<T>>;
+    fn tick_interval(&self) -> Option<Duration>;
+    /// Ticks the prompt and applies the first key of a timed out sequence.
+    fn tick(&mut self) -> InquireResult<KeyOutcome<T>>;
+}
+
+struct Session<'a, P, F> {
+    prompt: P,
+    backend: EmbeddedBackend<'a>,
+    map: F,
+    key_sequence: KeySequence,
+    started_at: SystemTime,
+}
+
+impl<'a, P, F, T> Driver<T> for Session<'a, P, F>
+where
+    P: Prompt<EmbeddedBackend<'a>>,
+    F: FnMut(P::Output) -> T,
+{
+    fn redraw(&mut self) -> InquireResult<()> {
+        self.prompt.redraw(&mut self.backend)
+    }
+
+    fn handle_key(&mut self, key: Key) -> InquireResult<KeyOutcome<T>> {
+        let outcome = handle_key(
+            &mut self.prompt,
+            key,
+            &mut self.backend,
+            &mut self.key_sequence,
+            self.started_at,
+        )?;
+        self.finish(outcome)
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        match (self.prompt.tick_interval(), self.key_sequence.remaining()) {
+            (Some(interval), Some(remaining)) => Some(interval.min(remaining)),
+            (interval, remaining) => interval.or(remaining),
+        }
+    }
+
+    fn tick(&mut self) -> InquireResult<KeyOutcome<T>> {
+        let ticked = self.prompt.tick()?;
+        let outcome = flush_key_sequence(
+            &mut self.prompt,
+            &mut self.backend,
+            &mut self.key_sequence,
+            self.started_at,
+        )?;
+
+        match self.finish(outcome)? {
+            KeyOutcome::Handled(result) => Ok(KeyOutcome::Handled(result.merge(ticked))),
+            submitted @ KeyOutcome::Submitted(_) => Ok(submitted),
+        }
+    }
+}
+
+impl<'a, P, F, T> Session<'a, P, F>
+where
+    P: Prompt<EmbeddedBackend<'a>>,
+    F: FnMut(P::Output) -> T,
+{
+    /// Renders the final answer if the outcome submitted it.
+    fn finish(&mut self, outcome: KeyOutcome<P::Output>) -> InquireResult<KeyOutcome<T>> {
+        match outcome {
+            KeyOutcome::Handled(result) => Ok(KeyOutcome::Handled(result)),
+            KeyOutcome::Submitted(answer) => {
+                let answer = finish(&self.prompt, answer, &mut self.backend, self.started_at)?;
+                Ok(KeyOutcome::Submitted((self.map)(answer)))
+            }
+        }
+    }
+}
+
+/// Input reader of embedded prompts, which never reads keys as they are
+/// handed to the prompt by the application.
+pub(crate) struct NoInput;
+
+impl InputReader for NoInput {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        Err(InquireError::InvalidConfiguration(
+            "keys of embedded prompts are given with EmbeddedPrompt::handle_key".into(),
+        ))
+    }
+}
+
+/// Contents of the area an embedded prompt is rendered to.
+#[derive(Default)]
+struct ScreenState {
+    screen: EmulatedScreen,
+    size: TerminalSize,
+    cursor_visible: bool,
+}
+
+impl ScreenState {
+    /// Sets the size of the area, returning whether it changed. Cells
+    /// outside of the new size are discarded, so that they don't show up
+    /// again if the area grows back.
+    fn resize(&mut self, size: TerminalSize) -> bool {
+        if self.size == size {
+            return false;
+        }
+
+        self.size = size;
+        self.screen
+            .truncate(size.width() as usize, size.height() as usize);
+        true
+    }
+}
+
+/// Terminal writing to the screen of an embedded prompt.
+pub(crate) struct EmbeddedTerminal {
+    screen: Rc<RefCell<ScreenState>>,
+}
+
+impl Terminal for EmbeddedTerminal {
+    fn get_size(&self) -> Result<Option<TerminalSize>> {
+        Ok(Some(self.screen.borrow().size))
+    }
+
+    fn write(&mut self, val: &str) -> Result<()> {
+        self.screen
+            .borrow_mut()
+            .screen
+            .print(val, StyleSheet::empty());
+        Ok(())
+    }
+
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
+        self.screen
+            .borrow_mut()
+            .screen
+            .print(val.content, val.style);
+        Ok(())
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        self.screen.borrow_mut().screen.clear_line();
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        self.screen.borrow_mut().screen.clear_until_new_line();
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        self.screen.borrow_mut().cursor_visible = false;
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        self.screen.borrow_mut().cursor_visible = true;
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_up(cnt);
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_down(cnt);
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, cnt: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_left(cnt);
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, cnt: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_right(cnt);
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_move_to_column(idx);
+        Ok(())
+    }
+
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        self.screen.borrow_mut().screen.cursor_move_to(col, row);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn style(style: StyleSheet) -> Style {
+    let mut result = Style::default();
+
+    if let Some(fg) = style.fg {
+        result = result.fg(color(fg));
+    }
+    if let Some(bg) = style.bg {
+        result = result.bg(color(bg));
+    }
+    if style.att.contains(Attributes::BOLD) {
+        result = result.add_modifier(Modifier::BOLD);
+    }
+    if style.att.contains(Attributes::ITALIC) {
+        result = result.add_modifier(Modifier::ITALIC);
+    }
+
+    result
+}
+
+fn color(color: Color) -> TuiColor {
+    match color {
+        Color::Black => TuiColor::Black,
+        Color::LightRed => TuiColor::LightRed,
+        Color::DarkRed => TuiColor::Red,
+        Color::LightGreen => TuiColor::LightGreen,
+        Color::DarkGreen => TuiColor::Green,
+        Color::LightYellow => TuiColor::LightYellow,
+        Color::DarkYellow => TuiColor::Yellow,
+        Color::LightBlue => TuiColor::LightBlue,
+        Color::DarkBlue => TuiColor::Blue,
+        Color::LightMagenta => TuiColor::LightMagenta,
+        Color::DarkMagenta => TuiColor::Magenta,
+        Color::LightCyan => TuiColor::LightCyan,
+        Color::DarkCyan => TuiColor::Cyan,
+        Color::White => TuiColor::White,
+        Color::Grey => TuiColor::Gray,
+        Color::DarkGrey => TuiColor::DarkGray,
+        Color::Rgb { r, g, b } => TuiColor::Rgb(r, g, b),
+        Color::AnsiValue(value) => TuiColor::Indexed(value),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, time::Duration};
+
+    use ratatui_core::{buffer::Buffer, layout::Rect};
+
+    use crate::{
+        event::PromptEvent,
+        key_bindings::{CancelGuard, EscapeBehavior, Handled, KeyBindings, PromptStateView},
+        ui::{AnswerRedaction, Key, KeyModifiers},
+        InquireError, Select, Text,
+    };
+
+    fn line(buf: &Buffer, y: u16) -> String {
+        let text: String = (0..buf.area.width)
+            .map(|x| buf[(x, y)].symbol().to_owned())
+            .collect();
+        text.trim_end().to_owned()
+    }
+
+    #[test]
+    fn text_prompt_is_answered_with_given_keys() {
+        let area = Rect::new(0, 0, 30, 3);
+        let mut prompt = Text::new("Name:").embed().unwrap();
+
+        let mut buf = Buffer::empty(area);
+        prompt.render(area, &mut buf);
+        assert_eq!("? Name:", line(&buf, 0));
+        assert_eq!(Some((8, 0).into()), prompt.cursor_position());
+
+        for c in ['a', 'b'] {
+            let answer = prompt.handle_key(Key::Char(c, KeyModifiers::NONE));
+            assert!(matches!(answer, Ok(None)));
+        }
+        assert_eq!(
+            Some("ab".to_owned()),
+            prompt.handle_key(Key::Enter).unwrap()
+        );
+        assert!(prompt.is_finished());
+
+        let mut buf = Buffer::empty(area);
+        prompt.render(area, &mut buf);
+        assert_eq!("> Name: ab", line(&buf, 0));
+        assert_eq!(None, prompt.cursor_position());
+    }
+
+    #[test]
+    fn first_keys_of_sequences_are_applied_when_ticked_after_the_timeout() {
+        let g = Key::Char('g', KeyModifiers::NONE);
+        let mut prompt = Text::new("Name:")
+            .with_key_bindings(
+                KeyBindings::new()
+                    .remap_sequence(g, g, Key::Home)
+                    .with_sequence_timeout(Duration::ZERO),
+            )
+            .embed()
+            .unwrap();
+
+        assert!(prompt.tick_interval().is_none());
+        assert!(prompt.handle_key(g).unwrap().is_none());
+        assert_eq!(Some(Duration::ZERO), prompt.tick_interval());
+
+        assert!(prompt.tick().unwrap().is_none());
+        assert!(prompt.tick_interval().is_none());
+        assert_eq!(Some("g".to_owned()), prompt.handle_key(Key::Enter).unwrap());
+    }
+
+    #[test]
+    fn redacted_answers_of_embedded_prompts_are_not_rendered() {
+        let area = Rect::new(0, 0, 30, 3);
+        let mut prompt = Text::new("Account ID:")
+            .with_answer_redaction(AnswerRedaction::Placeholder("****"))
+            .embed()
+            .unwrap();
+
+        prompt
+            .handle_key(Key::Char('4', KeyModifiers::NONE))
+            .unwrap();
+        assert_eq!(Some("4".to_owned()), prompt.handle_key(Key::Enter).unwrap());
+
+        let mut buf = Buffer::empty(area);
+        prompt.render(area, &mut buf);
+        assert_eq!("> Account ID: ****", line(&buf, 0));
+    }
+
+    #[test]
+    fn select_prompt_fits_the_area_and_can_be_canceled() {
+        let area = Rect::new(2, 1, 60, 5);
+        let options = vec!["a", "b", "c", "d", "e", "f"];
+        let mut prompt = Select::new("Letter:", options).embed().unwrap();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 70, 7));
+        prompt.render(area, &mut buf);
+        assert_eq!("", line(&buf, 0));
+        assert_eq!("  ? Letter:", line(&buf, 1));
+        assert_eq!("  > a", line(&buf, 2));
+        assert_eq!("  v c", line(&buf, 4));
+        assert!(line(&buf, 5).starts_with("  [↑↓ to move"));
+        assert_eq!("", line(&buf, 6));
+
+        for _ in 0..3 {
+            prompt.handle_key(Key::Down(KeyModifiers::NONE)).unwrap();
+        }
+        prompt.render(area, &mut buf);
+        assert_eq!("  > d", line(&buf, 3));
+        assert_eq!("  v e", line(&buf, 4));
+
+        let canceled = prompt.handle_key(Key::Escape);
+        assert!(matches!(canceled, Err(InquireError::OperationCanceled)));
+        assert!(prompt.handle_key(Key::Enter).unwrap().is_none());
+    }
+
+    #[test]
+    fn key_handlers_of_embedded_prompts_are_called() {
+        let on_key = |key: Key, view: &mut PromptStateView<'_>| match key {
+            Key::F(5, _) => {
+                view.cancel();
+                Handled::Yes
+            }
+            _ => Handled::No,
+        };
+        let mut prompt = Text::new("Name:")
+            .with_key_handler(&on_key)
+            .embed()
+            .unwrap();
+
+        let canceled = prompt.handle_key(Key::F(5, KeyModifiers::NONE));
+        assert!(matches!(canceled, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn escape_behaviors_of_embedded_prompts_are_followed() {
+        let mut prompt = Text::new("Name:")
+            .with_escape_behavior(EscapeBehavior::Ignore)
+            .embed()
+            .unwrap();
+
+        assert!(matches!(prompt.handle_key(Key::Escape), Ok(None)));
+        assert!(!prompt.is_finished());
+    }
+
+    #[test]
+    fn on_change_hooks_of_embedded_prompts_are_called() {
+        let events = RefCell::new(vec![]);
+        let on_change = |event: &PromptEvent| events.borrow_mut().push(event.clone());
+        let mut prompt = Text::new("Name:")
+            .with_on_change(&on_change)
+            .embed()
+            .unwrap();
+
+        assert!(matches!(
+            prompt.handle_key(Key::Char('a', KeyModifiers::NONE)),
+            Ok(None)
+        ));
+        drop(prompt);
+
+        assert_eq!(
+            vec![PromptEvent::InputChanged("a".into())],
+            events.into_inner()
+        );
+    }
+
+    #[test]
+    fn cancel_guards_of_embedded_prompts_are_followed() {
+        let mut prompt = Text::new("Name:")
+            .with_cancel_guard(CancelGuard::Confirm)
+            .embed()
+            .unwrap();
+
+        assert!(matches!(
+            prompt.handle_key(Key::Char('a', KeyModifiers::NONE)),
+            Ok(None)
+        ));
+        assert!(matches!(prompt.handle_key(Key::Escape), Ok(None)));
+        assert!(matches!(
+            prompt.handle_key(Key::Char('n', KeyModifiers::NONE)),
+            Ok(None)
+        ));
+        assert!(!prompt.is_finished());
+        assert_eq!(
+            Some("a".to_string()),
+            prompt.handle_key(Key::Enter).unwrap()
+        );
+    }
+}
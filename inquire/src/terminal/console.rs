@@ -5,6 +5,7 @@ use console::{Attribute, Color, Key, Style, Term};
 use crate::{
     error::InquireResult,
     ui::{Attributes, InputReader, StyleSheet, Styled},
+    OutputStream,
 };
 
 use super::Terminal;
@@ -16,10 +17,13 @@ pub struct ConsoleTerminal {
 
 impl ConsoleTerminal {
     #[allow(unused)]
-    pub fn new() -> Self {
-        Self {
-            term: Term::stderr(),
-        }
+    pub fn new(output: OutputStream) -> Self {
+        let term = match output {
+            OutputStream::Stderr => Term::stderr(),
+            OutputStream::Stdout => Term::stdout(),
+        };
+
+        Self { term }
     }
 }
 
@@ -67,6 +71,10 @@ impl Terminal for ConsoleTerminal {
         Ok(())
     }
 
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        self.term.move_cursor_to(col as usize, row as usize)
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.term.flush()
     }
@@ -77,11 +85,11 @@ impl Terminal for ConsoleTerminal {
         Ok(super::TerminalSize::new(width, height))
     }
 
-    fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
+    fn write(&mut self, val: &str) -> Result<()> {
         write!(self.term, "{}", val)
     }
 
-    fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
         let styled_object = Style::from(val.style).apply_to(&val.content);
         write!(self.term, "{}", styled_object)
     }
@@ -101,6 +109,16 @@ impl Terminal for ConsoleTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.term.show_cursor()
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<bool> {
+        // console has no built-in method for the alternate screen either
+        write!(self.term, "\x1b[?1049h\x1b[H")?;
+        Ok(true)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        write!(self.term, "\x1b[?1049l")
+    }
 }
 
 impl Drop for ConsoleTerminal {
@@ -113,12 +131,12 @@ impl From<StyleSheet> for Style {
     fn from(from: StyleSheet) -> Self {
         let mut style = Style::new();
 
-        let bg = from.bg.and_then(crate::ui::Color::into_console_color);
+        let bg = from.bg.map(crate::ui::Color::into_console_color);
         if let Some(bg) = bg {
             style = style.bg(bg);
         }
 
-        let fg = from.fg.and_then(crate::ui::Color::into_console_color);
+        let fg = from.fg.map(crate::ui::Color::into_console_color);
         if let Some(fg) = fg {
             style = style.fg(fg);
         }
@@ -136,19 +154,19 @@ impl From<StyleSheet> for Style {
 }
 
 impl crate::ui::Color {
-    fn into_console_color(self) -> Option<Color> {
+    fn into_console_color(self) -> Color {
         use crate::ui::Color as C;
         match self {
-            C::Black | C::DarkGrey => Some(Color::Black),
-            C::LightRed | C::DarkRed => Some(Color::Red),
-            C::LightGreen | C::DarkGreen => Some(Color::Green),
-            C::LightYellow | C::DarkYellow => Some(Color::Yellow),
-            C::LightBlue | C::DarkBlue => Some(Color::Blue),
-            C::LightMagenta | C::DarkMagenta => Some(Color::Magenta),
-            C::LightCyan | C::DarkCyan => Some(Color::Cyan),
-            C::White | C::Grey => Some(Color::White),
-            C::Rgb { r: _, g: _, b: _ } => None,
-            C::AnsiValue(v) => Some(Color::Color256(v)),
+            C::Black | C::DarkGrey => Color::Black,
+            C::LightRed | C::DarkRed => Color::Red,
+            C::LightGreen | C::DarkGreen => Color::Green,
+            C::LightYellow | C::DarkYellow => Color::Yellow,
+            C::LightBlue | C::DarkBlue => Color::Blue,
+            C::LightMagenta | C::DarkMagenta => Color::Magenta,
+            C::LightCyan | C::DarkCyan => Color::Cyan,
+            C::White | C::Grey => Color::White,
+            C::Rgb { r, g, b } => Color::TrueColor(r, g, b),
+            C::AnsiValue(v) => Color::Color256(v),
         }
     }
 }
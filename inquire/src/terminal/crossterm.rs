@@ -1,4 +1,7 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    io::{Result, Write},
+    time::Duration,
+};
 
 use crossterm::{
     cursor,
@@ -12,18 +15,20 @@ use crossterm::{
 use crate::{
     error::InquireResult,
     ui::{Attributes, InputReader, Key, Styled},
+    OutputStream,
 };
 
-use super::Terminal;
+use super::{StdStream, Terminal};
 
 enum IO {
-    Std(Stderr),
+    Std(StdStream),
     #[allow(unused)]
     Test(Vec<u8>),
 }
 
 pub struct CrosstermTerminal {
     io: IO,
+    batch: Option<Vec<u8>>,
 }
 
 pub struct CrosstermKeyReader;
@@ -44,18 +49,77 @@ impl InputReader for CrosstermKeyReader {
             }
         }
     }
+
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        if event::poll(timeout)? {
+            if let event::Event::Key(key_event) = event::read()? {
+                if KeyEventKind::Press == key_event.kind {
+                    return Ok(Some(key_event.into()));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Stream of the keys pressed by the user, read from crossterm's
+/// [EventStream](event::EventStream) without blocking the async runtime.
+#[cfg(feature = "async")]
+pub struct CrosstermKeyStream {
+    events: event::EventStream,
+}
+
+#[cfg(feature = "async")]
+impl CrosstermKeyStream {
+    pub fn new() -> Self {
+        Self {
+            events: event::EventStream::new(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl futures_core::Stream for CrosstermKeyStream {
+    type Item = InquireResult<Key>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        loop {
+            match std::pin::Pin::new(&mut self.events).poll_next(cx) {
+                Poll::Ready(Some(Ok(event::Event::Key(key_event)))) => {
+                    if KeyEventKind::Press == key_event.kind {
+                        return Poll::Ready(Some(Ok(key_event.into())));
+                    }
+                }
+                Poll::Ready(Some(Ok(_))) => {}
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
 }
 
 impl CrosstermTerminal {
-    pub fn new() -> InquireResult<Self> {
+    pub fn new(output: OutputStream) -> InquireResult<Self> {
         terminal::enable_raw_mode()?;
 
         Ok(Self {
-            io: IO::Std(stderr()),
+            io: IO::Std(StdStream::new(output)),
+            batch: None,
         })
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
+        if let Some(batch) = &mut self.batch {
+            return batch;
+        }
+
         match &mut self.io {
             IO::Std(w) => w,
             IO::Test(w) => w,
@@ -131,19 +195,44 @@ impl Terminal for CrosstermTerminal {
         self.write_command(cursor::MoveToColumn(idx))
     }
 
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        self.write_command(cursor::MoveTo(col, row))
+    }
+
     fn flush(&mut self) -> Result<()> {
-        self.get_writer().flush()
+        let writer: &mut dyn Write = match &mut self.io {
+            IO::Std(w) => w,
+            IO::Test(w) => w,
+        };
+
+        if let Some(batch) = &mut self.batch {
+            writer.write_all(batch)?;
+            batch.clear();
+        }
+
+        writer.flush()
+    }
+
+    fn set_batched_output(&mut self, batched: bool) {
+        match (batched, self.batch.is_some()) {
+            (true, false) => self.batch = Some(Vec::new()),
+            (false, true) => {
+                let _unused = self.flush();
+                self.batch = None;
+            }
+            _ => {}
+        }
     }
 
     fn get_size(&self) -> Result<Option<super::TerminalSize>> {
         terminal::size().map(|(width, height)| super::TerminalSize::new(width, height))
     }
 
-    fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
+    fn write(&mut self, val: &str) -> Result<()> {
         self.write_command(Print(val))
     }
 
-    fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
         if let Some(color) = val.style.fg {
             self.set_fg_color(color)?;
         }
@@ -154,7 +243,7 @@ impl Terminal for CrosstermTerminal {
             self.set_attributes(val.style.att)?;
         }
 
-        self.write(&val.content)?;
+        self.write(val.content)?;
 
         if val.style.fg.as_ref().is_some() {
             self.reset_fg_color()?;
@@ -184,6 +273,16 @@ impl Terminal for CrosstermTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.write_command(cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<bool> {
+        self.write_command(terminal::EnterAlternateScreen)?;
+        self.write_command(cursor::MoveTo(0, 0))?;
+        Ok(true)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::LeaveAlternateScreen)
+    }
 }
 
 impl Drop for CrosstermTerminal {
@@ -317,6 +416,11 @@ impl From<KeyEvent> for Key {
                 modifiers: m,
                 ..
             } => Self::Char(c, m.into()),
+            KeyEvent {
+                code: KeyCode::F(n),
+                modifiers: m,
+                ..
+            } => Self::F(n, m.into()),
             #[allow(deprecated)]
             _ => Self::Any,
         }
@@ -337,6 +441,7 @@ mod test {
         pub fn new_in_memory_output() -> Self {
             Self {
                 io: IO::Test(Vec::new()),
+                batch: None,
             }
         }
 
@@ -367,6 +472,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn batched_output_is_written_on_flush() {
+        let mut terminal = CrosstermTerminal::new_in_memory_output();
+        terminal.set_batched_output(true);
+
+        terminal.write("testing ").unwrap();
+        terminal.write("batching").unwrap();
+        assert!(terminal.get_buffer_content().is_empty());
+
+        terminal.flush().unwrap();
+        assert_eq!(
+            "testing batching",
+            std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
+        );
+
+        terminal.write("leftover").unwrap();
+        terminal.set_batched_output(false);
+        assert_eq!(
+            "leftover",
+            std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
+        );
+    }
+
     #[test]
     fn style_management() {
         let mut terminal = CrosstermTerminal::new_in_memory_output();
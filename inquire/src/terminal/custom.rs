@@ -0,0 +1,81 @@
+use std::{fmt, sync::Arc};
+
+use crate::{error::InquireResult, ui::InputReader};
+
+use super::Terminal;
+
+type Factory = dyn Fn() -> InquireResult<(Box<dyn InputReader>, Box<dyn Terminal>)> + Send + Sync;
+
+/// Input reader and terminal used by prompts instead of the default ones,
+/// e.g. to render prompts in an embedded terminal widget, over an SSH
+/// session or in a browser terminal bridged to the application.
+///
+/// The backend holds a function creating the reader and the terminal, which
+/// is called each time a prompt is displayed, so that the terminal can enter
+/// raw mode when created and leave it when dropped, like the default one.
+///
+/// It is set per prompt, e.g. with [Text::with_backend](crate::Text::with_backend),
+/// or for all prompts with [set_global_backend](crate::set_global_backend).
+/// Prompts using it are always considered interactive, whether or not the
+/// process runs in a TTY.
+#[derive(Clone)]
+pub struct CustomBackend {
+    factory: Arc<Factory>,
+}
+
+impl CustomBackend {
+    /// Creates a backend whose reader and terminal are created by the
+    /// provided function.
+    pub fn new<F, I, T>(factory: F) -> Self
+    where
+        F: Fn() -> InquireResult<(I, T)> + Send + Sync + 'static,
+        I: InputReader + 'static,
+        T: Terminal + 'static,
+    {
+        let factory = move || {
+            let (input_reader, terminal) = factory()?;
+            let input_reader: Box<dyn InputReader> = Box::new(input_reader);
+            let terminal: Box<dyn Terminal> = Box::new(terminal);
+            Ok((input_reader, terminal))
+        };
+
+        Self {
+            factory: Arc::new(factory),
+        }
+    }
+
+    /// Creates the reader and terminal of a prompt.
+    pub(crate) fn create(&self) -> InquireResult<(Box<dyn InputReader>, Box<dyn Terminal>)> {
+        (self.factory)()
+    }
+}
+
+impl fmt::Debug for CustomBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomBackend").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        terminal::test::recording_backend,
+        ui::{Key, KeyModifiers},
+        Text,
+    };
+
+    #[test]
+    fn prompts_are_rendered_with_the_custom_backend() {
+        let keys = vec![
+            Key::Char('h', KeyModifiers::NONE),
+            Key::Char('i', KeyModifiers::NONE),
+            Key::Enter,
+        ];
+        let (backend, output) = recording_backend(keys);
+
+        let answer = Text::new("Greeting:").with_backend(backend).prompt();
+
+        assert_eq!("hi", answer.unwrap());
+        assert!(output.lock().unwrap().contains("Greeting: hi"));
+    }
+}
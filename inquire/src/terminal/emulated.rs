@@ -0,0 +1,137 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::ui::StyleSheet;
+
+/// Column of the emulated screen. Wide characters are followed by a cell
+/// with empty text, standing for their second column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScreenCell {
+    pub text: String,
+    pub style: StyleSheet,
+}
+
+impl ScreenCell {
+    pub fn blank() -> Self {
+        Self {
+            text: " ".into(),
+            style: StyleSheet::empty(),
+        }
+    }
+}
+
+/// Grid of cells written by an in-memory terminal, without scrolling: rows
+/// are added as the cursor moves past the last one.
+#[derive(Default)]
+pub struct EmulatedScreen {
+    rows: Vec<Vec<ScreenCell>>,
+    row: usize,
+    col: usize,
+}
+
+impl EmulatedScreen {
+    /// Rows written so far, each as long as its last written column.
+    pub fn rows(&self) -> &[Vec<ScreenCell>] {
+        &self.rows
+    }
+
+    /// Position of the cursor, as `(row, col)`.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    pub fn print(&mut self, text: &str, style: StyleSheet) {
+        for c in text.chars() {
+            match c {
+                '\r' => self.col = 0,
+                '\n' => self.row += 1,
+                c => self.print_char(c, style),
+            }
+        }
+    }
+
+    fn print_char(&mut self, c: char, style: StyleSheet) {
+        let width = c.width().unwrap_or(0);
+
+        if width == 0 {
+            // combining characters belong to the previous cell
+            if let Some(cell) = self.col.checked_sub(1).and_then(|col| self.cell(col)) {
+                cell.text.push(c);
+            }
+            return;
+        }
+
+        if let Some(cell) = self.cell(self.col) {
+            *cell = ScreenCell {
+                text: c.to_string(),
+                style,
+            };
+        }
+        for col in self.col + 1..self.col + width {
+            if let Some(cell) = self.cell(col) {
+                *cell = ScreenCell {
+                    text: String::new(),
+                    style,
+                };
+            }
+        }
+        self.col += width;
+    }
+
+    /// Cell of the current row at the column, adding blank cells if needed.
+    fn cell(&mut self, col: usize) -> Option<&mut ScreenCell> {
+        if self.rows.len() <= self.row {
+            self.rows.resize_with(self.row + 1, Vec::new);
+        }
+        let row = self.rows.get_mut(self.row)?;
+        if row.len() <= col {
+            row.resize_with(col + 1, ScreenCell::blank);
+        }
+        row.get_mut(col)
+    }
+
+    /// Discards the cells past the given number of columns and rows.
+    #[cfg_attr(not(feature = "ratatui"), allow(dead_code))]
+    pub fn truncate(&mut self, cols: usize, rows: usize) {
+        self.rows.truncate(rows);
+        for row in &mut self.rows {
+            row.truncate(cols);
+        }
+    }
+
+    pub fn clear_line(&mut self) {
+        if let Some(row) = self.rows.get_mut(self.row) {
+            row.clear();
+        }
+    }
+
+    pub fn clear_until_new_line(&mut self) {
+        if let Some(row) = self.rows.get_mut(self.row) {
+            row.truncate(self.col);
+        }
+    }
+
+    pub fn cursor_up(&mut self, cnt: u16) {
+        self.row = self.row.saturating_sub(cnt as usize);
+    }
+
+    pub fn cursor_down(&mut self, cnt: u16) {
+        self.row += cnt as usize;
+    }
+
+    pub fn cursor_left(&mut self, cnt: u16) {
+        self.col = self.col.saturating_sub(cnt as usize);
+    }
+
+    pub fn cursor_right(&mut self, cnt: u16) {
+        self.col += cnt as usize;
+    }
+
+    pub fn cursor_move_to_column(&mut self, idx: u16) {
+        self.col = idx as usize;
+    }
+
+    pub fn cursor_move_to(&mut self, col: u16, row: u16) {
+        self.col = col as usize;
+        self.row = row as usize;
+    }
+}
@@ -1,10 +1,14 @@
-use std::{fmt::Display, io::Result};
+use std::io::{stderr, stdin, stdout, IsTerminal, Result, Stderr, Stdout, Write};
 
 use crate::{
-    error::InquireResult,
-    ui::{InputReader, Styled},
+    config::{get_global_backend, next_global_answer},
+    error::{InquireError, InquireResult},
+    ui::{InputReader, Key, PresetAnswer, Styled},
+    NonInteractiveBehavior, OutputStream,
 };
 
+use self::plain::{read_stdin_line, PlainTerminal, PresetInputReader};
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 pub mod crossterm;
@@ -17,9 +21,17 @@ pub mod termion;
 #[cfg_attr(docsrs, doc(cfg(feature = "console")))]
 pub mod console;
 
+mod custom;
+#[cfg(any(feature = "testing", feature = "ratatui"))]
+pub(crate) mod emulated;
+mod plain;
+
+pub use custom::CustomBackend;
+
 #[cfg(test)]
 pub(crate) mod test;
 
+/// Size of a terminal, in columns and rows.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TerminalSize {
     width: u16,
@@ -38,10 +50,12 @@ impl TerminalSize {
         }
     }
 
+    /// Number of columns of the terminal.
     pub fn width(&self) -> u16 {
         self.width
     }
 
+    /// Number of rows of the terminal.
     pub fn height(&self) -> u16 {
         self.height
     }
@@ -56,38 +70,112 @@ impl Default for TerminalSize {
     }
 }
 
-pub trait Terminal: Sized {
+/// Output device prompts are rendered to, such as the terminal of the
+/// process, an embedded terminal widget or a remote session.
+///
+/// Prompts write each frame row by row, moving the cursor relatively to its
+/// position, and flush once the frame is complete. Implementations are
+/// expected to behave like a terminal in raw mode: writing `\r` moves the
+/// cursor to the start of the line and `\n` to the next line, and content
+/// is not wrapped, as prompts already wrap it to the terminal's width.
+/// Entering raw mode, if relevant, is done when the terminal is created and
+/// undone when it is dropped.
+///
+/// Custom terminals are used by prompts through a
+/// [CustomBackend](crate::ui::CustomBackend).
+pub trait Terminal {
+    /// Returns the current size of the terminal, or `None` if it is unknown,
+    /// in which case prompts assume a size of 80x24.
     fn get_size(&self) -> Result<Option<TerminalSize>>;
 
-    fn write<T: Display>(&mut self, val: T) -> Result<()>;
-    fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()>;
+    /// Writes the text at the cursor position without styling it.
+    fn write(&mut self, val: &str) -> Result<()>;
+    /// Writes the text at the cursor position with the style sheet applied,
+    /// resetting the style afterwards.
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()>;
 
+    /// Clears the whole line the cursor is on, without moving the cursor.
     fn clear_line(&mut self) -> Result<()>;
+    /// Clears the line the cursor is on, from the cursor to its end.
     fn clear_until_new_line(&mut self) -> Result<()>;
 
+    /// Hides the cursor.
     fn cursor_hide(&mut self) -> Result<()>;
+    /// Shows the cursor.
     fn cursor_show(&mut self) -> Result<()>;
+    /// Moves the cursor up by the given number of rows.
     fn cursor_up(&mut self, cnt: u16) -> Result<()>;
+    /// Moves the cursor down by the given number of rows.
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
+    /// Moves the cursor left by the given number of columns.
     fn cursor_left(&mut self, cnt: u16) -> Result<()>;
+    /// Moves the cursor right by the given number of columns.
     fn cursor_right(&mut self, cnt: u16) -> Result<()>;
+    /// Moves the cursor to the column, 0-based, of its current row.
     #[allow(unused)]
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
+    /// Moves the cursor to the column and row, 0-based, of the screen. Only
+    /// used by prompts rendered into a [Viewport](crate::ui::Viewport).
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()>;
 
+    /// Flushes the content written so far to the device.
     fn flush(&mut self) -> Result<()>;
+
+    /// Enables or disables holding writes in memory until the next flush,
+    /// so that a whole frame reaches the terminal in a single write.
+    /// Terminals that don't support it write as usual.
+    fn set_batched_output(&mut self, _batched: bool) {}
+
+    /// Switches to the alternate screen, with the cursor at its top-left
+    /// corner, returning whether it did. Terminals without an alternate
+    /// screen keep rendering to the main one.
+    fn enter_alternate_screen(&mut self) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Switches back from the alternate screen, restoring the content and
+    /// cursor position of the main screen.
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
 }
 
-pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)> {
+#[cfg(feature = "crossterm")]
+pub type DefaultInputReader = crossterm::CrosstermKeyReader;
+#[cfg(feature = "crossterm")]
+pub type DefaultTerminal = crossterm::CrosstermTerminal;
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub type DefaultInputReader = termion::TermionKeyReader;
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub type DefaultTerminal = termion::TermionTerminal<'static>;
+
+#[cfg(all(
+    feature = "console",
+    not(feature = "termion"),
+    not(feature = "crossterm")
+))]
+pub type DefaultInputReader = console::ConsoleTerminal;
+#[cfg(all(
+    feature = "console",
+    not(feature = "termion"),
+    not(feature = "crossterm")
+))]
+pub type DefaultTerminal = console::ConsoleTerminal;
+
+pub fn get_default_terminal(
+    output: OutputStream,
+) -> InquireResult<(DefaultInputReader, DefaultTerminal)> {
     #[cfg(feature = "crossterm")]
     return Ok((
         crossterm::CrosstermKeyReader::new(),
-        crossterm::CrosstermTerminal::new()?,
+        crossterm::CrosstermTerminal::new(output)?,
     ));
 
     #[cfg(all(feature = "termion", not(feature = "crossterm")))]
     return Ok((
         termion::TermionKeyReader::new()?,
-        termion::TermionTerminal::new()?,
+        termion::TermionTerminal::new(output)?,
     ));
 
     #[cfg(all(
@@ -96,9 +184,9 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
         not(feature = "crossterm")
     ))]
     {
-        let console_terminal = console::ConsoleTerminal::new();
+        let console_terminal = console::ConsoleTerminal::new(output);
         let console_key_reader = console_terminal.clone();
-        return Ok((console_key_reader, console_terminal));
+        Ok((console_key_reader, console_terminal))
     }
 
     #[cfg(all(
@@ -107,6 +195,7 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
         not(feature = "console")
     ))]
     {
+        let _ = output;
         compile_error!("At least one of crossterm, termion or console must be enabled");
 
         // this is here to silence an additional compilation error
@@ -117,3 +206,247 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
         ))
     }
 }
+
+/// Input reader of prompts, reading key events from the default terminal or
+/// holding an answer to submit without user interaction.
+pub enum PromptInputReader {
+    Default(DefaultInputReader),
+    Preset(PresetInputReader),
+    Custom(Box<dyn InputReader>),
+}
+
+impl InputReader for PromptInputReader {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        match self {
+            Self::Default(reader) => reader.read_key(),
+            Self::Preset(reader) => reader.read_key(),
+            Self::Custom(reader) => reader.read_key(),
+        }
+    }
+
+    fn poll_key(&mut self, timeout: std::time::Duration) -> InquireResult<Option<Key>> {
+        match self {
+            Self::Default(reader) => reader.poll_key(timeout),
+            Self::Preset(reader) => reader.poll_key(timeout),
+            Self::Custom(reader) => reader.poll_key(timeout),
+        }
+    }
+
+    fn read_preset_answer(&mut self) -> InquireResult<Option<PresetAnswer>> {
+        match self {
+            Self::Default(reader) => reader.read_preset_answer(),
+            Self::Preset(reader) => reader.read_preset_answer(),
+            Self::Custom(reader) => reader.read_preset_answer(),
+        }
+    }
+}
+
+/// Standard stream selected by an [OutputStream].
+pub enum StdStream {
+    Stderr(Stderr),
+    Stdout(Stdout),
+}
+
+impl StdStream {
+    pub fn new(stream: OutputStream) -> Self {
+        match stream {
+            OutputStream::Stderr => Self::Stderr(stderr()),
+            OutputStream::Stdout => Self::Stdout(stdout()),
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Self::Stderr(stream) => stream.is_terminal(),
+            Self::Stdout(stream) => stream.is_terminal(),
+        }
+    }
+}
+
+impl Write for StdStream {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self {
+            Self::Stderr(stream) => stream.write(buf),
+            Self::Stdout(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            Self::Stderr(stream) => stream.flush(),
+            Self::Stdout(stream) => stream.flush(),
+        }
+    }
+}
+
+/// Terminal of prompts, the default one or a plain output when answers are
+/// submitted without user interaction.
+pub enum PromptTerminal {
+    Default(DefaultTerminal),
+    Plain(PlainTerminal<StdStream>),
+    Custom(Box<dyn Terminal>),
+}
+
+macro_rules! dispatch {
+    ($self:ident, $terminal:ident => $call:expr) => {
+        match $self {
+            Self::Default($terminal) => $call,
+            Self::Plain($terminal) => $call,
+            Self::Custom($terminal) => $call,
+        }
+    };
+}
+
+impl Terminal for PromptTerminal {
+    fn get_size(&self) -> Result<Option<TerminalSize>> {
+        dispatch!(self, t => t.get_size())
+    }
+
+    fn write(&mut self, val: &str) -> Result<()> {
+        dispatch!(self, t => t.write(val))
+    }
+
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
+        dispatch!(self, t => t.write_styled(val))
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        dispatch!(self, t => t.clear_line())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        dispatch!(self, t => t.clear_until_new_line())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        dispatch!(self, t => t.cursor_hide())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        dispatch!(self, t => t.cursor_show())
+    }
+
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_up(cnt))
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_down(cnt))
+    }
+
+    fn cursor_left(&mut self, cnt: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_left(cnt))
+    }
+
+    fn cursor_right(&mut self, cnt: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_right(cnt))
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_move_to_column(idx))
+    }
+
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        dispatch!(self, t => t.cursor_move_to(col, row))
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        dispatch!(self, t => t.flush())
+    }
+
+    fn set_batched_output(&mut self, batched: bool) {
+        dispatch!(self, t => t.set_batched_output(batched));
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<bool> {
+        dispatch!(self, t => t.enter_alternate_screen())
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        dispatch!(self, t => t.leave_alternate_screen())
+    }
+}
+
+/// Returns the input reader and terminal of a prompt: the default ones when
+/// stdin and the output stream, where prompts are rendered, are TTYs, and
+/// otherwise the ones answering the prompt according to the non-interactive
+/// behavior.
+///
+/// When the environment variable named by `env_override` is set, its value
+/// answers the prompt regardless of the above, and so does the next answer
+/// of the global [AnswerSource](crate::answer_source::AnswerSource).
+///
+/// A custom backend, either the provided one or the global one, replaces the
+/// default reader and terminal and is always considered interactive.
+pub fn get_prompt_terminal(
+    non_interactive_behavior: NonInteractiveBehavior,
+    env_override: Option<&str>,
+    custom_backend: Option<CustomBackend>,
+    output: OutputStream,
+) -> InquireResult<(PromptInputReader, PromptTerminal)> {
+    let custom_backend = custom_backend.or_else(get_global_backend);
+    let interactive =
+        custom_backend.is_some() || (stdin().is_terminal() && StdStream::new(output).is_terminal());
+
+    let answer = match read_env_override(env_override, |var| std::env::var(var))? {
+        Some(value) => Some(PresetAnswer::Text(value)),
+        None => next_global_answer()?,
+    };
+
+    let answer = match (answer, non_interactive_behavior) {
+        (Some(answer), _) => Some(answer),
+        _ if interactive => None,
+        (None, NonInteractiveBehavior::Fail) => None,
+        (None, NonInteractiveBehavior::UseDefault) => Some(PresetAnswer::Default),
+        (None, NonInteractiveBehavior::ReadLine) => Some(PresetAnswer::Text(read_stdin_line()?)),
+    };
+
+    if let Some(custom_backend) = custom_backend {
+        let (input_reader, terminal) = custom_backend.create()?;
+        let input_reader = match answer {
+            Some(answer) => PromptInputReader::Preset(PresetInputReader::new(answer)),
+            None => PromptInputReader::Custom(input_reader),
+        };
+        return Ok((input_reader, PromptTerminal::Custom(terminal)));
+    }
+
+    match (answer, interactive) {
+        (Some(answer), true) => {
+            let (_, terminal) = get_default_terminal(output)?;
+            Ok((
+                PromptInputReader::Preset(PresetInputReader::new(answer)),
+                PromptTerminal::Default(terminal),
+            ))
+        }
+        (Some(answer), false) => Ok((
+            PromptInputReader::Preset(PresetInputReader::new(answer)),
+            PromptTerminal::Plain(PlainTerminal::new(StdStream::new(output))),
+        )),
+        (None, _) => {
+            let (input_reader, terminal) = get_default_terminal(output)?;
+            Ok((
+                PromptInputReader::Default(input_reader),
+                PromptTerminal::Default(terminal),
+            ))
+        }
+    }
+}
+
+/// Reads the value of the environment variable overriding the answer of a
+/// prompt, if any, with the provided lookup, [std::env::var] outside of tests.
+fn read_env_override<F>(var: Option<&str>, lookup: F) -> InquireResult<Option<String>>
+where
+    F: FnOnce(&str) -> std::result::Result<String, std::env::VarError>,
+{
+    let Some(var) = var else {
+        return Ok(None);
+    };
+
+    match lookup(var) {
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(InquireError::InvalidAnswer(format!(
+            "The value of {var} is not valid unicode"
+        ))),
+    }
+}
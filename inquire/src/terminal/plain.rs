@@ -0,0 +1,276 @@
+use std::io::{self, BufRead, Result, Write};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::{InputReader, Key, PresetAnswer, Styled},
+};
+
+use super::{Terminal, TerminalSize};
+
+/// Terminal writing plain text to an output that is not a TTY, without
+/// styles nor cursor movements.
+pub struct PlainTerminal<W: Write> {
+    output: W,
+}
+
+impl<W: Write> PlainTerminal<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+}
+
+impl<W: Write> Terminal for PlainTerminal<W> {
+    fn get_size(&self) -> Result<Option<TerminalSize>> {
+        Ok(None)
+    }
+
+    fn write(&mut self, val: &str) -> Result<()> {
+        let val = val.replace('\r', "");
+        self.output.write_all(val.as_bytes())
+    }
+
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
+        self.write(val.content)
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to(&mut self, _col: u16, _row: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.output.flush()
+    }
+}
+
+/// Input reader holding the answer to submit to a prompt without user
+/// interaction.
+pub struct PresetInputReader {
+    answer: Option<PresetAnswer>,
+}
+
+impl PresetInputReader {
+    pub fn new(answer: PresetAnswer) -> Self {
+        Self {
+            answer: Some(answer),
+        }
+    }
+}
+
+impl InputReader for PresetInputReader {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        Err(InquireError::NotTTY)
+    }
+
+    fn read_preset_answer(&mut self) -> InquireResult<Option<PresetAnswer>> {
+        self.answer.take().map(Some).ok_or(InquireError::NotTTY)
+    }
+}
+
+/// Reads one line from stdin, without the line ending.
+pub fn read_stdin_line() -> InquireResult<String> {
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Err(InquireError::InvalidAnswer(
+            "No answer left to read from stdin".into(),
+        ));
+    }
+
+    let len = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(len);
+    Ok(line)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::VarError, ffi::OsString};
+
+    use crate::{
+        error::InquireError,
+        terminal::Terminal,
+        ui::{Backend, PresetAnswer, RenderConfig, Styled},
+        Amount, Confirm, CustomType, MultiSelect, Select, Text,
+    };
+
+    use super::{super::read_env_override, PlainTerminal, PresetInputReader};
+
+    fn backend(
+        answer: PresetAnswer,
+        output: &mut Vec<u8>,
+    ) -> Backend<'static, PresetInputReader, PlainTerminal<&mut Vec<u8>>> {
+        let terminal = PlainTerminal::new(output);
+        Backend::new(
+            PresetInputReader::new(answer),
+            terminal,
+            RenderConfig::empty(),
+        )
+        .unwrap()
+    }
+
+    fn text(answer: &str) -> PresetAnswer {
+        PresetAnswer::Text(answer.into())
+    }
+
+    #[test]
+    fn text_is_written_without_carriage_returns() {
+        let mut output = vec![];
+        let mut terminal = PlainTerminal::new(&mut output);
+
+        terminal.write_styled(&Styled::new("? Name: ")).unwrap();
+        terminal.cursor_up(2).unwrap();
+        terminal.write("Ana\r\n").unwrap();
+
+        assert_eq!("? Name: Ana\n", String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn only_the_answered_prompt_is_rendered() {
+        let mut output = vec![];
+
+        let ans = Text::new("Name:")
+            .with_help_message("Your first name")
+            .prompt_with_backend(&mut backend(text("Ana"), &mut output))
+            .unwrap();
+
+        assert_eq!("Ana", ans);
+        assert_eq!("? Name: Ana\n", String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn default_answer_is_submitted() {
+        let mut output = vec![];
+
+        let ans = Confirm::new("Continue?")
+            .with_default(true)
+            .prompt_with_backend(&mut backend(PresetAnswer::Default, &mut output))
+            .unwrap();
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn rejected_answers_fail_with_the_error_message() {
+        let mut output = vec![];
+
+        let ans = CustomType::<u8>::new("Replicas:")
+            .with_error_message("Please type a number")
+            .prompt_with_backend(&mut backend(text("many"), &mut output));
+
+        assert!(
+            matches!(ans, Err(InquireError::InvalidAnswer(msg)) if msg == "Please type a number")
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn options_are_selected_by_their_text() {
+        let options = vec!["Apple", "Banana", "Cherry"];
+
+        let mut output = vec![];
+        let ans = Select::new("Fruit:", options.clone())
+            .prompt_with_backend(&mut backend(text("banana"), &mut output))
+            .unwrap();
+        assert_eq!("Banana", ans.value);
+
+        let mut output = vec![];
+        let ans = MultiSelect::new("Fruits:", options.clone())
+            .prompt_with_backend(&mut backend(text("Cherry, apple"), &mut output))
+            .unwrap();
+        assert_eq!(
+            vec!["Apple", "Cherry"],
+            ans.into_iter().map(|o| o.value).collect::<Vec<_>>()
+        );
+
+        let mut output = vec![];
+        let ans = Select::new("Fruit:", options)
+            .prompt_with_backend(&mut backend(text("Durian"), &mut output));
+        assert!(matches!(ans, Err(InquireError::InvalidAnswer(_))));
+    }
+
+    #[test]
+    fn amounts_are_read_as_displayed() {
+        let mut output = vec![];
+
+        let ans = Amount::new("Price:")
+            .with_currency_symbol("$")
+            .prompt_with_backend(&mut backend(text("$1,234.5"), &mut output))
+            .unwrap();
+
+        assert_eq!(123450, ans);
+    }
+
+    #[test]
+    fn env_overrides_are_parsed_as_typed_answers() {
+        let var = "INQUIRE_REPLICAS";
+        let lookup = |name: &str| match name {
+            "INQUIRE_REPLICAS" => Ok(String::from("3")),
+            _ => Err(VarError::NotPresent),
+        };
+        assert_eq!(None, read_env_override(Some("OTHER"), lookup).unwrap());
+        assert_eq!(None, read_env_override(None, lookup).unwrap());
+
+        let not_unicode = |_: &str| Err(VarError::NotUnicode(OsString::from("3")));
+        assert!(matches!(
+            read_env_override(Some(var), not_unicode),
+            Err(InquireError::InvalidAnswer(_))
+        ));
+
+        let value = read_env_override(Some(var), lookup).unwrap().unwrap();
+
+        let mut output = vec![];
+        let ans = CustomType::<u8>::new("Replicas:")
+            .with_env_override(var)
+            .prompt_with_backend(&mut backend(PresetAnswer::Text(value), &mut output))
+            .unwrap();
+
+        assert_eq!(3, ans);
+        assert_eq!("? Replicas: 3\n", String::from_utf8(output).unwrap());
+    }
+
+    #[cfg(feature = "date")]
+    #[test]
+    fn dates_are_read_as_typed_in_the_date_input() {
+        let mut output = vec![];
+
+        let ans = crate::DateSelect::new("Date:")
+            .prompt_with_backend(&mut backend(text("2025-03-01"), &mut output))
+            .unwrap();
+
+        assert_eq!(chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(), ans);
+    }
+}
@@ -10,19 +10,23 @@ use termion::{
     event::Key,
     input::{Keys, TermRead},
     raw::{IntoRawMode, RawTerminal},
-    terminal_size,
+    screen, terminal_size,
 };
 
 use crate::{
     error::InquireResult,
     ui::{Attributes, InputReader, Styled},
+    OutputStream,
 };
 
-use super::Terminal;
+use super::{StdStream, Terminal};
 
-#[allow(clippy::upper_case_acronyms)]
 enum IO<'a> {
-    TTY(RawTerminal<File>),
+    /// Standard stream, with the TTY kept in raw mode while it is written to.
+    Std {
+        stream: StdStream,
+        _raw_mode: RawTerminal<File>,
+    },
     #[allow(unused)]
     Custom(&'a mut (dyn Write)),
 }
@@ -53,17 +57,20 @@ impl InputReader for TermionKeyReader {
 
 pub struct TermionTerminal<'a> {
     io: IO<'a>,
+    batch: Option<Vec<u8>>,
 }
 
 impl<'a> TermionTerminal<'a> {
     #[allow(unused)]
-    pub fn new() -> InquireResult<Self> {
-        let tty = termion::get_tty()?;
-        let raw_terminal = tty.into_raw_mode()?;
-        let keys = raw_terminal.try_clone()?.keys();
+    pub fn new(output: OutputStream) -> InquireResult<Self> {
+        let raw_terminal = termion::get_tty()?.into_raw_mode()?;
 
         Ok(Self {
-            io: IO::TTY(raw_terminal),
+            io: IO::Std {
+                stream: StdStream::new(output),
+                _raw_mode: raw_terminal,
+            },
+            batch: None,
         })
     }
 
@@ -74,12 +81,17 @@ impl<'a> TermionTerminal<'a> {
     pub fn new_with_writer<W: 'a + Write>(writer: &'a mut W) -> Self {
         Self {
             io: IO::Custom(writer),
+            batch: None,
         }
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
+        if let Some(batch) = &mut self.batch {
+            return batch;
+        }
+
         match &mut self.io {
-            IO::TTY(w) => w,
+            IO::Std { stream, .. } => stream,
             IO::Custom(w) => w,
         }
     }
@@ -149,19 +161,49 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "\x1b[{}G", idx.saturating_add(1))
     }
 
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        // termion positions are 1-based
+        write!(
+            self.get_writer(),
+            "{}",
+            cursor::Goto(col.saturating_add(1), row.saturating_add(1))
+        )
+    }
+
     fn flush(&mut self) -> Result<()> {
-        self.get_writer().flush()
+        let writer: &mut dyn Write = match &mut self.io {
+            IO::Std { stream, .. } => stream,
+            IO::Custom(w) => w,
+        };
+
+        if let Some(batch) = &mut self.batch {
+            writer.write_all(batch)?;
+            batch.clear();
+        }
+
+        writer.flush()
+    }
+
+    fn set_batched_output(&mut self, batched: bool) {
+        match (batched, self.batch.is_some()) {
+            (true, false) => self.batch = Some(Vec::new()),
+            (false, true) => {
+                let _unused = self.flush();
+                self.batch = None;
+            }
+            _ => {}
+        }
     }
 
     fn get_size(&self) -> Result<Option<super::TerminalSize>> {
         terminal_size().map(|(width, height)| super::TerminalSize::new(width, height))
     }
 
-    fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
+    fn write(&mut self, val: &str) -> Result<()> {
         write!(self.get_writer(), "{}", val)
     }
 
-    fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
         if let Some(color) = val.style.fg {
             self.set_fg_color(color)?;
         }
@@ -172,7 +214,7 @@ impl<'a> Terminal for TermionTerminal<'a> {
             self.set_attributes(val.style.att)?;
         }
 
-        self.write(&val.content)?;
+        self.write(val.content)?;
 
         if val.style.fg.as_ref().is_some() {
             self.reset_fg_color()?;
@@ -202,6 +244,20 @@ impl<'a> Terminal for TermionTerminal<'a> {
     fn cursor_show(&mut self) -> Result<()> {
         write!(self.get_writer(), "{}", cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<bool> {
+        write!(
+            self.get_writer(),
+            "{}{}",
+            screen::ToAlternateScreen,
+            cursor::Goto(1, 1)
+        )?;
+        Ok(true)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", screen::ToMainScreen)
+    }
 }
 
 impl<'a> Drop for TermionTerminal<'a> {
@@ -267,6 +323,7 @@ impl From<Key> for crate::ui::Key {
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
             Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
+            Key::F(n) => Self::F(n, KeyModifiers::empty()),
             #[allow(deprecated)]
             _ => Self::Any,
         }
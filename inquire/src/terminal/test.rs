@@ -1,8 +1,15 @@
-use std::{collections::VecDeque, fmt::Display};
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    sync::{Arc, Mutex},
+};
 
-use crate::ui::Styled;
+use crate::{
+    error::InquireResult,
+    ui::{InputReader, Key, Styled},
+};
 
-use super::{Terminal, TerminalSize};
+use super::{CustomBackend, Terminal, TerminalSize};
 
 pub struct MockTerminal<'a> {
     pub size: TerminalSize,
@@ -21,6 +28,9 @@ pub enum MockTerminalToken {
     CursorLeft(u16),
     CursorRight(u16),
     CursorMoveToColumn(u16),
+    CursorMoveTo(u16, u16),
+    EnterAlternateScreen,
+    LeaveAlternateScreen,
 }
 
 impl<T> From<T> for MockTerminalToken
@@ -88,15 +98,15 @@ impl<'a> Terminal for MockTerminal<'a> {
         Ok(Some(self.size))
     }
 
-    fn write<T: Display>(&mut self, val: T) -> std::io::Result<()> {
-        let styled = Styled::new(format!("{val}"));
+    fn write(&mut self, val: &str) -> std::io::Result<()> {
+        let styled = Styled::new(val.to_string());
         let token = MockTerminalToken::Text(styled);
         self.output.push_back(token);
         Ok(())
     }
 
-    fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> std::io::Result<()> {
-        let styled = Styled::new(format!("{}", val.content)).with_style_sheet(val.style);
+    fn write_styled(&mut self, val: &Styled<&str>) -> std::io::Result<()> {
+        let styled = Styled::new(val.content.to_string()).with_style_sheet(val.style);
         let token = MockTerminalToken::Text(styled);
         self.output.push_back(token);
         Ok(())
@@ -156,6 +166,177 @@ impl<'a> Terminal for MockTerminal<'a> {
         Ok(())
     }
 
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> std::io::Result<()> {
+        let token = MockTerminalToken::CursorMoveTo(col, row);
+        self.output.push_back(token);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> std::io::Result<bool> {
+        let token = MockTerminalToken::EnterAlternateScreen;
+        self.output.push_back(token);
+        Ok(true)
+    }
+
+    fn leave_alternate_screen(&mut self) -> std::io::Result<()> {
+        let token = MockTerminalToken::LeaveAlternateScreen;
+        self.output.push_back(token);
+        Ok(())
+    }
+}
+
+/// Custom backend whose prompts read keys from a queue shared by all of them
+/// and append the text they write to the returned buffer, for tests running
+/// several prompts in a row.
+pub fn recording_backend(keys: Vec<Key>) -> (CustomBackend, Arc<Mutex<String>>) {
+    let keys = Arc::new(Mutex::new(VecDeque::from(keys)));
+    let output = Arc::new(Mutex::new(String::new()));
+
+    let recorded = Arc::clone(&output);
+    let backend = CustomBackend::new(move || {
+        Ok((
+            SharedKeys(Arc::clone(&keys)),
+            Recorder(Arc::clone(&recorded)),
+        ))
+    });
+    (backend, output)
+}
+
+struct SharedKeys(Arc<Mutex<VecDeque<Key>>>);
+
+impl InputReader for SharedKeys {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        match self.0.lock().unwrap().pop_front() {
+            Some(key) => Ok(key),
+            None => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()),
+        }
+    }
+}
+
+/// Terminal appending the text written to it to a shared buffer.
+struct Recorder(Arc<Mutex<String>>);
+
+impl Terminal for Recorder {
+    fn get_size(&self) -> std::io::Result<Option<TerminalSize>> {
+        Ok(TerminalSize::new(40, 10))
+    }
+
+    fn write(&mut self, val: &str) -> std::io::Result<()> {
+        self.0.lock().unwrap().push_str(val);
+        Ok(())
+    }
+
+    fn write_styled(&mut self, val: &Styled<&str>) -> std::io::Result<()> {
+        self.write(val.content)
+    }
+
+    fn clear_line(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to(&mut self, _col: u16, _row: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Terminal discarding everything written to it, for tests that don't check
+/// the rendered output. It has no size, so frames are rendered at the
+/// default one regardless of where the tests run.
+pub struct NullTerminal;
+
+impl Terminal for NullTerminal {
+    fn get_size(&self) -> std::io::Result<Option<TerminalSize>> {
+        Ok(None)
+    }
+
+    fn write(&mut self, _val: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn write_styled(&mut self, _val: &Styled<&str>) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_line(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, _cnt: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to(&mut self, _col: u16, _row: u16) -> std::io::Result<()> {
+        Ok(())
+    }
+
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
@@ -0,0 +1,1004 @@
+//! Utilities to test applications that embed prompts.
+//!
+//! [`run_scripted`] runs a prompt flow against a scripted backend, reading a
+//! predefined sequence of keys and rendering to an in-memory terminal, and
+//! returns the [`Screen`]s rendered along the way together with the answers.
+//! This allows integration tests of prompt flows without a real terminal.
+//!
+//! Prompts put the terminal in raw mode and hide the cursor while rendering,
+//! and are expected to restore both however they end: when the user submits
+//! or cancels, when they press Ctrl+C, or when something panics halfway
+//! through. [`assert_interrupt_safe`] runs a prompt flow against a scripted
+//! backend, interrupting it at every possible point, and checks that the
+//! terminal is restored after each run.
+//!
+//! Prompts of this crate are run against the scripted backend through the
+//! [`ScriptedPrompt`] trait, which can also render the initial state of a
+//! configured prompt with [`ScriptedPrompt::render_snapshot`], e.g. to
+//! compare its layout across terminal widths with snapshots.
+//!
+//! With the `pty-testing` feature, the [`pty`] module runs prompts end to end
+//! in a pseudo-terminal instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use inquire::{
+//!     testing::{run_scripted, ScriptedPrompt},
+//!     ui::{Key, KeyModifiers},
+//!     Text,
+//! };
+//!
+//! let mut keys: Vec<Key> = "Ana".chars().map(|c| Key::Char(c, KeyModifiers::NONE)).collect();
+//! keys.push(Key::Enter);
+//!
+//! let (answer, screens) = run_scripted(keys, |backend| {
+//!     Text::new("Name:").prompt_with_scripted_backend(backend)
+//! });
+//!
+//! assert_eq!("Ana", answer.unwrap());
+//! assert_eq!("? Name: Ana", screens[screens.len() - 2].text());
+//! assert_eq!("> Name: Ana", screens.last().unwrap().text());
+//! ```
+//!
+//! ```
+//! use inquire::{
+//!     testing::{assert_interrupt_safe, ScriptedPrompt},
+//!     ui::{Key, KeyModifiers},
+//!     Confirm, Text,
+//! };
+//!
+//! let keys = vec![
+//!     Key::Char('y', KeyModifiers::NONE),
+//!     Key::Enter,
+//!     Key::Char('a', KeyModifiers::NONE),
+//!     Key::Enter,
+//! ];
+//!
+//! assert_interrupt_safe(keys, |backend| -> inquire::error::InquireResult<()> {
+//!     if Confirm::new("Continue?").prompt_with_scripted_backend(backend)? {
+//!         Text::new("Name:").prompt_with_scripted_backend(backend)?;
+//!     }
+//!     Ok(())
+//! });
+//! ```
+
+#[cfg(feature = "pty-testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pty-testing")))]
+pub mod pty;
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt::{self, Display},
+    io::{self, Result},
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    sync::Once,
+};
+
+use crate::{
+    error::InquireResult,
+    list_option::ListOption,
+    prompts::PromptSettings,
+    terminal::{
+        emulated::{EmulatedScreen, ScreenCell},
+        Terminal, TerminalSize,
+    },
+    ui::{Backend, InputReader, Key, KeyModifiers, RenderConfig, StyleSheet, Styled},
+    Amount, Confirm, CustomType, InquireError, MultiSelect, Password, Select, Text, TypedConfirm,
+};
+
+/// Point of a prompt's lifecycle at which a run is interrupted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interruption {
+    /// The user presses Ctrl+C after the given number of scripted keys.
+    CtrlC {
+        /// Number of scripted keys read before the interruption.
+        after_keys: usize,
+    },
+
+    /// Reading a key panics after the given number of scripted keys.
+    PanicOnRead {
+        /// Number of scripted keys read before the interruption.
+        after_keys: usize,
+    },
+
+    /// An operation on the terminal panics, e.g. while a frame is being
+    /// rendered with the cursor hidden.
+    PanicOnRender {
+        /// Number of terminal operations before the interruption.
+        after_operations: usize,
+    },
+}
+
+/// Terminal state tracked by a [`ScriptedTerminal`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TerminalState {
+    /// Whether the terminal is in raw mode.
+    pub raw_mode: bool,
+
+    /// Whether the cursor is visible.
+    pub cursor_visible: bool,
+}
+
+impl TerminalState {
+    /// State the terminal should be left in once a prompt is done.
+    pub const RESTORED: Self = Self {
+        raw_mode: false,
+        cursor_visible: true,
+    };
+}
+
+/// Backend reading scripted keys and rendering to a [`ScriptedTerminal`].
+///
+/// Its lifetime is the one of the settings of the prompts run on it, e.g.
+/// the callbacks set with `with_on_change`.
+pub type ScriptedBackend<'a> = Backend<'a, ScriptedInput, ScriptedTerminal>;
+
+/// Input reader returning a predefined sequence of keys.
+///
+/// Reading past the last key returns an [`InquireError::IO`] error.
+pub struct ScriptedInput {
+    keys: VecDeque<Key>,
+    keys_read: usize,
+    interruption: Option<Interruption>,
+}
+
+impl ScriptedInput {
+    /// Creates an input reader returning the provided keys.
+    pub fn new(keys: Vec<Key>) -> Self {
+        Self {
+            keys: keys.into(),
+            keys_read: 0,
+            interruption: None,
+        }
+    }
+
+    fn with_interruption(mut self, interruption: Interruption) -> Self {
+        self.interruption = Some(interruption);
+        self
+    }
+}
+
+impl InputReader for ScriptedInput {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        match self.interruption {
+            Some(Interruption::CtrlC { after_keys }) if after_keys == self.keys_read => {
+                self.interruption = None;
+                return Ok(Key::Char('c', KeyModifiers::CONTROL));
+            }
+            Some(Interruption::PanicOnRead { after_keys }) if after_keys == self.keys_read => {
+                self.interruption = None;
+                panic::panic_any(SimulatedPanic);
+            }
+            _ => {}
+        }
+
+        self.keys_read += 1;
+        self.keys.pop_front().ok_or_else(|| {
+            InquireError::IO(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "No more scripted keys",
+            ))
+        })
+    }
+}
+
+/// Contents of a [`ScriptedTerminal`] captured when it was flushed, i.e.
+/// once a frame was rendered.
+///
+/// Lines are kept as rendered, without wrapping them at the terminal width
+/// as the prompts already do so.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Screen {
+    lines: Vec<Vec<Styled<String>>>,
+}
+
+impl Screen {
+    /// Lines of the screen, each made of spans of text sharing the same
+    /// style. Trailing unstyled blanks are omitted.
+    pub fn lines(&self) -> &[Vec<Styled<String>>] {
+        &self.lines
+    }
+
+    /// Text of the screen without styles, with lines separated by `\n`.
+    /// Trailing whitespace of the lines and trailing empty lines are omitted.
+    pub fn text(&self) -> String {
+        let lines: Vec<String> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let text: String = line.iter().map(|span| span.content.as_str()).collect();
+                text.trim_end().to_string()
+            })
+            .collect();
+
+        lines.join("\n").trim_end_matches('\n').to_string()
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text())
+    }
+}
+
+impl From<&EmulatedScreen> for Screen {
+    fn from(screen: &EmulatedScreen) -> Self {
+        let lines = screen
+            .rows()
+            .iter()
+            .map(|row| {
+                let mut spans: Vec<Styled<String>> = vec![];
+                let end = row
+                    .iter()
+                    .rposition(|cell| cell != &ScreenCell::blank())
+                    .map(|last| last + 1)
+                    .unwrap_or(0);
+
+                for cell in row.iter().take(end) {
+                    match spans.last_mut() {
+                        Some(span) if span.style == cell.style => {
+                            span.content.push_str(&cell.text);
+                        }
+                        _ => {
+                            let span = Styled::new(cell.text.clone()).with_style_sheet(cell.style);
+                            spans.push(span);
+                        }
+                    }
+                }
+                spans
+            })
+            .collect();
+
+        Screen { lines }
+    }
+}
+
+/// In-memory terminal emulating a screen, and tracking whether it is in raw
+/// mode and whether the cursor is visible.
+///
+/// Like real terminals, it enters raw mode when created and leaves it when
+/// dropped. The contents of the screen are captured each time they change
+/// and the terminal is flushed, see [`ScriptedTerminal::screens`].
+pub struct ScriptedTerminal {
+    state: Rc<Cell<TerminalState>>,
+    size: TerminalSize,
+    operations: Rc<Cell<usize>>,
+    panic_at: Option<usize>,
+    screen: EmulatedScreen,
+    screens: Rc<RefCell<Vec<Screen>>>,
+}
+
+impl ScriptedTerminal {
+    /// Creates a terminal with the provided size, in raw mode.
+    pub fn new(size: TerminalSize) -> Self {
+        Self {
+            state: Rc::new(Cell::new(TerminalState {
+                raw_mode: true,
+                cursor_visible: true,
+            })),
+            size,
+            operations: Rc::default(),
+            panic_at: None,
+            screen: EmulatedScreen::default(),
+            screens: Rc::default(),
+        }
+    }
+
+    /// Shared handle to the state of the terminal, readable after the
+    /// terminal is dropped.
+    pub fn state(&self) -> Rc<Cell<TerminalState>> {
+        self.state.clone()
+    }
+
+    /// Shared handle to the screens captured so far, in order, readable
+    /// after the terminal is dropped.
+    pub fn screens(&self) -> Rc<RefCell<Vec<Screen>>> {
+        self.screens.clone()
+    }
+
+    fn operation(&mut self) {
+        let count = self.operations.get();
+        self.operations.set(count + 1);
+
+        if self.panic_at == Some(count) {
+            // Only panic once, so that cleanup done while unwinding does not abort.
+            self.panic_at = None;
+            panic::panic_any(SimulatedPanic);
+        }
+    }
+
+    fn set_cursor_visible(&mut self, cursor_visible: bool) {
+        self.operation();
+        let state = self.state.get();
+        self.state.set(TerminalState {
+            cursor_visible,
+            ..state
+        });
+    }
+}
+
+impl Drop for ScriptedTerminal {
+    fn drop(&mut self) {
+        let state = self.state.get();
+        self.state.set(TerminalState {
+            raw_mode: false,
+            ..state
+        });
+    }
+}
+
+impl Terminal for ScriptedTerminal {
+    fn get_size(&self) -> Result<Option<TerminalSize>> {
+        Ok(Some(self.size))
+    }
+
+    fn write(&mut self, val: &str) -> Result<()> {
+        self.operation();
+        self.screen.print(val, StyleSheet::empty());
+        Ok(())
+    }
+
+    fn write_styled(&mut self, val: &Styled<&str>) -> Result<()> {
+        self.operation();
+        self.screen.print(val.content, val.style);
+        Ok(())
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        self.operation();
+        self.screen.clear_line();
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        self.operation();
+        self.screen.clear_until_new_line();
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        self.set_cursor_visible(false);
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        self.set_cursor_visible(true);
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_up(cnt);
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_down(cnt);
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, cnt: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_left(cnt);
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, cnt: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_right(cnt);
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_move_to_column(idx);
+        Ok(())
+    }
+
+    fn cursor_move_to(&mut self, col: u16, row: u16) -> Result<()> {
+        self.operation();
+        self.screen.cursor_move_to(col, row);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.operation();
+
+        let screen = Screen::from(&self.screen);
+        let mut screens = self.screens.borrow_mut();
+        if screens.last() != Some(&screen) {
+            screens.push(screen);
+        }
+        Ok(())
+    }
+}
+
+/// Prompts that can be run against a [`ScriptedBackend`].
+///
+/// Implemented by all prompts of this crate. User-defined prompts can be run
+/// with [`Prompt::prompt`](crate::Prompt::prompt) instead.
+pub trait ScriptedPrompt {
+    /// Type of the answer returned by the prompt.
+    type Output;
+
+    /// Runs the prompt, reading keys from and rendering to the backend with
+    /// the prompt's render config and settings.
+    fn prompt_with_scripted_backend<'s>(
+        self,
+        backend: &mut ScriptedBackend<'s>,
+    ) -> InquireResult<Self::Output>
+    where
+        Self: 's;
+
+    /// Renders the prompt as it is displayed before any key is pressed, in a
+    /// terminal of the given width and 24 rows, using the prompt's render
+    /// config. Nothing is written to the actual terminal.
+    ///
+    /// Returns the error of the prompt when it fails before rendering, e.g.
+    /// for a [`Select`] without options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{testing::ScriptedPrompt, Text};
+    ///
+    /// let screen = Text::new("What's your name?")
+    ///     .with_help_message("As shown on your ID")
+    ///     .render_snapshot(40)?;
+    ///
+    /// assert_eq!("? What's your name?\n[As shown on your ID]", screen.text());
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    fn render_snapshot(self, width: u16) -> InquireResult<Screen>;
+}
+
+/// Renders the first frame displayed by `run` in a terminal of the given
+/// width, where reading a key fails as no keys are provided.
+#[allow(clippy::large_types_passed_by_value)]
+fn render_snapshot<'a, F, R>(
+    width: u16,
+    render_config: RenderConfig<'a>,
+    settings: PromptSettings<'a>,
+    run: F,
+) -> InquireResult<Screen>
+where
+    F: FnOnce(&mut Backend<'a, ScriptedInput, ScriptedTerminal>) -> InquireResult<R>,
+{
+    let size = TerminalSize::new(width.max(1), 24).unwrap_or_default();
+    let terminal = ScriptedTerminal::new(size);
+    let screens = terminal.screens();
+
+    let mut backend = Backend::new(ScriptedInput::new(vec![]), terminal, render_config)?;
+    backend.apply_settings(settings);
+    let result = run(&mut backend);
+    drop(backend);
+
+    match result {
+        Err(InquireError::IO(err)) if err.kind() == io::ErrorKind::UnexpectedEof => {}
+        Err(err) => return Err(err),
+        Ok(_) => {}
+    }
+
+    let screen = screens.take().into_iter().next().unwrap_or_default();
+    Ok(screen)
+}
+
+macro_rules! scripted_prompt {
+    ($([$($generics:tt)*] $prompt:ty => $output:ty $(where [$($bounds:tt)*])?;)*) => {
+        $(
+            impl<$($generics)*> ScriptedPrompt for $prompt $(where $($bounds)*)? {
+                type Output = $output;
+
+                fn prompt_with_scripted_backend<'s>(
+                    self,
+                    backend: &mut ScriptedBackend<'s>,
+                ) -> InquireResult<Self::Output>
+                where
+                    Self: 's,
+                {
+                    backend.set_render_config(self.render_config);
+                    backend.apply_settings(self.settings.clone());
+                    self.prompt_with_backend(backend)
+                }
+
+                fn render_snapshot(self, width: u16) -> InquireResult<Screen> {
+                    render_snapshot(width, self.render_config, self.settings.clone(), |backend| {
+                        self.prompt_with_backend(backend)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+scripted_prompt! {
+    ['a, 'b] Text<'a, 'b> => String;
+    ['a] Password<'a> => String;
+    ['a] Confirm<'a> => bool;
+    ['a, T] CustomType<'a, T> => T where [T: Clone];
+    ['a, T] Select<'a, T> => ListOption<T> where [T: Display];
+    ['a, T] MultiSelect<'a, T> => Vec<ListOption<T>> where [T: Display];
+    ['a] TypedConfirm<'a> => bool;
+    ['a] Amount<'a> => u64;
+}
+
+#[cfg(feature = "date")]
+scripted_prompt! {
+    ['a] crate::DateSelect<'a> => chrono::NaiveDate;
+}
+
+#[cfg(feature = "editor")]
+scripted_prompt! {
+    ['a] crate::Editor<'a> => String;
+}
+
+/// Payload of the panics simulated by the scripted backend.
+struct SimulatedPanic;
+
+fn silence_simulated_panics() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !info.payload().is::<SimulatedPanic>() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `run` with a backend reading the provided keys and rendering to a
+/// [`ScriptedTerminal`] of 80x24 cells, returning the result of `run` and the
+/// screens rendered while it ran.
+///
+/// The result holds the answers of the prompts run by `run`, which fail with
+/// an [`InquireError::IO`] error if they read more keys than provided.
+pub fn run_scripted<'a, F, R>(keys: Vec<Key>, run: F) -> (R, Vec<Screen>)
+where
+    F: FnOnce(&mut ScriptedBackend<'a>) -> R,
+{
+    let terminal = ScriptedTerminal::new(TerminalSize::default());
+    let screens = terminal.screens();
+
+    let mut backend = Backend::new(ScriptedInput::new(keys), terminal, RenderConfig::default())
+        .expect("scripted backend can always be created");
+    let result = run(&mut backend);
+    drop(backend);
+
+    let screens = screens.take();
+    (result, screens)
+}
+
+/// Runs `run` once without interruptions and then once for each possible
+/// [`Interruption`], panicking if the terminal is not restored after any
+/// of the runs.
+///
+/// `run` receives a fresh backend reading the provided keys on each call,
+/// and should run the prompt flow under test with it. Errors returned by
+/// the prompts are expected, as interrupted prompts fail.
+///
+/// # Panics
+///
+/// Panics if the terminal is left in raw mode or with the cursor hidden
+/// after a run, or if `run` panics for reasons other than a simulated
+/// interruption.
+pub fn assert_interrupt_safe<'a, F, R>(keys: Vec<Key>, mut run: F)
+where
+    F: FnMut(&mut ScriptedBackend<'a>) -> R,
+{
+    silence_simulated_panics();
+
+    let operations = run_interrupted(&keys, None, &mut run);
+
+    let interruptions = (0..=keys.len())
+        .flat_map(|after_keys| {
+            [
+                Interruption::CtrlC { after_keys },
+                Interruption::PanicOnRead { after_keys },
+            ]
+        })
+        .chain(
+            (0..operations)
+                .map(|after_operations| Interruption::PanicOnRender { after_operations }),
+        );
+
+    for interruption in interruptions {
+        run_interrupted(&keys, Some(interruption), &mut run);
+    }
+}
+
+/// Runs the flow with the given interruption, asserting that the terminal
+/// is restored, and returns the number of terminal operations performed.
+fn run_interrupted<'a, F, R>(keys: &[Key], interruption: Option<Interruption>, run: &mut F) -> usize
+where
+    F: FnMut(&mut ScriptedBackend<'a>) -> R,
+{
+    let mut input = ScriptedInput::new(keys.to_vec());
+    let mut terminal = ScriptedTerminal::new(TerminalSize::default());
+
+    match interruption {
+        Some(Interruption::PanicOnRender { after_operations }) => {
+            terminal.panic_at = Some(after_operations);
+        }
+        Some(interruption) => input = input.with_interruption(interruption),
+        None => {}
+    }
+
+    let state = terminal.state();
+    let operations = terminal.operations.clone();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut backend = Backend::new(input, terminal, RenderConfig::default())
+            .expect("scripted backend can always be created");
+        let _ = run(&mut backend);
+    }));
+
+    if let Err(payload) = result {
+        if !payload.is::<SimulatedPanic>() {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    assert_eq!(
+        TerminalState::RESTORED,
+        state.get(),
+        "terminal was not restored after interruption {interruption:?}"
+    );
+
+    operations.get()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, time::Duration};
+
+    use crate::{
+        event::PromptEvent,
+        key_bindings::{CancelGuard, EscapeBehavior, Handled, PromptStateView},
+        ui::{
+            AnswerRedaction, Attributes, Color, Key, KeyModifiers, RenderAfterSubmit, RenderConfig,
+            Styled, StyledText,
+        },
+        Confirm, InquireError, Select, Text,
+    };
+
+    use super::{assert_interrupt_safe, run_scripted, Screen, ScriptedPrompt};
+
+    #[test]
+    fn scripted_runs_capture_screens_and_answers() {
+        let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+
+        let (answer, screens) = run_scripted(keys, |backend| {
+            Select::new("Color?", vec!["red", "green"]).prompt_with_backend(backend)
+        });
+
+        assert_eq!("green", answer.unwrap().value);
+        let texts: Vec<String> = screens.iter().map(Screen::text).collect();
+        assert_eq!(
+            vec![
+                "? Color?\n> red\n  green\n[↑↓ to move, enter to select, type to filter]",
+                "? Color?\n  red\n> green\n[↑↓ to move, enter to select, type to filter]",
+                "> Color? green",
+            ],
+            texts
+        );
+
+        let answered = screens.last().unwrap().lines().first().unwrap();
+        assert_eq!(
+            &Styled::new("green".to_string()).with_fg(Color::LightCyan),
+            answered.last().unwrap()
+        );
+    }
+
+    #[test]
+    fn submitted_prompts_leave_what_is_requested_on_screen() {
+        let last_screen = |render_after_submit| {
+            let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+            let (_, screens) = run_scripted(keys, |backend| {
+                backend.set_render_after_submit(render_after_submit);
+                Select::new("Color?", vec!["red", "green"]).prompt_with_backend(backend)
+            });
+            screens.last().unwrap().text()
+        };
+
+        assert_eq!("", last_screen(RenderAfterSubmit::Clear));
+        assert_eq!("> Color? green", last_screen(RenderAfterSubmit::AnswerLine));
+        assert_eq!(
+            "? Color?\n  red\n> green\n[↑↓ to move, enter to select, type to filter]",
+            last_screen(RenderAfterSubmit::Full)
+        );
+    }
+
+    #[test]
+    fn failed_submissions_discard_the_final_frame() {
+        let (answers, screens) = run_scripted(vec![Key::Enter, Key::Enter], |backend| {
+            backend.set_render_after_submit(RenderAfterSubmit::Full);
+            let failed = Text::new("Name?")
+                .with_validator(|_: &str| Err("validator is down".into()))
+                .prompt_with_backend(backend);
+            let answered = Text::new("Age?").prompt_with_backend(backend);
+            (failed, answered)
+        });
+
+        assert!(matches!(answers.0, Err(InquireError::Custom(_))));
+        assert_eq!("", answers.1.unwrap());
+        let texts: Vec<String> = screens.iter().map(Screen::text).collect();
+        assert_eq!(vec!["? Name?", "? Age?"], texts);
+    }
+
+    #[test]
+    fn redacted_answers_are_not_rendered() {
+        let last_screen = |answer_redaction| {
+            let keys = vec![Key::Char('4', KeyModifiers::NONE), Key::Enter];
+            let (answer, screens) = run_scripted(keys, |backend| {
+                Text::new("Account ID:")
+                    .with_answer_redaction(answer_redaction)
+                    .prompt_with_scripted_backend(backend)
+            });
+            assert_eq!("4", answer.unwrap());
+            screens.last().unwrap().text()
+        };
+
+        assert_eq!("> Account ID:", last_screen(AnswerRedaction::Hidden));
+        assert_eq!(
+            "> Account ID: ****",
+            last_screen(AnswerRedaction::Placeholder("****"))
+        );
+    }
+
+    #[test]
+    fn wide_characters_are_overwritten_as_a_whole() {
+        let mut keys = Key::char_keys_from_str("日本x");
+        keys.push(Key::Left(KeyModifiers::NONE));
+        keys.push(Key::Left(KeyModifiers::NONE));
+        keys.push(Key::Char('a', KeyModifiers::NONE));
+        keys.push(Key::Enter);
+
+        let (answer, screens) = run_scripted(keys, |backend| {
+            Text::new("Name?").prompt_with_backend(backend)
+        });
+
+        assert_eq!("日a本x", answer.unwrap());
+        assert_eq!(
+            "? Name? 日a本x",
+            screens.iter().rev().nth(1).unwrap().text()
+        );
+    }
+
+    #[test]
+    fn snapshots_are_rendered_at_the_given_width() {
+        let prompt = Text::new("Describe the problem:").with_initial_value("it crashes");
+
+        assert_eq!(
+            "? Describe the problem: it crashes",
+            prompt.clone().render_snapshot(40).unwrap().text()
+        );
+        assert_eq!(
+            "? Describe t\n  he problem\n  : it crash\nes",
+            prompt.render_snapshot(12).unwrap().text()
+        );
+    }
+
+    #[test]
+    fn lines_of_prompt_messages_are_aligned_with_the_first_one() {
+        let screen = Text::new("Describe the problem,\nif any:")
+            .with_initial_value("it crashes")
+            .render_snapshot(40)
+            .unwrap();
+        assert_eq!(
+            "? Describe the problem,\n  if any: it crashes",
+            screen.text()
+        );
+
+        let keys = vec![Key::Enter];
+        let (_, screens) = run_scripted(keys, |backend| {
+            Confirm::new("Overwrite\nthe file?")
+                .with_default(false)
+                .prompt_with_backend(backend)
+        });
+        assert_eq!(
+            "> Overwrite\n  the file? No",
+            screens.last().unwrap().text()
+        );
+    }
+
+    #[test]
+    fn snapshots_use_the_render_config_of_the_prompt() {
+        let screen = Text::new("Name:")
+            .with_render_config(RenderConfig::empty())
+            .render_snapshot(20)
+            .unwrap();
+        assert_eq!(
+            vec![vec![Styled::new("? Name:".to_string())]],
+            screen.lines()
+        );
+
+        let err = Select::<&str>::new("Color?", vec![]).render_snapshot(20);
+        assert!(matches!(err, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn scripted_runs_use_the_render_config_of_the_prompt() {
+        let render_config = RenderConfig::empty().with_prompt_prefix(Styled::new("$$"));
+        let prompt = || Text::new("Name:").with_render_config(render_config);
+
+        let snapshot = prompt().render_snapshot(80).unwrap();
+        let (answer, screens) = run_scripted(vec![Key::Enter], |backend| {
+            prompt().prompt_with_scripted_backend(backend)
+        });
+
+        assert_eq!("", answer.unwrap());
+        assert_eq!("$$ Name:", snapshot.text());
+        assert_eq!(Some(&snapshot), screens.first());
+    }
+
+    #[test]
+    fn interrupted_prompts_use_their_render_config() {
+        let render_config = RenderConfig::empty().with_fixed_height(Some(4));
+        let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+
+        assert_interrupt_safe(keys, |backend| {
+            Select::new("Color?", vec!["red", "green", "blue", "cyan", "pink"])
+                .with_render_config(render_config)
+                .prompt_with_scripted_backend(backend)
+        });
+    }
+
+    #[test]
+    fn snapshots_render_styled_texts_of_the_prompt() {
+        let message = StyledText::new()
+            .with_text("Delete ")
+            .with_styled(Styled::new("report.pdf").with_attr(Attributes::BOLD))
+            .with_text("?");
+        let help_message = StyledText::new()
+            .with_text("This action ")
+            .with_styled(Styled::new("can't be undone").with_fg(Color::LightRed));
+
+        let screen = Confirm::new("")
+            .with_styled_message(&message)
+            .with_styled_help_message(&help_message)
+            .with_render_config(RenderConfig::empty())
+            .render_snapshot(40)
+            .unwrap();
+
+        let lines = screen.lines();
+        assert_eq!(
+            Some(&Styled::new("report.pdf".to_string()).with_attr(Attributes::BOLD)),
+            lines.first().and_then(|line| line.get(1))
+        );
+        assert_eq!(
+            Some(&vec![
+                Styled::new("[This action ".to_string()),
+                Styled::new("can't be undone".to_string()).with_fg(Color::LightRed),
+                Styled::new("]".to_string()),
+            ]),
+            lines.get(1)
+        );
+    }
+
+    #[test]
+    fn styled_answers_are_rendered_on_top_of_the_answer_style() {
+        let keys = vec![Key::Char('y', KeyModifiers::NONE), Key::Enter];
+        let (_, screens) = run_scripted(keys, |backend| {
+            Confirm::new("Save?")
+                .with_styled_answer_formatter(&|answer| {
+                    StyledText::new()
+                        .with_text(answer)
+                        .with_styled(Styled::new(" (saved)").with_attr(Attributes::ITALIC))
+                })
+                .prompt_with_scripted_backend(backend)
+        });
+
+        let answered = screens.last().unwrap().lines().first().unwrap();
+        assert_eq!(
+            &[
+                Styled::new("Yes".to_string()).with_fg(Color::LightCyan),
+                Styled::new(" (saved)".to_string())
+                    .with_fg(Color::LightCyan)
+                    .with_attr(Attributes::ITALIC),
+            ],
+            answered.get(answered.len() - 2..).unwrap()
+        );
+    }
+
+    #[test]
+    fn text_prompt_restores_terminal_when_interrupted() {
+        let keys = vec![
+            Key::Char('h', KeyModifiers::NONE),
+            Key::Char('i', KeyModifiers::NONE),
+            Key::Enter,
+        ];
+
+        assert_interrupt_safe(keys, |backend| {
+            Text::new("Name?").prompt_with_backend(backend)
+        });
+    }
+
+    #[test]
+    fn select_prompt_restores_terminal_when_interrupted() {
+        let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+
+        assert_interrupt_safe(keys, |backend| {
+            Select::new("Color?", vec!["red", "green"]).prompt_with_backend(backend)
+        });
+    }
+
+    #[test]
+    fn key_handlers_of_scripted_prompts_are_called() {
+        let on_key = |key: Key, view: &mut PromptStateView<'_>| match key {
+            Key::F(5, _) => {
+                view.cancel();
+                Handled::Yes
+            }
+            _ => Handled::No,
+        };
+        let keys = vec![
+            Key::Char('a', KeyModifiers::NONE),
+            Key::F(5, KeyModifiers::NONE),
+            Key::Enter,
+        ];
+
+        let (answer, _) = run_scripted(keys, |backend| {
+            Text::new("Name?")
+                .with_key_handler(&on_key)
+                .prompt_with_scripted_backend(backend)
+        });
+
+        assert!(matches!(answer, Err(InquireError::OperationCanceled)));
+    }
+
+    #[test]
+    fn escape_behaviors_of_scripted_prompts_are_followed() {
+        let keys = vec![Key::Char('a', KeyModifiers::NONE), Key::Escape, Key::Enter];
+        let (answer, _) = run_scripted(keys, |backend| {
+            Text::new("Name?")
+                .with_escape_behavior(EscapeBehavior::Ignore)
+                .prompt_with_scripted_backend(backend)
+        });
+        assert_eq!("a", answer.unwrap());
+
+        let keys = vec![Key::Char('b', KeyModifiers::NONE), Key::Escape, Key::Enter];
+        let (answer, _) = run_scripted(keys, |backend| {
+            Select::new("Color?", vec!["red", "blue"])
+                .with_escape_behavior(EscapeBehavior::ClearInput)
+                .prompt_with_scripted_backend(backend)
+        });
+        assert_eq!("red", answer.unwrap().value);
+    }
+
+    #[test]
+    fn on_change_hooks_of_scripted_prompts_are_called() {
+        let events = RefCell::new(vec![]);
+        let on_change = |event: &PromptEvent| events.borrow_mut().push(event.clone());
+
+        let keys = vec![Key::Down(KeyModifiers::NONE), Key::Enter];
+        let (answer, _) = run_scripted(keys, |backend| {
+            Select::new("Color?", vec!["red", "green"])
+                .with_on_change(&on_change)
+                .prompt_with_scripted_backend(backend)
+        });
+
+        assert_eq!("green", answer.unwrap().value);
+        assert_eq!(vec![PromptEvent::HighlightChanged(1)], events.into_inner());
+    }
+
+    #[test]
+    fn cancel_guards_of_scripted_prompts_are_followed() {
+        let keys = vec![Key::Char('a', KeyModifiers::NONE), Key::Escape, Key::Enter];
+        let (answer, screens) = run_scripted(keys, |backend| {
+            Text::new("Name?")
+                .with_cancel_guard(CancelGuard::PressTwice(Duration::from_secs(60)))
+                .prompt_with_scripted_backend(backend)
+        });
+
+        assert_eq!("a", answer.unwrap());
+        assert!(screens
+            .iter()
+            .any(|screen| screen.text().contains("Press esc again to discard input")));
+    }
+}
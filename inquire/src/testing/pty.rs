@@ -0,0 +1,290 @@
+//! End-to-end testing of prompts in a pseudo-terminal.
+//!
+//! The scripted backend of this module's parent renders to an in-memory
+//! terminal, which doesn't cover what happens between the prompts and an
+//! actual terminal: raw mode, the ANSI sequences written, or how resizes
+//! are handled. [`PtySession`] spawns a process attached to a
+//! pseudo-terminal, sends it raw bytes as if they were typed, and emulates
+//! the screen from its output.
+//!
+//! The spawned process is usually a binary or example of the application
+//! under test, which runs its prompts as usual.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::{process::Command, time::Duration};
+//!
+//! use inquire::testing::pty::PtySession;
+//!
+//! let mut session = PtySession::spawn(&Command::new("target/debug/examples/text_simple"), 80, 24)?;
+//!
+//! session.wait_for_text("What is your name?", Duration::from_secs(5))?;
+//! session.send("Ana\r")?;
+//! session.wait_for_text("Hello Ana", Duration::from_secs(5))?;
+//!
+//! assert!(session.wait(Duration::from_secs(5))?);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{
+    io::{self, Read, Write},
+    process::Command,
+    sync::{Arc, Mutex, MutexGuard},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+
+/// Interval at which the session checks whether a condition is met while
+/// waiting for it.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Process running in a pseudo-terminal, whose output is interpreted by a
+/// terminal emulator.
+///
+/// The process is killed when the session is dropped, if it is still
+/// running.
+pub struct PtySession {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    output: Arc<Mutex<Output>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+/// Output of the process, both raw and as interpreted by the emulator.
+struct Output {
+    raw: Vec<u8>,
+    parser: vt100::Parser,
+}
+
+impl PtySession {
+    /// Spawns the command in a pseudo-terminal of the given size, with the
+    /// `TERM` environment variable set to `xterm-256color` unless the
+    /// command sets it.
+    pub fn spawn(command: &Command, cols: u16, rows: u16) -> io::Result<Self> {
+        let pair = native_pty_system()
+            .openpty(pty_size(cols, rows))
+            .map_err(pty_error)?;
+
+        let child = pair
+            .slave
+            .spawn_command(command_builder(command))
+            .map_err(pty_error)?;
+        // the slave is only needed by the child, and keeping it open would
+        // prevent the reader from noticing that the child exited
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().map_err(pty_error)?;
+        let writer = pair.master.take_writer().map_err(pty_error)?;
+
+        let output = Arc::new(Mutex::new(Output {
+            raw: vec![],
+            parser: vt100::Parser::new(rows, cols, 0),
+        }));
+
+        let shared = Arc::clone(&output);
+        let reader = thread::spawn(move || {
+            let mut buf = [0; 4096];
+            while let Ok(read @ 1..) = reader.read(&mut buf) {
+                let bytes = buf.get(..read).unwrap_or_default();
+                let mut output = lock(&shared);
+                output.raw.extend_from_slice(bytes);
+                output.parser.process(bytes);
+            }
+        });
+
+        Ok(Self {
+            master: pair.master,
+            writer,
+            child,
+            output,
+            reader: Some(reader),
+        })
+    }
+
+    /// Sends the bytes to the process as if they were typed, e.g. `"\r"`
+    /// for the enter key or `"\x1b[B"` for the down arrow.
+    pub fn send(&mut self, bytes: impl AsRef<[u8]>) -> io::Result<()> {
+        self.writer.write_all(bytes.as_ref())?;
+        self.writer.flush()
+    }
+
+    /// Resizes the pseudo-terminal and the emulated screen, notifying the
+    /// process of the new size.
+    pub fn resize(&mut self, cols: u16, rows: u16) -> io::Result<()> {
+        lock(&self.output).parser.screen_mut().set_size(rows, cols);
+        self.master.resize(pty_size(cols, rows)).map_err(pty_error)
+    }
+
+    /// Text displayed on the emulated screen, with rows separated by `\n`
+    /// and trailing whitespace omitted.
+    pub fn screen_text(&self) -> String {
+        lock(&self.output).parser.screen().contents()
+    }
+
+    /// Position of the cursor on the emulated screen, as `(row, col)`.
+    pub fn cursor_position(&self) -> (u16, u16) {
+        lock(&self.output).parser.screen().cursor_position()
+    }
+
+    /// Whether the process hid the cursor of the emulated screen.
+    pub fn cursor_hidden(&self) -> bool {
+        lock(&self.output).parser.screen().hide_cursor()
+    }
+
+    /// Everything written by the process so far, including ANSI sequences.
+    pub fn raw_output(&self) -> Vec<u8> {
+        lock(&self.output).raw.clone()
+    }
+
+    /// Waits until the emulated screen contains the text, failing with an
+    /// [`io::ErrorKind::TimedOut`] error holding the screen's text if it
+    /// doesn't within the timeout.
+    pub fn wait_for_text(&self, text: &str, timeout: Duration) -> io::Result<()> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let screen = self.screen_text();
+            if screen.contains(text) {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out waiting for {text:?}, screen:\n{screen}"),
+                ));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Waits for the process to exit, returning whether it succeeded. The
+    /// process is killed if it doesn't exit within the timeout, failing
+    /// with an [`io::ErrorKind::TimedOut`] error.
+    ///
+    /// The output of the process is fully processed once this returns.
+    pub fn wait(&mut self, timeout: Duration) -> io::Result<bool> {
+        let deadline = Instant::now() + timeout;
+
+        let status = loop {
+            if let Some(status) = self.child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                self.child.kill()?;
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("process still running, screen:\n{}", self.screen_text()),
+                ));
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+
+        Ok(status.success())
+    }
+}
+
+impl Drop for PtySession {
+    fn drop(&mut self) {
+        if let Ok(None) = self.child.try_wait() {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+fn command_builder(command: &Command) -> CommandBuilder {
+    let mut builder = CommandBuilder::new(command.get_program());
+    builder.args(command.get_args());
+    builder.env("TERM", "xterm-256color");
+
+    for (key, value) in command.get_envs() {
+        match value {
+            Some(value) => builder.env(key, value),
+            None => builder.env_remove(key),
+        }
+    }
+
+    match command.get_current_dir() {
+        Some(dir) => builder.cwd(dir),
+        None => {
+            if let Ok(dir) = std::env::current_dir() {
+                builder.cwd(dir);
+            }
+        }
+    }
+
+    builder
+}
+
+fn pty_size(cols: u16, rows: u16) -> PtySize {
+    PtySize {
+        rows,
+        cols,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+fn pty_error(err: impl std::fmt::Display) -> io::Error {
+    io::Error::other(err.to_string())
+}
+
+fn lock(output: &Mutex<Output>) -> MutexGuard<'_, Output> {
+    // the reader thread can't leave the output in an inconsistent state
+    output.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, process::Command, time::Duration};
+
+    use crate::Text;
+
+    use super::PtySession;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const CHILD_VAR: &str = "INQUIRE_PTY_TEST_CHILD";
+
+    /// Runs a single test of this binary in a pseudo-terminal.
+    fn spawn_test(name: &str, cols: u16, rows: u16) -> PtySession {
+        let mut command = Command::new(env::current_exe().unwrap());
+        command
+            .args(["--exact", name, "--ignored", "--nocapture"])
+            .env(CHILD_VAR, "1");
+
+        PtySession::spawn(&command, cols, rows).unwrap()
+    }
+
+    #[test]
+    #[ignore = "run in a pseudo-terminal by text_prompt_is_answered_in_a_pty"]
+    fn text_prompt_in_pty() {
+        if env::var_os(CHILD_VAR).is_none() {
+            return;
+        }
+
+        let name = Text::new("What's your name?").prompt().unwrap();
+        println!("answer: {name}");
+    }
+
+    #[test]
+    fn text_prompt_is_answered_in_a_pty() {
+        let mut session = spawn_test("testing::pty::test::text_prompt_in_pty", 40, 10);
+
+        session
+            .wait_for_text("? What's your name?", TIMEOUT)
+            .unwrap();
+        session.send("Anaa\x7f\r").unwrap();
+        session.wait_for_text("answer: Ana", TIMEOUT).unwrap();
+
+        assert!(session.wait(TIMEOUT).unwrap());
+        assert!(session.screen_text().contains("> What's your name? Ana\n"));
+        assert!(!session.cursor_hidden());
+    }
+}
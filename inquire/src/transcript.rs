@@ -0,0 +1,261 @@
+//! Recording of prompt sessions into machine-readable transcripts.
+//!
+//! Once recording is started with
+//! [start_global_transcript](crate::start_global_transcript), every prompt
+//! adds a [TranscriptEntry] to the transcript when it finishes, holding its
+//! message, its outcome and when it started and finished. The transcript is
+//! retrieved with [take_global_transcript](crate::take_global_transcript),
+//! which also stops the recording, and can be exported as JSON, e.g. to log
+//! what the user chose or to generate an equivalent non-interactive command.
+//!
+//! Answers are recorded as displayed to the user once submitted, so answers
+//! of [Password](crate::Password) prompts are masked unless they are
+//! displayed in full, and answers redacted with
+//! [AnswerRedaction](crate::ui::AnswerRedaction) are replaced by their
+//! placeholder, or recorded as empty when hidden.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{Confirm, Text};
+//!
+//! inquire::start_global_transcript();
+//!
+//! let name = Text::new("Project name:").prompt()?;
+//! let git = Confirm::new("Initialize a git repository?").prompt()?;
+//!
+//! if let Some(transcript) = inquire::take_global_transcript() {
+//!     std::fs::write("session.json", transcript.to_json())?;
+//! }
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    fmt::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::config::record_transcript_entry;
+
+/// Prompts finished while recording was active, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Transcript {
+    /// Entries of the finished prompts, in the order they finished.
+    pub entries: Vec<TranscriptEntry>,
+}
+
+/// Record of a single prompt in a [Transcript].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TranscriptEntry {
+    /// Message of the prompt.
+    pub message: String,
+
+    /// How the prompt finished.
+    pub outcome: TranscriptOutcome,
+
+    /// When the prompt was displayed.
+    pub started_at: SystemTime,
+
+    /// When the prompt was answered, canceled or interrupted.
+    pub finished_at: SystemTime,
+}
+
+/// How a prompt recorded in a [Transcript] finished.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TranscriptOutcome {
+    /// The prompt was answered, with the answer as displayed to the user.
+    Answered(String),
+
+    /// The user canceled the prompt, e.g. by pressing ESC.
+    Canceled,
+
+    /// The user interrupted the prompt, e.g. by pressing Ctrl-C.
+    Interrupted,
+}
+
+impl Transcript {
+    /// Exports the transcript as a JSON object, where timestamps are the
+    /// number of milliseconds since the Unix epoch:
+    ///
+    /// ```json
+    /// {"entries":[{"message":"Project name:","outcome":"answered","answer":"demo","started_at_ms":1700000000000,"finished_at_ms":1700000004200}]}
+    /// ```
+    ///
+    /// The `answer` key is only present for answered prompts, while the
+    /// `outcome` is one of `answered`, `canceled` or `interrupted`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"entries\":[");
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+
+            let (outcome, answer) = match &entry.outcome {
+                TranscriptOutcome::Answered(answer) => ("answered", Some(answer)),
+                TranscriptOutcome::Canceled => ("canceled", None),
+                TranscriptOutcome::Interrupted => ("interrupted", None),
+            };
+
+            json.push_str("{\"message\":");
+            push_json_string(&mut json, &entry.message);
+            let _ = write!(json, ",\"outcome\":\"{outcome}\"");
+            if let Some(answer) = answer {
+                json.push_str(",\"answer\":");
+                push_json_string(&mut json, answer);
+            }
+            let _ = write!(
+                json,
+                ",\"started_at_ms\":{},\"finished_at_ms\":{}}}",
+                unix_millis(entry.started_at),
+                unix_millis(entry.finished_at)
+            );
+        }
+
+        json.push_str("]}");
+        json
+    }
+}
+
+/// Adds an entry to the global transcript, if it is being recorded. The
+/// outcome is only computed in that case.
+pub(crate) fn record<F>(message: &str, started_at: SystemTime, outcome: F)
+where
+    F: FnOnce() -> TranscriptOutcome,
+{
+    record_transcript_entry(|| TranscriptEntry {
+        message: message.to_string(),
+        outcome: outcome(),
+        started_at,
+        finished_at: SystemTime::now(),
+    });
+}
+
+fn unix_millis(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{
+        test::fake_backend,
+        ui::{test::FakeBackend, AnswerRedaction, Key, KeyModifiers},
+        Text,
+    };
+
+    use super::{Transcript, TranscriptEntry, TranscriptOutcome};
+
+    #[test]
+    fn transcript_is_exported_as_json() {
+        let at = |ms| UNIX_EPOCH + Duration::from_millis(ms);
+        let transcript = Transcript {
+            entries: vec![
+                TranscriptEntry {
+                    message: "Say \"hi\":".into(),
+                    outcome: TranscriptOutcome::Answered("hi\n\u{1}".into()),
+                    started_at: at(1000),
+                    finished_at: at(2500),
+                },
+                TranscriptEntry {
+                    message: "Region:".into(),
+                    outcome: TranscriptOutcome::Canceled,
+                    started_at: at(3000),
+                    finished_at: at(3001),
+                },
+            ],
+        };
+
+        assert_eq!(
+            "{\"entries\":[\
+             {\"message\":\"Say \\\"hi\\\":\",\"outcome\":\"answered\",\"answer\":\"hi\\n\\u0001\",\
+             \"started_at_ms\":1000,\"finished_at_ms\":2500},\
+             {\"message\":\"Region:\",\"outcome\":\"canceled\",\
+             \"started_at_ms\":3000,\"finished_at_ms\":3001}]}",
+            transcript.to_json()
+        );
+    }
+
+    #[test]
+    fn finished_prompts_are_recorded() {
+        crate::start_global_transcript();
+
+        let mut keys = Key::char_keys_from_str("Ana");
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+        Text::new("Transcript test name:")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        let mut backend = FakeBackend::new(vec![Key::Escape]);
+        let _ = Text::new("Transcript test city:").prompt_with_backend(&mut backend);
+
+        for (message, redaction) in [
+            (
+                "Transcript test token:",
+                AnswerRedaction::Placeholder("****"),
+            ),
+            ("Transcript test secret:", AnswerRedaction::Hidden),
+        ] {
+            let prompt = Text::new(message).with_answer_redaction(redaction);
+            let mut backend = fake_backend(vec![Key::Char('x', KeyModifiers::NONE), Key::Enter]);
+            backend.apply_settings(prompt.settings.clone());
+            prompt.prompt_with_backend(&mut backend).unwrap();
+        }
+
+        let transcript = crate::take_global_transcript().unwrap();
+
+        // tests run in parallel, so prompts of other tests might be recorded too
+        let outcomes: Vec<_> = transcript
+            .entries
+            .into_iter()
+            .filter(|entry| entry.message.starts_with("Transcript test"))
+            .map(|entry| (entry.message, entry.outcome))
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "Transcript test name:".to_string(),
+                    TranscriptOutcome::Answered("Ana".into())
+                ),
+                (
+                    "Transcript test city:".to_string(),
+                    TranscriptOutcome::Canceled
+                ),
+                (
+                    "Transcript test token:".to_string(),
+                    TranscriptOutcome::Answered("****".into())
+                ),
+                (
+                    "Transcript test secret:".to_string(),
+                    TranscriptOutcome::Answered(String::new())
+                ),
+            ],
+            outcomes
+        );
+        assert_eq!(None, crate::take_global_transcript());
+    }
+}
@@ -0,0 +1,84 @@
+//! Trait and structs used by [Select](crate::Select) and
+//! [MultiSelect](crate::MultiSelect) prompts to match options written in
+//! non-Latin scripts.
+//!
+//! A transliterator converts the string value of each option to another
+//! script, typically Latin, so that the user can filter options such as
+//! "Москва" or "北京" by typing "moskva" or "beijing". The scorer is called
+//! with both the original and the transliterated string values, and the
+//! best of the two scores is used, so options still match input typed in
+//! their original script.
+
+use dyn_clone::DynClone;
+
+/// Converts the string value of an option to another script before it is
+/// scored against the filter input.
+///
+/// This trait is implemented for any cloneable closure with the signature
+/// `Fn(&str) -> String`.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{transliteration::Transliterator, Select};
+///
+/// let greek_to_latin = |value: &str| {
+///     value
+///         .chars()
+///         .map(|c| match c {
+///             'α' => 'a',
+///             'β' => 'b',
+///             'γ' => 'g',
+///             other => other,
+///         })
+///         .collect::<String>()
+/// };
+///
+/// assert_eq!("abg", greek_to_latin.transliterate("αβγ"));
+///
+/// let prompt = Select::new("Letter:", vec!["α", "β", "γ"]).with_transliterator(greek_to_latin);
+/// ```
+pub trait Transliterator: DynClone {
+    /// Returns the transliterated version of the provided string value.
+    fn transliterate(&self, value: &str) -> String;
+}
+
+impl Clone for Box<dyn Transliterator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F> Transliterator for F
+where
+    F: Fn(&str) -> String + Clone,
+{
+    fn transliterate(&self, value: &str) -> String {
+        (self)(value)
+    }
+}
+
+/// Transliterator that converts any Unicode text to its closest ASCII
+/// representation, covering scripts such as Cyrillic, Greek and Han
+/// (as pinyin), among many others.
+///
+/// Words transliterated from ideographic scripts are separated by spaces.
+///
+/// # Example
+///
+/// ```
+/// use inquire::transliteration::{AsciiTransliterator, Transliterator};
+///
+/// assert_eq!("Moskva", AsciiTransliterator.transliterate("Москва"));
+/// assert_eq!("Athena", AsciiTransliterator.transliterate("Αθηνα"));
+/// ```
+#[cfg(feature = "transliteration")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AsciiTransliterator;
+
+#[cfg(feature = "transliteration")]
+impl Transliterator for AsciiTransliterator {
+    fn transliterate(&self, value: &str) -> String {
+        deunicode::deunicode(value)
+    }
+}
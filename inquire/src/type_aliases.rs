@@ -1,6 +1,8 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::cmp::Ordering;
+
+use crate::{error::CustomUserError, ui::Icon};
 
 /// Type alias to represent the function used to Score and filter options.
 ///
@@ -73,6 +75,97 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent the function used to sort options for display when
+/// no filter is applied.
+///
+/// The function receives two options and returns their relative order, as in
+/// [`slice::sort_by`]. Sorting only affects the display order: the index of each
+/// option in the original list is preserved in the answer.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use inquire::type_aliases::Sorter;
+///
+/// // Sort by length, shortest first
+/// let sorter: Sorter<&str> = &|a, b| a.len().cmp(&b.len());
+///
+/// assert_eq!(Ordering::Less, sorter(&"Lima", &"Berlin"));
+/// ```
+pub type Sorter<'a, T> = &'a dyn Fn(&T, &T) -> Ordering;
+
+/// Type alias to represent the function used to decide, as the user types,
+/// whether a character is accepted in a text input. Rejected characters are
+/// not inserted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::InputFilter;
+///
+/// let digits_only: InputFilter = &|c| c.is_ascii_digit();
+///
+/// assert!(digits_only('7'));
+/// assert!(!digits_only('a'));
+/// ```
+pub type InputFilter<'a> = &'a dyn Fn(char) -> bool;
+
+/// Type alias to represent the function used to transform each character
+/// typed in a text input before it is inserted, e.g. to uppercase it.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::InputTransform;
+///
+/// let uppercase: InputTransform = &|c| c.to_ascii_uppercase();
+///
+/// assert_eq!('A', uppercase('a'));
+/// ```
+pub type InputTransform<'a> = &'a dyn Fn(char) -> char;
+
+/// Type alias to represent the function used to generate a custom prefix for each
+/// option of a list, such as a status emoji or a file-type icon.
+///
+/// The prefix is rendered in a dedicated column before the option, padded to the
+/// width of the widest prefix in the page so that options stay aligned.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionPrefix;
+///
+/// let prefix: OptionPrefix<(&str, bool)> = &|(_name, done)| match done {
+///     true => String::from("✔"),
+///     false => String::from("✘"),
+/// };
+///
+/// assert_eq!("✔", prefix(&("Write docs", true)));
+/// assert_eq!("✘", prefix(&("Release", false)));
+/// ```
+pub type OptionPrefix<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to generate the icon of each option
+/// of a list.
+///
+/// Icons are rendered in their own column before the option and any custom
+/// prefix, and are not taken into account when filtering the options.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::OptionIcon, ui::Icon};
+///
+/// let icon: OptionIcon<&str> = &|path| match path.ends_with('/') {
+///     true => Icon::new("📁", "d"),
+///     false => Icon::new("📄", "f"),
+/// };
+///
+/// assert_eq!(Icon::new("📁", "d"), icon(&"src/"));
+/// ```
+pub type OptionIcon<'a, T> = &'a dyn Fn(&T) -> Icon;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
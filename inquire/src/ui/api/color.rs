@@ -1,3 +1,11 @@
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::{env, str::FromStr};
+
+use crate::error::InquireError;
+
+use super::StyleSheet;
+
 /// Represents a color to be used for text styling purposes.
 ///
 /// Currently a clone of [crossterm::style::Color]. Check their documentation
@@ -132,8 +140,9 @@ pub enum Color {
     /// Most UNIX terminals and Windows 10 supported only.
     /// See [Platform-specific notes](enum.Color.html#platform-specific-notes) for more info.
     ///
-    /// Supported on the default terminal back-end `crossterm` and on `termion`.
-    /// Not supported on `console`.
+    /// Supported on all terminal back-ends: `crossterm`, `termion` and `console`.
+    /// On terminals without true color support, it is replaced by the closest color
+    /// they support. See [ColorSupport].
     Rgb {
         /// red value of RGB.
         r: u8,
@@ -151,6 +160,8 @@ pub enum Color {
     /// See [Platform-specific notes](enum.Color.html#platform-specific-notes) for more info.
     ///
     /// Supported on all terminal back-ends: `crossterm`, `termion` and `console`.
+    /// On terminals supporting only 16 colors, it is replaced by the closest named
+    /// color. See [ColorSupport].
     AnsiValue(u8),
 }
 
@@ -165,4 +176,437 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Color {
         Color::Rgb { r, g, b }
     }
+
+    /// Closest color of the 256-color palette, keeping the 16 named colors
+    /// and ANSI values as they are.
+    fn to_ansi256(self) -> Color {
+        match self {
+            Color::Rgb { r, g, b } => Color::AnsiValue(rgb_to_ansi256(r, g, b)),
+            color => color,
+        }
+    }
+
+    /// Closest of the 16 named colors, as defined by the xterm palette.
+    fn to_basic(self) -> Color {
+        let (r, g, b) = match self {
+            Color::Rgb { r, g, b } => (r, g, b),
+            Color::AnsiValue(value) => match BASIC_COLORS.get(value as usize) {
+                Some((color, _)) => return *color,
+                None => ansi256_to_rgb(value),
+            },
+            color => return color,
+        };
+
+        let distance = |(cr, cg, cb): (u8, u8, u8)| {
+            let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2);
+            d(r, cr) + d(g, cg) + d(b, cb)
+        };
+
+        BASIC_COLORS
+            .iter()
+            .min_by_key(|(_, rgb)| distance(*rgb))
+            .map(|(color, _)| *color)
+            .unwrap_or(Color::Grey)
+    }
+}
+
+/// Parses a color from its name in snake case, e.g. `"light_red"`, ignoring
+/// case, `-` and `_`, from a hex RGB string such as `"#2a1161"` or `"#f80"`,
+/// or from an ANSI value such as `"208"`.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::Color;
+///
+/// assert_eq!(Some(Color::rgb(42, 17, 97)), "#2a1161".parse().ok());
+/// assert_eq!(Some(Color::AnsiValue(208)), "208".parse().ok());
+/// assert_eq!(Some(Color::DarkRed), "DarkRed".parse().ok());
+/// ```
+impl FromStr for Color {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InquireError::InvalidConfiguration(format!("invalid color: {:?}", s));
+
+        if let Some(hex) = s.strip_prefix('#') {
+            let component = |i: usize, len: usize| {
+                let c = hex.get(i * len..(i + 1) * len)?;
+                let c = u8::from_str_radix(c, 16).ok()?;
+                // "#f80" is short for "#ff8800"
+                Some(if len == 1 { c * 17 } else { c })
+            };
+            let len = match hex.len() {
+                _ if !hex.chars().all(|c| c.is_ascii_hexdigit()) => return Err(invalid()),
+                3 => 1,
+                6 => 2,
+                _ => return Err(invalid()),
+            };
+            return match (component(0, len), component(1, len), component(2, len)) {
+                (Some(r), Some(g), Some(b)) => Ok(Color::rgb(r, g, b)),
+                _ => Err(invalid()),
+            };
+        }
+
+        if let Ok(value) = s.parse::<u8>() {
+            return Ok(Color::AnsiValue(value));
+        }
+
+        let normalized: String = s
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        NAMED_COLORS
+            .iter()
+            .find(|(name, _)| name.replace('_', "") == normalized)
+            .map(|(_, color)| *color)
+            .ok_or_else(invalid)
+    }
+}
+
+/// Names of the 16 named colors, in snake case.
+const NAMED_COLORS: [(&str, Color); 16] = [
+    ("black", Color::Black),
+    ("light_red", Color::LightRed),
+    ("dark_red", Color::DarkRed),
+    ("light_green", Color::LightGreen),
+    ("dark_green", Color::DarkGreen),
+    ("light_yellow", Color::LightYellow),
+    ("dark_yellow", Color::DarkYellow),
+    ("light_blue", Color::LightBlue),
+    ("dark_blue", Color::DarkBlue),
+    ("light_magenta", Color::LightMagenta),
+    ("dark_magenta", Color::DarkMagenta),
+    ("light_cyan", Color::LightCyan),
+    ("dark_cyan", Color::DarkCyan),
+    ("white", Color::White),
+    ("grey", Color::Grey),
+    ("dark_grey", Color::DarkGrey),
+];
+
+/// The 16 named colors in the order of their ANSI codes, with their RGB
+/// values in the xterm palette.
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (205, 0, 0)),
+    (Color::DarkGreen, (0, 205, 0)),
+    (Color::DarkYellow, (205, 205, 0)),
+    (Color::DarkBlue, (0, 0, 238)),
+    (Color::DarkMagenta, (205, 0, 205)),
+    (Color::DarkCyan, (0, 205, 205)),
+    (Color::Grey, (229, 229, 229)),
+    (Color::DarkGrey, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Levels of each component in the 6x6x6 color cube of the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        // the grayscale ramp goes from 8 to 238 in steps of 10
+        return match r {
+            0..=3 => 16,
+            247..=255 => 231,
+            gray => 232 + (gray.saturating_sub(3) / 10).min(23),
+        };
+    }
+
+    let level = |c: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (i32::from(**level) - i32::from(c)).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or_default()
+    };
+
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn ansi256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => BASIC_COLORS
+            .get(value as usize)
+            .map(|(_, rgb)| *rgb)
+            .unwrap_or_default(),
+        16..=231 => {
+            let level = |i: u8| CUBE_LEVELS.get(i as usize).copied().unwrap_or_default();
+            let i = value - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        gray => {
+            let gray = 8 + (gray - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Colors supported by a terminal, to which the colors of prompts are
+/// downgraded when rendering them.
+///
+/// Unless set in the [RenderConfig](crate::ui::RenderConfig), it is
+/// detected from the environment when a prompt is rendered. See
+/// [ColorSupport::detect].
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{ColorSupport, RenderConfig};
+///
+/// // e.g. when the application has a `--color=never` flag
+/// let config = RenderConfig::default().with_color_support(Some(ColorSupport::None));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ColorSupport {
+    /// No colors are rendered, only attributes such as bold text.
+    None,
+
+    /// The 16 named colors are rendered, and other colors are replaced by
+    /// the closest of them.
+    Basic,
+
+    /// The 256 colors of ANSI values are rendered, and RGB colors are
+    /// replaced by the closest of them.
+    Ansi256,
+
+    /// All colors are rendered as they are.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detects the colors supported by the terminal from the environment
+    /// variables, in this order of precedence:
+    ///
+    /// - `CLICOLOR_FORCE` set to anything other than `0` forces colors to
+    ///   be rendered, even on dumb terminals.
+    /// - `NO_COLOR` set to a non-empty value disables colors.
+    /// - `TERM` set to `dumb` disables colors.
+    /// - `COLORTERM` set to `truecolor` or `24bit`, `TERM` ending with
+    ///   `-direct`, or `WT_SESSION` being set, as in Windows Terminal,
+    ///   enable all colors.
+    /// - `TERM` containing `256color` enables the 256-color palette.
+    ///
+    /// Otherwise, only the 16 named colors are assumed to be supported.
+    pub fn detect() -> Self {
+        Self::from_env(|var| env::var(var).ok().filter(|value| !value.is_empty()))
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let forced = var("CLICOLOR_FORCE").is_some_and(|value| value != "0");
+        let term = var("TERM").unwrap_or_default();
+
+        if !forced && (var("NO_COLOR").is_some() || term == "dumb") {
+            return Self::None;
+        }
+
+        let truecolor = var("COLORTERM").is_some_and(|value| {
+            value.eq_ignore_ascii_case("truecolor") || value.eq_ignore_ascii_case("24bit")
+        });
+
+        if truecolor || term.ends_with("-direct") || var("WT_SESSION").is_some() {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Basic
+        }
+    }
+
+    /// Removes or downgrades the colors of the style sheet that are not
+    /// supported, keeping its attributes.
+    pub fn adapt(self, style: StyleSheet) -> StyleSheet {
+        let adapt = |color: Color| match self {
+            Self::None => None,
+            Self::Basic => Some(color.to_basic()),
+            Self::Ansi256 => Some(color.to_ansi256()),
+            Self::TrueColor => Some(color),
+        };
+
+        StyleSheet {
+            fg: style.fg.and_then(adapt),
+            bg: style.bg.and_then(adapt),
+            att: style.att,
+        }
+    }
+}
+
+/// Colors are serialized as their name in snake case, e.g. `"light_red"`,
+/// RGB colors as a hex string such as `"#2a1161"`, and ANSI colors as their
+/// number.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Color::Rgb { r, g, b } => {
+                serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", r, g, b))
+            }
+            Color::AnsiValue(value) => serializer.serialize_u8(*value),
+            named => {
+                let name = NAMED_COLORS
+                    .iter()
+                    .find(|(_, color)| color == named)
+                    .map(|(name, _)| *name)
+                    .unwrap_or_default();
+                serializer.serialize_str(name)
+            }
+        }
+    }
+}
+
+/// Colors are deserialized from the formats they are serialized to, or from
+/// any string accepted by the [FromStr] implementation.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ColorVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a color name, a hex RGB string such as \"#2a1161\" or an ANSI value")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Color, E>
+    where
+        E: serde::de::Error,
+    {
+        match u8::try_from(value) {
+            Ok(value) => Ok(Color::AnsiValue(value)),
+            Err(_) => Err(E::invalid_value(
+                serde::de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Color, E>
+    where
+        E: serde::de::Error,
+    {
+        match u8::try_from(value) {
+            Ok(value) => Ok(Color::AnsiValue(value)),
+            Err(_) => Err(E::invalid_value(
+                serde::de::Unexpected::Signed(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Color, E>
+    where
+        E: serde::de::Error,
+    {
+        match value.parse() {
+            Ok(color) => Ok(color),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ui::{Attributes, StyleSheet};
+
+    use super::{Color, ColorSupport};
+
+    fn detect(vars: &[(&str, &str)]) -> ColorSupport {
+        ColorSupport::from_env(|var| {
+            vars.iter()
+                .find(|(name, _)| *name == var)
+                .map(|(_, value)| (*value).to_owned())
+        })
+    }
+
+    #[test]
+    fn color_support_is_detected_from_the_environment() {
+        assert_eq!(ColorSupport::Basic, detect(&[("TERM", "xterm")]));
+        assert_eq!(ColorSupport::Ansi256, detect(&[("TERM", "xterm-256color")]));
+        assert_eq!(
+            ColorSupport::TrueColor,
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")])
+        );
+        assert_eq!(ColorSupport::TrueColor, detect(&[("WT_SESSION", "1")]));
+        assert_eq!(ColorSupport::None, detect(&[("TERM", "dumb")]));
+        assert_eq!(
+            ColorSupport::None,
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")])
+        );
+        assert_eq!(
+            ColorSupport::Basic,
+            detect(&[("TERM", "dumb"), ("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")])
+        );
+        assert_eq!(
+            ColorSupport::None,
+            detect(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "0")])
+        );
+    }
+
+    #[test]
+    fn colors_are_downgraded_to_the_supported_ones() {
+        let style = StyleSheet::empty()
+            .with_fg(Color::rgb(255, 135, 0))
+            .with_bg(Color::AnsiValue(4))
+            .with_attr(Attributes::BOLD);
+
+        assert_eq!(style, ColorSupport::TrueColor.adapt(style));
+        assert_eq!(
+            style.with_fg(Color::AnsiValue(208)),
+            ColorSupport::Ansi256.adapt(style)
+        );
+        assert_eq!(
+            style.with_fg(Color::DarkYellow).with_bg(Color::DarkBlue),
+            ColorSupport::Basic.adapt(style)
+        );
+        assert_eq!(
+            StyleSheet::empty().with_attr(Attributes::BOLD),
+            ColorSupport::None.adapt(style)
+        );
+
+        assert_eq!(
+            Color::AnsiValue(244),
+            Color::rgb(128, 128, 128).to_ansi256()
+        );
+        assert_eq!(Color::DarkGrey, Color::AnsiValue(244).to_basic());
+    }
+
+    #[test]
+    fn colors_are_parsed_from_names_hex_and_ansi_values() {
+        assert_eq!(Some(Color::rgb(255, 136, 0)), "#f80".parse().ok());
+        assert_eq!(Some(Color::rgb(255, 136, 0)), "#FF8800".parse().ok());
+        assert_eq!(Some(Color::DarkGrey), "dark-grey".parse().ok());
+        assert_eq!(Some(Color::AnsiValue(0)), "0".parse().ok());
+
+        for invalid in ["#ff880", "#+f8800", "256", "purple", ""] {
+            assert!(invalid.parse::<Color>().is_err(), "{}", invalid);
+        }
+    }
 }
@@ -0,0 +1,537 @@
+//! Loading of render configurations from TOML or JSON files.
+
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+use crate::error::{InquireError, InquireResult};
+
+use super::{
+    AmbiguousWidth, Attributes, Color, ColorSupport, ErrorMessageRenderConfig, IndexPrefix,
+    RenderConfig, RenderProfile, StyleSheet, Styled,
+};
+
+#[cfg(feature = "rtl")]
+use super::TextDirection;
+
+impl RenderConfig<'static> {
+    /// Loads a render configuration from a TOML or JSON file, so that users
+    /// of an application can customize the appearance of its prompts
+    /// without recompiling it. See the [`FromStr`] implementation for the
+    /// format of the file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::ui::RenderConfig;
+    ///
+    /// let config = RenderConfig::from_path("theme.toml")?;
+    /// inquire::set_global_render_config(config);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> InquireResult<Self> {
+        let contents = fs::read_to_string(path).map_err(InquireError::IO)?;
+        contents.parse()
+    }
+}
+
+/// Parses a render configuration from TOML, or from JSON when the content
+/// is an object, i.e. starts with `{`. Parsing TOML requires the `toml`
+/// feature.
+///
+/// The configuration starts from the preset named by the `theme` key, one
+/// of `default`, `empty`, `classic`, `minimal`, `colorful`, `monochrome` or
+/// `high_contrast`, and the other keys override its fields. They are named
+/// after the fields of [`RenderConfig`]:
+///
+/// - Tokens such as `prompt_prefix` or `selected_checkbox` take either the
+///   text of the token, or a table with its `content` and style.
+/// - Styles such as `answer` or `help_message` take a table with `fg` and
+///   `bg` colors and a list of `attributes`. Only the keys present replace
+///   those of the preset.
+/// - Colors are named in snake case (`"light_red"`), written as hex RGB
+///   strings (`"#2a1161"`) or given as ANSI values (`208`).
+/// - `error_message` and `calendar` are tables of the fields of
+///   [`ErrorMessageRenderConfig`] and `CalendarRenderConfig`.
+///
+/// Unknown keys are rejected, so that typos are reported.
+///
+/// The text of the tokens is leaked, as render configurations only borrow
+/// it: configurations are meant to be loaded once, e.g. when the
+/// application starts.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "toml")]
+/// # {
+/// use inquire::ui::{Color, RenderConfig};
+///
+/// let config: RenderConfig<'_> = r##"
+///     theme = "minimal"
+///     prompt_prefix = { content = "$", fg = "light_green" }
+///     answer = { fg = "#ffaf00", attributes = ["bold"] }
+///
+///     [error_message]
+///     prefix = "!"
+/// "##
+/// .parse()?;
+///
+/// assert_eq!("$", config.prompt_prefix.content);
+/// assert_eq!(Some(Color::rgb(255, 175, 0)), config.answer.fg);
+/// # }
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl FromStr for RenderConfig<'static> {
+    type Err = InquireError;
+
+    fn from_str(s: &str) -> InquireResult<Self> {
+        let file: ConfigFile = if s.trim_start().starts_with('{') {
+            serde_json::from_str(s).map_err(invalid_config)?
+        } else {
+            parse_toml(s)?
+        };
+
+        Ok(file.into_render_config())
+    }
+}
+
+#[cfg(feature = "toml")]
+fn parse_toml(s: &str) -> InquireResult<ConfigFile> {
+    toml::from_str(s).map_err(invalid_config)
+}
+
+#[cfg(not(feature = "toml"))]
+fn parse_toml(_s: &str) -> InquireResult<ConfigFile> {
+    Err(InquireError::InvalidConfiguration(
+        "render configs in TOML require the `toml` feature".into(),
+    ))
+}
+
+fn invalid_config(err: impl fmt::Display) -> InquireError {
+    InquireError::InvalidConfiguration(format!("invalid render config: {}", err))
+}
+
+fn leak(content: String) -> &'static str {
+    Box::leak(content.into_boxed_str())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Theme {
+    Default,
+    Empty,
+    Classic,
+    Minimal,
+    Colorful,
+    Monochrome,
+    HighContrast,
+}
+
+impl Theme {
+    fn render_config(self) -> RenderConfig<'static> {
+        match self {
+            Theme::Default => RenderConfig::default(),
+            Theme::Empty => RenderConfig::empty(),
+            Theme::Classic => RenderConfig::classic(),
+            Theme::Minimal => RenderConfig::minimal(),
+            Theme::Colorful => RenderConfig::colorful(),
+            Theme::Monochrome => RenderConfig::monochrome(),
+            Theme::HighContrast => RenderConfig::high_contrast(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    theme: Option<Theme>,
+
+    prompt_prefix: Option<TokenOverride>,
+    answered_prompt_prefix: Option<TokenOverride>,
+    canceled_prompt_prefix: Option<TokenOverride>,
+    error_prompt_prefix: Option<TokenOverride>,
+    canceled_prompt_indicator: Option<TokenOverride>,
+    highlighted_option_prefix: Option<TokenOverride>,
+    scroll_up_prefix: Option<TokenOverride>,
+    scroll_down_prefix: Option<TokenOverride>,
+    selected_checkbox: Option<TokenOverride>,
+    unselected_checkbox: Option<TokenOverride>,
+
+    prompt: Option<StyleOverride>,
+    default_value: Option<StyleOverride>,
+    placeholder: Option<StyleOverride>,
+    ghost_text: Option<StyleOverride>,
+    help_message: Option<StyleOverride>,
+    text_input: Option<StyleOverride>,
+    answer: Option<StyleOverride>,
+    option: Option<StyleOverride>,
+    selected_option: Option<StyleOverride>,
+    answer_tag: Option<StyleOverride>,
+    #[cfg(feature = "editor")]
+    editor_prompt: Option<StyleOverride>,
+
+    error_message: Option<ErrorMessageOverride>,
+    #[cfg(feature = "date")]
+    calendar: Option<calendar::CalendarOverride>,
+
+    password_mask: Option<char>,
+    option_index_prefix: Option<IndexPrefix>,
+    icon_fallback: Option<bool>,
+    render_profile: Option<RenderProfile>,
+    fixed_height: Option<u16>,
+    color_support: Option<ColorSupport>,
+    ambiguous_width: Option<AmbiguousWidth>,
+    #[cfg(feature = "rtl")]
+    text_direction: Option<TextDirection>,
+}
+
+impl ConfigFile {
+    fn into_render_config(self) -> RenderConfig<'static> {
+        let mut config = self.theme.unwrap_or(Theme::Default).render_config();
+
+        apply(&mut config.prompt_prefix, self.prompt_prefix);
+        apply(
+            &mut config.answered_prompt_prefix,
+            self.answered_prompt_prefix,
+        );
+        apply_or(
+            &mut config.canceled_prompt_prefix,
+            config.prompt_prefix,
+            self.canceled_prompt_prefix,
+        );
+        apply_or(
+            &mut config.error_prompt_prefix,
+            config.prompt_prefix,
+            self.error_prompt_prefix,
+        );
+        apply(
+            &mut config.canceled_prompt_indicator,
+            self.canceled_prompt_indicator,
+        );
+        apply(
+            &mut config.highlighted_option_prefix,
+            self.highlighted_option_prefix,
+        );
+        apply(&mut config.scroll_up_prefix, self.scroll_up_prefix);
+        apply(&mut config.scroll_down_prefix, self.scroll_down_prefix);
+        apply(&mut config.selected_checkbox, self.selected_checkbox);
+        apply(&mut config.unselected_checkbox, self.unselected_checkbox);
+
+        apply(&mut config.prompt, self.prompt);
+        apply(&mut config.default_value, self.default_value);
+        apply(&mut config.placeholder, self.placeholder);
+        apply(&mut config.ghost_text, self.ghost_text);
+        apply(&mut config.help_message, self.help_message);
+        apply(&mut config.text_input, self.text_input);
+        apply(&mut config.answer, self.answer);
+        apply(&mut config.option, self.option);
+        apply(&mut config.selected_option, self.selected_option);
+        apply(&mut config.answer_tag, self.answer_tag);
+        #[cfg(feature = "editor")]
+        apply(&mut config.editor_prompt, self.editor_prompt);
+
+        apply(&mut config.error_message, self.error_message);
+        #[cfg(feature = "date")]
+        apply(&mut config.calendar, self.calendar);
+
+        set(&mut config.password_mask, self.password_mask);
+        set(&mut config.option_index_prefix, self.option_index_prefix);
+        set(&mut config.icon_fallback, self.icon_fallback);
+        set(&mut config.render_profile, self.render_profile);
+        set(&mut config.ambiguous_width, self.ambiguous_width);
+        set(&mut config.fixed_height, self.fixed_height.map(Some));
+        set(&mut config.color_support, self.color_support.map(Some));
+        #[cfg(feature = "rtl")]
+        set(&mut config.text_direction, self.text_direction);
+
+        config
+    }
+}
+
+/// Override of a part of the render config read from the file.
+trait Override<T> {
+    fn apply_to(self, target: &mut T);
+}
+
+fn apply<T, O: Override<T>>(target: &mut T, value: Option<O>) {
+    if let Some(value) = value {
+        value.apply_to(target);
+    }
+}
+
+/// Applies the override to the optional target, starting from the fallback
+/// it stands for when it is None.
+fn apply_or<T, O: Override<T>>(target: &mut Option<T>, fallback: T, value: Option<O>) {
+    if let Some(value) = value {
+        value.apply_to(target.get_or_insert(fallback));
+    }
+}
+
+fn set<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+/// Colors and attributes replacing those of a style sheet, when present.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StyleOverride {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Option<Attributes>,
+}
+
+impl Override<StyleSheet> for StyleOverride {
+    fn apply_to(self, target: &mut StyleSheet) {
+        set(&mut target.fg, self.fg.map(Some));
+        set(&mut target.bg, self.bg.map(Some));
+        set(&mut target.att, self.attributes);
+    }
+}
+
+impl Override<Option<StyleSheet>> for StyleOverride {
+    fn apply_to(self, target: &mut Option<StyleSheet>) {
+        self.apply_to(target.get_or_insert_with(StyleSheet::empty));
+    }
+}
+
+/// Token written either as its text, e.g. `prompt_prefix = "$"`, or as a
+/// table with its style, e.g. `prompt_prefix = { content = "$", fg = "red" }`.
+struct TokenOverride {
+    content: Option<String>,
+    style: Option<StyleOverride>,
+}
+
+impl Override<Styled<&'static str>> for TokenOverride {
+    fn apply_to(self, target: &mut Styled<&'static str>) {
+        set(&mut target.content, self.content.map(leak));
+        apply(&mut target.style, self.style);
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TokenTable {
+    content: Option<String>,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Option<Attributes>,
+}
+
+impl<'de> Deserialize<'de> for TokenOverride {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(TokenVisitor)
+    }
+}
+
+struct TokenVisitor;
+
+impl<'de> Visitor<'de> for TokenVisitor {
+    type Value = TokenOverride;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the text of the token or a table with its content and style")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<TokenOverride, E>
+    where
+        E: de::Error,
+    {
+        Ok(TokenOverride {
+            content: Some(value.to_owned()),
+            style: None,
+        })
+    }
+
+    fn visit_map<M>(self, map: M) -> Result<TokenOverride, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let table = TokenTable::deserialize(de::value::MapAccessDeserializer::new(map))?;
+
+        Ok(TokenOverride {
+            content: table.content,
+            style: Some(StyleOverride {
+                fg: table.fg,
+                bg: table.bg,
+                attributes: table.attributes,
+            }),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ErrorMessageOverride {
+    prefix: Option<TokenOverride>,
+    separator: Option<StyleOverride>,
+    message: Option<StyleOverride>,
+    default_message: Option<String>,
+}
+
+impl Override<ErrorMessageRenderConfig<'static>> for ErrorMessageOverride {
+    fn apply_to(self, target: &mut ErrorMessageRenderConfig<'static>) {
+        apply(&mut target.prefix, self.prefix);
+        apply(&mut target.separator, self.separator);
+        apply(&mut target.message, self.message);
+        set(&mut target.default_message, self.default_message.map(leak));
+    }
+}
+
+#[cfg(feature = "date")]
+mod calendar {
+    use serde::Deserialize;
+
+    use crate::ui::calendar::CalendarRenderConfig;
+
+    use super::{apply, Override, StyleOverride, TokenOverride};
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub(super) struct CalendarOverride {
+        prefix: Option<TokenOverride>,
+        header: Option<StyleOverride>,
+        week_header: Option<StyleOverride>,
+        selected_date: Option<StyleOverride>,
+        today_date: Option<StyleOverride>,
+        checked_date: Option<StyleOverride>,
+        different_month_date: Option<StyleOverride>,
+        week_number: Option<StyleOverride>,
+        unavailable_date: Option<StyleOverride>,
+    }
+
+    impl Override<CalendarRenderConfig<'static>> for CalendarOverride {
+        fn apply_to(self, target: &mut CalendarRenderConfig<'static>) {
+            apply(&mut target.prefix, self.prefix);
+            apply(&mut target.header, self.header);
+            apply(&mut target.week_header, self.week_header);
+            apply(&mut target.selected_date, self.selected_date);
+            apply(&mut target.today_date, self.today_date);
+            apply(&mut target.checked_date, self.checked_date);
+            apply(&mut target.different_month_date, self.different_month_date);
+            apply(&mut target.week_number, self.week_number);
+            apply(&mut target.unavailable_date, self.unavailable_date);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ui::{Attributes, Color, IndexPrefix, RenderConfig, StyleSheet},
+        InquireError,
+    };
+
+    #[test]
+    fn json_keys_override_the_theme() {
+        let config: RenderConfig<'_> = r##"{
+            "theme": "empty",
+            "prompt_prefix": "$",
+            "selected_checkbox": { "content": "[x]", "fg": "DarkGreen" },
+            "help_message": { "fg": 208, "attributes": ["italic", "BOLD"] },
+            "error_message": { "prefix": { "bg": "#ff0000" } },
+            "option_index_prefix": "zero_padded",
+            "fixed_height": 8
+        }"##
+        .parse()
+        .unwrap();
+
+        assert_eq!("$", config.prompt_prefix.content);
+        assert_eq!(StyleSheet::empty(), config.prompt_prefix.style);
+        assert_eq!("[x]", config.selected_checkbox.content);
+        assert_eq!(Some(Color::DarkGreen), config.selected_checkbox.style.fg);
+        assert_eq!(
+            StyleSheet::empty()
+                .with_fg(Color::AnsiValue(208))
+                .with_attr(Attributes::BOLD | Attributes::ITALIC),
+            config.help_message
+        );
+        assert_eq!(
+            RenderConfig::empty().error_message.prefix.content,
+            config.error_message.prefix.content
+        );
+        assert_eq!(
+            Some(Color::rgb(255, 0, 0)),
+            config.error_message.prefix.style.bg
+        );
+        assert_eq!(IndexPrefix::ZeroPadded, config.option_index_prefix);
+        assert_eq!(Some(8), config.fixed_height);
+    }
+
+    #[test]
+    fn style_keys_only_replace_those_present() {
+        let config: RenderConfig<'_> = r#"{ "theme": "classic", "answer": { "bg": "black" } }"#
+            .parse()
+            .unwrap();
+
+        let classic = RenderConfig::classic().answer;
+        assert_eq!(classic.with_bg(Color::Black), config.answer);
+    }
+
+    #[test]
+    fn unknown_keys_and_colors_are_rejected() {
+        for json in [
+            r#"{ "promt_prefix": "$" }"#,
+            r#"{ "answer": { "fg": "purple" } }"#,
+            r##"{ "answer": { "fg": "#12345" } }"##,
+            r#"{ "prompt_prefix": { "content": "$", "underline": true } }"#,
+        ] {
+            let err = json.parse::<RenderConfig<'_>>().unwrap_err();
+            assert!(
+                matches!(err, InquireError::InvalidConfiguration(_)),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_is_parsed() {
+        let config: RenderConfig<'_> = r#"
+            theme = "monochrome"
+            password_mask = "•"
+
+            [scroll_up_prefix]
+            content = "▲"
+            fg = "light_cyan"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!('•', config.password_mask);
+        assert_eq!("▲", config.scroll_up_prefix.content);
+        assert_eq!(Some(Color::LightCyan), config.scroll_up_prefix.style.fg);
+        assert_eq!(RenderConfig::monochrome().answer, config.answer);
+    }
+
+    #[test]
+    fn colors_and_style_sheets_are_serialized_to_what_is_deserialized() {
+        let style_sheet = StyleSheet::empty()
+            .with_fg(Color::rgb(42, 17, 97))
+            .with_bg(Color::DarkGrey)
+            .with_attr(Attributes::BOLD);
+
+        let json = serde_json::to_value(style_sheet).unwrap();
+
+        assert_eq!(
+            serde_json::json!({ "fg": "#2a1161", "bg": "dark_grey", "attributes": ["bold"] }),
+            json
+        );
+        assert_eq!(style_sheet, serde_json::from_value(json).unwrap());
+        assert_eq!(
+            Color::AnsiValue(12),
+            serde_json::from_value(serde_json::to_value(Color::AnsiValue(12)).unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,48 @@
+/// Icon displayed in a dedicated column before an option of a
+/// [Select](crate::Select) or [MultiSelect](crate::MultiSelect) prompt.
+///
+/// Icons hold a glyph, such as an emoji or a nerd-font symbol, and an ASCII
+/// fallback rendered instead of the glyph when
+/// [`icon_fallback`](crate::ui::RenderConfig::icon_fallback) is enabled in the
+/// render config, e.g. for terminals or fonts that can't display the glyph.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::Icon;
+///
+/// let folder = Icon::new("📁", "d");
+///
+/// assert_eq!("📁", folder.content(false));
+/// assert_eq!("d", folder.content(true));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Icon {
+    /// Glyph rendered by default.
+    pub glyph: String,
+
+    /// ASCII string rendered in place of the glyph when fallbacks are enabled.
+    pub fallback: String,
+}
+
+impl Icon {
+    /// Creates an icon with the provided glyph and ASCII fallback.
+    pub fn new<G, F>(glyph: G, fallback: F) -> Self
+    where
+        G: Into<String>,
+        F: Into<String>,
+    {
+        Self {
+            glyph: glyph.into(),
+            fallback: fallback.into(),
+        }
+    }
+
+    /// Returns the content to be rendered, either the glyph or the fallback.
+    pub fn content(&self, use_fallback: bool) -> &str {
+        match use_fallback {
+            true => &self.fallback,
+            false => &self.glyph,
+        }
+    }
+}
@@ -3,34 +3,61 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held down when a key event was triggered.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Key event read from the terminal, already translated from the
+/// representation used by the underlying terminal library.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp(KeyModifiers),
+    /// Page down key.
     PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Character key, including any modifiers held along with it.
     Char(char, KeyModifiers),
+    /// Function key, e.g. `F(5, KeyModifiers::NONE)` for F5.
+    F(u8, KeyModifiers),
+    /// Any other key not mapped by this library.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
@@ -40,6 +67,7 @@ pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
+        /// Converts each char of the string into an unmodified key event.
         pub fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
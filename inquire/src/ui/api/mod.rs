@@ -1,9 +1,17 @@
 mod color;
+#[cfg(feature = "serde")]
+mod config_file;
+mod icon;
 mod key;
 mod render_config;
 mod style;
+mod styled_text;
+mod viewport;
 
 pub use color::*;
-pub(crate) use key::*;
+pub use icon::*;
+pub use key::*;
 pub use render_config::*;
 pub use style::*;
+pub use styled_text::*;
+pub use viewport::*;
@@ -1,6 +1,6 @@
-use std::env;
+use std::{env, fmt::Debug};
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, ColorSupport, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -33,6 +33,22 @@ pub struct RenderConfig<'a> {
     /// and the prompt message.
     pub answered_prompt_prefix: Styled<&'a str>,
 
+    /// Prefix added before prompts canceled by the user, when rendered
+    /// with the [`canceled_prompt_indicator`](crate::ui::RenderConfig::canceled_prompt_indicator).
+    /// If the value is None, it will fall back to `prompt_prefix`.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub canceled_prompt_prefix: Option<Styled<&'a str>>,
+
+    /// Prefix added before prompts while an error message, e.g. of a
+    /// failed validation, is displayed. If the value is None, it will fall
+    /// back to `prompt_prefix`.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub error_prompt_prefix: Option<Styled<&'a str>>,
+
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
@@ -50,6 +66,10 @@ pub struct RenderConfig<'a> {
     /// and after the default value, as separators.
     pub placeholder: StyleSheet,
 
+    /// Style sheet of the inline suggestion rendered after the cursor in
+    /// text inputs, also known as ghost text.
+    pub ghost_text: StyleSheet,
+
     /// Render configuration of help messages.
     ///
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
@@ -81,6 +101,12 @@ pub struct RenderConfig<'a> {
     /// a separator from the prompt message.
     pub canceled_prompt_indicator: Styled<&'a str>,
 
+    /// Defines what is rendered in place of a prompt canceled by the user.
+    ///
+    /// By default, the prompt message is rendered followed by the
+    /// [`canceled_prompt_indicator`](crate::ui::RenderConfig::canceled_prompt_indicator).
+    pub canceled_prompt: CanceledPrompt<'a>,
+
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig<'a>,
 
@@ -134,6 +160,20 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet of the tags in which the selected options of a
+    /// [MultiSelect](crate::MultiSelect) are rendered once the prompt is
+    /// submitted, e.g. with a colored background.
+    ///
+    /// When set, each option is rendered as a separate tag, padded with a
+    /// space on both sides, instead of the comma-separated answer of the
+    /// formatter. Tags are wrapped to the next line when they don't fit in the
+    /// terminal width. If the value is None, the formatted answer is rendered.
+    pub answer_tag: Option<StyleSheet>,
+
+    /// Whether option [icons](crate::ui::Icon) are rendered with their ASCII
+    /// fallbacks instead of their glyphs. Defaults to false.
+    pub icon_fallback: bool,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -146,6 +186,35 @@ pub struct RenderConfig<'a> {
     /// with the editor name.
     #[cfg(feature = "editor")]
     pub editor_prompt: StyleSheet,
+
+    /// Trade-offs made when writing frames to the terminal. See [RenderProfile].
+    pub render_profile: RenderProfile,
+
+    /// Number of lines reserved for the prompt below the cursor, which it
+    /// never grows beyond.
+    ///
+    /// The lines are reserved when the prompt is first rendered, so that
+    /// output printed before it is only scrolled off-screen once, if at all.
+    /// Option lists are shortened to fit in the region, and other content
+    /// taller than it is scrolled to keep the cursor visible. It is capped
+    /// to the terminal height and ignored for prompts rendered in a
+    /// [Viewport](crate::ui::Viewport). If the value is None, prompts grow
+    /// as needed, shortening their option lists to fit in the terminal.
+    pub fixed_height: Option<u16>,
+
+    /// Colors supported by the terminal, to which the colors of the config
+    /// are downgraded when rendered. If the value is None, it is detected
+    /// from the environment, e.g. disabling colors when `NO_COLOR` is set.
+    /// See [ColorSupport].
+    pub color_support: Option<ColorSupport>,
+
+    /// Width of characters of ambiguous width, such as `±` or `①`, used to
+    /// wrap lines and place the cursor. See [AmbiguousWidth].
+    pub ambiguous_width: AmbiguousWidth,
+
+    /// Base direction of the rendered text. See [TextDirection].
+    #[cfg(feature = "rtl")]
+    pub text_direction: TextDirection,
 }
 
 impl<'a> RenderConfig<'a> {
@@ -157,11 +226,13 @@ impl<'a> RenderConfig<'a> {
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
+            ghost_text: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
+            canceled_prompt: CanceledPrompt::Indicator,
             password_mask: '*',
             highlighted_option_prefix: Styled::new(">"),
             scroll_up_prefix: Styled::new("^"),
@@ -171,12 +242,24 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            answer_tag: None,
+            icon_fallback: false,
+            canceled_prompt_prefix: None,
+            error_prompt_prefix: None,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
 
             #[cfg(feature = "editor")]
             editor_prompt: StyleSheet::empty(),
+
+            render_profile: RenderProfile::Standard,
+            fixed_height: None,
+            color_support: None,
+            ambiguous_width: AmbiguousWidth::Narrow,
+
+            #[cfg(feature = "rtl")]
+            text_direction: TextDirection::Ltr,
         }
     }
 
@@ -188,12 +271,14 @@ impl<'a> RenderConfig<'a> {
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
+            ghost_text: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::default_colored(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
+            canceled_prompt: CanceledPrompt::Indicator,
             highlighted_option_prefix: Styled::new(">").with_fg(Color::LightCyan),
             scroll_up_prefix: Styled::new("^"),
             scroll_down_prefix: Styled::new("v"),
@@ -202,12 +287,169 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            answer_tag: None,
+            icon_fallback: false,
+            canceled_prompt_prefix: None,
+            error_prompt_prefix: None,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
 
             #[cfg(feature = "editor")]
             editor_prompt: StyleSheet::new().with_fg(Color::DarkCyan),
+
+            render_profile: RenderProfile::Standard,
+            fixed_height: None,
+            color_support: None,
+            ambiguous_width: AmbiguousWidth::Narrow,
+
+            #[cfg(feature = "rtl")]
+            text_direction: TextDirection::Ltr,
+        }
+    }
+
+    /// Theme with the default colors of the crate, the same as
+    /// [default_colored](Self::default_colored).
+    pub fn classic() -> Self {
+        Self::default_colored()
+    }
+
+    /// Theme without colors nor attributes, except for hints such as help
+    /// messages and placeholders, which are dimmed.
+    pub fn minimal() -> Self {
+        let dimmed = StyleSheet::new().with_fg(Color::DarkGrey);
+
+        Self {
+            answered_prompt_prefix: Styled::new(">"),
+            default_value: dimmed,
+            placeholder: dimmed,
+            ghost_text: dimmed,
+            help_message: dimmed,
+            canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkGrey),
+            ..Self::empty()
+        }
+    }
+
+    /// Theme with bright colors and Unicode glyphs for prefixes and
+    /// checkboxes.
+    pub fn colorful() -> Self {
+        let accent = Color::LightMagenta;
+        let dimmed = StyleSheet::new().with_fg(Color::DarkGrey);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_fg(accent).with_attr(Attributes::BOLD),
+            answered_prompt_prefix: Styled::new("✔").with_fg(Color::LightGreen),
+            prompt: StyleSheet::new().with_attr(Attributes::BOLD),
+            default_value: dimmed,
+            placeholder: dimmed.with_attr(Attributes::ITALIC),
+            ghost_text: dimmed,
+            help_message: StyleSheet::new()
+                .with_fg(Color::LightBlue)
+                .with_attr(Attributes::ITALIC),
+            text_input: StyleSheet::new().with_fg(Color::LightYellow),
+            answer: StyleSheet::new().with_fg(Color::LightGreen),
+            canceled_prompt_prefix: Some(Styled::new("✖").with_fg(Color::LightRed)),
+            error_prompt_prefix: Some(Styled::new("▲").with_fg(Color::LightYellow)),
+            canceled_prompt_indicator: Styled::new("canceled").with_fg(Color::DarkGrey),
+            error_message: ErrorMessageRenderConfig::default_colored()
+                .with_prefix(Styled::new("✖").with_fg(Color::LightRed)),
+            highlighted_option_prefix: Styled::new("❯").with_fg(accent),
+            scroll_up_prefix: Styled::new("↑").with_fg(Color::DarkGrey),
+            scroll_down_prefix: Styled::new("↓").with_fg(Color::DarkGrey),
+            selected_checkbox: Styled::new("◉").with_fg(Color::LightGreen),
+            unselected_checkbox: Styled::new("◯").with_fg(Color::DarkGrey),
+            selected_option: Some(
+                StyleSheet::new()
+                    .with_fg(accent)
+                    .with_attr(Attributes::BOLD),
+            ),
+            ..Self::default_colored()
+        }
+    }
+
+    /// Theme without colors, using bold and italic text to set elements
+    /// apart, for terminals or users that don't distinguish colors.
+    pub fn monochrome() -> Self {
+        let bold = StyleSheet::new().with_attr(Attributes::BOLD);
+        let italic = StyleSheet::new().with_attr(Attributes::ITALIC);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_attr(Attributes::BOLD),
+            answered_prompt_prefix: Styled::new(">").with_attr(Attributes::BOLD),
+            prompt: bold,
+            placeholder: italic,
+            ghost_text: italic,
+            help_message: italic,
+            answer: bold,
+            error_message: ErrorMessageRenderConfig::empty()
+                .with_prefix(Styled::new("!").with_attr(Attributes::BOLD))
+                .with_message(bold),
+            highlighted_option_prefix: Styled::new(">").with_attr(Attributes::BOLD),
+            selected_checkbox: Styled::new("[x]").with_attr(Attributes::BOLD),
+            selected_option: Some(bold),
+
+            #[cfg(feature = "date")]
+            calendar: calendar::CalendarRenderConfig {
+                selected_date: Some(bold),
+                today_date: italic,
+                ..calendar::CalendarRenderConfig::empty()
+            },
+
+            ..Self::empty()
+        }
+    }
+
+    /// Theme with bold, bright colors and no dimmed text, for readability
+    /// on low quality displays or for users with low vision.
+    pub fn high_contrast() -> Self {
+        let bright = StyleSheet::new().with_fg(Color::White);
+        let bold = |color| StyleSheet::new().with_fg(color).with_attr(Attributes::BOLD);
+        let highlighted = StyleSheet::new()
+            .with_fg(Color::Black)
+            .with_bg(Color::LightYellow);
+
+        Self {
+            prompt_prefix: Styled::new("?").with_style_sheet(bold(Color::LightYellow)),
+            answered_prompt_prefix: Styled::new(">").with_style_sheet(bold(Color::LightGreen)),
+            prompt: bold(Color::White),
+            default_value: StyleSheet::new().with_fg(Color::Grey),
+            placeholder: StyleSheet::new()
+                .with_fg(Color::Grey)
+                .with_attr(Attributes::ITALIC),
+            ghost_text: StyleSheet::new().with_fg(Color::Grey),
+            help_message: StyleSheet::new().with_fg(Color::LightCyan),
+            text_input: bright,
+            answer: bold(Color::LightYellow),
+            canceled_prompt_indicator: Styled::new("<canceled>")
+                .with_style_sheet(bold(Color::White).with_bg(Color::DarkRed)),
+            error_message: ErrorMessageRenderConfig::default_colored()
+                .with_prefix(
+                    Styled::new("!").with_style_sheet(bold(Color::White).with_bg(Color::DarkRed)),
+                )
+                .with_message(bold(Color::LightRed)),
+            highlighted_option_prefix: Styled::new(">").with_style_sheet(bold(Color::LightYellow)),
+            selected_checkbox: Styled::new("[x]").with_style_sheet(bold(Color::LightGreen)),
+            unselected_checkbox: Styled::new("[ ]").with_fg(Color::White),
+            option: bright,
+            selected_option: Some(highlighted),
+
+            #[cfg(feature = "date")]
+            calendar: calendar::CalendarRenderConfig {
+                prefix: Styled::new(">").with_style_sheet(bold(Color::LightYellow)),
+                header: bold(Color::White),
+                week_header: bright,
+                selected_date: Some(highlighted),
+                today_date: bold(Color::LightGreen),
+                different_month_date: StyleSheet::new().with_fg(Color::Grey),
+                week_number: StyleSheet::new().with_fg(Color::Grey),
+                unavailable_date: StyleSheet::new().with_fg(Color::Grey),
+                ..calendar::CalendarRenderConfig::default_colored()
+            },
+
+            #[cfg(feature = "editor")]
+            editor_prompt: StyleSheet::new().with_fg(Color::LightCyan),
+
+            ..Self::default_colored()
         }
     }
 
@@ -223,6 +465,25 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the prefix of canceled prompts, or None to use the prompt prefix.
+    pub fn with_canceled_prompt_prefix(
+        mut self,
+        canceled_prompt_prefix: Option<Styled<&'a str>>,
+    ) -> Self {
+        self.canceled_prompt_prefix = canceled_prompt_prefix;
+        self
+    }
+
+    /// Sets the prefix of prompts displaying an error message, or None to
+    /// use the prompt prefix.
+    pub fn with_error_prompt_prefix(
+        mut self,
+        error_prompt_prefix: Option<Styled<&'a str>>,
+    ) -> Self {
+        self.error_prompt_prefix = error_prompt_prefix;
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
@@ -235,6 +496,12 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet of inline suggestions (ghost text).
+    pub fn with_ghost_text(mut self, ghost_text: StyleSheet) -> Self {
+        self.ghost_text = ghost_text;
+        self
+    }
+
     /// Sets the style sheet for help messages.
     pub fn with_help_message(mut self, help_message: StyleSheet) -> Self {
         self.help_message = help_message;
@@ -304,6 +571,50 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet of the tags in which the answer of MultiSelect
+    /// prompts is rendered, or None to render the formatted answer.
+    pub fn with_answer_tag(mut self, answer_tag: Option<StyleSheet>) -> Self {
+        self.answer_tag = answer_tag;
+        self
+    }
+
+    /// Sets the trade-offs made when writing frames to the terminal.
+    pub fn with_render_profile(mut self, render_profile: RenderProfile) -> Self {
+        self.render_profile = render_profile;
+        self
+    }
+
+    /// Sets the number of lines reserved for the prompt below the cursor.
+    pub fn with_fixed_height(mut self, fixed_height: Option<u16>) -> Self {
+        self.fixed_height = fixed_height;
+        self
+    }
+
+    /// Sets the colors supported by the terminal, or None to detect them.
+    pub fn with_color_support(mut self, color_support: Option<ColorSupport>) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
+    /// Sets the width of characters of ambiguous width.
+    pub fn with_ambiguous_width(mut self, ambiguous_width: AmbiguousWidth) -> Self {
+        self.ambiguous_width = ambiguous_width;
+        self
+    }
+
+    /// Sets the base direction of the rendered text.
+    #[cfg(feature = "rtl")]
+    pub fn with_text_direction(mut self, text_direction: TextDirection) -> Self {
+        self.text_direction = text_direction;
+        self
+    }
+
+    /// Sets whether option icons are rendered with their ASCII fallbacks.
+    pub fn with_icon_fallback(mut self, icon_fallback: bool) -> Self {
+        self.icon_fallback = icon_fallback;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -313,6 +624,12 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets what is rendered in place of canceled prompts.
+    pub fn with_canceled_prompt(mut self, canceled_prompt: CanceledPrompt<'a>) -> Self {
+        self.canceled_prompt = canceled_prompt;
+        self
+    }
+
     #[cfg(feature = "date")]
     /// Sets the render configuration for calendars.
     pub fn with_calendar_config(mut self, calendar: calendar::CalendarRenderConfig<'a>) -> Self {
@@ -330,15 +647,276 @@ impl<'a> RenderConfig<'a> {
 
 impl<'a> Default for RenderConfig<'a> {
     fn default() -> Self {
-        match env::var("NO_COLOR") {
-            Ok(_) => Self::empty(),
-            Err(_) => Self::default_colored(),
+        match ColorSupport::detect() {
+            ColorSupport::None => Self::empty(),
+            _ => Self::default_colored(),
+        }
+    }
+}
+
+/// Type alias for the function used to render a prompt canceled by the user.
+///
+/// The function receives the prompt message and the text typed by the user
+/// until the prompt was canceled, when the prompt has a text input. The
+/// returned content is rendered as the whole final line of the prompt.
+///
+/// The function must be `Sync` so that render configurations can be set
+/// as the [global default](crate::set_global_render_config).
+pub type CanceledPromptFormatter<'a> = &'a (dyn Fn(&str, Option<&str>) -> Styled<String> + Sync);
+
+/// Definition of what is rendered in place of a prompt canceled by the user.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{CanceledPrompt, Color, RenderConfig, Styled};
+///
+/// let config = RenderConfig::default().with_canceled_prompt(CanceledPrompt::Custom(
+///     &|message, input| {
+///         let content = match input {
+///             Some(input) if !input.is_empty() => format!("{message} (discarded \"{input}\")"),
+///             _ => format!("{message} (skipped)"),
+///         };
+///         Styled::new(content).with_fg(Color::DarkGrey)
+///     },
+/// ));
+/// ```
+#[derive(Copy, Clone)]
+pub enum CanceledPrompt<'a> {
+    /// The prompt message is rendered followed by the
+    /// [`canceled_prompt_indicator`](crate::ui::RenderConfig::canceled_prompt_indicator).
+    Indicator,
+
+    /// Nothing is rendered, the prompt is erased from the terminal.
+    Hidden,
+
+    /// The output of the formatter is rendered.
+    Custom(CanceledPromptFormatter<'a>),
+}
+
+impl<'a> Debug for CanceledPrompt<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Indicator => f.write_str("Indicator"),
+            Self::Hidden => f.write_str("Hidden"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Definition of what is left on the screen once a prompt is submitted.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::RenderAfterSubmit, Select};
+///
+/// // the menu is erased once an option is picked
+/// let action = Select::new("What next?", vec!["Build", "Test", "Deploy"])
+///     .with_render_after_submit(RenderAfterSubmit::Clear)
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RenderAfterSubmit {
+    /// Nothing is left, the prompt is erased from the terminal.
+    Clear,
+
+    /// The prompt message is rendered followed by the formatted answer, in
+    /// a single line.
+    #[default]
+    AnswerLine,
+
+    /// The last frame rendered before the submission is left as it is,
+    /// e.g. with the options and help message of a select prompt.
+    Full,
+}
+
+/// Replacement of the answer rendered next to the prompt message once the
+/// prompt is submitted, for answers that shouldn't be left on the screen.
+///
+/// The answer returned by the prompt is not affected. The replacement is
+/// also recorded in [transcripts](crate::transcript), where hidden answers
+/// are recorded as empty.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::AnswerRedaction, Text};
+///
+/// // renders "> Account ID: [redacted]" once answered
+/// let account_id = Text::new("Account ID:")
+///     .with_answer_redaction(AnswerRedaction::Placeholder("[redacted]"))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnswerRedaction<'a> {
+    /// Only the prompt message is rendered.
+    Hidden,
+
+    /// The placeholder is rendered instead of the answer, with the style of
+    /// answers.
+    Placeholder(&'a str),
+}
+
+/// Trade-offs made when writing rendered frames to the terminal.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{RenderConfig, RenderProfile};
+///
+/// let over_ssh = std::env::var_os("SSH_CONNECTION").is_some();
+/// let profile = match over_ssh {
+///     true => RenderProfile::RemoteOptimized,
+///     false => RenderProfile::Standard,
+/// };
+///
+/// let config = RenderConfig::default().with_render_profile(profile);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum RenderProfile {
+    /// The cursor is hidden while each frame is drawn, and escape sequences
+    /// are written to the terminal as they are produced.
+    #[default]
+    Standard,
+
+    /// Minimizes what is sent to the terminal, improving responsiveness over
+    /// high-latency links such as SSH sessions.
+    ///
+    /// Each frame is buffered and written at once, instead of in many small
+    /// writes that might each end up in a separate packet. The cursor is not
+    /// hidden and shown around each frame, and frames identical to the last
+    /// one are not written at all. The crossterm and termion backends support
+    /// buffering; with the console backend, only the other savings apply.
+    RemoteOptimized,
+}
+
+/// Base direction of the text rendered by prompts.
+///
+/// Most terminals display characters in the order in which they are
+/// written, which scrambles right-to-left scripts such as Arabic and Hebrew.
+/// With a right-to-left direction, each line is reordered as described by
+/// the Unicode Bidirectional Algorithm before being written, and aligned to
+/// the right edge of the terminal, so that the prompt prefix and the rest of
+/// the prompt are mirrored. Left-to-right text inside of the line, such as
+/// numbers, keeps its order. The cursor is placed next to the last character
+/// typed, while the arrow keys keep moving it in the order the text is
+/// written.
+///
+/// Lines containing ANSI escape sequences are not reordered, and neither are
+/// prompts rendered in a viewport.
+///
+/// Do not enable it on terminals that reorder text themselves, as the text
+/// would be reordered twice.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{ui::{RenderConfig, TextDirection}, Text};
+///
+/// let name = Text::new("שם:")
+///     .with_render_config(RenderConfig::default().with_text_direction(TextDirection::Auto))
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[cfg(feature = "rtl")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum TextDirection {
+    /// Lines are written as they are, left to right.
+    #[default]
+    Ltr,
+
+    /// Lines are laid out right to left.
+    Rtl,
+
+    /// The direction of each line is detected from its first character with
+    /// a strong direction, such as a letter, so that e.g. lines with Hebrew
+    /// options are laid out right to left while an English help message is
+    /// laid out left to right.
+    Auto,
+}
+
+/// Number of columns taken by characters of ambiguous width in the terminal.
+///
+/// Unicode leaves the width of some characters, such as `±`, `①` or `○`, up
+/// to the context: terminals configured for Chinese, Japanese or Korean
+/// usually render them over two columns, like CJK ideographs, while others
+/// render them over one. When this doesn't match the terminal, lines
+/// containing such characters wrap at the wrong point and the cursor drifts
+/// from where it should be.
+///
+/// CJK ideographs, Hangul and other wide characters always take two columns,
+/// regardless of this setting.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{AmbiguousWidth, RenderConfig};
+///
+/// let config = RenderConfig::default().with_ambiguous_width(AmbiguousWidth::from_locale());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AmbiguousWidth {
+    /// Characters of ambiguous width take one column.
+    #[default]
+    Narrow,
+
+    /// Characters of ambiguous width take two columns, as in East Asian
+    /// contexts.
+    Wide,
+}
+
+impl AmbiguousWidth {
+    /// Guesses the width from the locale set in the `LC_ALL`, `LC_CTYPE` or
+    /// `LANG` environment variables, in this order of precedence: `Wide` for
+    /// Chinese, Japanese and Korean locales, `Narrow` otherwise.
+    pub fn from_locale() -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+
+        Self::from_locale_name(&locale)
+    }
+
+    fn from_locale_name(locale: &str) -> Self {
+        let language = locale
+            .split(['_', '.', '@', '-'])
+            .next()
+            .unwrap_or_default();
+
+        match language {
+            "zh" | "ja" | "ko" => Self::Wide,
+            _ => Self::Narrow,
         }
     }
 }
 
 /// Definition of index prefixes in option lists.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum IndexPrefix {
     /// Lists of options will not display any hints regarding
     /// the position/index of the positions.
@@ -466,10 +1044,18 @@ pub mod calendar {
         /// Style sheet for today's date, just for hinting purposes.
         pub today_date: StyleSheet,
 
+        /// Style sheet for the dates checked so far when selecting multiple
+        /// dates. See [`DateSelect::prompt_multiple`](crate::DateSelect::prompt_multiple).
+        pub checked_date: StyleSheet,
+
         /// Style sheet for dates that are from the previous or next month
         /// displayed in the calendar.
         pub different_month_date: StyleSheet,
 
+        /// Style sheet for the ISO week numbers displayed to the left of the
+        /// calendar. See [`DateSelect::with_week_numbers`](crate::DateSelect::with_week_numbers).
+        pub week_number: StyleSheet,
+
         /// Style sheet for dates that can not be selected due to the
         /// min/max settings.
         pub unavailable_date: StyleSheet,
@@ -484,7 +1070,9 @@ pub mod calendar {
                 week_header: StyleSheet::empty(),
                 selected_date: None,
                 today_date: StyleSheet::empty(),
+                checked_date: StyleSheet::empty(),
                 different_month_date: StyleSheet::empty(),
+                week_number: StyleSheet::empty(),
                 unavailable_date: StyleSheet::empty(),
             }
         }
@@ -501,7 +1089,11 @@ pub mod calendar {
                         .with_bg(Color::Grey),
                 ),
                 today_date: StyleSheet::empty().with_fg(Color::LightGreen),
+                checked_date: StyleSheet::empty()
+                    .with_fg(Color::Black)
+                    .with_bg(Color::LightCyan),
                 different_month_date: StyleSheet::empty().with_fg(Color::DarkGrey),
+                week_number: StyleSheet::empty().with_fg(Color::DarkGrey),
                 unavailable_date: StyleSheet::empty().with_fg(Color::DarkGrey),
             }
         }
@@ -513,3 +1105,50 @@ pub mod calendar {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{AmbiguousWidth, RenderConfig, StyleSheet};
+
+    #[test]
+    fn ambiguous_width_is_wide_for_cjk_locales() {
+        for locale in ["zh_CN.UTF-8", "ja_JP.UTF-8", "ko_KR", "zh-Hant"] {
+            assert_eq!(
+                AmbiguousWidth::Wide,
+                AmbiguousWidth::from_locale_name(locale)
+            );
+        }
+
+        for locale in ["en_US.UTF-8", "C", "POSIX", "", "kok_IN"] {
+            assert_eq!(
+                AmbiguousWidth::Narrow,
+                AmbiguousWidth::from_locale_name(locale)
+            );
+        }
+    }
+
+    #[test]
+    fn monochrome_theme_has_no_colors() {
+        let config = RenderConfig::monochrome();
+
+        let style_sheets = [
+            config.prompt_prefix.style,
+            config.answered_prompt_prefix.style,
+            config.prompt,
+            config.placeholder,
+            config.help_message,
+            config.answer,
+            config.error_message.prefix.style,
+            config.error_message.message,
+            config.highlighted_option_prefix.style,
+            config.selected_checkbox.style,
+            config.selected_option.unwrap_or_default(),
+        ];
+
+        for style_sheet in style_sheets {
+            assert_eq!(None, style_sheet.fg);
+            assert_eq!(None, style_sheet.bg);
+        }
+        assert_ne!(StyleSheet::empty(), config.answer);
+    }
+}
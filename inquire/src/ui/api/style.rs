@@ -31,6 +31,44 @@ bitflags! {
     }
 }
 
+/// Attributes are serialized as a list of their names in lowercase, e.g.
+/// `["bold", "italic"]`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Attributes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let names: Vec<String> = self
+            .iter_names()
+            .map(|(name, _)| name.to_lowercase())
+            .collect();
+        names.serialize(serializer)
+    }
+}
+
+/// Attributes are deserialized from a list of their names, matched ignoring
+/// case.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Attributes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let names = Vec::<String>::deserialize(deserializer)?;
+
+        names.iter().try_fold(
+            Attributes::empty(),
+            |attributes, name| match Attributes::from_name(&name.to_uppercase()) {
+                Some(attribute) => Ok(attributes | attribute),
+                None => Err(serde::de::Error::unknown_variant(name, &["bold", "italic"])),
+            },
+        )
+    }
+}
+
 /// Style definitions that can be applied to the rendered content.
 ///
 /// # Example
@@ -49,12 +87,18 @@ bitflags! {
 /// assert!(!style_sheet.is_empty());
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct StyleSheet {
     /// Foreground color of text.
     pub fg: Option<Color>,
     /// Background color of text.
     pub bg: Option<Color>,
     /// Attributes applied to text.
+    #[cfg_attr(feature = "serde", serde(rename = "attributes"))]
     pub att: Attributes,
 }
 
@@ -192,6 +236,17 @@ where
             style: self.style,
         }
     }
+
+    /// Borrows the content as a string slice, keeping the style sheet.
+    pub fn as_str(&self) -> Styled<&str>
+    where
+        T: AsRef<str>,
+    {
+        Styled {
+            content: self.content.as_ref(),
+            style: self.style,
+        }
+    }
 }
 
 impl<T> Copy for Styled<T> where T: Copy + Display {}
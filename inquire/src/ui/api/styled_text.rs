@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use super::{StyleSheet, Styled};
+
+/// Text made of fragments with their own styles, used to emphasize parts of
+/// prompt messages, help messages or answers, e.g. a file name in a question.
+///
+/// Each fragment is rendered with its style applied on top of the style the
+/// whole text would have otherwise, e.g. the prompt style of the
+/// [RenderConfig](crate::ui::RenderConfig) for prompt messages: colors of the
+/// fragment replace the base ones and attributes are added to them.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{
+///     ui::{Attributes, Color, Styled, StyledText},
+///     Confirm,
+/// };
+///
+/// let message = StyledText::new()
+///     .with_text("Delete ")
+///     .with_styled(Styled::new("report.pdf").with_attr(Attributes::BOLD))
+///     .with_text("?");
+/// let help_message = StyledText::new()
+///     .with_text("This action ")
+///     .with_styled(Styled::new("can't be undone").with_fg(Color::LightRed));
+///
+/// let delete = Confirm::new("")
+///     .with_styled_message(&message)
+///     .with_styled_help_message(&help_message)
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyledText {
+    content: String,
+    /// End of each fragment in the content, along with its style.
+    fragments: Vec<(usize, StyleSheet)>,
+}
+
+impl StyledText {
+    /// Creates an empty text, to which fragments are appended.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a fragment rendered with the base style.
+    pub fn with_text(self, text: &str) -> Self {
+        self.with_styled(Styled::new(text))
+    }
+
+    /// Appends a fragment rendered with its style applied on top of the base
+    /// one.
+    pub fn with_styled(mut self, fragment: Styled<&str>) -> Self {
+        self.content.push_str(fragment.content);
+        self.fragments.push((self.content.len(), fragment.style));
+        self
+    }
+
+    /// Content of all fragments, without styles.
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+
+    /// Iterates over the fragments of the text, with their style applied on
+    /// top of the base one.
+    pub(crate) fn fragments(&self, base: StyleSheet) -> impl Iterator<Item = Styled<&str>> {
+        let starts = std::iter::once(0).chain(self.fragments.iter().map(|(end, _)| *end));
+
+        starts
+            .zip(self.fragments.iter())
+            .filter_map(move |(start, (end, style))| {
+                let content = self.content.get(start..*end)?;
+                Some(Styled::new(content).with_style_sheet(StyleSheet {
+                    fg: style.fg.or(base.fg),
+                    bg: style.bg.or(base.bg),
+                    att: base.att | style.att,
+                }))
+            })
+    }
+}
+
+impl Display for StyledText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.content)
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(text: &str) -> Self {
+        Self::new().with_text(text)
+    }
+}
+
+impl From<Styled<&str>> for StyledText {
+    fn from(fragment: Styled<&str>) -> Self {
+        Self::new().with_styled(fragment)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ui::{Attributes, Color, StyleSheet, Styled};
+
+    use super::StyledText;
+
+    #[test]
+    fn fragments_are_styled_on_top_of_the_base_style() {
+        let text = StyledText::new()
+            .with_text("Delete ")
+            .with_styled(Styled::new("report.pdf").with_attr(Attributes::BOLD))
+            .with_styled(Styled::new("?").with_fg(Color::LightRed));
+        let base = StyleSheet::new()
+            .with_fg(Color::DarkCyan)
+            .with_attr(Attributes::ITALIC);
+
+        let fragments: Vec<_> = text
+            .fragments(base)
+            .map(|fragment| (fragment.content, fragment.style))
+            .collect();
+
+        assert_eq!("Delete report.pdf?", text.as_str());
+        assert_eq!(
+            vec![
+                ("Delete ", base),
+                (
+                    "report.pdf",
+                    base.with_attr(Attributes::ITALIC | Attributes::BOLD)
+                ),
+                ("?", base.with_fg(Color::LightRed)),
+            ],
+            fragments
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/// Rectangular region of the terminal screen in which a prompt is rendered.
+///
+/// By default, prompts are rendered inline, starting at the current cursor
+/// position and spanning the whole terminal width. When a viewport is set,
+/// the prompt is instead drawn inside the given rectangle, using absolute
+/// cursor positioning, and never writes outside of it.
+///
+/// This is useful for applications that manage their own screen layout,
+/// such as those with status bars or split panes, and want to embed a prompt
+/// in a specific area of the screen.
+///
+/// Rows and columns are 0-based. If the region extends beyond the terminal
+/// bounds, it is clipped to the visible area.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::Viewport;
+///
+/// // 40 columns wide and 10 rows tall, starting at the second row.
+/// let viewport = Viewport::new(1, 0, 40, 10);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Viewport {
+    /// Row of the top-left corner of the region.
+    pub row: u16,
+
+    /// Column of the top-left corner of the region.
+    pub col: u16,
+
+    /// Width of the region, in columns.
+    pub width: u16,
+
+    /// Height of the region, in rows.
+    pub height: u16,
+}
+
+impl Viewport {
+    /// Creates a viewport with its top-left corner at the provided
+    /// row and column, and with the provided dimensions.
+    pub fn new(row: u16, col: u16, width: u16, height: u16) -> Self {
+        Self {
+            row,
+            col,
+            width,
+            height,
+        }
+    }
+}
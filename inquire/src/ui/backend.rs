@@ -1,36 +1,113 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{
+    collections::BTreeSet,
+    convert::TryFrom,
+    fmt::Display,
+    io::Result,
+    time::{Duration, Instant},
+};
 
-use unicode_width::UnicodeWidthStr;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
+    cancellation::CancellationToken,
+    config::{get_non_interactive_behavior, get_output_stream},
     error::InquireResult,
+    event::OnChange,
+    formatter::StyledAnswerFormatter,
     input::Input,
     list_option::ListOption,
-    terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
-    utils::{int_log10, Page},
+    prompts::{InputCounter, PromptSettings},
+    terminal::{
+        get_default_terminal, get_prompt_terminal, DefaultInputReader, DefaultTerminal,
+        PromptInputReader, PromptTerminal, Terminal,
+    },
+    ui::{
+        AmbiguousWidth, AnswerRedaction, CanceledPrompt, ColorSupport, IndexPrefix, Key,
+        RenderAfterSubmit, RenderConfig, StyleSheet, Styled, StyledText, Viewport,
+    },
+    utils::{int_log10, text_width, Page},
     validator::ErrorMessage,
 };
 
-use super::{frame_renderer::FrameRenderer, InputReader};
-
+use super::{frame_renderer::FrameRenderer, InputReader, PresetAnswer};
+
+/// Rendering and input operations shared by all prompt backends.
+///
+/// Prompts render their UI in frames: each frame is built from scratch between
+/// calls to [`frame_setup`](CommonBackend::frame_setup) and
+/// [`frame_finish`](CommonBackend::frame_finish), and only the lines that changed
+/// since the last frame are written to the terminal.
+///
+/// Styles used by the rendering methods come from the
+/// [`RenderConfig`](crate::ui::RenderConfig) the backend was created with.
 pub trait CommonBackend: InputReader {
+    /// Starts building a new frame.
     fn frame_setup(&mut self) -> Result<()>;
+    /// Flushes the current frame to the terminal. The last frame of a prompt
+    /// should set `is_last_frame`, so that a line break is added after it.
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
     /// Abort the current frame without flushing it to the terminal.
     fn frame_abort(&mut self) -> Result<()>;
 
-    /// Height (rows) of the currently built frame, if any.
     /// Height (rows) of the terminal size used for the current frame, if any.
     fn current_terminal_height(&self) -> Option<u16>;
     /// Height (rows) that would be flushed if the current frame were finished now.
     /// This is the max between the last rendered and current frames.
     fn current_flush_height(&self) -> Option<u16>;
 
-    fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()>;
+    /// Token to cancel the prompt from another thread or task, if any.
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        None
+    }
+
+    /// What is left on the screen once the prompt is submitted.
+    fn render_after_submit(&self) -> RenderAfterSubmit {
+        RenderAfterSubmit::AnswerLine
+    }
+
+    /// Hook called with the changes made by the user to the prompt, if any.
+    fn on_change(&self) -> Option<OnChange<'_>> {
+        None
+    }
+
+    /// Replacement of the answer once the prompt is submitted, if any.
+    fn answer_redaction(&self) -> Option<AnswerRedaction<'_>> {
+        None
+    }
+
+    /// Error message displayed above the prompt while it has none of its
+    /// own, e.g. the one of a [Form](crate::Form) validator rejecting the
+    /// answer of the prompt, which is asked again.
+    fn initial_error(&self) -> Option<&ErrorMessage> {
+        None
+    }
+
+    /// When the user asked to cancel the prompt, if the request is held
+    /// back by the [cancel guard](crate::key_bindings::CancelGuard) of the
+    /// prompt until they confirm it.
+    fn pending_cancel(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Records a request to cancel the prompt held back by the cancel guard,
+    /// or clears it with `None`.
+    fn set_pending_cancel(&mut self, _requested_at: Option<Instant>) {}
+
+    /// Renders the prompt prefix followed by the prompt message. Content
+    /// rendered afterwards is placed in the same line.
+    fn render_prompt_message(&mut self, prompt: &str) -> Result<()>;
+    /// Renders arbitrary styled text. Line breaks in the content are respected.
+    fn render_text(&mut self, text: Styled<&str>) -> Result<()>;
+
+    /// Renders the final state of a prompt canceled by the user, along with
+    /// the text typed so far, if the prompt has a text input.
+    fn render_canceled_prompt(&mut self, prompt: &str, partial_input: Option<&str>) -> Result<()>;
+    /// Renders the final state of a prompt, with the formatted answer.
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
+    /// Renders a validation error message, in its own line.
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
+    /// Renders a help message, in its own line.
     fn render_help_message(&mut self, help: &str) -> Result<()>;
 }
 
@@ -40,8 +117,18 @@ pub trait TextBackend: CommonBackend {
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        counter: Option<&InputCounter>,
+    ) -> Result<()>;
+    fn render_prompt_with_ghost_text(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        ghost_text: &str,
+        counter: Option<&InputCounter>,
     ) -> Result<()>;
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_suggestions_loading(&mut self, indicator: &str) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
@@ -52,6 +139,11 @@ pub trait EditorBackend: CommonBackend {
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
     fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_options_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        columns: usize,
+    ) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
@@ -61,6 +153,21 @@ pub trait MultiSelectBackend: CommonBackend {
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
     ) -> Result<()>;
+    fn render_options_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+        columns: usize,
+    ) -> Result<()>;
+    /// Renders the final state of the prompt with the selected options as
+    /// tags, when enabled in the render config. Otherwise, renders the
+    /// formatted answer.
+    fn render_prompt_with_answer_tags(
+        &mut self,
+        prompt: &str,
+        answer: &str,
+        tags: &[String],
+    ) -> Result<()>;
 }
 
 pub trait CustomTypeBackend: CommonBackend {
@@ -78,7 +185,105 @@ pub trait PasswordBackend: CommonBackend {
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+pub trait TypedConfirmBackend: CommonBackend {
+    /// Renders the input typed so far, highlighting the characters that
+    /// don't match the required phrase.
+    fn render_prompt_with_phrase(
+        &mut self,
+        prompt: &str,
+        phrase: &str,
+        cur_input: &Input,
+    ) -> Result<()>;
+}
+
+/// Backend used by prompts to render to the default terminal, which is
+/// chosen according to the enabled features.
+pub type DefaultBackend<'a> = Backend<'a, DefaultInputReader, DefaultTerminal>;
+
+/// Creates a backend that renders to the default terminal using the provided
+/// render config.
+///
+/// This is the same backend used by the prompts in this crate, and can be used to
+/// run user-defined prompts through [`Prompt::prompt`](crate::Prompt::prompt).
+pub fn default_backend(render_config: RenderConfig<'_>) -> InquireResult<DefaultBackend<'_>> {
+    let (input_reader, terminal) = get_default_terminal(get_output_stream())?;
+    let backend = Backend::new(input_reader, terminal, render_config)?;
+    Ok(backend)
+}
+
+/// Backend used by the prompts of this crate, which renders to the default
+/// terminal or answers prompts without user interaction when it is not a TTY.
+pub(crate) type PromptBackend<'a> = Backend<'a, PromptInputReader, PromptTerminal>;
+
+/// Creates the backend of a prompt, falling back to the global
+/// non-interactive behavior and output stream when the prompt doesn't set
+/// them.
+#[allow(clippy::large_types_passed_by_value)]
+pub(crate) fn prompt_backend<'a>(
+    render_config: RenderConfig<'a>,
+    settings: PromptSettings<'a>,
+) -> InquireResult<PromptBackend<'a>> {
+    let behavior = settings
+        .non_interactive_behavior
+        .unwrap_or_else(get_non_interactive_behavior);
+    let output_stream = settings.output_stream.unwrap_or_else(get_output_stream);
+    let (input_reader, terminal) = get_prompt_terminal(
+        behavior,
+        settings.env_override,
+        settings.backend.clone(),
+        output_stream,
+    )?;
+    let mut backend = Backend::new(input_reader, terminal, render_config)?;
+    backend.set_fullscreen(settings.fullscreen);
+    backend.apply_settings(settings);
+    Ok(backend)
+}
+
+/// Creates the backend of a prompt awaiting the keys pressed by the user,
+/// which are read from crossterm's event stream.
+///
+/// Fails when the prompt renders to a custom backend, whose input reader can
+/// only be read by blocking the thread, instead of reading the keys from the
+/// standard input while rendering elsewhere.
+#[cfg(feature = "async")]
+#[allow(clippy::large_types_passed_by_value)]
+pub(crate) fn async_prompt_backend<'a>(
+    render_config: RenderConfig<'a>,
+    settings: PromptSettings<'a>,
+) -> InquireResult<PromptBackend<'a>> {
+    if settings.backend.is_some() || crate::config::get_global_backend().is_some() {
+        return Err(crate::error::InquireError::InvalidConfiguration(
+            "prompts rendered to a custom backend can't be awaited, use prompt() instead".into(),
+        ));
+    }
+    prompt_backend(render_config, settings)
+}
+
+/// Styled versions of the texts of a prompt, rendered instead of the plain
+/// ones.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct StyledTexts<'a> {
+    pub message: Option<&'a StyledText>,
+    pub help_message: Option<&'a StyledText>,
+    pub answer: Option<StyledAnswerFormatter<'a>>,
+}
+
+/// Amount of spaces between the widest option of a column and the next column
+/// when rendering options in a grid.
+pub(crate) const GRID_COLUMN_SPACING: usize = 2;
+
+fn grid_column_width<D: Display>(
+    page: &Page<'_, ListOption<D>>,
+    ambiguous_width: AmbiguousWidth,
+) -> usize {
+    page.content
+        .iter()
+        .map(|option| text_width(&option.value.to_string(), ambiguous_width))
+        .max()
+        .unwrap_or(0)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Position {
     pub row: u16,
     pub col: u16,
@@ -92,6 +297,15 @@ where
     frame_renderer: FrameRenderer<T>,
     input_reader: I,
     render_config: RenderConfig<'a>,
+    cancellation: Option<CancellationToken>,
+    render_after_submit: RenderAfterSubmit,
+    answer_redaction: Option<AnswerRedaction<'a>>,
+    styled_texts: StyledTexts<'a>,
+    on_change: Option<OnChange<'a>>,
+    initial_error: Option<ErrorMessage>,
+    pending_cancel: Option<Instant>,
+    /// whether an error message was rendered in the current frame
+    error_rendered: bool,
 }
 
 impl<'a, I, T> Backend<'a, I, T>
@@ -101,15 +315,80 @@ where
 {
     #[allow(clippy::large_types_passed_by_value)]
     pub fn new(input_reader: I, terminal: T, render_config: RenderConfig<'a>) -> Result<Self> {
-        let backend = Self {
+        let mut backend = Self {
             frame_renderer: FrameRenderer::new(terminal)?,
             input_reader,
             render_config,
+            cancellation: None,
+            render_after_submit: RenderAfterSubmit::AnswerLine,
+            answer_redaction: None,
+            styled_texts: StyledTexts::default(),
+            on_change: None,
+            initial_error: None,
+            pending_cancel: None,
+            error_rendered: false,
         };
+        backend.set_render_config(render_config);
 
         Ok(backend)
     }
 
+    /// Sets the render config of the prompts rendered by this backend.
+    #[allow(clippy::large_types_passed_by_value)]
+    pub fn set_render_config(&mut self, render_config: RenderConfig<'a>) {
+        self.frame_renderer
+            .set_render_profile(render_config.render_profile);
+        self.frame_renderer
+            .set_fixed_height(render_config.fixed_height);
+        self.frame_renderer.set_color_support(
+            render_config
+                .color_support
+                .unwrap_or_else(ColorSupport::detect),
+        );
+        self.frame_renderer
+            .set_ambiguous_width(render_config.ambiguous_width);
+        #[cfg(feature = "rtl")]
+        self.frame_renderer
+            .set_text_direction(render_config.text_direction);
+        self.render_config = render_config;
+    }
+
+    pub fn set_viewport(&mut self, viewport: Option<Viewport>) {
+        self.frame_renderer.set_viewport(viewport);
+    }
+
+    /// Sets whether prompts are rendered in the alternate screen of the
+    /// terminal, leaving it to render their final answer.
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.frame_renderer.set_fullscreen(fullscreen);
+    }
+
+    /// Sets what is left on the screen once prompts are submitted.
+    pub fn set_render_after_submit(&mut self, render_after_submit: RenderAfterSubmit) {
+        self.render_after_submit = render_after_submit;
+    }
+
+    /// Applies the settings of the prompt about to run that don't depend on
+    /// the terminal it renders to.
+    pub(crate) fn apply_settings(&mut self, settings: PromptSettings<'a>) {
+        self.cancellation = settings.cancellation;
+        self.render_after_submit = settings.render_after_submit;
+        self.answer_redaction = settings.answer_redaction;
+        self.styled_texts = StyledTexts {
+            message: settings.styled_message,
+            help_message: settings.styled_help_message,
+            answer: settings.styled_answer_formatter,
+        };
+        self.on_change = settings.on_change;
+        self.initial_error = settings.initial_error;
+    }
+
+    /// Sets the token to cancel prompts rendered by this backend from another
+    /// thread or task.
+    pub fn set_cancellation(&mut self, cancellation: Option<CancellationToken>) {
+        self.cancellation = cancellation;
+    }
+
     fn print_option_prefix<D: Display>(
         &mut self,
         option_relative_index: usize,
@@ -149,6 +428,96 @@ where
             .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
     }
 
+    fn print_option_checkbox<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+    ) -> Result<()> {
+        let mut checkbox = match checked.contains(&option.index) {
+            true => self.render_config.selected_checkbox,
+            false => self.render_config.unselected_checkbox,
+        };
+
+        match (self.render_config.selected_option, page.cursor) {
+            (Some(stylesheet), Some(cursor)) if cursor == option_relative_index => {
+                checkbox.style = stylesheet;
+            }
+            _ => {}
+        }
+
+        self.frame_renderer.write_styled(checkbox)
+    }
+
+    /// Finishes a cell of an option grid, either by breaking the line at the
+    /// end of a row or by padding the option value up to the column width.
+    fn finish_grid_cell<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+        columns: usize,
+        column_width: usize,
+    ) -> Result<()> {
+        let next_index = option_relative_index.saturating_add(1);
+
+        if next_index % columns == 0 || next_index == page.content.len() {
+            return self.new_line();
+        }
+
+        let value_width = self.text_width(&option.value.to_string());
+        let padding = column_width.saturating_sub(value_width) + GRID_COLUMN_SPACING;
+        self.frame_renderer.write(" ".repeat(padding))
+    }
+
+    fn print_option_icon<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Result<()> {
+        let use_fallback = self.render_config.icon_fallback;
+        let icon = match page.icons.get(option_relative_index) {
+            Some(icon) => icon.content(use_fallback),
+            None => return Ok(()),
+        };
+
+        let column_width = page
+            .icons
+            .iter()
+            .map(|icon| self.text_width(icon.content(use_fallback)))
+            .max()
+            .unwrap_or(0);
+        let padding = column_width.saturating_sub(self.text_width(icon)) + 1;
+
+        self.frame_renderer
+            .write_styled(Styled::new(icon).with_style_sheet(self.render_config.option))?;
+        self.frame_renderer.write(" ".repeat(padding))
+    }
+
+    fn print_option_custom_prefix<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Result<()> {
+        let prefix = match page.prefixes.get(option_relative_index) {
+            Some(prefix) => prefix,
+            None => return Ok(()),
+        };
+
+        let column_width = page
+            .prefixes
+            .iter()
+            .map(|prefix| self.text_width(prefix))
+            .max()
+            .unwrap_or(0);
+        let padding = column_width.saturating_sub(self.text_width(prefix)) + 1;
+
+        self.frame_renderer
+            .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))?;
+        self.frame_renderer.write(" ".repeat(padding))
+    }
+
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
         let index = index.saturating_add(1);
 
@@ -178,22 +547,57 @@ where
         self.frame_renderer.write_styled(token)
     }
 
+    /// Number of terminal columns taken by a text.
+    fn text_width(&self, text: &str) -> usize {
+        text_width(text, self.render_config.ambiguous_width)
+    }
+
     fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
         self.frame_renderer.write_styled(prefix)?;
 
         self.frame_renderer.write(" ")?;
 
         if !prompt.is_empty() {
+            // lines of multi-line or wrapped messages are aligned with the first one
+            let indent = text_width(prefix.content, self.render_config.ambiguous_width) + 1;
             self.frame_renderer
-                .write_styled(Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+                .set_indent(u16::try_from(indent).unwrap_or(u16::MAX));
+            self.write_text(prompt, self.styled_texts.message, self.render_config.prompt)?;
+            self.frame_renderer.set_indent(0);
             self.frame_renderer.write(" ")?;
         }
 
         Ok(())
     }
 
+    /// Writes the text with the given style, or the fragments of its styled
+    /// version instead, if they have the same content.
+    fn write_text(
+        &mut self,
+        text: &str,
+        styled: Option<&StyledText>,
+        style: StyleSheet,
+    ) -> Result<()> {
+        match styled {
+            Some(styled) if styled.as_str() == text => {
+                for fragment in styled.fragments(style) {
+                    self.frame_renderer.write_styled(fragment)?;
+                }
+                Ok(())
+            }
+            _ => self
+                .frame_renderer
+                .write_styled(Styled::new(text).with_style_sheet(style)),
+        }
+    }
+
     fn print_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+        let prefix = match self.render_config.error_prompt_prefix {
+            Some(prefix) if self.error_rendered => prefix,
+            _ => self.render_config.prompt_prefix,
+        };
+
+        self.print_prompt_with_prefix(prefix, prompt)
     }
 
     fn print_input(&mut self, input: &Input) -> Result<()> {
@@ -202,7 +606,9 @@ where
         // (based on the underlying input struct), as it's a simple
         // cur_pos + offset calculation.
         self.frame_renderer
-            .mark_cursor_position(input.pre_cursor().width() as isize);
+            .mark_cursor_position(
+                text_width(input.pre_cursor(), self.render_config.ambiguous_width) as isize,
+            );
 
         if input.is_empty() {
             match input.placeholder() {
@@ -227,6 +633,16 @@ where
         Ok(())
     }
 
+    fn print_input_counter(&mut self, counter: &InputCounter, input: &Input) -> Result<()> {
+        let style = match counter.is_exceeded(input.content()) {
+            true => self.render_config.error_message.message,
+            false => self.render_config.help_message,
+        };
+        let token = Styled::new(counter.format(input.content())).with_style_sheet(style);
+
+        self.frame_renderer.write_styled(token)
+    }
+
     fn print_prompt_with_input(
         &mut self,
         prompt: &str,
@@ -259,10 +675,42 @@ where
     T: Terminal,
 {
     fn frame_setup(&mut self) -> Result<()> {
+        self.error_rendered = false;
         self.frame_renderer.start_frame()
     }
 
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    fn render_after_submit(&self) -> RenderAfterSubmit {
+        self.render_after_submit
+    }
+
+    fn on_change(&self) -> Option<OnChange<'_>> {
+        self.on_change
+    }
+
+    fn answer_redaction(&self) -> Option<AnswerRedaction<'_>> {
+        self.answer_redaction
+    }
+
+    fn initial_error(&self) -> Option<&ErrorMessage> {
+        self.initial_error.as_ref()
+    }
+
+    fn pending_cancel(&self) -> Option<Instant> {
+        self.pending_cancel
+    }
+
+    fn set_pending_cancel(&mut self, requested_at: Option<Instant>) {
+        self.pending_cancel = requested_at;
+    }
+
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()> {
+        #[cfg(feature = "instrument")]
+        let _span = crate::instrument::span(crate::instrument::Phase::Flush);
+
         self.frame_renderer.finish_current_frame(is_last_frame)
     }
 
@@ -278,11 +726,32 @@ where
         self.frame_renderer.current_flush_height()
     }
 
-    fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt(prompt)?;
+    fn render_prompt_message(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)
+    }
 
-        self.frame_renderer
-            .write_styled(self.render_config.canceled_prompt_indicator)?;
+    fn render_text(&mut self, text: Styled<&str>) -> Result<()> {
+        self.frame_renderer.write_styled(text)
+    }
+
+    fn render_canceled_prompt(&mut self, prompt: &str, partial_input: Option<&str>) -> Result<()> {
+        match self.render_config.canceled_prompt {
+            CanceledPrompt::Indicator => {
+                let prefix = self
+                    .render_config
+                    .canceled_prompt_prefix
+                    .unwrap_or(self.render_config.prompt_prefix);
+                self.print_prompt_with_prefix(prefix, prompt)?;
+
+                self.frame_renderer
+                    .write_styled(self.render_config.canceled_prompt_indicator)?;
+            }
+            CanceledPrompt::Hidden => return Ok(()),
+            CanceledPrompt::Custom(formatter) => {
+                self.frame_renderer
+                    .write_styled(formatter(prompt, partial_input))?;
+            }
+        }
 
         self.new_line()?;
 
@@ -292,8 +761,20 @@ where
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()> {
         self.print_prompt_with_prefix(self.render_config.answered_prompt_prefix, prompt)?;
 
-        let token = Styled::new(answer).with_style_sheet(self.render_config.answer);
-        self.frame_renderer.write_styled(token)?;
+        let answer = match self.answer_redaction {
+            None => Some(answer),
+            Some(AnswerRedaction::Hidden) => None,
+            Some(AnswerRedaction::Placeholder(placeholder)) => Some(placeholder),
+        };
+
+        match (answer, self.styled_texts.answer) {
+            (Some(answer), Some(formatter)) if self.answer_redaction.is_none() => {
+                let styled = formatter(answer);
+                self.write_text(styled.as_str(), Some(&styled), self.render_config.answer)?;
+            }
+            (Some(answer), _) => self.write_text(answer, None, self.render_config.answer)?,
+            (None, _) => {}
+        }
 
         self.new_line()?;
 
@@ -301,6 +782,7 @@ where
     }
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.error_rendered = true;
         self.frame_renderer
             .write_styled(self.render_config.error_message.prefix)?;
 
@@ -326,8 +808,11 @@ where
         self.frame_renderer
             .write_styled(Styled::new("[").with_style_sheet(self.render_config.help_message))?;
 
-        self.frame_renderer
-            .write_styled(Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+        self.write_text(
+            help,
+            self.styled_texts.help_message,
+            self.render_config.help_message,
+        )?;
 
         self.frame_renderer
             .write_styled(Styled::new("]").with_style_sheet(self.render_config.help_message))?;
@@ -348,8 +833,65 @@ where
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        counter: Option<&InputCounter>,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        let Some(counter) = counter else {
+            return self.print_prompt_with_input(prompt, default, cur_input);
+        };
+
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.print_default_value(default)?;
+            self.frame_renderer.write(" ")?;
+        }
+
+        self.print_input(cur_input)?;
+        // The input is already followed by a space when the cursor is at
+        // its end.
+        if cur_input.cursor() != cur_input.length() {
+            self.frame_renderer.write(" ")?;
+        }
+        self.print_input_counter(counter, cur_input)?;
+
+        self.new_line()
+    }
+
+    fn render_prompt_with_ghost_text(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        ghost_text: &str,
+        counter: Option<&InputCounter>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.print_default_value(default)?;
+            self.frame_renderer.write(" ")?;
+        }
+
+        // The cursor rests on the first character of the ghost text, which
+        // is only rendered when the cursor is at the end of the input.
+        self.frame_renderer.mark_cursor_position(text_width(
+            cur_input.pre_cursor(),
+            self.render_config.ambiguous_width,
+        ) as isize);
+
+        self.frame_renderer.write_styled(
+            Styled::new(cur_input.content()).with_style_sheet(self.render_config.text_input),
+        )?;
+        self.frame_renderer.write_styled(
+            Styled::new(ghost_text).with_style_sheet(self.render_config.ghost_text),
+        )?;
+
+        if let Some(counter) = counter {
+            self.frame_renderer.write(" ")?;
+            self.print_input_counter(counter, cur_input)?;
+        }
+
+        self.new_line()
     }
 
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
@@ -364,6 +906,14 @@ where
 
         Ok(())
     }
+
+    fn render_suggestions_loading(&mut self, indicator: &str) -> Result<()> {
+        let message = format!("{indicator} loading suggestions…");
+        let token = Styled::new(message).with_style_sheet(self.render_config.help_message);
+        self.frame_renderer.write_styled(token)?;
+
+        self.new_line()
+    }
 }
 
 #[cfg(feature = "editor")]
@@ -410,6 +960,9 @@ where
                 self.frame_renderer.write(" ")?;
             }
 
+            self.print_option_icon(idx, &page)?;
+            self.print_option_custom_prefix(idx, &page)?;
+
             self.print_option_value(idx, option, &page)?;
 
             self.new_line()?;
@@ -417,6 +970,34 @@ where
 
         Ok(())
     }
+
+    fn render_options_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        columns: usize,
+    ) -> Result<()> {
+        let column_width = grid_column_width(&page, self.render_config.ambiguous_width);
+
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.frame_renderer.write(" ")?;
+            }
+
+            self.print_option_icon(idx, &page)?;
+            self.print_option_custom_prefix(idx, &page)?;
+
+            self.print_option_value(idx, option, &page)?;
+
+            self.finish_grid_cell(idx, option, &page, columns, column_width)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
@@ -448,56 +1029,148 @@ where
                 self.frame_renderer.write(" ")?;
             }
 
-            let mut checkbox = match checked.contains(&option.index) {
-                true => self.render_config.selected_checkbox,
-                false => self.render_config.unselected_checkbox,
-            };
+            self.print_option_checkbox(idx, option, &page, checked)?;
+
+            self.frame_renderer.write(" ")?;
+
+            self.print_option_icon(idx, &page)?;
+            self.print_option_custom_prefix(idx, &page)?;
+
+            self.print_option_value(idx, option, &page)?;
+
+            self.new_line()?;
+        }
 
-            match (self.render_config.selected_option, page.cursor) {
-                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
-                _ => {}
+        Ok(())
+    }
+
+    fn render_options_grid<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+        columns: usize,
+    ) -> Result<()> {
+        let column_width = grid_column_width(&page, self.render_config.ambiguous_width);
+
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.frame_renderer.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.frame_renderer.write(" ")?;
             }
 
-            self.frame_renderer.write_styled(checkbox)?;
+            self.print_option_checkbox(idx, option, &page, checked)?;
 
             self.frame_renderer.write(" ")?;
 
+            self.print_option_icon(idx, &page)?;
+            self.print_option_custom_prefix(idx, &page)?;
+
             self.print_option_value(idx, option, &page)?;
 
-            self.new_line()?;
+            self.finish_grid_cell(idx, option, &page, columns, column_width)?;
         }
 
         Ok(())
     }
+
+    fn render_prompt_with_answer_tags(
+        &mut self,
+        prompt: &str,
+        answer: &str,
+        tags: &[String],
+    ) -> Result<()> {
+        let Some(tag_style) = self.render_config.answer_tag else {
+            return self.render_prompt_with_answer(prompt, answer);
+        };
+
+        if tags.is_empty() || self.answer_redaction.is_some() || self.styled_texts.answer.is_some()
+        {
+            return self.render_prompt_with_answer(prompt, answer);
+        }
+
+        self.print_prompt_with_prefix(self.render_config.answered_prompt_prefix, prompt)?;
+
+        let terminal_width = self.frame_renderer.current_terminal_width();
+
+        for (i, tag) in tags.iter().enumerate() {
+            let tag = format!(" {tag} ");
+
+            if i > 0 {
+                // Tags are moved to the next line as a whole instead of
+                // being broken up at the edge of the terminal.
+                let line_width = self.frame_renderer.current_line_width().unwrap_or(0);
+                let fits = match terminal_width {
+                    Some(terminal_width) => {
+                        line_width as usize + 1 + self.text_width(&tag) <= terminal_width as usize
+                    }
+                    None => true,
+                };
+
+                match fits {
+                    true => self.frame_renderer.write(" ")?,
+                    false => self.new_line()?,
+                }
+            }
+
+            self.frame_renderer
+                .write_styled(Styled::new(tag).with_style_sheet(tag_style))?;
+        }
+
+        self.new_line()?;
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "date")]
 pub mod date {
     use std::{io::Result, ops::Sub};
 
-    use chrono::{Datelike, Duration};
+    use chrono::{Datelike, Duration, Months, NaiveDate};
 
     use crate::{
-        date_utils::get_start_date,
+        date_utils::{get_month, get_start_date},
+        input::Input,
         terminal::Terminal,
-        ui::{InputReader, Styled},
+        ui::{InputReader, StyleSheet, Styled},
+        CalendarLayout, DateLocale,
     };
 
     use super::{Backend, CommonBackend};
 
     pub trait DateSelectBackend: CommonBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
+        fn render_calendar_prompt(&mut self, prompt: &str, input: Option<&Input>) -> Result<()>;
 
         #[allow(clippy::too_many_arguments)]
         fn render_calendar(
             &mut self,
+            layout: CalendarLayout,
             month: chrono::Month,
             year: i32,
             week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
+            today: NaiveDate,
+            selected_date: NaiveDate,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+            is_disabled: &dyn Fn(NaiveDate) -> bool,
+            is_checked: &dyn Fn(NaiveDate) -> bool,
+            date_style: &dyn Fn(NaiveDate) -> Option<StyleSheet>,
+            show_week_numbers: bool,
+            locale: &DateLocale<'_>,
+        ) -> Result<()>;
+
+        fn render_month_picker(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            typed_year: &str,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+            locale: &DateLocale<'_>,
         ) -> Result<()>;
     }
 
@@ -506,7 +1179,11 @@ pub mod date {
         I: InputReader,
         T: Terminal,
     {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
+        fn render_calendar_prompt(&mut self, prompt: &str, input: Option<&Input>) -> Result<()> {
+            if let Some(input) = input {
+                return self.print_prompt_with_input(prompt, None, input);
+            }
+
             self.print_prompt(prompt)?;
             self.new_line()?;
             Ok(())
@@ -514,13 +1191,19 @@ pub mod date {
 
         fn render_calendar(
             &mut self,
+            layout: CalendarLayout,
             month: chrono::Month,
             year: i32,
             week_start: chrono::Weekday,
-            today: chrono::NaiveDate,
-            selected_date: chrono::NaiveDate,
-            min_date: Option<chrono::NaiveDate>,
-            max_date: Option<chrono::NaiveDate>,
+            today: NaiveDate,
+            selected_date: NaiveDate,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+            is_disabled: &dyn Fn(NaiveDate) -> bool,
+            is_checked: &dyn Fn(NaiveDate) -> bool,
+            date_style: &dyn Fn(NaiveDate) -> Option<StyleSheet>,
+            show_week_numbers: bool,
+            locale: &DateLocale<'_>,
         ) -> Result<()> {
             macro_rules! write_prefix {
                 () => {{
@@ -530,9 +1213,60 @@ pub mod date {
                 }};
             }
 
+            let calendar_config = self.render_config.calendar;
+            let style_sheet_of = |date: NaiveDate| {
+                let mut style_sheet = StyleSheet::empty();
+
+                if date == selected_date {
+                    if let Some(custom_style_sheet) = calendar_config.selected_date {
+                        style_sheet = custom_style_sheet;
+                    }
+                } else if is_checked(date) {
+                    style_sheet = calendar_config.checked_date;
+                } else if let Some(custom_style_sheet) = date_style(date) {
+                    style_sheet = custom_style_sheet;
+                } else if date == today {
+                    style_sheet = calendar_config.today_date;
+                } else if date.month() != month.number_from_month() {
+                    style_sheet = calendar_config.different_month_date;
+                }
+
+                if let Some(min_date) = min_date {
+                    if date < min_date {
+                        style_sheet = calendar_config.unavailable_date;
+                    }
+                }
+
+                if let Some(max_date) = max_date {
+                    if date > max_date {
+                        style_sheet = calendar_config.unavailable_date;
+                    }
+                }
+
+                if is_disabled(date) {
+                    style_sheet = calendar_config.unavailable_date;
+                }
+
+                style_sheet
+            };
+
             // print header (month year)
-            let header = format!("{} {}", month.name().to_lowercase(), year);
-            let header = format!("{header:^20}");
+            let header = format!(
+                "{} {}",
+                locale
+                    .month_name(get_start_date(month, year))
+                    .to_lowercase(),
+                year
+            );
+            // the week numbers take two columns plus a separator
+            let gutter = match (layout, show_week_numbers) {
+                (CalendarLayout::Grid, true) => "   ",
+                _ => "",
+            };
+            let header = match layout {
+                CalendarLayout::Grid => format!("{gutter}{header:^20}"),
+                CalendarLayout::Strip | CalendarLayout::Agenda => header,
+            };
             let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
 
             write_prefix!()?;
@@ -541,84 +1275,237 @@ pub mod date {
 
             self.new_line()?;
 
-            // print week header
-            let mut current_weekday = week_start;
-            let mut week_days: Vec<String> = vec![];
-            for _ in 0..7 {
-                let mut formatted = format!("{current_weekday}");
-                formatted.make_ascii_lowercase();
-                formatted.pop();
-                week_days.push(formatted);
+            match layout {
+                CalendarLayout::Grid => {
+                    // print week header
+                    let mut current_weekday = week_start;
+                    let mut week_days: Vec<String> = vec![];
+                    for _ in 0..7 {
+                        week_days.push(locale.weekday_abbreviation(current_weekday).to_lowercase());
 
-                current_weekday = current_weekday.succ();
-            }
+                        current_weekday = current_weekday.succ();
+                    }
 
-            let week_days = Styled::new(week_days.join(" "))
-                .with_style_sheet(self.render_config.calendar.week_header);
+                    let week_days = Styled::new(week_days.join(" "))
+                        .with_style_sheet(self.render_config.calendar.week_header);
+
+                    write_prefix!()?;
+
+                    self.frame_renderer.write(gutter)?;
+                    self.frame_renderer.write_styled(week_days)?;
+                    self.new_line()?;
+
+                    // print dates
+                    let mut date_it = get_start_date(month, year);
+                    // first date of week-line is possibly in the previous month
+                    if date_it.weekday() == week_start {
+                        date_it = date_it.sub(
+                            Duration::try_weeks(1)
+                                .expect("overflow when calculating duration of 1 week"),
+                        );
+                    } else {
+                        while date_it.weekday() != week_start {
+                            date_it = match date_it.pred_opt() {
+                                Some(date) => date,
+                                None => break,
+                            };
+                        }
+                    }
 
-            write_prefix!()?;
+                    for _ in 0..6 {
+                        write_prefix!()?;
+
+                        if show_week_numbers {
+                            // the thursday decides which ISO week a row mostly belongs to,
+                            // whatever the first day of the week is
+                            let thursday = date_it
+                                .iter_days()
+                                .take(7)
+                                .find(|date| date.weekday() == chrono::Weekday::Thu)
+                                .unwrap_or(date_it);
+                            let week_number =
+                                Styled::new(format!("{:2}", thursday.iso_week().week()))
+                                    .with_style_sheet(calendar_config.week_number);
+                            self.frame_renderer.write_styled(week_number)?;
+                            self.frame_renderer.write(" ")?;
+                        }
 
-            self.frame_renderer.write_styled(week_days)?;
-            self.new_line()?;
+                        for i in 0..7 {
+                            if i > 0 {
+                                self.frame_renderer.write(" ")?;
+                            }
 
-            // print dates
-            let mut date_it = get_start_date(month, year);
-            // first date of week-line is possibly in the previous month
-            if date_it.weekday() == week_start {
-                date_it = date_it.sub(
-                    Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"),
-                );
-            } else {
-                while date_it.weekday() != week_start {
-                    date_it = match date_it.pred_opt() {
-                        Some(date) => date,
-                        None => break,
-                    };
-                }
-            }
+                            let date = format!("{:2}", date_it.day());
 
-            for _ in 0..6 {
-                write_prefix!()?;
+                            let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
 
-                for i in 0..7 {
-                    if i > 0 {
-                        self.frame_renderer.write(" ")?;
-                    }
+                            if date_it == selected_date {
+                                self.frame_renderer.mark_cursor_position(cursor_offset);
+                            }
 
-                    let date = format!("{:2}", date_it.day());
+                            let token = Styled::new(date).with_style_sheet(style_sheet_of(date_it));
+                            self.frame_renderer.write_styled(token)?;
 
-                    let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
+                            date_it = date_it.succ_opt().unwrap_or(date_it);
+                        }
 
-                    let mut style_sheet = crate::ui::StyleSheet::empty();
+                        self.new_line()?;
+                    }
+                }
+                CalendarLayout::Strip => {
+                    let month_start = get_start_date(month, year);
+                    let days: Vec<NaiveDate> = month_start
+                        .iter_days()
+                        .take_while(|date| date.month() == month_start.month())
+                        .collect();
+
+                    // each day takes two columns plus a separator, except the last one
+                    let prefix_width =
+                        self.text_width(self.render_config.calendar.prefix.content) + 1;
+                    let available_width = self
+                        .frame_renderer
+                        .current_terminal_width()
+                        .map(usize::from)
+                        .unwrap_or(80)
+                        .saturating_sub(prefix_width);
+                    let visible_days = ((available_width + 1) / 3).clamp(1, days.len());
+
+                    let selected_idx = days
+                        .iter()
+                        .position(|date| *date == selected_date)
+                        .unwrap_or(0);
+                    let first_visible = selected_idx
+                        .saturating_sub(visible_days / 2)
+                        .min(days.len() - visible_days);
+                    let days = days.iter().skip(first_visible).take(visible_days);
+
+                    let week_days = days
+                        .clone()
+                        .map(|date| locale.weekday_abbreviation(date.weekday()).to_lowercase())
+                        .collect::<Vec<String>>();
+                    let week_days = Styled::new(week_days.join(" "))
+                        .with_style_sheet(self.render_config.calendar.week_header);
+
+                    write_prefix!()?;
+                    self.frame_renderer.write_styled(week_days)?;
+                    self.new_line()?;
+
+                    write_prefix!()?;
+
+                    for (i, date) in days.enumerate() {
+                        if i > 0 {
+                            self.frame_renderer.write(" ")?;
+                        }
 
-                    if date_it == selected_date {
-                        self.frame_renderer.mark_cursor_position(cursor_offset);
-                        if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
-                        {
-                            style_sheet = custom_style_sheet;
+                        if *date == selected_date {
+                            let cursor_offset = if date.day() < 10 { 1 } else { 0 };
+                            self.frame_renderer.mark_cursor_position(cursor_offset);
                         }
-                    } else if date_it == today {
-                        style_sheet = self.render_config.calendar.today_date;
-                    } else if date_it.month() != month.number_from_month() {
-                        style_sheet = self.render_config.calendar.different_month_date;
+
+                        let token = Styled::new(format!("{:2}", date.day()))
+                            .with_style_sheet(style_sheet_of(*date));
+                        self.frame_renderer.write_styled(token)?;
+                    }
+
+                    self.new_line()?;
+                }
+                CalendarLayout::Agenda => {
+                    let mut date_it = selected_date;
+                    while date_it.weekday() != week_start {
+                        date_it = match date_it.pred_opt() {
+                            Some(date) => date,
+                            None => break,
+                        };
                     }
 
-                    if let Some(min_date) = min_date {
-                        if date_it < min_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
+                    for _ in 0..7 {
+                        write_prefix!()?;
+
+                        if date_it == selected_date {
+                            self.frame_renderer.mark_cursor_position(0);
                         }
+
+                        let label = format!(
+                            "{} {:2} {}",
+                            locale.weekday_abbreviation(date_it.weekday()),
+                            date_it.day(),
+                            locale.month_abbreviation(date_it)
+                        )
+                        .to_lowercase();
+                        let token = Styled::new(label).with_style_sheet(style_sheet_of(date_it));
+                        self.frame_renderer.write_styled(token)?;
+
+                        self.new_line()?;
+
+                        date_it = match date_it.succ_opt() {
+                            Some(date) => date,
+                            None => break,
+                        };
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn render_month_picker(
+            &mut self,
+            month: chrono::Month,
+            year: i32,
+            typed_year: &str,
+            min_date: Option<NaiveDate>,
+            max_date: Option<NaiveDate>,
+            locale: &DateLocale<'_>,
+        ) -> Result<()> {
+            let calendar_config = self.render_config.calendar;
+
+            self.frame_renderer.write_styled(calendar_config.prefix)?;
+            self.frame_renderer.write(" ")?;
+
+            // while a year is being typed, it replaces the displayed one
+            if typed_year.is_empty() {
+                let header =
+                    Styled::new(format!("{year:^11}")).with_style_sheet(calendar_config.header);
+                self.frame_renderer.write_styled(header)?;
+            } else {
+                self.frame_renderer.write(typed_year)?;
+                self.frame_renderer.mark_cursor_position(0);
+            }
+
+            self.new_line()?;
+
+            for row in 0..4 {
+                self.frame_renderer.write_styled(calendar_config.prefix)?;
+                self.frame_renderer.write(" ")?;
+
+                for col in 0..3 {
+                    if col > 0 {
+                        self.frame_renderer.write(" ")?;
                     }
 
-                    if let Some(max_date) = max_date {
-                        if date_it > max_date {
-                            style_sheet = self.render_config.calendar.unavailable_date;
+                    let it = get_month(row * 3 + col + 1);
+                    let first_day = get_start_date(it, year);
+                    let last_day = first_day
+                        .checked_add_months(Months::new(1))
+                        .and_then(|date| date.pred_opt())
+                        .unwrap_or(first_day);
+
+                    let mut style_sheet = StyleSheet::empty();
+                    if it == month {
+                        if let Some(custom_style_sheet) = calendar_config.selected_date {
+                            style_sheet = custom_style_sheet;
                         }
+                        self.frame_renderer.mark_cursor_position(0);
+                    }
+                    if min_date.is_some_and(|min_date| last_day < min_date)
+                        || max_date.is_some_and(|max_date| first_day > max_date)
+                    {
+                        style_sheet = calendar_config.unavailable_date;
                     }
 
-                    let token = Styled::new(date).with_style_sheet(style_sheet);
+                    let name = locale.month_abbreviation(first_day).to_lowercase();
+                    let token = Styled::new(name).with_style_sheet(style_sheet);
                     self.frame_renderer.write_styled(token)?;
-
-                    date_it = date_it.succ_opt().unwrap_or(date_it);
                 }
 
                 self.new_line()?;
@@ -670,14 +1557,82 @@ where
     }
 }
 
+impl<'a, I, T> TypedConfirmBackend for Backend<'a, I, T>
+where
+    I: InputReader,
+    T: Terminal,
+{
+    fn render_prompt_with_phrase(
+        &mut self,
+        prompt: &str,
+        phrase: &str,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        self.frame_renderer
+            .mark_cursor_position(self.text_width(cur_input.pre_cursor()) as isize);
+
+        let mut expected = phrase.graphemes(true);
+        let mut is_prefix = true;
+        let mut runs: Vec<(String, bool)> = vec![];
+
+        for grapheme in cur_input.content().graphemes(true) {
+            let matches = expected.next() == Some(grapheme);
+            is_prefix &= matches;
+
+            match runs.last_mut() {
+                Some((run, run_matches)) if *run_matches == matches => run.push_str(grapheme),
+                _ => runs.push((grapheme.to_owned(), matches)),
+            }
+        }
+
+        for (run, matches) in runs {
+            let style = match matches {
+                true => self.render_config.text_input,
+                false => self.render_config.error_message.message,
+            };
+            self.frame_renderer
+                .write_styled(Styled::new(run).with_style_sheet(style))?;
+        }
+
+        // The rest of the phrase is only a useful hint while the input
+        // matches it so far, in which case the cursor rests on it.
+        let remaining: String = expected.collect();
+        if is_prefix && !remaining.is_empty() {
+            self.frame_renderer.write_styled(
+                Styled::new(remaining).with_style_sheet(self.render_config.ghost_text),
+            )?;
+        } else if cur_input.cursor() == cur_input.length() {
+            self.frame_renderer.write(' ')?;
+        }
+
+        self.new_line()
+    }
+}
+
 impl<'a, I, T> InputReader for Backend<'a, I, T>
 where
     I: InputReader,
     T: Terminal,
 {
     fn read_key(&mut self) -> InquireResult<Key> {
+        #[cfg(feature = "instrument")]
+        let _span = crate::instrument::span(crate::instrument::Phase::InputWait);
+
         self.input_reader.read_key()
     }
+
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        #[cfg(feature = "instrument")]
+        let _span = crate::instrument::span(crate::instrument::Phase::InputWait);
+
+        self.input_reader.poll_key(timeout)
+    }
+
+    fn read_preset_answer(&mut self) -> InquireResult<Option<PresetAnswer>> {
+        self.input_reader.read_preset_answer()
+    }
 }
 
 #[cfg(test)]
@@ -687,13 +1642,14 @@ pub(crate) mod test {
     use chrono::{Month, NaiveDate, Weekday};
 
     use crate::{
+        cancellation::CancellationToken,
         input::Input,
         terminal::test::{MockTerminal, MockTerminalToken},
-        ui::{InputReader, Key},
+        ui::{InputReader, Key, Styled},
         validator::ErrorMessage,
     };
 
-    use super::{CommonBackend, CustomTypeBackend};
+    use super::{CommonBackend, CustomTypeBackend, TextBackend};
 
     #[derive(Debug, Clone, PartialEq)]
     pub enum Token {
@@ -704,7 +1660,11 @@ pub(crate) mod test {
         AnsweredPrompt(String, String),
         ErrorMessage(ErrorMessage),
         HelpMessage(String),
+        Text(String),
+        #[cfg_attr(not(feature = "date"), allow(dead_code))]
         Calendar {
+            #[cfg(feature = "date")]
+            layout: crate::CalendarLayout,
             month: Month,
             year: i32,
             week_start: Weekday,
@@ -713,6 +1673,12 @@ pub(crate) mod test {
             min_date: Option<NaiveDate>,
             max_date: Option<NaiveDate>,
         },
+        #[cfg(feature = "date")]
+        MonthPicker {
+            month: Month,
+            year: i32,
+            typed_year: String,
+        },
         PromptEnd,
     }
 
@@ -736,6 +1702,7 @@ pub(crate) mod test {
         pub input: VecDeque<Key>,
         pub frames: Vec<Frame>,
         pub cur_frame: Option<Frame>,
+        pub cancellation: Option<CancellationToken>,
     }
 
     impl FakeBackend {
@@ -744,6 +1711,7 @@ pub(crate) mod test {
                 input: input.into(),
                 frames: vec![],
                 cur_frame: None,
+                cancellation: None,
             }
         }
 
@@ -795,6 +1763,10 @@ pub(crate) mod test {
             Ok(())
         }
 
+        fn cancellation(&self) -> Option<&CancellationToken> {
+            self.cancellation.as_ref()
+        }
+
         fn current_terminal_height(&self) -> Option<u16> {
             None
         }
@@ -803,7 +1775,21 @@ pub(crate) mod test {
             None
         }
 
-        fn render_canceled_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+        fn render_prompt_message(&mut self, prompt: &str) -> std::io::Result<()> {
+            self.push_token(Token::Prompt(prompt.to_string()));
+            Ok(())
+        }
+
+        fn render_text(&mut self, text: Styled<&str>) -> std::io::Result<()> {
+            self.push_token(Token::Text(text.content.to_string()));
+            Ok(())
+        }
+
+        fn render_canceled_prompt(
+            &mut self,
+            prompt: &str,
+            _partial_input: Option<&str>,
+        ) -> std::io::Result<()> {
             self.push_token(Token::CanceledPrompt(prompt.to_string()));
             Ok(())
         }
@@ -829,13 +1815,21 @@ pub(crate) mod test {
 
     #[cfg(feature = "date")]
     impl crate::ui::date::DateSelectBackend for FakeBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
+        fn render_calendar_prompt(
+            &mut self,
+            prompt: &str,
+            input: Option<&Input>,
+        ) -> std::io::Result<()> {
             self.push_token(Token::Prompt(prompt.to_string()));
+            if let Some(input) = input {
+                self.push_token(Token::Input(input.clone()));
+            }
             Ok(())
         }
 
         fn render_calendar(
             &mut self,
+            layout: crate::CalendarLayout,
             month: Month,
             year: i32,
             week_start: Weekday,
@@ -843,8 +1837,14 @@ pub(crate) mod test {
             selected_date: NaiveDate,
             min_date: Option<NaiveDate>,
             max_date: Option<NaiveDate>,
+            _is_disabled: &dyn Fn(NaiveDate) -> bool,
+            _is_checked: &dyn Fn(NaiveDate) -> bool,
+            _date_style: &dyn Fn(NaiveDate) -> Option<crate::ui::StyleSheet>,
+            _show_week_numbers: bool,
+            _locale: &crate::DateLocale<'_>,
         ) -> std::io::Result<()> {
             self.push_token(Token::Calendar {
+                layout,
                 month,
                 year,
                 week_start,
@@ -855,6 +1855,23 @@ pub(crate) mod test {
             });
             Ok(())
         }
+
+        fn render_month_picker(
+            &mut self,
+            month: Month,
+            year: i32,
+            typed_year: &str,
+            _min_date: Option<NaiveDate>,
+            _max_date: Option<NaiveDate>,
+            _locale: &crate::DateLocale<'_>,
+        ) -> std::io::Result<()> {
+            self.push_token(Token::MonthPicker {
+                month,
+                year,
+                typed_year: typed_year.to_string(),
+            });
+            Ok(())
+        }
     }
 
     impl CustomTypeBackend for FakeBackend {
@@ -873,6 +1890,40 @@ pub(crate) mod test {
         }
     }
 
+    impl TextBackend for FakeBackend {
+        fn render_prompt(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+            _counter: Option<&crate::prompts::InputCounter>,
+        ) -> std::io::Result<()> {
+            CustomTypeBackend::render_prompt(self, prompt, default, cur_input)
+        }
+
+        fn render_prompt_with_ghost_text(
+            &mut self,
+            prompt: &str,
+            default: Option<&str>,
+            cur_input: &Input,
+            _ghost_text: &str,
+            counter: Option<&crate::prompts::InputCounter>,
+        ) -> std::io::Result<()> {
+            TextBackend::render_prompt(self, prompt, default, cur_input, counter)
+        }
+
+        fn render_suggestions<D: std::fmt::Display>(
+            &mut self,
+            _page: crate::utils::Page<'_, crate::list_option::ListOption<D>>,
+        ) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn render_suggestions_loading(&mut self, _indicator: &str) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
     #[test]
     fn test_empty_prompt_spacing() {
         use super::Backend;
@@ -930,4 +1981,52 @@ pub(crate) mod test {
         match_token(&mut output, "\r".into());
         match_token(&mut output, MockTerminalToken::CursorRight(9));
     }
+
+    #[test]
+    fn prompt_prefix_follows_the_state_of_the_prompt() {
+        use super::Backend;
+        use crate::ui::RenderConfig;
+
+        struct NoInput;
+        impl InputReader for NoInput {
+            fn read_key(&mut self) -> crate::error::InquireResult<Key> {
+                Ok(Key::Enter)
+            }
+        }
+
+        let error_prefix = Styled::new("▲ !").with_fg(crate::ui::Color::LightYellow);
+        let canceled_prefix = Styled::new("✖");
+        let render_config = RenderConfig::empty()
+            .with_error_prompt_prefix(Some(error_prefix))
+            .with_canceled_prompt_prefix(Some(canceled_prefix));
+
+        let mut output = VecDeque::new();
+        {
+            let terminal = MockTerminal::new(&mut output);
+            let mut backend = Backend::new(NoInput, terminal, render_config).unwrap();
+
+            backend.frame_setup().unwrap();
+            backend
+                .render_error_message(&ErrorMessage::Default)
+                .unwrap();
+            backend.render_prompt_message("Age?").unwrap();
+            backend.frame_finish(false).unwrap();
+
+            backend.frame_setup().unwrap();
+            backend.render_canceled_prompt("Age?", None).unwrap();
+            backend.frame_finish(true).unwrap();
+        }
+
+        let prefixes: Vec<MockTerminalToken> = output
+            .into_iter()
+            .filter(|token| {
+                [error_prefix, canceled_prefix, render_config.prompt_prefix]
+                    .iter()
+                    .any(|prefix| *token == (*prefix).into())
+            })
+            .collect();
+
+        let expected: Vec<MockTerminalToken> = vec![error_prefix.into(), canceled_prefix.into()];
+        assert_eq!(expected, prefixes);
+    }
 }
@@ -0,0 +1,224 @@
+//! Reordering of rows containing right-to-left text, such as Arabic or
+//! Hebrew, for terminals displaying characters in the order they are written.
+
+use unicode_bidi::{Level, ParagraphBidiInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::utils::grapheme_width;
+
+use super::{AmbiguousWidth, StyleSheet, Styled, TextDirection};
+
+/// Position of a grapheme of a row, in logical and visual order.
+struct Cell {
+    logical_col: u16,
+    visual_col: u16,
+    width: u16,
+    rtl: bool,
+}
+
+/// Row of a frame laid out in visual order, right-aligned when its base
+/// direction is right-to-left.
+pub struct VisualRow {
+    pub content: Vec<Styled<String>>,
+    cells: Vec<Cell>,
+    rtl: bool,
+    terminal_width: u16,
+}
+
+impl VisualRow {
+    /// Lays out a row in visual order, returning `None` when it is displayed
+    /// as written, either because it only contains left-to-right text or
+    /// because it contains ANSI escape sequences, which can't be reordered.
+    pub fn layout(
+        row: &[Styled<String>],
+        direction: TextDirection,
+        terminal_width: u16,
+        ambiguous_width: AmbiguousWidth,
+    ) -> Option<Self> {
+        let base_level = match direction {
+            TextDirection::Ltr => return None,
+            TextDirection::Rtl => Some(Level::rtl()),
+            TextDirection::Auto => None,
+        };
+
+        if row.iter().any(|styled| styled.content.contains('\x1b')) {
+            return None;
+        }
+
+        let text: String = row.iter().map(|styled| styled.content.as_str()).collect();
+        let info = ParagraphBidiInfo::new(&text, base_level);
+        let rtl = info.paragraph_level.is_rtl();
+
+        if info.is_pure_ltr && !rtl {
+            return None;
+        }
+
+        let mut segment_ends = Vec::with_capacity(row.len());
+        let mut end = 0;
+        for styled in row {
+            end += styled.content.len();
+            segment_ends.push((end, styled.style));
+        }
+        let style_at = |byte: usize| {
+            segment_ends
+                .iter()
+                .find(|(end, _)| byte < *end)
+                .map(|(_, style)| *style)
+                .unwrap_or_default()
+        };
+
+        let mut logical_cols = Vec::new();
+        let mut row_width = 0u16;
+        for (start, grapheme) in text.grapheme_indices(true) {
+            logical_cols.push((start, row_width));
+            row_width += grapheme_width(grapheme, ambiguous_width) as u16;
+        }
+
+        let padding = match rtl {
+            true => terminal_width.saturating_sub(row_width),
+            false => 0,
+        };
+
+        let mut visual = Self {
+            content: vec![],
+            cells: Vec::with_capacity(logical_cols.len()),
+            rtl,
+            terminal_width,
+        };
+        if padding > 0 {
+            visual
+                .content
+                .push(Styled::new(" ".repeat(usize::from(padding))));
+        }
+
+        let (levels, runs) = info.visual_runs(0..text.len());
+        let mut visual_col = padding;
+
+        for run in runs {
+            let run_rtl = levels.get(run.start).is_some_and(Level::is_rtl);
+            let run_text = text.get(run.clone()).unwrap_or_default();
+
+            let mut graphemes: Vec<(usize, &str)> = run_text
+                .grapheme_indices(true)
+                .map(|(offset, grapheme)| (run.start + offset, grapheme))
+                .collect();
+            if run_rtl {
+                graphemes.reverse();
+            }
+
+            for (start, grapheme) in graphemes {
+                let width = grapheme_width(grapheme, ambiguous_width) as u16;
+                let logical_col = logical_cols
+                    .binary_search_by_key(&start, |(start, _)| *start)
+                    .ok()
+                    .and_then(|i| logical_cols.get(i))
+                    .map(|(_, col)| *col)
+                    .unwrap_or_default();
+
+                visual.push(grapheme, style_at(start));
+                visual.cells.push(Cell {
+                    logical_col,
+                    visual_col,
+                    width,
+                    rtl: run_rtl,
+                });
+                visual_col += width;
+            }
+        }
+
+        Some(visual)
+    }
+
+    fn push(&mut self, grapheme: &str, style: StyleSheet) {
+        match self.content.last_mut() {
+            Some(last) if last.style == style => last.content.push_str(grapheme),
+            _ => self
+                .content
+                .push(Styled::new(grapheme.to_owned()).with_style_sheet(style)),
+        }
+    }
+
+    /// Visual column of the cursor placed at the provided logical column,
+    /// next to the character typed before it.
+    pub fn cursor_col(&self, logical_col: u16) -> u16 {
+        let previous = self
+            .cells
+            .iter()
+            .find(|cell| cell.logical_col + cell.width == logical_col && cell.width > 0);
+        let next = self
+            .cells
+            .iter()
+            .find(|cell| cell.logical_col == logical_col && cell.width > 0);
+
+        match (previous, next) {
+            (Some(previous), _) if previous.rtl => previous.visual_col.saturating_sub(1),
+            (Some(previous), _) => previous.visual_col + previous.width,
+            (None, Some(next)) if next.rtl => next.visual_col + next.width,
+            (None, Some(next)) => next.visual_col,
+            (None, None) if self.rtl => self.terminal_width.saturating_sub(1),
+            (None, None) => logical_col,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::VisualRow;
+    use crate::ui::{AmbiguousWidth, Color, StyleSheet, Styled, TextDirection};
+
+    fn layout(row: &[Styled<String>], direction: TextDirection) -> Option<VisualRow> {
+        VisualRow::layout(row, direction, 20, AmbiguousWidth::Narrow)
+    }
+
+    fn text(visual: &VisualRow) -> String {
+        visual
+            .content
+            .iter()
+            .map(|styled| styled.content.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn rtl_rows_are_reversed_and_right_aligned() {
+        let prefix = StyleSheet::new().with_fg(Color::LightGreen);
+        let row = vec![
+            Styled::new("?".to_owned()).with_style_sheet(prefix),
+            Styled::new(" \u{5e9}\u{5dd}: \u{5d3}\u{5df}".to_owned()),
+        ];
+
+        let visual = layout(&row, TextDirection::Auto).unwrap();
+
+        assert_eq!(
+            "            \u{5df}\u{5d3} :\u{5dd}\u{5e9} ?",
+            text(&visual)
+        );
+        assert_eq!(
+            Some(&Styled::new("?".to_owned()).with_style_sheet(prefix)),
+            visual.content.last()
+        );
+
+        // The next character typed is displayed to the left of the input.
+        assert_eq!(11, visual.cursor_col(8));
+    }
+
+    #[test]
+    fn ltr_text_keeps_its_order_inside_rtl_rows() {
+        let row = vec![Styled::new("\u{5e9}\u{5dd} abc 123".to_owned())];
+
+        let visual = layout(&row, TextDirection::Rtl).unwrap();
+
+        assert_eq!("          abc 123 \u{5dd}\u{5e9}", text(&visual));
+        assert_eq!(13, visual.cursor_col(6));
+    }
+
+    #[test]
+    fn ltr_rows_are_only_reordered_when_forced() {
+        let row = vec![Styled::new("? Name: abc".to_owned())];
+
+        assert!(layout(&row, TextDirection::Auto).is_none());
+        assert!(layout(&row, TextDirection::Ltr).is_none());
+
+        let visual = layout(&row, TextDirection::Rtl).unwrap();
+        assert_eq!("         Name: abc ?", text(&visual));
+    }
+}
@@ -3,22 +3,32 @@ use std::fmt::Display;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io;
 
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
 
+#[cfg(feature = "rtl")]
+use super::bidi::VisualRow;
 use super::dimension::Dimension;
-use super::{Position, Styled};
+#[cfg(feature = "rtl")]
+use super::TextDirection;
+use super::{AmbiguousWidth, ColorSupport, Position, RenderProfile, StyleSheet, Styled, Viewport};
 use crate::ansi::{AnsiAware, AnsiAwareChar};
 use crate::terminal::{Terminal, TerminalSize};
+use crate::utils::grapheme_width;
 
 #[derive(Debug, Default)]
 struct FrameRow {
     content: Vec<Styled<String>>,
     hash: u64,
+    width: u16,
 }
 
 impl FrameRow {
-    pub fn new(content: Vec<Styled<String>>, hash: u64) -> Self {
-        Self { content, hash }
+    pub fn new(content: Vec<Styled<String>>, hash: u64, width: u16) -> Self {
+        Self {
+            content,
+            hash,
+            width,
+        }
     }
 
     pub fn get_content(&self) -> &[Styled<String>] {
@@ -28,19 +38,34 @@ impl FrameRow {
     pub fn hash(&self) -> u64 {
         self.hash
     }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
 }
 
 #[derive(Debug)]
 struct FrameState {
     /// terminal size when the frame was rendered
     pub terminal_size: TerminalSize,
+    /// width of characters of ambiguous width in the terminal
+    pub ambiguous_width: AmbiguousWidth,
     /// resulting frame size
     pub frame_size: Dimension,
     /// position to put cursor after writing all present content
     pub expected_cursor_position: Option<Position>,
+    /// columns left to write until reaching the expected cursor position,
+    /// used to correct it when wide characters wrap before it
+    pub cursor_distance: Option<u16>,
     /// content and pre-calculated hashes for each rendered line
     /// the length of this vector should be equal to frame_size.height
     pub finished_rows: Vec<FrameRow>,
+    /// first row displayed when the frame is taller than its fixed-height
+    /// region, so that the cursor stays visible
+    pub scroll: u16,
+    /// columns left blank at the start of lines continuing the text being
+    /// written, either after a line break or when it wraps
+    pub indent: u16,
     pub current_styled: Styled<String>,
     pub current_line: Vec<Styled<String>>,
     pub current_line_width: u16,
@@ -48,9 +73,10 @@ struct FrameState {
 }
 
 impl FrameState {
-    pub fn new(terminal_size: TerminalSize) -> Self {
+    pub fn new(terminal_size: TerminalSize, ambiguous_width: AmbiguousWidth) -> Self {
         Self {
             terminal_size,
+            ambiguous_width,
             frame_size: Dimension::new(0, 0),
             finished_rows: Vec::new(),
             current_styled: Styled::default(),
@@ -58,28 +84,56 @@ impl FrameState {
             current_line_hasher: DefaultHasher::default(),
             current_line_width: 0,
             expected_cursor_position: None,
+            cursor_distance: None,
+            scroll: 0,
+            indent: 0,
         }
     }
 
     pub fn write(&mut self, value: &Styled<impl AsRef<str> + Display>) {
         self.current_styled.style = value.style;
 
-        for piece in value.content.ansi_aware_chars() {
-            piece.hash(&mut self.current_line_hasher);
-            value.style.hash(&mut self.current_line_hasher);
+        // Characters are laid out per grapheme cluster, so that e.g. emoji
+        // sequences are measured as a whole, as terminals render them.
+        let mut text = String::new();
 
-            let current_char = match piece {
-                AnsiAwareChar::Char(c) => c,
+        for piece in value.content.ansi_aware_chars() {
+            match piece {
+                AnsiAwareChar::Char(c) => text.push(c),
                 AnsiAwareChar::AnsiEscapeSequence(seq) => {
+                    self.write_text(&text, value.style);
+                    text.clear();
+
                     // we don't care for escape sequences when calculating cursor position
                     // and box size
+                    piece.hash(&mut self.current_line_hasher);
+                    value.style.hash(&mut self.current_line_hasher);
                     self.current_styled.content.push_str(seq);
-                    continue;
                 }
-            };
+            }
+        }
+
+        self.write_text(&text, value.style);
 
-            if current_char == '\n' {
+        if !self.current_styled.content.is_empty() {
+            self.current_line
+                .push(std::mem::take(&mut self.current_styled));
+        }
+    }
+
+    fn write_text(&mut self, text: &str, style: StyleSheet) {
+        for grapheme in text.graphemes(true) {
+            for c in grapheme.chars() {
+                AnsiAwareChar::Char(c).hash(&mut self.current_line_hasher);
+                style.hash(&mut self.current_line_hasher);
+            }
+
+            // CR LF is a single grapheme cluster.
+            if let Some(rest) = grapheme.strip_suffix('\n') {
+                self.current_styled.content.push_str(rest);
+                self.cursor_distance = None;
                 self.finish_line();
+                self.indent_line();
                 continue;
             }
 
@@ -87,32 +141,68 @@ impl FrameState {
                 .terminal_size
                 .width()
                 .saturating_sub(self.current_line_width);
-            let character_length = UnicodeWidthChar::width(current_char).unwrap_or(0) as u16;
+            let grapheme_length = grapheme_width(grapheme, self.ambiguous_width) as u16;
 
-            if character_length > remaining_width_space {
-                // the character will (probably) not fit into the current line
+            if grapheme_length > remaining_width_space {
+                // the grapheme will (probably) not fit into the current line
                 self.finish_line();
+                self.indent_line();
+
+                // the columns left blank at the end of the line push the
+                // cursor further away
+                if let Some(distance) = self.cursor_distance {
+                    self.expected_cursor_position = Some(self.position_at(distance));
+                }
             }
 
-            self.current_line_width = self.current_line_width.saturating_add(character_length);
-            self.current_styled.content.push(current_char);
+            if let Some(distance) = self.cursor_distance {
+                if grapheme_length > 0 {
+                    self.cursor_distance = distance
+                        .checked_sub(grapheme_length)
+                        .filter(|_| distance > 0);
+                }
+            }
+
+            self.current_line_width = self.current_line_width.saturating_add(grapheme_length);
+            self.current_styled.content.push_str(grapheme);
         }
+    }
 
-        if !self.current_styled.content.is_empty() {
-            self.current_line
-                .push(std::mem::take(&mut self.current_styled));
+    /// Starts a continuation line with the indentation, unless it would take
+    /// half of the terminal width or more.
+    fn indent_line(&mut self) {
+        if self.indent == 0 || self.indent >= self.terminal_size.width() / 2 {
+            return;
         }
+
+        let spaces = " ".repeat(usize::from(self.indent));
+        for c in spaces.chars() {
+            AnsiAwareChar::Char(c).hash(&mut self.current_line_hasher);
+            StyleSheet::empty().hash(&mut self.current_line_hasher);
+        }
+
+        self.current_line.push(Styled::new(spaces));
+        self.current_line_width = self.indent;
     }
 
     pub fn mark_cursor_position(&mut self, offset: isize) {
+        let offset = offset as u16;
+
+        self.expected_cursor_position = Some(self.position_at(offset));
+        self.cursor_distance = Some(offset);
+    }
+
+    /// Position of the column at the provided distance from the end of the
+    /// current line, assuming no characters wrap early in between.
+    fn position_at(&self, distance: u16) -> Position {
         let row = self.finished_rows.len() as u16;
-        let col = self.current_line_width.saturating_add(offset as u16);
+        let col = self.current_line_width.saturating_add(distance);
 
         let row_offset = col / self.terminal_size.width();
         let row = row + row_offset;
         let col = col % self.terminal_size.width();
 
-        self.expected_cursor_position = Some(Position { row, col });
+        Position { row, col }
     }
 
     pub fn finish(&mut self) {
@@ -124,7 +214,7 @@ impl FrameState {
             return;
         }
 
-        let mut new_state = Self::new(new_size);
+        let mut new_state = Self::new(new_size, self.ambiguous_width);
         for row in &self.finished_rows {
             for styled in row.get_content() {
                 new_state.write(styled);
@@ -135,6 +225,8 @@ impl FrameState {
             new_state.write(styled);
         }
         new_state.finish_line();
+        new_state.scroll = self.scroll;
+        new_state.indent = self.indent;
 
         *self = new_state;
     }
@@ -154,8 +246,11 @@ impl FrameState {
             return;
         }
 
-        self.finished_rows
-            .push(FrameRow::new(content, hasher.finish()));
+        self.finished_rows.push(FrameRow::new(
+            content,
+            hasher.finish(),
+            self.current_line_width,
+        ));
 
         self.frame_size = Dimension::new(
             self.frame_size.width().max(self.current_line_width),
@@ -190,6 +285,16 @@ where
     terminal: T,
     cursor_position: Position,
     state: RenderState,
+    viewport: Option<Viewport>,
+    fixed_height: Option<u16>,
+    fullscreen: bool,
+    /// whether the terminal switched to its alternate screen
+    alternate_screen: bool,
+    render_profile: RenderProfile,
+    color_support: ColorSupport,
+    ambiguous_width: AmbiguousWidth,
+    #[cfg(feature = "rtl")]
+    text_direction: TextDirection,
 }
 
 impl<T> FrameRenderer<T>
@@ -201,9 +306,71 @@ where
             terminal,
             cursor_position: Position::default(),
             state: RenderState::Initial,
+            viewport: None,
+            fixed_height: None,
+            fullscreen: false,
+            alternate_screen: false,
+            render_profile: RenderProfile::Standard,
+            color_support: ColorSupport::TrueColor,
+            ambiguous_width: AmbiguousWidth::Narrow,
+            #[cfg(feature = "rtl")]
+            text_direction: TextDirection::Ltr,
         })
     }
 
+    /// Sets the base direction of the text, used to reorder the rows of the
+    /// following frames when writing them.
+    #[cfg(feature = "rtl")]
+    pub fn set_text_direction(&mut self, text_direction: TextDirection) {
+        self.text_direction = text_direction;
+    }
+
+    /// Sets the width of characters of ambiguous width in the terminal,
+    /// used to lay out the following frames.
+    pub fn set_ambiguous_width(&mut self, ambiguous_width: AmbiguousWidth) {
+        self.ambiguous_width = ambiguous_width;
+    }
+
+    /// Sets the colors supported by the terminal, to which the colors of the
+    /// following writes are downgraded.
+    pub fn set_color_support(&mut self, color_support: ColorSupport) {
+        self.color_support = color_support;
+    }
+
+    /// Sets the trade-offs made when writing frames to the terminal.
+    pub fn set_render_profile(&mut self, render_profile: RenderProfile) {
+        self.render_profile = render_profile;
+        self.terminal
+            .set_batched_output(render_profile == RenderProfile::RemoteOptimized);
+    }
+
+    /// Restricts rendering to the provided region of the terminal screen.
+    ///
+    /// When set, frames are wrapped and clipped to the viewport dimensions
+    /// and drawn with absolute cursor positioning, instead of inline at the
+    /// current cursor position.
+    pub fn set_viewport(&mut self, viewport: Option<Viewport>) {
+        self.viewport = viewport;
+    }
+
+    /// Restricts inline rendering to a region of the provided number of
+    /// rows, starting at the current cursor position.
+    ///
+    /// The whole region is written with the first frame, even if shorter,
+    /// and frames taller than it only display the rows around the cursor.
+    /// Ignored when a viewport is set.
+    pub fn set_fixed_height(&mut self, fixed_height: Option<u16>) {
+        self.fixed_height = fixed_height;
+    }
+
+    /// Renders frames in the alternate screen of the terminal, starting at
+    /// its top-left corner, until the final frame, which is rendered back in
+    /// the main screen where the prompt started. Ignored when a viewport is
+    /// set.
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.fullscreen = fullscreen;
+    }
+
     pub fn write(&mut self, value: impl Display) -> io::Result<()> {
         self.write_styled(Styled::new(value))
     }
@@ -218,7 +385,8 @@ where
                 // we pay a little bit in memory/cpu usage for this so we can
                 // calculate incremental rendering and cursor position on-the-fly.
                 let formatted = format!("{}", value.content);
-                let value = value.with_content(formatted);
+                let mut value = value.with_content(formatted);
+                value.style = self.color_support.adapt(value.style);
 
                 current_frame.write(&value);
             }
@@ -227,6 +395,14 @@ where
         Ok(())
     }
 
+    /// Sets the indentation of lines continuing the text written next, e.g.
+    /// to align the lines of a prompt message with its first one.
+    pub fn set_indent(&mut self, indent: u16) {
+        if let RenderState::ActiveRender { current_frame, .. } = &mut self.state {
+            current_frame.indent = indent;
+        }
+    }
+
     pub fn mark_cursor_position(&mut self, offset: isize) {
         match &mut self.state {
             RenderState::Rendered(_) | RenderState::Initial => {}
@@ -241,13 +417,13 @@ where
 
         self.state = match std::mem::replace(&mut self.state, RenderState::Initial) {
             RenderState::Initial => RenderState::ActiveRender {
-                last_rendered_frame: FrameState::new(terminal_size),
-                current_frame: FrameState::new(terminal_size),
+                last_rendered_frame: FrameState::new(terminal_size, self.ambiguous_width),
+                current_frame: FrameState::new(terminal_size, self.ambiguous_width),
             },
 
             RenderState::Rendered(last_rendered_frame) => RenderState::ActiveRender {
                 last_rendered_frame,
-                current_frame: FrameState::new(terminal_size),
+                current_frame: FrameState::new(terminal_size, self.ambiguous_width),
             },
 
             RenderState::ActiveRender {
@@ -265,8 +441,14 @@ where
     /// Returns the height that would be flushed to the terminal if the current frame
     /// were finished now. This is the max between the last rendered frame and the
     /// current frame, matching `finish_current_frame`'s clearing behavior.
+    ///
+    /// In a fixed-height region, previous frames are always cleared within
+    /// the region, so only the height of the current frame matters.
     pub fn current_flush_height(&self) -> Option<u16> {
         match &self.state {
+            RenderState::ActiveRender { current_frame, .. } if self.is_fixed_height() => {
+                Some(current_frame.frame_size.height())
+            }
             RenderState::ActiveRender {
                 last_rendered_frame,
                 current_frame,
@@ -290,6 +472,28 @@ where
         }
     }
 
+    /// Returns the terminal width associated with the current frame, if any.
+    pub fn current_terminal_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.terminal_size.width())
+            }
+            RenderState::Rendered(frame) => Some(frame.terminal_size.width()),
+            RenderState::Initial => None,
+        }
+    }
+
+    /// Returns the width of the content written so far to the current line
+    /// of the frame being rendered, if any.
+    pub fn current_line_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.current_line_width)
+            }
+            RenderState::Rendered(_) | RenderState::Initial => None,
+        }
+    }
+
     /// Aborts the current frame without writing it to the terminal,
     /// restoring the last rendered frame as the active state.
     ///
@@ -308,7 +512,7 @@ where
     }
 
     pub fn finish_current_frame(&mut self, add_empty_line: bool) -> io::Result<()> {
-        let (last_rendered_frame, mut current_frame) = match std::mem::take(&mut self.state) {
+        let (mut last_rendered_frame, mut current_frame) = match std::mem::take(&mut self.state) {
             RenderState::Rendered(_) | RenderState::Initial => {
                 return Ok(());
             }
@@ -320,41 +524,83 @@ where
 
         current_frame.finish();
 
-        let rows_to_iterate = std::cmp::max(
-            last_rendered_frame.frame_size.height(),
-            current_frame.frame_size.height(),
-        );
+        if let Some(viewport) = self.viewport {
+            return self.finish_frame_in_viewport(viewport, last_rendered_frame, current_frame);
+        }
 
-        self.terminal.cursor_hide()?;
+        if self.fullscreen && !add_empty_line && !self.alternate_screen {
+            self.alternate_screen = self.terminal.enter_alternate_screen()?;
+            if self.alternate_screen {
+                self.cursor_position = Position::default();
+                last_rendered_frame =
+                    FrameState::new(current_frame.terminal_size, self.ambiguous_width);
+            }
+        } else if add_empty_line && self.alternate_screen {
+            // the main screen is left untouched, with the cursor restored to
+            // where the prompt started
+            self.terminal.leave_alternate_screen()?;
+            self.alternate_screen = false;
+            self.cursor_position = Position::default();
+            last_rendered_frame =
+                FrameState::new(current_frame.terminal_size, self.ambiguous_width);
+        }
+
+        if self.is_fixed_height() {
+            current_frame.scroll = region_scroll(&current_frame);
+        }
+
+        let remote_optimized = self.render_profile == RenderProfile::RemoteOptimized;
+
+        if remote_optimized
+            && !add_empty_line
+            && is_same_frame(&last_rendered_frame, &current_frame)
+        {
+            self.state = RenderState::Rendered(current_frame);
+            return Ok(());
+        }
+
+        let rows_to_iterate = if self.is_fixed_height() {
+            current_frame.terminal_size.height()
+        } else {
+            std::cmp::max(
+                last_rendered_frame.frame_size.height(),
+                current_frame.frame_size.height(),
+            )
+        };
+
+        if !remote_optimized {
+            self.terminal.cursor_hide()?;
+        }
         self.move_cursor_to(Position { row: 0, col: 0 })?;
 
         for i in 0..rows_to_iterate {
-            let last_row = last_rendered_frame.finished_rows.get(i as usize);
-            let current_row = current_frame.finished_rows.get(i as usize);
+            let last_row = last_rendered_frame
+                .finished_rows
+                .get(usize::from(i.saturating_add(last_rendered_frame.scroll)));
+            let current_row = current_frame
+                .finished_rows
+                .get(usize::from(i.saturating_add(current_frame.scroll)));
 
             match (last_row, current_row) {
                 (Some(last_row), Some(current_row)) => {
                     if last_row.hash() != current_row.hash() {
-                        for styled in current_row.get_content() {
-                            self.terminal.write_styled(styled)?;
-                        }
-                        self.terminal.clear_until_new_line()?;
+                        self.write_row(current_row, current_frame.terminal_size, true)?;
                     }
                 }
                 (Some(_), None) => {
                     self.terminal.clear_line()?;
                 }
                 (None, Some(current_row)) => {
-                    for styled in current_row.get_content() {
-                        self.terminal.write_styled(styled)?;
-                    }
+                    self.write_row(current_row, current_frame.terminal_size, false)?;
                 }
                 (None, None) => {
-                    // unreachable, but we don't want to panic live :)
+                    // only reachable for the empty rows of a fixed-height
+                    // region, but we don't want to panic live :)
                     #[cfg(test)]
-                    unreachable!(
+                    assert!(
+                        self.is_fixed_height(),
                         "frame_size should never be larger then finished_rows for both frames"
-                    )
+                    );
                 }
             }
 
@@ -372,10 +618,152 @@ where
         }
 
         if let Some(expected_cursor_position) = current_frame.expected_cursor_position {
+            let expected_cursor_position =
+                self.visual_cursor_position(&current_frame, expected_cursor_position);
+            let expected_cursor_position = Position {
+                row: expected_cursor_position
+                    .row
+                    .saturating_sub(current_frame.scroll),
+                ..expected_cursor_position
+            };
             self.move_cursor_to(expected_cursor_position)?;
         }
 
-        self.terminal.cursor_show()?;
+        if !remote_optimized {
+            self.terminal.cursor_show()?;
+        }
+        self.terminal.flush()?;
+
+        self.state = RenderState::Rendered(current_frame);
+
+        Ok(())
+    }
+
+    /// Writes a row at the start of the current line, clearing what remains
+    /// of the previous content of the line if requested.
+    #[cfg_attr(not(feature = "rtl"), allow(unused_variables))]
+    fn write_row(
+        &mut self,
+        row: &FrameRow,
+        terminal_size: TerminalSize,
+        clear: bool,
+    ) -> io::Result<()> {
+        #[cfg(feature = "rtl")]
+        if let Some(visual_row) = self.layout_row(row, terminal_size) {
+            // Right-aligned rows can reach the last column, which would be
+            // erased by clearing after writing them.
+            if clear {
+                self.terminal.clear_until_new_line()?;
+            }
+            for styled in &visual_row.content {
+                self.terminal.write_styled(&styled.as_str())?;
+            }
+            return Ok(());
+        }
+
+        for styled in row.get_content() {
+            self.terminal.write_styled(&styled.as_str())?;
+        }
+        if clear {
+            self.terminal.clear_until_new_line()?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "rtl")]
+    fn layout_row(&self, row: &FrameRow, terminal_size: TerminalSize) -> Option<VisualRow> {
+        VisualRow::layout(
+            row.get_content(),
+            self.text_direction,
+            terminal_size.width(),
+            self.ambiguous_width,
+        )
+    }
+
+    /// Position of the cursor on screen, which differs from its position in
+    /// the frame when its row is reordered.
+    #[cfg(feature = "rtl")]
+    fn visual_cursor_position(&self, frame: &FrameState, position: Position) -> Position {
+        let visual_row = frame
+            .finished_rows
+            .get(usize::from(position.row))
+            .and_then(|row| self.layout_row(row, frame.terminal_size));
+
+        match visual_row {
+            Some(visual_row) => Position {
+                row: position.row,
+                col: visual_row.cursor_col(position.col),
+            },
+            None => position,
+        }
+    }
+
+    #[cfg(not(feature = "rtl"))]
+    #[allow(clippy::unused_self)]
+    fn visual_cursor_position(&self, _frame: &FrameState, position: Position) -> Position {
+        position
+    }
+
+    fn finish_frame_in_viewport(
+        &mut self,
+        viewport: Viewport,
+        last_rendered_frame: FrameState,
+        current_frame: FrameState,
+    ) -> io::Result<()> {
+        // the frame state was built with the (clipped) viewport size,
+        // so any row that does not fit in it is simply not drawn.
+        let visible_rows = current_frame.terminal_size.height();
+        let visible_width = current_frame.terminal_size.width();
+
+        let rows_to_iterate = std::cmp::max(
+            last_rendered_frame.frame_size.height(),
+            current_frame.frame_size.height(),
+        )
+        .min(visible_rows);
+
+        self.terminal.cursor_hide()?;
+
+        for i in 0..rows_to_iterate {
+            let last_row = last_rendered_frame.finished_rows.get(i as usize);
+            let current_row = current_frame.finished_rows.get(i as usize);
+
+            let row_width = match (last_row, current_row) {
+                (Some(last_row), Some(current_row)) if last_row.hash() == current_row.hash() => {
+                    continue;
+                }
+                (_, Some(current_row)) => current_row.width(),
+                (_, None) => 0,
+            };
+
+            self.terminal
+                .cursor_move_to(viewport.col, viewport.row.saturating_add(i))?;
+
+            if let Some(current_row) = current_row {
+                for styled in current_row.get_content() {
+                    self.terminal.write_styled(&styled.as_str())?;
+                }
+            }
+
+            // we can't clear until the end of the line, as that could erase
+            // content the application rendered to the right of the viewport.
+            let padding = visible_width.saturating_sub(row_width) as usize;
+            if padding > 0 {
+                self.terminal.write(&" ".repeat(padding))?;
+            }
+        }
+
+        match current_frame.expected_cursor_position {
+            Some(position) if position.row < visible_rows => {
+                self.terminal.cursor_move_to(
+                    viewport.col.saturating_add(position.col),
+                    viewport.row.saturating_add(position.row),
+                )?;
+                self.terminal.cursor_show()?;
+            }
+            _ => {}
+        }
+
         self.terminal.flush()?;
 
         self.state = RenderState::Rendered(current_frame);
@@ -384,6 +772,12 @@ where
     }
 
     fn move_cursor_to_end_position(&mut self) -> io::Result<()> {
+        if self.viewport.is_some() {
+            // the viewport is owned by the application, which is
+            // responsible for deciding where the cursor goes next.
+            return Ok(());
+        }
+
         self.refresh_terminal_size();
 
         let last_rendered = match &mut self.state {
@@ -395,9 +789,16 @@ where
             | RenderState::Rendered(last_rendered_frame) => last_rendered_frame,
         };
 
+        let mut end_row = last_rendered.frame_size.height();
+        if self.fixed_height.is_some() {
+            end_row = end_row
+                .saturating_sub(last_rendered.scroll)
+                .min(last_rendered.terminal_size.height());
+        }
+
         let end_position = Position {
             col: 0,
-            row: last_rendered.frame_size.height(),
+            row: end_row,
         };
 
         self.move_cursor_to(end_position)?;
@@ -447,6 +848,28 @@ where
             .unwrap_or_default()
             .unwrap_or_default();
 
+        let terminal_size = match self.viewport {
+            Some(viewport) => {
+                let width = viewport
+                    .width
+                    .min(terminal_size.width().saturating_sub(viewport.col));
+                let height = viewport
+                    .height
+                    .min(terminal_size.height().saturating_sub(viewport.row));
+
+                // frames still need a valid size to be built with, even
+                // if the viewport is entirely off-screen.
+                TerminalSize::new(width.max(1), height.max(1)).unwrap_or(terminal_size)
+            }
+            None => match self.fixed_height {
+                Some(height) => {
+                    let height = height.clamp(1, terminal_size.height());
+                    TerminalSize::new(terminal_size.width(), height).unwrap_or(terminal_size)
+                }
+                None => terminal_size,
+            },
+        };
+
         if terminal_size.width() < self.cursor_position.col {
             let new_line_offset = self.cursor_position.col / terminal_size.width();
             let new_col = self.cursor_position.col % terminal_size.width();
@@ -472,6 +895,34 @@ where
 
         terminal_size
     }
+
+    /// Whether frames are rendered inline in a fixed-height region.
+    fn is_fixed_height(&self) -> bool {
+        self.fixed_height.is_some() && self.viewport.is_none()
+    }
+}
+
+/// First row of the frame to display in its fixed-height region, scrolling
+/// just enough to keep the cursor in the region.
+fn region_scroll(frame: &FrameState) -> u16 {
+    let height = frame.terminal_size.height();
+
+    match frame.expected_cursor_position {
+        Some(position) if position.row >= height => position.row + 1 - height,
+        _ => 0,
+    }
+}
+
+/// Whether writing the current frame would leave the terminal unchanged.
+fn is_same_frame(last: &FrameState, current: &FrameState) -> bool {
+    last.terminal_size == current.terminal_size
+        && last.expected_cursor_position == current.expected_cursor_position
+        && last.finished_rows.len() == current.finished_rows.len()
+        && last
+            .finished_rows
+            .iter()
+            .zip(&current.finished_rows)
+            .all(|(last, current)| last.hash() == current.hash())
 }
 
 impl<T> Drop for FrameRenderer<T>
@@ -479,7 +930,11 @@ where
     T: Terminal,
 {
     fn drop(&mut self) {
-        let _unused = self.move_cursor_to_end_position();
+        if self.alternate_screen {
+            let _unused = self.terminal.leave_alternate_screen();
+        } else {
+            let _unused = self.move_cursor_to_end_position();
+        }
         let _unused = self.terminal.cursor_show();
         let _unused = self.terminal.flush();
     }
@@ -619,4 +1074,260 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn viewport_rendering_is_absolute_and_clipped() -> InquireResult<()> {
+        use crate::{terminal::test::MockTerminalToken, ui::Viewport};
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::default());
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_viewport(Some(Viewport::new(5, 10, 6, 2)));
+
+        renderer.start_frame()?;
+        renderer.write("abc\ndefghijk\nxyz\n")?;
+
+        // "defghijk" wraps at the viewport width, and the viewport only
+        // fits the first two of the resulting four rows.
+        assert_eq!(renderer.current_terminal_height(), Some(2));
+        assert_eq!(renderer.current_flush_height(), Some(4));
+
+        renderer.finish_current_frame(true)?;
+
+        let expected = vec![
+            MockTerminalToken::CursorHide,
+            MockTerminalToken::CursorMoveTo(10, 5),
+            "abc".into(),
+            "   ".into(),
+            MockTerminalToken::CursorMoveTo(10, 6),
+            "defghi".into(),
+        ];
+
+        let terminal = &mut renderer.terminal;
+        for token in expected {
+            crate::terminal::test::match_token(terminal.output, token);
+        }
+        assert!(terminal.output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_height_region_is_reserved_and_scrolled_to_the_cursor() -> InquireResult<()> {
+        use crate::terminal::test::MockTerminalToken;
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::default());
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_fixed_height(Some(3));
+
+        // the first frame writes the whole region, even if shorter
+        renderer.start_frame()?;
+        renderer.write("a\n")?;
+        assert_eq!(renderer.current_terminal_height(), Some(3));
+        renderer.finish_current_frame(false)?;
+
+        // the last rows are displayed to keep the cursor in the region
+        renderer.start_frame()?;
+        renderer.write("1\n2\n3\n4\n5")?;
+        renderer.mark_cursor_position(0);
+        renderer.finish_current_frame(false)?;
+
+        let expected = vec![
+            MockTerminalToken::CursorHide,
+            "a".into(),
+            "\r".into(),
+            "\n".into(),
+            "\r".into(),
+            "\n".into(),
+            "\r".into(),
+            MockTerminalToken::CursorShow,
+            MockTerminalToken::CursorHide,
+            MockTerminalToken::CursorUp(2),
+            "3".into(),
+            MockTerminalToken::ClearUntilNewLine,
+            "\r".into(),
+            "\n".into(),
+            "4".into(),
+            "\r".into(),
+            "\n".into(),
+            "5".into(),
+            "\r".into(),
+            MockTerminalToken::CursorRight(1),
+            MockTerminalToken::CursorShow,
+        ];
+
+        let terminal = &mut renderer.terminal;
+        for token in expected {
+            crate::terminal::test::match_token(terminal.output, token);
+        }
+        assert!(terminal.output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fullscreen_frames_are_rendered_in_the_alternate_screen() -> InquireResult<()> {
+        use crate::terminal::test::MockTerminalToken;
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::default());
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_fullscreen(true);
+
+        renderer.start_frame()?;
+        renderer.write("? a\nb\n")?;
+        renderer.finish_current_frame(false)?;
+
+        // the final frame is written from scratch to the main screen
+        renderer.start_frame()?;
+        renderer.write("> a\n")?;
+        renderer.finish_current_frame(true)?;
+
+        let expected = vec![
+            MockTerminalToken::EnterAlternateScreen,
+            MockTerminalToken::CursorHide,
+            "? a".into(),
+            "\r".into(),
+            "\n".into(),
+            "b".into(),
+            "\r".into(),
+            MockTerminalToken::CursorShow,
+            MockTerminalToken::LeaveAlternateScreen,
+            MockTerminalToken::CursorHide,
+            "> a".into(),
+            "\r".into(),
+            "\n".into(),
+            MockTerminalToken::CursorShow,
+        ];
+
+        let terminal = &mut renderer.terminal;
+        for token in expected {
+            crate::terminal::test::match_token(terminal.output, token);
+        }
+        assert!(terminal.output.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn grapheme_clusters_are_measured_as_a_whole() -> InquireResult<()> {
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(10, 10).unwrap());
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write("> \u{1f3c3}\u{200d}\u{2642}\u{fe0f}e\u{301}")?;
+        assert_eq!(Some(5), renderer.current_line_width());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous_width_characters_wrap_according_to_setting() -> InquireResult<()> {
+        use crate::ui::AmbiguousWidth;
+
+        for (ambiguous_width, rows) in [(AmbiguousWidth::Narrow, 1), (AmbiguousWidth::Wide, 2)] {
+            let mut output = VecDeque::new();
+            let terminal =
+                MockTerminal::new(&mut output).with_size(TerminalSize::new(10, 10).unwrap());
+            let mut renderer = FrameRenderer::new(terminal)?;
+            renderer.set_ambiguous_width(ambiguous_width);
+
+            renderer.start_frame()?;
+            // circled digits one to five
+            renderer.write("\u{2460}\u{2461}\u{2462}\u{2463}\u{2464}x\n")?;
+            assert_eq!(Some(rows), renderer.current_flush_height());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_position_accounts_for_wide_characters_wrapped_early() -> InquireResult<()> {
+        use super::{Position, RenderState};
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(10, 10).unwrap());
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write("abcdefghi")?;
+        renderer.mark_cursor_position(2);
+        // The wide character doesn't fit in the last column, so it is
+        // moved to the next row along with the cursor after it.
+        renderer.write("\u{4e2d}x")?;
+
+        let expected_position = match &renderer.state {
+            RenderState::ActiveRender { current_frame, .. } => {
+                current_frame.expected_cursor_position
+            }
+            _ => None,
+        };
+        assert_eq!(Some(Position { row: 1, col: 2 }), expected_position);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_optimized_profile_skips_cursor_toggles_and_unchanged_frames() -> InquireResult<()> {
+        use crate::{terminal::test::MockTerminalToken, ui::RenderProfile};
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::default());
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_render_profile(RenderProfile::RemoteOptimized);
+
+        renderer.start_frame()?;
+        renderer.write("Hello")?;
+        renderer.finish_current_frame(false)?;
+
+        let terminal = &mut renderer.terminal;
+        assert!(!terminal.output.is_empty());
+        assert!(!terminal.output.iter().any(|token| matches!(
+            token,
+            MockTerminalToken::CursorHide | MockTerminalToken::CursorShow
+        )));
+        terminal.output.clear();
+
+        renderer.start_frame()?;
+        renderer.write("Hello")?;
+        renderer.finish_current_frame(false)?;
+        assert!(renderer.terminal.output.is_empty());
+
+        renderer.start_frame()?;
+        renderer.write("World")?;
+        renderer.finish_current_frame(false)?;
+        renderer.terminal.match_text("World");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rtl")]
+    fn rtl_rows_are_written_in_visual_order_with_mapped_cursor() -> InquireResult<()> {
+        use crate::ui::{Position, TextDirection};
+
+        let mut output = VecDeque::new();
+        let terminal = MockTerminal::new(&mut output).with_size(TerminalSize::new(10, 5).unwrap());
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_text_direction(TextDirection::Auto);
+
+        renderer.start_frame()?;
+        renderer.write("\u{5e9}\u{5dd}: ")?;
+        renderer.mark_cursor_position(0);
+        renderer.write("\nHelp")?;
+        renderer.finish_current_frame(false)?;
+
+        let terminal = &mut renderer.terminal;
+        terminal.match_text("       :\u{5dd}\u{5e9}");
+        terminal.match_text("\r");
+        terminal.match_text("\n");
+        terminal.match_text("Help");
+
+        // The cursor is placed to the left of the trailing space.
+        assert_eq!(Position { row: 0, col: 5 }, renderer.cursor_position);
+
+        Ok(())
+    }
 }
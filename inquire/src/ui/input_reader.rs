@@ -1,7 +1,44 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
-pub trait InputReader: Sized {
+/// Answer submitted to a prompt without user interaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PresetAnswer {
+    /// The answer the prompt submits when the user presses enter right away,
+    /// e.g. the default value of a [Text](crate::Text) prompt or the option
+    /// under the starting cursor of a [Select](crate::Select) prompt.
+    Default,
+
+    /// An answer given as text, filled in the prompt as if typed by the user.
+    Text(String),
+}
+
+/// Source of key events consumed by prompts.
+pub trait InputReader {
+    /// Blocks until the next key event is available and returns it.
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Waits up to `timeout` for the next key event, returning `None` if
+    /// no key was pressed in the meantime.
+    ///
+    /// The default implementation ignores the timeout and blocks until a
+    /// key is available. Readers that can wait on their input source with
+    /// a timeout should override it.
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let _ = timeout;
+        self.read_key().map(Some)
+    }
+
+    /// Returns the answer to submit to the next prompt without reading any
+    /// key event, if the reader has one. In that case, no UI is displayed
+    /// besides the final answer.
+    ///
+    /// The default implementation returns `None`, which lets the user answer
+    /// the prompt interactively.
+    fn read_preset_answer(&mut self) -> InquireResult<Option<PresetAnswer>> {
+        Ok(None)
+    }
 }
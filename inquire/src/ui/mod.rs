@@ -2,11 +2,16 @@
 
 mod api;
 mod backend;
+#[cfg(feature = "rtl")]
+mod bidi;
 pub(crate) mod dimension;
 mod frame_renderer;
 mod input_reader;
 
 pub(crate) use backend::*;
-pub(crate) use input_reader::*;
+pub use backend::{default_backend, CommonBackend, DefaultBackend};
+pub use input_reader::{InputReader, PresetAnswer};
+
+pub use crate::terminal::{CustomBackend, Terminal, TerminalSize};
 
 pub use api::*;
@@ -1,6 +1,14 @@
 // sorry for this file
 
-use std::fmt::Debug;
+use std::{cmp::Ordering, fmt::Debug, iter::Peekable, str::Chars};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    ansi::{AnsiAware, AnsiAwareChar},
+    ui::{AmbiguousWidth, Icon},
+};
 
 pub struct Page<'a, T> {
     /// Whether this is the first page.
@@ -17,6 +25,14 @@ pub struct Page<'a, T> {
 
     /// Total amount of elements in the original list of choices.
     pub total: usize,
+
+    /// Custom prefixes of the elements in the page, rendered in a dedicated
+    /// column before each element. Empty when no custom prefixes are used.
+    pub prefixes: Vec<String>,
+
+    /// Icons of the elements in the page, rendered in a dedicated column
+    /// before the custom prefixes. Empty when no icons are used.
+    pub icons: Vec<Icon>,
 }
 
 pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
@@ -65,6 +81,90 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
         content: choices.get(start..end).unwrap(),
         cursor,
         total: choices.len(),
+        prefixes: vec![],
+        icons: vec![],
+    }
+}
+
+/// Paginates choices laid out in a grid of `columns` columns, where
+/// `page_size` is the number of rows in a page.
+///
+/// Pages always contain whole rows, so the content of the returned page can be
+/// split into chunks of `columns` elements to get the rows to render.
+pub fn paginate_grid<T>(
+    columns: usize,
+    page_size: usize,
+    choices: &[T],
+    sel: Option<usize>,
+) -> Page<'_, T> {
+    let columns = columns.max(1);
+    let rows = (0..choices.len().div_ceil(columns)).collect::<Vec<usize>>();
+
+    let row_page = paginate(page_size, &rows, sel.map(|index| index / columns));
+
+    let start = row_page
+        .content
+        .first()
+        .map(|row| row * columns)
+        .unwrap_or(0);
+    let end = row_page
+        .content
+        .last()
+        .map(|row| (row + 1) * columns)
+        .unwrap_or(0)
+        .min(choices.len());
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: choices.get(start..end).unwrap(),
+        cursor: sel.map(|index| index - start),
+        total: choices.len(),
+        prefixes: vec![],
+        icons: vec![],
+    }
+}
+
+/// Compares two strings in natural order, where runs of ASCII digits are
+/// compared by their numeric value, e.g. "item2" < "item10".
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    fn take_number(chars: &mut Peekable<Chars<'_>>) -> String {
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+            number.push(c);
+        }
+        number
+    }
+
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        let ordering = match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x_number = take_number(&mut a_chars);
+                let y_number = take_number(&mut b_chars);
+                let x_digits = x_number.trim_start_matches('0');
+                let y_digits = y_number.trim_start_matches('0');
+
+                x_digits
+                    .len()
+                    .cmp(&y_digits.len())
+                    .then_with(|| x_digits.cmp(y_digits))
+            }
+            (Some(x), Some(y)) => {
+                a_chars.next();
+                b_chars.next();
+                x.cmp(&y)
+            }
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
     }
 }
 
@@ -84,6 +184,50 @@ where
     len
 }
 
+/// Number of terminal columns taken by a grapheme cluster, such as an emoji
+/// ZWJ sequence or a letter followed by combining marks. Control characters
+/// are not printed, so they take no columns.
+pub fn grapheme_width(grapheme: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    if grapheme.chars().all(char::is_control) {
+        return 0;
+    }
+
+    match ambiguous_width {
+        AmbiguousWidth::Narrow => grapheme.width(),
+        AmbiguousWidth::Wide => grapheme.width_cjk(),
+    }
+}
+
+/// Number of terminal columns taken by a text, measured per grapheme cluster
+/// in the same way as the text is laid out when rendered. ANSI escape
+/// sequences take no columns.
+pub fn text_width(text: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    let plain_width = |text: &str| -> usize {
+        text.graphemes(true)
+            .map(|grapheme| grapheme_width(grapheme, ambiguous_width))
+            .sum()
+    };
+
+    if !text.contains('\x1b') {
+        return plain_width(text);
+    }
+
+    let mut width = 0;
+    let mut plain = String::new();
+
+    for piece in text.ansi_aware_chars() {
+        match piece {
+            AnsiAwareChar::Char(c) => plain.push(c),
+            AnsiAwareChar::AnsiEscapeSequence(_) => {
+                width += plain_width(&plain);
+                plain.clear();
+            }
+        }
+    }
+
+    width + plain_width(&plain)
+}
+
 impl<'a, T> Debug for Page<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Page")
@@ -102,7 +246,8 @@ mod test {
 
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        ui::AmbiguousWidth,
+        utils::{int_log10, natural_cmp, paginate, paginate_grid, text_width},
     };
 
     impl<T> ListOption<T> {
@@ -133,6 +278,17 @@ mod test {
         }
     }
 
+    #[test]
+    fn natural_cmp_compares_numbers_by_value() {
+        use std::cmp::Ordering;
+
+        assert_eq!(Ordering::Less, natural_cmp("item2", "item10"));
+        assert_eq!(Ordering::Greater, natural_cmp("item10", "item9"));
+        assert_eq!(Ordering::Equal, natural_cmp("v007", "v7"));
+        assert_eq!(Ordering::Less, natural_cmp("a", "b1"));
+        assert_eq!(Ordering::Less, natural_cmp("file", "file1"));
+    }
+
     #[test]
     fn paginate_too_few() {
         let choices = ListOption::from_list(vec!["1", "2", "3"]);
@@ -260,4 +416,75 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn paginate_grid_keeps_whole_rows() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6", "7", "8"]);
+
+        let columns = 3usize;
+        let page_size = 2usize;
+        let sel = Some(7usize);
+
+        let page = paginate_grid(columns, page_size, &choices, sel);
+
+        assert_eq!(choices.get(3..8).unwrap(), page.content);
+        assert_eq!(Some(4usize), page.cursor);
+        assert_eq!(false, page.first);
+        assert_eq!(true, page.last);
+        assert_eq!(8, page.total);
+    }
+
+    #[test]
+    fn paginate_grid_first_rows_no_cursor() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6", "7", "8"]);
+
+        let columns = 3usize;
+        let page_size = 2usize;
+        let sel = None;
+
+        let page = paginate_grid(columns, page_size, &choices, sel);
+
+        assert_eq!(choices.get(0..6).unwrap(), page.content);
+        assert_eq!(None, page.cursor);
+        assert_eq!(true, page.first);
+        assert_eq!(false, page.last);
+        assert_eq!(8, page.total);
+    }
+
+    #[test]
+    fn text_width_counts_grapheme_clusters_once() {
+        let width = |text| text_width(text, AmbiguousWidth::Narrow);
+
+        // man running: ZWJ sequence with a variation selector
+        assert_eq!(2, width("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}"));
+        // family: several emoji joined by ZWJs
+        assert_eq!(2, width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"));
+        // flag of Brazil: pair of regional indicators
+        assert_eq!(2, width("\u{1f1e7}\u{1f1f7}"));
+        // e with a combining acute accent
+        assert_eq!(1, width("e\u{301}"));
+        assert_eq!(3, width("a\tb\r\nc"));
+    }
+
+    #[test]
+    fn text_width_of_wide_and_ambiguous_characters() {
+        // ideographs and hangul are always wide
+        assert_eq!(
+            8,
+            text_width("\u{4e2d}\u{6587}\u{d55c}\u{ad6d}", AmbiguousWidth::Narrow)
+        );
+        assert_eq!(
+            8,
+            text_width("\u{4e2d}\u{6587}\u{d55c}\u{ad6d}", AmbiguousWidth::Wide)
+        );
+
+        // plus-minus sign and circled digit one
+        assert_eq!(3, text_width("\u{b1}1\u{2460}", AmbiguousWidth::Narrow));
+        assert_eq!(5, text_width("\u{b1}1\u{2460}", AmbiguousWidth::Wide));
+
+        assert_eq!(
+            4,
+            text_width("\x1b[1;31m\u{4e2d}\x1b[0m!!", AmbiguousWidth::Narrow)
+        );
+    }
 }